@@ -0,0 +1,586 @@
+//! Command line access to the `tfhe` high level API: key generation, encryption into compact or
+//! compressed lists, decryption of lists and simple homomorphic operations.
+//!
+//! All files are read and written with the `tfhe` safe (versioned) serialization.
+
+mod params;
+mod values;
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use tfhe::named::Named;
+use tfhe::prelude::*;
+use tfhe::safe_deserialization::{read_serialization_header, safe_deserialize_versioned};
+use tfhe::{
+    safe_serialize_versioned, set_server_key, ClientKey, CompactCiphertextList,
+    CompactCiphertextListBuilder, CompactCiphertextListExpander, CompactPublicKey,
+    CompressedCiphertextList, CompressedCiphertextListBuilder, CompressedServerKey, Config,
+    FheTypes, ServerKey,
+};
+use tfhe_versionable::{Unversionize, Versionize};
+
+use values::{BinaryOp, ClearValue, CliCiphertext, TypeVisitor};
+
+/// Upper bound on the size of the objects read and written, large enough for server keys.
+const SERIALIZED_SIZE_LIMIT: u64 = 1 << 36;
+
+const CLIENT_KEY_FILE: &str = "client_key.bin";
+const SERVER_KEY_FILE: &str = "server_key.bin";
+const PUBLIC_KEY_FILE: &str = "public_key.bin";
+
+#[derive(Parser)]
+#[command(name = "tfhe-cli", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Lists the parameter sets accepted by `keygen --params` and the supported types
+    ListParams,
+    /// Generates a client key, a compressed server key and a compact public key
+    Keygen {
+        /// Name of the parameter set to use
+        #[arg(long, default_value = "PARAM_MESSAGE_2_CARRY_2_KS_PBS")]
+        params: String,
+        /// JSON file holding a serialized `Config`, used instead of `--params`
+        #[arg(long, conflicts_with = "params")]
+        config: Option<PathBuf>,
+        /// Tag to attach to the keys and to everything derived from them
+        #[arg(long)]
+        tag: Option<u64>,
+        /// Directory where the keys are written
+        #[arg(long, short, default_value = ".")]
+        output_dir: PathBuf,
+    },
+    /// Encrypts `type:value` pairs (e.g. `uint8:42`, `int16:-3`, `bool:true`) into a list
+    Encrypt {
+        #[arg(long, default_value = PUBLIC_KEY_FILE)]
+        public_key: PathBuf,
+        /// Produce a `CompressedCiphertextList` instead of a `CompactCiphertextList`, this
+        /// requires the server key
+        #[arg(long)]
+        compressed: bool,
+        /// Server key used to build compressed lists, only accepted with `--compressed`
+        #[arg(long, requires = "compressed", default_value = SERVER_KEY_FILE)]
+        server_key: PathBuf,
+        #[arg(long, short)]
+        output: PathBuf,
+        #[arg(required = true)]
+        values: Vec<String>,
+    },
+    /// Decrypts a compact or compressed list and prints its content as `type:value` lines
+    Decrypt {
+        #[arg(long, default_value = CLIENT_KEY_FILE)]
+        client_key: PathBuf,
+        /// Needed for compressed lists and for compact lists that must be cast
+        #[arg(long)]
+        server_key: Option<PathBuf>,
+        input: PathBuf,
+    },
+    /// Computes `lhs op rhs` on two elements of a list and writes the result as a compressed
+    /// list, this requires keys generated with compression parameters
+    Op {
+        #[arg(long, default_value = SERVER_KEY_FILE)]
+        server_key: PathBuf,
+        #[arg(long, short)]
+        input: PathBuf,
+        #[arg(value_enum)]
+        op: BinaryOp,
+        /// Index of the left operand in the input list
+        lhs: usize,
+        /// Index of the right operand in the input list
+        rhs: usize,
+        #[arg(long, short)]
+        output: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::ListParams => {
+            list_params();
+            Ok(())
+        }
+        Command::Keygen {
+            params,
+            config,
+            tag,
+            output_dir,
+        } => keygen(&params, config.as_deref(), tag, &output_dir),
+        Command::Encrypt {
+            public_key,
+            compressed,
+            server_key,
+            output,
+            values,
+        } => encrypt(
+            &public_key,
+            compressed.then_some(server_key.as_path()),
+            &output,
+            &values,
+        ),
+        Command::Decrypt {
+            client_key,
+            server_key,
+            input,
+        } => decrypt(&client_key, server_key.as_deref(), &input),
+        Command::Op {
+            server_key,
+            input,
+            op,
+            lhs,
+            rhs,
+            output,
+        } => apply_op(&server_key, &input, op, lhs, rhs, &output),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn read_file<T: Unversionize + Named>(path: &Path) -> Result<T, String> {
+    let file = File::open(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    safe_deserialize_versioned(BufReader::new(file), SERIALIZED_SIZE_LIMIT)
+        .map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn write_file<T: Versionize + Named>(object: &T, path: &Path) -> Result<(), String> {
+    let file = File::create(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    let mut writer = BufWriter::new(file);
+    safe_serialize_versioned(object, &mut writer, SERIALIZED_SIZE_LIMIT)
+        .map_err(|err| format!("{}: {err}", path.display()))?;
+    writer
+        .flush()
+        .map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn read_server_key(path: &Path) -> Result<ServerKey, String> {
+    read_file::<CompressedServerKey>(path).map(|key| key.decompress())
+}
+
+/// Reads a server key that must be able to build compressed lists.
+///
+/// Fails early with an explicit error if the keys were generated from a parameter set without
+/// compression parameters, instead of failing once the operations have been computed.
+fn read_server_key_with_compression(path: &Path) -> Result<ServerKey, String> {
+    let (integer_key, cpk_ksk, compression_key, decompression_key, tag) =
+        read_file::<CompressedServerKey>(path)?.into_raw_parts();
+    if compression_key.is_none() || decompression_key.is_none() {
+        return Err(format!(
+            "{}: the server key has no compression keys, generate the keys with a parameter set \
+            that supports compression (see `tfhe-cli list-params`)",
+            path.display()
+        ));
+    }
+
+    Ok(CompressedServerKey::from_raw_parts(
+        integer_key,
+        cpk_ksk,
+        compression_key,
+        decompression_key,
+        tag,
+    )
+    .decompress())
+}
+
+fn list_params() {
+    println!("Parameter sets:");
+    for params in params::KNOWN_PARAMETERS {
+        let compression = if params.compression_parameters.is_some() {
+            " (with compression)"
+        } else {
+            ""
+        };
+        println!("  {}{compression}", params.name);
+    }
+
+    println!("Types:");
+    println!("  {}", values::type_names().collect::<Vec<_>>().join(", "));
+}
+
+fn keygen(
+    params: &str,
+    config: Option<&Path>,
+    tag: Option<u64>,
+    output_dir: &Path,
+) -> Result<(), String> {
+    let config: Config = match config {
+        Some(path) => {
+            let file = File::open(path).map_err(|err| format!("{}: {err}", path.display()))?;
+            serde_json::from_reader(BufReader::new(file))
+                .map_err(|err| format!("{}: {err}", path.display()))?
+        }
+        None => params::config_from_name(params)?,
+    };
+
+    let mut client_key = ClientKey::generate(config);
+    if let Some(tag) = tag {
+        client_key.tag_mut().set_u64(tag);
+    }
+    let server_key = CompressedServerKey::new(&client_key);
+    let public_key = CompactPublicKey::try_new(&client_key).map_err(|err| err.to_string())?;
+
+    std::fs::create_dir_all(output_dir)
+        .map_err(|err| format!("{}: {err}", output_dir.display()))?;
+    write_file(&client_key, &output_dir.join(CLIENT_KEY_FILE))?;
+    write_file(&server_key, &output_dir.join(SERVER_KEY_FILE))?;
+    write_file(&public_key, &output_dir.join(PUBLIC_KEY_FILE))?;
+
+    Ok(())
+}
+
+struct PushCompact<'a> {
+    value: ClearValue,
+    builder: &'a mut CompactCiphertextListBuilder,
+}
+
+impl TypeVisitor for PushCompact<'_> {
+    type Output = Result<(), String>;
+
+    fn visit<T: CliCiphertext>(self) -> Self::Output {
+        T::push_compact(self.value, self.builder)
+    }
+}
+
+/// The server key is only given when a compressed list is requested.
+fn encrypt(
+    public_key: &Path,
+    server_key: Option<&Path>,
+    output: &Path,
+    values: &[String],
+) -> Result<(), String> {
+    let public_key: CompactPublicKey = read_file(public_key)?;
+
+    let mut builder = CompactCiphertextList::builder(&public_key);
+    for value in values {
+        let (kind, value) = values::parse_typed_value(value)?;
+        values::visit_type(
+            kind,
+            PushCompact {
+                value,
+                builder: &mut builder,
+            },
+        )?;
+    }
+    let compact_list = builder.build();
+
+    let Some(server_key) = server_key else {
+        return write_file(&compact_list, output);
+    };
+
+    let server_key = read_server_key_with_compression(server_key)?;
+    let list = InputList::Compact(
+        compact_list
+            .expand_with_key(&server_key)
+            .map_err(|err| err.to_string())?,
+    );
+    set_server_key(server_key);
+
+    let mut builder = CompressedCiphertextListBuilder::new();
+    for index in 0..list.len() {
+        let kind = list.get_kind_of(index)?;
+        values::visit_type(
+            kind,
+            CopyElement {
+                list: &list,
+                index,
+                builder: &mut builder,
+            },
+        )?;
+    }
+    let compressed_list = builder.build().map_err(|err| err.to_string())?;
+
+    write_file(&compressed_list, output)
+}
+
+/// A list read from a file, either kind can be used as the input of `decrypt` and `op`.
+enum InputList {
+    Compact(CompactCiphertextListExpander),
+    Compressed(CompressedCiphertextList),
+}
+
+impl InputList {
+    /// Reads a compact or compressed list.
+    ///
+    /// The server key is used to expand compact lists, compressed lists need it to be set as the
+    /// global server key before accessing their elements.
+    fn read(path: &Path, server_key: Option<&ServerKey>) -> Result<Self, String> {
+        let mut file = File::open(path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let header = read_serialization_header(&mut file)
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        file.rewind()
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+
+        if header.type_name == CompactCiphertextList::NAME {
+            let list: CompactCiphertextList = read_file(path)?;
+            let expander = match server_key {
+                Some(server_key) => list.expand_with_key(server_key),
+                None => list.expand(),
+            };
+            expander
+                .map(Self::Compact)
+                .map_err(|err| format!("{}: {err}", path.display()))
+        } else if header.type_name == CompressedCiphertextList::NAME {
+            read_file(path).map(Self::Compressed)
+        } else {
+            Err(format!(
+                "{}: expected a ciphertext list, found a {}",
+                path.display(),
+                header.type_name
+            ))
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Compact(expander) => expander.len(),
+            Self::Compressed(list) => list.len(),
+        }
+    }
+
+    fn get_kind_of(&self, index: usize) -> Result<FheTypes, String> {
+        match self {
+            Self::Compact(expander) => expander.get_kind_of(index),
+            Self::Compressed(list) => list.get_kind_of(index),
+        }
+        .ok_or_else(|| format!("No ciphertext of a known type at index {index}"))
+    }
+
+    fn get<T: CliCiphertext>(&self, index: usize) -> Result<T, String> {
+        match self {
+            Self::Compact(expander) => T::get_compact(expander, index),
+            Self::Compressed(list) => T::get_compressed(list, index),
+        }
+    }
+}
+
+struct CopyElement<'a> {
+    list: &'a InputList,
+    index: usize,
+    builder: &'a mut CompressedCiphertextListBuilder,
+}
+
+impl TypeVisitor for CopyElement<'_> {
+    type Output = Result<(), String>;
+
+    fn visit<T: CliCiphertext>(self) -> Self::Output {
+        self.list
+            .get::<T>(self.index)?
+            .push_compressed(self.builder);
+        Ok(())
+    }
+}
+
+struct DecryptElement<'a> {
+    list: &'a InputList,
+    index: usize,
+    client_key: &'a ClientKey,
+}
+
+impl TypeVisitor for DecryptElement<'_> {
+    type Output = Result<String, String>;
+
+    fn visit<T: CliCiphertext>(self) -> Self::Output {
+        let ct = self.list.get::<T>(self.index)?;
+        Ok(format!(
+            "{}:{}",
+            T::NAME,
+            ct.decrypt_to_string(self.client_key)
+        ))
+    }
+}
+
+fn decrypt(client_key: &Path, server_key: Option<&Path>, input: &Path) -> Result<(), String> {
+    for line in decrypt_to_lines(client_key, server_key, input)? {
+        println!("{line}");
+    }
+
+    Ok(())
+}
+
+/// Decrypts every element of the list to a `type:value` line.
+fn decrypt_to_lines(
+    client_key: &Path,
+    server_key: Option<&Path>,
+    input: &Path,
+) -> Result<Vec<String>, String> {
+    let client_key: ClientKey = read_file(client_key)?;
+    let server_key = server_key.map(read_server_key).transpose()?;
+
+    let list = InputList::read(input, server_key.as_ref())?;
+    if let Some(server_key) = server_key {
+        set_server_key(server_key);
+    }
+
+    (0..list.len())
+        .map(|index| {
+            let kind = list.get_kind_of(index)?;
+            values::visit_type(
+                kind,
+                DecryptElement {
+                    list: &list,
+                    index,
+                    client_key: &client_key,
+                },
+            )
+        })
+        .collect()
+}
+
+struct ApplyOp<'a> {
+    list: &'a InputList,
+    op: BinaryOp,
+    lhs: usize,
+    rhs: usize,
+    builder: &'a mut CompressedCiphertextListBuilder,
+}
+
+impl TypeVisitor for ApplyOp<'_> {
+    type Output = Result<(), String>;
+
+    fn visit<T: CliCiphertext>(self) -> Self::Output {
+        let lhs = self.list.get::<T>(self.lhs)?;
+        let rhs = self.list.get::<T>(self.rhs)?;
+        T::apply(self.op, &lhs, &rhs, self.builder)
+    }
+}
+
+fn apply_op(
+    server_key: &Path,
+    input: &Path,
+    op: BinaryOp,
+    lhs: usize,
+    rhs: usize,
+    output: &Path,
+) -> Result<(), String> {
+    let server_key = read_server_key_with_compression(server_key)?;
+    let list = InputList::read(input, Some(&server_key))?;
+    set_server_key(server_key);
+
+    let lhs_kind = list.get_kind_of(lhs)?;
+    let rhs_kind = list.get_kind_of(rhs)?;
+    if lhs_kind != rhs_kind {
+        return Err(format!(
+            "Operands must have the same type, got {} and {}",
+            values::type_name(lhs_kind),
+            values::type_name(rhs_kind)
+        ));
+    }
+
+    let mut builder = CompressedCiphertextListBuilder::new();
+    values::visit_type(
+        lhs_kind,
+        ApplyOp {
+            list: &list,
+            op,
+            lhs,
+            rhs,
+            builder: &mut builder,
+        },
+    )?;
+    let result = builder.build().map_err(|err| err.to_string())?;
+
+    write_file(&result, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory for the files written by a test.
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tfhe-cli-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn keygen_encrypt_op_decrypt_round_trip() {
+        let dir = test_dir("round-trip");
+        let client_key = dir.join(CLIENT_KEY_FILE);
+        let server_key = dir.join(SERVER_KEY_FILE);
+        let public_key = dir.join(PUBLIC_KEY_FILE);
+        let compact = dir.join("compact.bin");
+        let compressed = dir.join("compressed.bin");
+        let sum = dir.join("sum.bin");
+        let lt = dir.join("lt.bin");
+
+        keygen("PARAM_MESSAGE_2_CARRY_2_KS_PBS", None, Some(7), &dir).unwrap();
+
+        let values = ["uint8:200".to_owned(), "uint8:100".to_owned()];
+        encrypt(&public_key, None, &compact, &values).unwrap();
+        encrypt(&public_key, Some(&server_key), &compressed, &values).unwrap();
+
+        assert_eq!(
+            decrypt_to_lines(&client_key, None, &compact).unwrap(),
+            ["uint8:200", "uint8:100"]
+        );
+        assert_eq!(
+            decrypt_to_lines(&client_key, Some(&server_key), &compressed).unwrap(),
+            ["uint8:200", "uint8:100"]
+        );
+
+        apply_op(&server_key, &compact, BinaryOp::Add, 0, 1, &sum).unwrap();
+        assert_eq!(
+            decrypt_to_lines(&client_key, Some(&server_key), &sum).unwrap(),
+            ["uint8:44"]
+        );
+
+        apply_op(&server_key, &compressed, BinaryOp::Lt, 1, 0, &lt).unwrap();
+        assert_eq!(
+            decrypt_to_lines(&client_key, Some(&server_key), &lt).unwrap(),
+            ["bool:true"]
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn op_requires_compression_parameters() {
+        let dir = test_dir("no-compression");
+        let server_key = dir.join(SERVER_KEY_FILE);
+        let public_key = dir.join(PUBLIC_KEY_FILE);
+        let compact = dir.join("compact.bin");
+
+        keygen("PARAM_MESSAGE_1_CARRY_1_KS_PBS", None, None, &dir).unwrap();
+
+        let values = ["bool:true".to_owned(), "bool:false".to_owned()];
+        encrypt(&public_key, None, &compact, &values).unwrap();
+
+        let err = apply_op(
+            &server_key,
+            &compact,
+            BinaryOp::BitAnd,
+            0,
+            1,
+            &dir.join("and.bin"),
+        )
+        .unwrap_err();
+        assert!(err.contains("no compression keys"), "{err}");
+
+        let err = encrypt(
+            &public_key,
+            Some(&server_key),
+            &dir.join("compressed.bin"),
+            &values,
+        )
+        .unwrap_err();
+        assert!(err.contains("no compression keys"), "{err}");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
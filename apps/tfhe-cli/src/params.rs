@@ -0,0 +1,53 @@
+use tfhe::shortint::parameters::*;
+use tfhe::{Config, ConfigBuilder};
+
+/// A parameter set that can be selected by name on the command line.
+pub struct NamedParameters {
+    pub name: &'static str,
+    pub block_parameters: ClassicPBSParameters,
+    pub compression_parameters: Option<CompressionParameters>,
+}
+
+macro_rules! named_parameters {
+    ($($block_params:ident $(=> $comp_params:ident)?),* $(,)?) => {
+        &[
+            $(
+                NamedParameters {
+                    name: stringify!($block_params),
+                    block_parameters: $block_params,
+                    compression_parameters: named_parameters!(@comp $($comp_params)?),
+                },
+            )*
+        ]
+    };
+    (@comp $comp_params:ident) => { Some($comp_params) };
+    (@comp) => { None };
+}
+
+pub const KNOWN_PARAMETERS: &[NamedParameters] = named_parameters!(
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS => COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64 => COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64 => COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
+);
+
+/// Builds a [Config] from one of the [KNOWN_PARAMETERS], enabling compression when the
+/// parameter set has matching compression parameters.
+pub fn config_from_name(name: &str) -> Result<Config, String> {
+    let params = KNOWN_PARAMETERS
+        .iter()
+        .find(|params| params.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| {
+            format!("Unknown parameter set '{name}', use `tfhe-cli list-params` to list them")
+        })?;
+
+    let mut builder = ConfigBuilder::with_custom_parameters(params.block_parameters);
+    if let Some(compression_parameters) = params.compression_parameters {
+        builder = builder.enable_compression(compression_parameters);
+    }
+
+    Ok(builder.build())
+}
@@ -0,0 +1,473 @@
+use std::fmt::Write;
+
+use tfhe::integer::bigint::U2048;
+use tfhe::integer::I256;
+use tfhe::prelude::*;
+use tfhe::{
+    ClientKey, CompactCiphertextListBuilder, CompactCiphertextListExpander,
+    CompressedCiphertextList, CompressedCiphertextListBuilder, FheBool, FheInt10, FheInt12,
+    FheInt128, FheInt14, FheInt16, FheInt160, FheInt2, FheInt256, FheInt32, FheInt4, FheInt6,
+    FheInt64, FheInt8, FheTypes, FheUint10, FheUint1024, FheUint12, FheUint128, FheUint14,
+    FheUint16, FheUint160, FheUint2, FheUint2048, FheUint256, FheUint32, FheUint4, FheUint512,
+    FheUint6, FheUint64, FheUint8,
+};
+
+/// A clear value given on the command line.
+///
+/// Values of the wider types can be encrypted, but only from the range of `u128`/`i128`.
+#[derive(Copy, Clone, Debug)]
+pub enum ClearValue {
+    Bool(bool),
+    Unsigned(u128),
+    Signed(i128),
+}
+
+/// The binary operations that can be run with the `op` command.
+#[derive(Copy, Clone, Debug, clap::ValueEnum)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// What the CLI needs to know about each of the HL ciphertext types.
+pub trait CliCiphertext: Sized {
+    /// Name used on the command line and in the output of `decrypt`.
+    const NAME: &'static str;
+    const NUM_BITS: u32;
+
+    fn parse_clear(value: &str) -> Result<ClearValue, String>;
+
+    fn push_compact(
+        value: ClearValue,
+        builder: &mut CompactCiphertextListBuilder,
+    ) -> Result<(), String>;
+
+    fn push_compressed(self, builder: &mut CompressedCiphertextListBuilder);
+
+    fn get_compact(expander: &CompactCiphertextListExpander, index: usize) -> Result<Self, String>;
+
+    fn get_compressed(list: &CompressedCiphertextList, index: usize) -> Result<Self, String>;
+
+    fn decrypt_to_string(&self, client_key: &ClientKey) -> String;
+
+    /// Computes `op` and pushes the result, which is an [FheBool] for comparisons.
+    fn apply(
+        op: BinaryOp,
+        lhs: &Self,
+        rhs: &Self,
+        builder: &mut CompressedCiphertextListBuilder,
+    ) -> Result<(), String>;
+}
+
+/// Generic operation over the ciphertext type matching a [FheTypes].
+pub trait TypeVisitor {
+    type Output;
+
+    fn visit<T: CliCiphertext>(self) -> Self::Output;
+}
+
+macro_rules! fhe_types {
+    ($($variant:ident => $fhe_type:ident),* $(,)?) => {
+        const ALL_TYPES: &[FheTypes] = &[$(FheTypes::$variant),*];
+
+        /// Calls the visitor with the ciphertext type of the given kind.
+        pub fn visit_type<V: TypeVisitor>(kind: FheTypes, visitor: V) -> V::Output {
+            match kind {
+                $(FheTypes::$variant => visitor.visit::<$fhe_type>(),)*
+            }
+        }
+    };
+}
+
+fhe_types!(
+    Bool => FheBool,
+    Uint2 => FheUint2,
+    Uint4 => FheUint4,
+    Uint6 => FheUint6,
+    Uint8 => FheUint8,
+    Uint10 => FheUint10,
+    Uint12 => FheUint12,
+    Uint14 => FheUint14,
+    Uint16 => FheUint16,
+    Uint32 => FheUint32,
+    Uint64 => FheUint64,
+    Uint128 => FheUint128,
+    Uint160 => FheUint160,
+    Uint256 => FheUint256,
+    Uint512 => FheUint512,
+    Uint1024 => FheUint1024,
+    Uint2048 => FheUint2048,
+    Int2 => FheInt2,
+    Int4 => FheInt4,
+    Int6 => FheInt6,
+    Int8 => FheInt8,
+    Int10 => FheInt10,
+    Int12 => FheInt12,
+    Int14 => FheInt14,
+    Int16 => FheInt16,
+    Int32 => FheInt32,
+    Int64 => FheInt64,
+    Int128 => FheInt128,
+    Int160 => FheInt160,
+    Int256 => FheInt256,
+);
+
+struct NameOf;
+
+impl TypeVisitor for NameOf {
+    type Output = &'static str;
+
+    fn visit<T: CliCiphertext>(self) -> Self::Output {
+        T::NAME
+    }
+}
+
+pub fn type_name(kind: FheTypes) -> &'static str {
+    visit_type(kind, NameOf)
+}
+
+pub fn type_names() -> impl Iterator<Item = &'static str> {
+    ALL_TYPES.iter().map(|kind| type_name(*kind))
+}
+
+pub fn parse_type(name: &str) -> Result<FheTypes, String> {
+    ALL_TYPES
+        .iter()
+        .copied()
+        .find(|kind| type_name(*kind).eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("Unknown type '{name}'"))
+}
+
+struct ParseValue<'a>(&'a str);
+
+impl TypeVisitor for ParseValue<'_> {
+    type Output = Result<ClearValue, String>;
+
+    fn visit<T: CliCiphertext>(self) -> Self::Output {
+        T::parse_clear(self.0)
+    }
+}
+
+fn parse_unsigned(value: &str, num_bits: u32, name: &str) -> Result<ClearValue, String> {
+    let out_of_range = || format!("{value} is not a valid {name} value");
+    let value: u128 = value.parse().map_err(|_| out_of_range())?;
+    if num_bits < 128 && value >> num_bits != 0 {
+        return Err(out_of_range());
+    }
+    Ok(ClearValue::Unsigned(value))
+}
+
+fn parse_signed(value: &str, num_bits: u32, name: &str) -> Result<ClearValue, String> {
+    let out_of_range = || format!("{value} is not a valid {name} value");
+    let value: i128 = value.parse().map_err(|_| out_of_range())?;
+    if num_bits < 128 {
+        let max = (1i128 << (num_bits - 1)) - 1;
+        if value > max || value < -max - 1 {
+            return Err(out_of_range());
+        }
+    }
+    Ok(ClearValue::Signed(value))
+}
+
+/// Parses a `type:value` pair, e.g. `uint8:42`, `int16:-3` or `bool:true`.
+pub fn parse_typed_value(input: &str) -> Result<(FheTypes, ClearValue), String> {
+    let (name, value) = input
+        .split_once(':')
+        .ok_or_else(|| format!("Expected a 'type:value' pair, got '{input}'"))?;
+    let kind = parse_type(name)?;
+    let value = visit_type(kind, ParseValue(value))?;
+    Ok((kind, value))
+}
+
+/// Formats a little endian list of limbs as a decimal number.
+fn limbs_to_decimal(mut limbs: Vec<u64>) -> String {
+    const CHUNK: u128 = 10_000_000_000_000_000_000;
+
+    let mut chunks = Vec::new();
+    while limbs.iter().any(|limb| *limb != 0) {
+        let mut remainder = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let current = (remainder << 64) | u128::from(*limb);
+            *limb = (current / CHUNK) as u64;
+            remainder = current % CHUNK;
+        }
+        chunks.push(remainder as u64);
+    }
+
+    let Some((most_significant, rest)) = chunks.split_last() else {
+        return "0".to_owned();
+    };
+    let mut output = most_significant.to_string();
+    for chunk in rest.iter().rev() {
+        write!(output, "{chunk:019}").unwrap();
+    }
+    output
+}
+
+fn format_u2048(value: &U2048) -> String {
+    let mut bytes = [0u8; 256];
+    value.copy_to_le_byte_slice(&mut bytes);
+    let limbs = bytes
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect();
+    limbs_to_decimal(limbs)
+}
+
+fn format_i256(value: &I256) -> String {
+    let is_negative = value.data()[3] >> 63 == 1;
+    // The wrapping abs of the minimum value is itself, which is the correct magnitude once read
+    // as unsigned.
+    let magnitude = limbs_to_decimal(value.wrapping_abs().data().to_vec());
+    if is_negative {
+        format!("-{magnitude}")
+    } else {
+        magnitude
+    }
+}
+
+fn get_compact<T>(expander: &CompactCiphertextListExpander, index: usize) -> Result<T, String>
+where
+    T: tfhe::integer::ciphertext::Expandable + Tagged,
+{
+    expander
+        .get::<T>(index)
+        .ok_or_else(|| format!("No ciphertext at index {index}"))?
+        .map_err(|err| err.to_string())
+}
+
+fn get_compressed<T>(list: &CompressedCiphertextList, index: usize) -> Result<T, String>
+where
+    T: tfhe::integer::ciphertext::Expandable + Tagged,
+{
+    list.get::<T>(index)
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("No ciphertext at index {index}"))
+}
+
+impl CliCiphertext for FheBool {
+    const NAME: &'static str = "bool";
+    const NUM_BITS: u32 = 1;
+
+    fn parse_clear(value: &str) -> Result<ClearValue, String> {
+        match value {
+            "true" | "1" => Ok(ClearValue::Bool(true)),
+            "false" | "0" => Ok(ClearValue::Bool(false)),
+            _ => Err(format!("{value} is not a valid bool value")),
+        }
+    }
+
+    fn push_compact(
+        value: ClearValue,
+        builder: &mut CompactCiphertextListBuilder,
+    ) -> Result<(), String> {
+        let ClearValue::Bool(value) = value else {
+            return Err(format!("Expected a boolean value, got {value:?}"));
+        };
+        builder.push(value);
+        Ok(())
+    }
+
+    fn push_compressed(self, builder: &mut CompressedCiphertextListBuilder) {
+        builder.push(self);
+    }
+
+    fn get_compact(expander: &CompactCiphertextListExpander, index: usize) -> Result<Self, String> {
+        get_compact(expander, index)
+    }
+
+    fn get_compressed(list: &CompressedCiphertextList, index: usize) -> Result<Self, String> {
+        get_compressed(list, index)
+    }
+
+    fn decrypt_to_string(&self, client_key: &ClientKey) -> String {
+        let value: bool = self.decrypt(client_key);
+        value.to_string()
+    }
+
+    fn apply(
+        op: BinaryOp,
+        lhs: &Self,
+        rhs: &Self,
+        builder: &mut CompressedCiphertextListBuilder,
+    ) -> Result<(), String> {
+        match op {
+            BinaryOp::BitAnd => builder.push(lhs & rhs),
+            BinaryOp::BitOr => builder.push(lhs | rhs),
+            BinaryOp::BitXor => builder.push(lhs ^ rhs),
+            BinaryOp::Eq => builder.push(lhs.eq(rhs)),
+            BinaryOp::Ne => builder.push(lhs.ne(rhs)),
+            _ => return Err(format!("{op:?} is not supported on booleans")),
+        };
+        Ok(())
+    }
+}
+
+macro_rules! impl_cli_ciphertext_for_integers {
+    (
+        $clear_variant:ident($clear_type:ty), $parse:ident, $decrypt_type:ty, $format:path;
+        $($fhe_type:ident => $name:literal, $num_bits:literal);* $(;)?
+    ) => {
+        $(
+            impl CliCiphertext for $fhe_type {
+                const NAME: &'static str = $name;
+                const NUM_BITS: u32 = $num_bits;
+
+                fn parse_clear(value: &str) -> Result<ClearValue, String> {
+                    $parse(value, Self::NUM_BITS, Self::NAME)
+                }
+
+                fn push_compact(
+                    value: ClearValue,
+                    builder: &mut CompactCiphertextListBuilder,
+                ) -> Result<(), String> {
+                    let ClearValue::$clear_variant(value) = value else {
+                        return Err(format!("Expected a {} value, got {value:?}", Self::NAME));
+                    };
+                    builder
+                        .push_with_num_bits::<$clear_type>(value, $num_bits)
+                        .map_err(|err| err.to_string())?;
+                    Ok(())
+                }
+
+                fn push_compressed(self, builder: &mut CompressedCiphertextListBuilder) {
+                    builder.push(self);
+                }
+
+                fn get_compact(
+                    expander: &CompactCiphertextListExpander,
+                    index: usize,
+                ) -> Result<Self, String> {
+                    get_compact(expander, index)
+                }
+
+                fn get_compressed(
+                    list: &CompressedCiphertextList,
+                    index: usize,
+                ) -> Result<Self, String> {
+                    get_compressed(list, index)
+                }
+
+                fn decrypt_to_string(&self, client_key: &ClientKey) -> String {
+                    let value: $decrypt_type = self.decrypt(client_key);
+                    $format(&value)
+                }
+
+                fn apply(
+                    op: BinaryOp,
+                    lhs: &Self,
+                    rhs: &Self,
+                    builder: &mut CompressedCiphertextListBuilder,
+                ) -> Result<(), String> {
+                    match op {
+                        BinaryOp::Add => builder.push(lhs + rhs),
+                        BinaryOp::Sub => builder.push(lhs - rhs),
+                        BinaryOp::Mul => builder.push(lhs * rhs),
+                        BinaryOp::BitAnd => builder.push(lhs & rhs),
+                        BinaryOp::BitOr => builder.push(lhs | rhs),
+                        BinaryOp::BitXor => builder.push(lhs ^ rhs),
+                        BinaryOp::Eq => builder.push(lhs.eq(rhs)),
+                        BinaryOp::Ne => builder.push(lhs.ne(rhs)),
+                        BinaryOp::Lt => builder.push(lhs.lt(rhs)),
+                        BinaryOp::Le => builder.push(lhs.le(rhs)),
+                        BinaryOp::Gt => builder.push(lhs.gt(rhs)),
+                        BinaryOp::Ge => builder.push(lhs.ge(rhs)),
+                    };
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_cli_ciphertext_for_integers!(
+    Unsigned(u128), parse_unsigned, u128, ToString::to_string;
+    FheUint2 => "uint2", 2;
+    FheUint4 => "uint4", 4;
+    FheUint6 => "uint6", 6;
+    FheUint8 => "uint8", 8;
+    FheUint10 => "uint10", 10;
+    FheUint12 => "uint12", 12;
+    FheUint14 => "uint14", 14;
+    FheUint16 => "uint16", 16;
+    FheUint32 => "uint32", 32;
+    FheUint64 => "uint64", 64;
+    FheUint128 => "uint128", 128;
+);
+
+impl_cli_ciphertext_for_integers!(
+    Unsigned(u128), parse_unsigned, U2048, format_u2048;
+    FheUint160 => "uint160", 160;
+    FheUint256 => "uint256", 256;
+    FheUint512 => "uint512", 512;
+    FheUint1024 => "uint1024", 1024;
+    FheUint2048 => "uint2048", 2048;
+);
+
+impl_cli_ciphertext_for_integers!(
+    Signed(i128), parse_signed, i128, ToString::to_string;
+    FheInt2 => "int2", 2;
+    FheInt4 => "int4", 4;
+    FheInt6 => "int6", 6;
+    FheInt8 => "int8", 8;
+    FheInt10 => "int10", 10;
+    FheInt12 => "int12", 12;
+    FheInt14 => "int14", 14;
+    FheInt16 => "int16", 16;
+    FheInt32 => "int32", 32;
+    FheInt64 => "int64", 64;
+    FheInt128 => "int128", 128;
+);
+
+impl_cli_ciphertext_for_integers!(
+    Signed(i128), parse_signed, I256, format_i256;
+    FheInt160 => "int160", 160;
+    FheInt256 => "int256", 256;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_formatting() {
+        assert_eq!(limbs_to_decimal(vec![0, 0]), "0");
+        assert_eq!(limbs_to_decimal(vec![42]), "42");
+        assert_eq!(
+            limbs_to_decimal(vec![u64::MAX, u64::MAX]),
+            u128::MAX.to_string()
+        );
+        assert_eq!(format_i256(&I256::from(-5i128)), "-5");
+        assert_eq!(format_i256(&I256::from(i128::MIN)), i128::MIN.to_string());
+    }
+
+    #[test]
+    fn typed_value_parsing() {
+        assert!(matches!(
+            parse_typed_value("uint8:255"),
+            Ok((FheTypes::Uint8, ClearValue::Unsigned(255)))
+        ));
+        assert!(matches!(
+            parse_typed_value("int4:-8"),
+            Ok((FheTypes::Int4, ClearValue::Signed(-8)))
+        ));
+        assert!(matches!(
+            parse_typed_value("bool:true"),
+            Ok((FheTypes::Bool, ClearValue::Bool(true)))
+        ));
+        assert!(parse_typed_value("uint8:256").is_err());
+        assert!(parse_typed_value("int4:8").is_err());
+        assert!(parse_typed_value("uint7:1").is_err());
+        assert!(parse_typed_value("uint8").is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! Prints the type, version and main parameters of files written with the `tfhe` safe
+//! serialization functions, without loading them in memory.
+//!
+//! Usage: `tfhe-inspect [--size-limit <bytes>] <file>...`
+
+use std::fs::File;
+use std::io::BufReader;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: tfhe-inspect [--size-limit <bytes>] <file>...";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut size_limit = u64::MAX;
+    let mut paths = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            "--size-limit" => {
+                let Some(limit) = args.next().and_then(|limit| limit.parse().ok()) else {
+                    eprintln!("--size-limit expects a number of bytes\n{USAGE}");
+                    return ExitCode::FAILURE;
+                };
+                size_limit = limit;
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    }
+
+    let mut status = ExitCode::SUCCESS;
+
+    for path in &paths {
+        let info = File::open(path)
+            .map_err(|err| err.to_string())
+            .and_then(|file| tfhe::inspect::inspect(BufReader::new(file), size_limit));
+
+        match info {
+            Ok(info) => {
+                println!("{path}:");
+                for line in info.to_string().lines() {
+                    println!("  {line}");
+                }
+            }
+            Err(err) => {
+                eprintln!("{path}: {err}");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    status
+}
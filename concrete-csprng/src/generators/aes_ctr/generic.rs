@@ -1,8 +1,11 @@
 use crate::generators::aes_ctr::block_cipher::{AesBlockCipher, AesKey};
 use crate::generators::aes_ctr::index::TableIndex;
-use crate::generators::aes_ctr::states::{BufferPointer, ShiftAction, State};
-use crate::generators::aes_ctr::BYTES_PER_BATCH;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
+use crate::generators::aes_ctr::{
+    AesIndex, BufferPointer, ByteIndex, ShiftAction, State, BYTES_PER_BATCH,
+};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, StateError,
+};
 
 // Usually, to work with iterators and parallel iterators, we would use opaque types such as
 // `impl Iterator<..>`. Unfortunately, it is not yet possible to return existential types in
@@ -168,6 +171,35 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         Ok(output)
     }
 
+    /// Returns the state of the generator, that is its current table index and its bound.
+    pub fn state(&self) -> GeneratorState {
+        let table_index = self.table_index();
+        let bound = self.get_bound();
+        GeneratorState {
+            table_index: (table_index.aes_index.0, table_index.byte_index.0),
+            bound: (bound.aes_index.0, bound.byte_index.0),
+        }
+    }
+
+    /// Moves the generator to the given state.
+    ///
+    /// The buffer is refreshed on the next generated byte, so the block cipher must be the one
+    /// the state was taken with, for the generator to output the same bytes.
+    pub fn restore(&mut self, state: GeneratorState) -> Result<(), StateError> {
+        state.validate()?;
+        let to_table_index = |(aes_index, byte_index): (u128, usize)| {
+            TableIndex::new(AesIndex(aes_index), ByteIndex(byte_index))
+        };
+        let table_index = to_table_index(state.table_index);
+        let bound = to_table_index(state.bound);
+
+        // The state stores the table index of the last outputted byte, which is before the bound
+        // so incrementing it can not wrap to the first table index.
+        self.state = State::new(table_index.incremented());
+        self.last = bound.decremented();
+        Ok(())
+    }
+
     pub(crate) fn is_fork_in_bound(
         &self,
         n_child: ChildrenCount,
@@ -37,7 +37,6 @@ impl TableIndex {
     };
 
     /// Creates a table index from an aes index and a byte index.
-    #[allow(unused)] // to please clippy when tests are not activated
     pub fn new(aes_index: AesIndex, byte_index: ByteIndex) -> Self {
         assert!(byte_index.0 < BYTES_PER_AES_CALL);
         TableIndex {
@@ -1,6 +1,8 @@
 use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
 use crate::generators::implem::aesni::block_cipher::AesniBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator, StateError,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using the `aesni` instructions.
@@ -36,6 +38,12 @@ impl RandomGenerator for AesniRandomGenerator {
             .try_fork(n_children, n_bytes)
             .map(AesniChildrenIterator)
     }
+    fn state(&self) -> Result<GeneratorState, StateError> {
+        Ok(self.0.state())
+    }
+    fn restore(&mut self, state: GeneratorState) -> Result<(), StateError> {
+        self.0.restore(state)
+    }
 }
 
 impl Iterator for AesniRandomGenerator {
@@ -97,6 +105,16 @@ mod test {
         generator_generic_test::test_generator_determinism::<AesniRandomGenerator>();
     }
 
+    #[test]
+    fn test_state_restore() {
+        generator_generic_test::test_state_restore::<AesniRandomGenerator>();
+    }
+
+    #[test]
+    fn test_invalid_state() {
+        generator_generic_test::test_invalid_state::<AesniRandomGenerator>();
+    }
+
     #[test]
     fn test_fork() {
         generator_generic_test::test_fork_children::<AesniRandomGenerator>();
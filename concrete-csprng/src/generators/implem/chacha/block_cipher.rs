@@ -0,0 +1,143 @@
+use crate::generators::aes_ctr::{
+    AesBlockCipher, AesIndex, AesKey, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+};
+
+/// The number of rounds of the ChaCha permutation.
+const ROUNDS: usize = 20;
+/// The number of bytes in a block of ChaCha keystream.
+const BYTES_PER_CHACHA_BLOCK: usize = 64;
+/// The number of 16 bytes chunks (i.e. of aes indices) in a block of ChaCha keystream.
+const CHUNKS_PER_CHACHA_BLOCK: u128 = 4;
+/// The ChaCha constant for 128 bits keys, `"expand 16-byte k"`.
+const TAU: [u32; 4] = [0x61707865, 0x3120646e, 0x79622d36, 0x6b206574];
+
+/// A block cipher implementation using the ChaCha20 stream cipher.
+///
+/// The `u128` seed is used as a 128 bits ChaCha key, and the four last words of the ChaCha state
+/// hold a 128 bits block counter. The aes index `i` of the table is mapped to the bytes
+/// `16 * i..16 * (i + 1)` of the keystream, that is, to the chunk `i % 4` of the ChaCha block
+/// `i / 4`, so that the whole table index machinery of the aes ctr generator is reused as is.
+#[derive(Clone)]
+pub struct ChachaBlockCipher {
+    // The constants and key part of the ChaCha state
+    key_state: [u32; 12],
+}
+
+impl AesBlockCipher for ChachaBlockCipher {
+    fn new(key: AesKey) -> ChachaBlockCipher {
+        let key = key.0.to_le_bytes();
+        let mut key_state = [0u32; 12];
+        key_state[..4].copy_from_slice(&TAU);
+        for (i, word) in key.chunks_exact(4).enumerate() {
+            let word = u32::from_le_bytes(word.try_into().unwrap());
+            // 128 bits keys are repeated to fill the 256 bits of key of the state
+            key_state[4 + i] = word;
+            key_state[8 + i] = word;
+        }
+        ChachaBlockCipher { key_state }
+    }
+
+    fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
+        let mut output = [0u8; BYTES_PER_BATCH];
+        let mut current: Option<(u128, [u8; BYTES_PER_CHACHA_BLOCK])> = None;
+
+        for (i, chunk) in output.chunks_exact_mut(BYTES_PER_AES_CALL).enumerate() {
+            let aes_index = aes_ctr.wrapping_add(i as u128);
+            let block_index = aes_index / CHUNKS_PER_CHACHA_BLOCK;
+            let offset = (aes_index % CHUNKS_PER_CHACHA_BLOCK) as usize * BYTES_PER_AES_CALL;
+
+            let block = match current {
+                Some((index, block)) if index == block_index => block,
+                _ => {
+                    let block = self.keystream_block(block_index);
+                    current = Some((block_index, block));
+                    block
+                }
+            };
+            chunk.copy_from_slice(&block[offset..offset + BYTES_PER_AES_CALL]);
+        }
+
+        output
+    }
+}
+
+impl ChachaBlockCipher {
+    fn keystream_block(&self, block_index: u128) -> [u8; BYTES_PER_CHACHA_BLOCK] {
+        let mut state = [0u32; 16];
+        state[..12].copy_from_slice(&self.key_state);
+        for (i, word) in block_index.to_le_bytes().chunks_exact(4).enumerate() {
+            state[12 + i] = u32::from_le_bytes(word.try_into().unwrap());
+        }
+        chacha_block(&state)
+    }
+}
+
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
+
+// Computes the ChaCha block function on a full input state, as specified in RFC 8439.
+fn chacha_block(input: &[u32; 16]) -> [u8; BYTES_PER_CHACHA_BLOCK] {
+    let mut state = *input;
+    for _ in 0..ROUNDS / 2 {
+        // Column rounds
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 1, 5, 9, 13);
+        quarter_round(&mut state, 2, 6, 10, 14);
+        quarter_round(&mut state, 3, 7, 11, 15);
+        // Diagonal rounds
+        quarter_round(&mut state, 0, 5, 10, 15);
+        quarter_round(&mut state, 1, 6, 11, 12);
+        quarter_round(&mut state, 2, 7, 8, 13);
+        quarter_round(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut output = [0u8; BYTES_PER_CHACHA_BLOCK];
+    for ((bytes, word), input_word) in output.chunks_exact_mut(4).zip(state).zip(input) {
+        bytes.copy_from_slice(&word.wrapping_add(*input_word).to_le_bytes());
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Test vector for the ChaCha20 block function, from the section 2.3.2 of RFC 8439
+    const INPUT_STATE: [u32; 16] = [
+        0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, 0x03020100, 0x07060504, 0x0b0a0908,
+        0x0f0e0d0c, 0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c, 0x00000001, 0x09000000,
+        0x4a000000, 0x00000000,
+    ];
+    const OUTPUT_BLOCK: [u8; BYTES_PER_CHACHA_BLOCK] = [
+        0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20, 0x71,
+        0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a, 0xc3, 0xd4,
+        0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2, 0xd7, 0x05, 0xd9,
+        0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9, 0xcb, 0xd0, 0x83, 0xe8,
+        0xa2, 0x50, 0x3c, 0x4e,
+    ];
+
+    #[test]
+    fn test_chacha_block() {
+        assert_eq!(chacha_block(&INPUT_STATE), OUTPUT_BLOCK);
+    }
+
+    #[test]
+    fn test_unaligned_batches() {
+        // Checks that batches starting in the middle of a ChaCha block are slices of the keystream.
+        let mut cipher = ChachaBlockCipher::new(AesKey(0x000102030405060708090a0b0c0d0e0f));
+        let keystream = cipher.generate_batch(AesIndex(0));
+        for start in 1..8 {
+            let batch = cipher.generate_batch(AesIndex(start));
+            let start = start as usize * BYTES_PER_AES_CALL;
+            assert_eq!(batch[..BYTES_PER_BATCH - start], keystream[start..]);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
+use crate::generators::implem::chacha::block_cipher::ChachaBlockCipher;
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator, StateError,
+};
+use crate::seeders::Seed;
+
+/// A random number generator using the ChaCha20 stream cipher.
+pub struct ChachaRandomGenerator(pub(super) AesCtrGenerator<ChachaBlockCipher>);
+
+/// The children iterator used by [`ChachaRandomGenerator`].
+///
+/// Outputs children generators one by one.
+pub struct ChachaChildrenIterator(ChildrenIterator<ChachaBlockCipher>);
+
+impl Iterator for ChachaChildrenIterator {
+    type Item = ChachaRandomGenerator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(ChachaRandomGenerator)
+    }
+}
+
+impl RandomGenerator for ChachaRandomGenerator {
+    type ChildrenIter = ChachaChildrenIterator;
+    fn new(seed: Seed) -> Self {
+        ChachaRandomGenerator(AesCtrGenerator::new(AesKey(seed.0), None, None))
+    }
+    fn remaining_bytes(&self) -> ByteCount {
+        self.0.remaining_bytes()
+    }
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        self.0
+            .try_fork(n_children, n_bytes)
+            .map(ChachaChildrenIterator)
+    }
+    fn state(&self) -> Result<GeneratorState, StateError> {
+        Ok(self.0.state())
+    }
+    fn restore(&mut self, state: GeneratorState) -> Result<(), StateError> {
+        self.0.restore(state)
+    }
+}
+
+impl Iterator for ChachaRandomGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::aes_ctr::aes_ctr_generic_test;
+    use crate::generators::generator_generic_test;
+
+    #[test]
+    fn prop_fork_first_state_table_index() {
+        aes_ctr_generic_test::prop_fork_first_state_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_last_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_last_bound_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_bound_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_state_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_state_table_index::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork() {
+        aes_ctr_generic_test::prop_fork::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_children_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_children_remaining_bytes::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<ChachaBlockCipher>();
+    }
+
+    #[test]
+    fn test_roughly_uniform() {
+        generator_generic_test::test_roughly_uniform::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_fork() {
+        generator_generic_test::test_fork_children::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_generator_determinism() {
+        generator_generic_test::test_generator_determinism::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_state_restore() {
+        generator_generic_test::test_state_restore::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_invalid_state() {
+        generator_generic_test::test_invalid_state::<ChachaRandomGenerator>();
+    }
+
+    #[test]
+    #[should_panic(expected = "expected test panic")]
+    fn test_bounded_panic() {
+        generator_generic_test::test_bounded_none_should_panic::<ChachaRandomGenerator>();
+    }
+}
@@ -0,0 +1,16 @@
+//! A module implementing a random number generator using the ChaCha20 stream cipher.
+//!
+//! This generator does not rely on any dedicated cpu instruction, and is much faster than the
+//! software aes fallback on targets without aes instructions. The ChaCha20 keystream is used in
+//! place of the aes lookup table, so that this generator has the same forking semantics and byte
+//! bounds as the aes ctr generators, but it does not output the same stream for a given seed.
+
+mod block_cipher;
+
+mod generator;
+pub use generator::*;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
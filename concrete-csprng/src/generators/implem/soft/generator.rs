@@ -1,6 +1,8 @@
 use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
 use crate::generators::implem::soft::block_cipher::SoftwareBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator, StateError,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using a software implementation.
@@ -36,6 +38,12 @@ impl RandomGenerator for SoftwareRandomGenerator {
             .try_fork(n_children, n_bytes)
             .map(SoftwareChildrenIterator)
     }
+    fn state(&self) -> Result<GeneratorState, StateError> {
+        Ok(self.0.state())
+    }
+    fn restore(&mut self, state: GeneratorState) -> Result<(), StateError> {
+        self.0.restore(state)
+    }
 }
 
 impl Iterator for SoftwareRandomGenerator {
@@ -102,6 +110,16 @@ mod test {
         generator_generic_test::test_generator_determinism::<SoftwareRandomGenerator>();
     }
 
+    #[test]
+    fn test_state_restore() {
+        generator_generic_test::test_state_restore::<SoftwareRandomGenerator>();
+    }
+
+    #[test]
+    fn test_invalid_state() {
+        generator_generic_test::test_invalid_state::<SoftwareRandomGenerator>();
+    }
+
     #[test]
     #[should_panic(expected = "expected test panic")]
     fn test_bounded_panic() {
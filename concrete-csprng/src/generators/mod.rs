@@ -51,6 +51,126 @@ impl Display for ForkError {
 }
 impl Error for ForkError {}
 
+/// The state of a generator, from which the generation can be resumed.
+///
+/// Table indices are stored as an aes index and a byte index, see the
+/// [crate-level](`crate`) documentation for details.
+///
+/// Note:
+/// -----
+///
+/// The state does not contain the seed of the generator. For a restored generator to output the
+/// same bytes as the one the state was taken from, both must have been created from the same seed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GeneratorState {
+    /// The table index of the last outputted byte.
+    pub table_index: (u128, usize),
+    /// The table index of the first byte that can not be outputted by the generator.
+    pub bound: (u128, usize),
+}
+
+/// The version of the layout written by [`GeneratorState::to_bytes`].
+const STATE_ENCODING_VERSION: u8 = 0;
+
+impl GeneratorState {
+    /// Encodes the state in a representation which is kept stable across versions of this crate,
+    /// to store it outside of the crate, e.g. in a checkpoint.
+    ///
+    /// The first byte is the version of the layout, the current version 0 being followed by the
+    /// table index then the bound, each as a little endian `u128` aes index and a little endian
+    /// `u64` byte index. A new version is introduced each time the layout changes, states encoded
+    /// with an older version of the crate can still be decoded by
+    /// [`from_bytes`](GeneratorState::from_bytes).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + 2 * (16 + 8));
+        bytes.push(STATE_ENCODING_VERSION);
+        for (aes_index, byte_index) in [self.table_index, self.bound] {
+            bytes.extend_from_slice(&aes_index.to_le_bytes());
+            bytes.extend_from_slice(&(byte_index as u64).to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes a state encoded by [`to_bytes`](GeneratorState::to_bytes).
+    ///
+    /// The decoded state is not validated, this is done when it is restored.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, StateError> {
+        match bytes.split_first() {
+            Some((&0, data)) if data.len() == 2 * (16 + 8) => {
+                let table_index = |data: &[u8]| -> Result<(u128, usize), StateError> {
+                    let (aes_index, byte_index) = data.split_at(16);
+                    let aes_index = u128::from_le_bytes(aes_index.try_into().unwrap());
+                    let byte_index = u64::from_le_bytes(byte_index.try_into().unwrap());
+                    let byte_index =
+                        usize::try_from(byte_index).map_err(|_| StateError::InvalidEncoding)?;
+                    Ok((aes_index, byte_index))
+                };
+                let (table_index_data, bound_data) = data.split_at(16 + 8);
+                Ok(Self {
+                    table_index: table_index(table_index_data)?,
+                    bound: table_index(bound_data)?,
+                })
+            }
+            _ => Err(StateError::InvalidEncoding),
+        }
+    }
+
+    /// Checks that the state can be restored, that is that its byte indices are smaller than the
+    /// aes block size and that its table index is before its bound.
+    pub fn validate(&self) -> Result<(), StateError> {
+        if self.table_index.1 >= aes_ctr::BYTES_PER_AES_CALL
+            || self.bound.1 >= aes_ctr::BYTES_PER_AES_CALL
+        {
+            return Err(StateError::InvalidByteIndex);
+        }
+        if self.table_index >= self.bound {
+            return Err(StateError::IndexAfterBound);
+        }
+        Ok(())
+    }
+}
+
+/// An error occurring when restoring a generator state.
+#[derive(Debug)]
+pub enum StateError {
+    InvalidByteIndex,
+    IndexAfterBound,
+    InvalidEncoding,
+    Unsupported,
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::InvalidByteIndex => {
+                write!(
+                    f,
+                    "The byte indices of the state must be smaller than the aes block size."
+                )
+            }
+            StateError::IndexAfterBound => {
+                write!(
+                    f,
+                    "The table index of the state must be smaller than its bound."
+                )
+            }
+            StateError::InvalidEncoding => {
+                write!(
+                    f,
+                    "The encoded state has an unknown version or an invalid length."
+                )
+            }
+            StateError::Unsupported => {
+                write!(
+                    f,
+                    "The generator does not support saving and restoring its state."
+                )
+            }
+        }
+    }
+}
+impl Error for StateError {}
+
 /// A trait for cryptographically secure pseudo-random generators.
 ///
 /// See the [crate-level](#crate) documentation for details.
@@ -95,6 +215,27 @@ pub trait RandomGenerator: Iterator<Item = u8> {
         n_children: ChildrenCount,
         n_bytes: BytesPerChild,
     ) -> Result<Self::ChildrenIter, ForkError>;
+
+    /// Returns the current state of the generator, which can be saved to resume the generation
+    /// later with [`restore`](RandomGenerator::restore).
+    ///
+    /// The default implementation returns [`StateError::Unsupported`].
+    fn state(&self) -> Result<GeneratorState, StateError> {
+        Err(StateError::Unsupported)
+    }
+
+    /// Moves the generator to a previously saved state, including its bound.
+    ///
+    /// The default implementation returns [`StateError::Unsupported`].
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The generator must have been created from the same seed as the one the state was taken
+    /// from, for the outputted bytes to be the same.
+    fn restore(&mut self, _state: GeneratorState) -> Result<(), StateError> {
+        Err(StateError::Unsupported)
+    }
 }
 
 /// A trait extending [`RandomGenerator`] to the parallel iterators of `rayon`.
@@ -212,6 +353,66 @@ pub mod generator_generic_test {
         }
     }
 
+    /// Checks that a generator restored from a state outputs the same bytes as the generator the
+    /// state was taken from, including for bounded children.
+    pub fn test_state_restore<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let ((seed, n_children), n_bytes) = any_seed()
+                .zip(some_children_count())
+                .zip(some_bytes_per_child())
+                .next()
+                .unwrap();
+            let mut gen = G::new(seed);
+            for _ in 0..n_bytes.0 {
+                gen.next().unwrap();
+            }
+            // The state goes through its encoding, as a checkpoint saved to disk would
+            let state = GeneratorState::from_bytes(&gen.state().unwrap().to_bytes()).unwrap();
+            assert_eq!(state, gen.state().unwrap());
+            let mut restored = G::new(seed);
+            restored.restore(state).unwrap();
+            assert_eq!(restored.remaining_bytes(), gen.remaining_bytes());
+            for _ in 0..1024 {
+                assert_eq!(restored.next(), gen.next());
+            }
+
+            let mut child = gen.try_fork(n_children, n_bytes).unwrap().last().unwrap();
+            child.next().unwrap();
+            let mut restored = G::new(seed);
+            restored.restore(child.state().unwrap()).unwrap();
+            assert_eq!(restored.remaining_bytes(), child.remaining_bytes());
+            for _ in 1..n_bytes.0 {
+                assert_eq!(restored.next(), child.next());
+            }
+            assert!(restored.next().is_none());
+        }
+    }
+
+    /// Checks that invalid states are rejected.
+    pub fn test_invalid_state<G: RandomGenerator>() {
+        let seed = any_seed().next().unwrap();
+        let mut gen = G::new(seed);
+        let state = gen.state().unwrap();
+        assert!(gen
+            .restore(GeneratorState {
+                table_index: (0, 16),
+                ..state
+            })
+            .is_err());
+        assert!(gen
+            .restore(GeneratorState {
+                table_index: (1, 0),
+                bound: (1, 0),
+            })
+            .is_err());
+
+        let bytes = state.to_bytes();
+        assert!(GeneratorState::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(GeneratorState::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+        assert!(GeneratorState::from_bytes(&[&[1], &bytes[1..]].concat()).is_err());
+        assert!(GeneratorState::from_bytes(&[]).is_err());
+    }
+
     /// Checks that a bounded prng returns none when exceeding the allowed number of bytes.
     ///
     /// To properly check for panic use `#[should_panic(expected = "expected test panic")]` as an
@@ -0,0 +1,40 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::curve_api::Curve;
+use crate::proofs::{pke, pke_v2, range};
+use crate::serialization::SerializablePublicParams;
+
+#[derive(VersionsDispatch)]
+pub enum SerializablePublicParamsVersions {
+    V0(SerializablePublicParams),
+}
+
+#[derive(VersionsDispatch)]
+pub enum PkeProofVersions<G: Curve> {
+    V0(pke::Proof<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum PkeV2ProofVersions<G: Curve> {
+    V0(pke_v2::Proof<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum RangeProofVersions<G: Curve> {
+    V0(range::Proof<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum RangePublicCommitVersions<G: Curve> {
+    V0(range::PublicCommit<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum UpperBoundProofVersions<G: Curve> {
+    V0(range::UpperBoundProof<G>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum LinkProofVersions<G: Curve> {
+    V0(range::LinkProof<G>),
+}
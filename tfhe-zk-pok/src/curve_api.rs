@@ -1,12 +1,13 @@
 use ark_ec::{AdditiveGroup as Group, CurveGroup, VariableBaseMSM};
-use ark_ff::{BigInt, Field, MontFp, Zero};
+use ark_ff::{BigInt, Field, MontFp, PrimeField, Zero};
 use ark_poly::univariate::DensePolynomial;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 use core::fmt;
 use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 use serde::{Deserialize, Serialize};
+use tfhe_versionable::NotVersioned;
 
-fn ark_se<S, A: CanonicalSerialize>(a: &A, s: S) -> Result<S::Ok, S::Error>
+pub(crate) fn ark_se<S, A: CanonicalSerialize>(a: &A, s: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
 {
@@ -16,7 +17,7 @@ where
     s.serialize_bytes(&bytes)
 }
 
-fn ark_de<'de, D, A: CanonicalDeserialize>(data: D) -> Result<A, D::Error>
+pub(crate) fn ark_de<'de, D, A: CanonicalDeserialize>(data: D) -> Result<A, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
@@ -41,6 +42,7 @@ pub mod msm;
 
 pub mod bls12_381;
 pub mod bls12_446;
+pub mod bn254;
 
 pub trait FieldOps:
     Copy
@@ -63,6 +65,8 @@ pub trait FieldOps:
     fn from_u64(n: u64) -> Self;
     fn from_i64(n: i64) -> Self;
     fn to_bytes(self) -> impl AsRef<[u8]>;
+    /// Returns the canonical representation of the element as little-endian limbs.
+    fn to_bigint(self) -> impl AsRef<[u64]>;
     fn rand(rng: &mut dyn rand::RngCore) -> Self;
     fn hash(values: &mut [Self], data: &[&[u8]]);
     fn hash_128bit(values: &mut [Self], data: &[&[u8]]);
@@ -174,6 +178,9 @@ impl FieldOps for bls12_381::Zp {
     fn to_bytes(self) -> impl AsRef<[u8]> {
         self.to_bytes()
     }
+    fn to_bigint(self) -> impl AsRef<[u64]> {
+        self.inner.into_bigint().0
+    }
     fn rand(rng: &mut dyn rand::RngCore) -> Self {
         Self::rand(rng)
     }
@@ -306,6 +313,9 @@ impl FieldOps for bls12_446::Zp {
     fn to_bytes(self) -> impl AsRef<[u8]> {
         self.to_bytes()
     }
+    fn to_bigint(self) -> impl AsRef<[u64]> {
+        self.inner.into_bigint().0
+    }
     fn rand(rng: &mut dyn rand::RngCore) -> Self {
         Self::rand(rng)
     }
@@ -427,10 +437,147 @@ impl PairingGroupOps<bls12_446::Zp, bls12_446::G1, bls12_446::G2> for bls12_446:
     }
 }
 
+impl FieldOps for bn254::Zp {
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+
+    fn from_u128(n: u128) -> Self {
+        Self::from_bigint([n as u64, (n >> 64) as u64, 0, 0])
+    }
+    fn from_u64(n: u64) -> Self {
+        Self::from_u64(n)
+    }
+    fn from_i64(n: i64) -> Self {
+        Self::from_i64(n)
+    }
+    fn to_bytes(self) -> impl AsRef<[u8]> {
+        self.to_bytes()
+    }
+    fn to_bigint(self) -> impl AsRef<[u64]> {
+        self.inner.into_bigint().0
+    }
+    fn rand(rng: &mut dyn rand::RngCore) -> Self {
+        Self::rand(rng)
+    }
+    fn hash(values: &mut [Self], data: &[&[u8]]) {
+        Self::hash(values, data)
+    }
+    fn hash_128bit(values: &mut [Self], data: &[&[u8]]) {
+        Self::hash_128bit(values, data)
+    }
+
+    fn poly_mul(p: &[Self], q: &[Self]) -> Vec<Self> {
+        let p = p.iter().map(|x| x.inner).collect();
+        let q = q.iter().map(|x| x.inner).collect();
+        let p = DensePolynomial { coeffs: p };
+        let q = DensePolynomial { coeffs: q };
+        (&p * &q)
+            .coeffs
+            .into_iter()
+            .map(|inner| bn254::Zp { inner })
+            .collect()
+    }
+}
+
+impl CurveGroupOps<bn254::Zp> for bn254::G1 {
+    const ZERO: Self = Self::ZERO;
+    const GENERATOR: Self = Self::GENERATOR;
+    const BYTE_SIZE: usize = Self::BYTE_SIZE;
+    type Affine = bn254::G1Affine;
+
+    fn projective(affine: Self::Affine) -> Self {
+        Self {
+            inner: affine.inner.into(),
+        }
+    }
+
+    fn mul_scalar(self, scalar: bn254::Zp) -> Self {
+        if scalar.inner == MontFp!("2") {
+            self.double()
+        } else {
+            self.mul_scalar(scalar)
+        }
+    }
+
+    #[track_caller]
+    fn multi_mul_scalar(bases: &[Self::Affine], scalars: &[bn254::Zp]) -> Self {
+        Self::Affine::multi_mul_scalar(bases, scalars)
+    }
+
+    fn to_bytes(self) -> impl AsRef<[u8]> {
+        self.to_bytes()
+    }
+
+    fn double(self) -> Self {
+        self.double()
+    }
+
+    fn normalize(self) -> Self::Affine {
+        Self::Affine {
+            inner: self.inner.into_affine(),
+        }
+    }
+}
+
+impl CurveGroupOps<bn254::Zp> for bn254::G2 {
+    const ZERO: Self = Self::ZERO;
+    const GENERATOR: Self = Self::GENERATOR;
+    const BYTE_SIZE: usize = Self::BYTE_SIZE;
+    type Affine = bn254::G2Affine;
+
+    fn projective(affine: Self::Affine) -> Self {
+        Self {
+            inner: affine.inner.into(),
+        }
+    }
+
+    fn mul_scalar(self, scalar: bn254::Zp) -> Self {
+        if scalar.inner == MontFp!("2") {
+            self.double()
+        } else {
+            self.mul_scalar(scalar)
+        }
+    }
+
+    #[track_caller]
+    fn multi_mul_scalar(bases: &[Self::Affine], scalars: &[bn254::Zp]) -> Self {
+        Self::Affine::multi_mul_scalar(bases, scalars)
+    }
+
+    fn to_bytes(self) -> impl AsRef<[u8]> {
+        self.to_bytes()
+    }
+
+    fn double(self) -> Self {
+        self.double()
+    }
+
+    fn normalize(self) -> Self::Affine {
+        Self::Affine {
+            inner: self.inner.into_affine(),
+        }
+    }
+}
+
+impl PairingGroupOps<bn254::Zp, bn254::G1, bn254::G2> for bn254::Gt {
+    fn mul_scalar(self, scalar: bn254::Zp) -> Self {
+        self.mul_scalar(scalar)
+    }
+
+    fn pairing(x: bn254::G1, y: bn254::G2) -> Self {
+        if x == bn254::G1::ZERO || y == bn254::G2::ZERO {
+            return Self::pairing(bn254::G1::ZERO, bn254::G2::GENERATOR);
+        }
+        Self::pairing(x, y)
+    }
+}
+
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Bls12_381;
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Bls12_446;
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Bn254;
 
 impl Curve for Bls12_381 {
     type Zp = bls12_381::Zp;
@@ -444,3 +591,9 @@ impl Curve for Bls12_446 {
     type G2 = bls12_446::G2;
     type Gt = bls12_446::Gt;
 }
+impl Curve for Bn254 {
+    type Zp = bn254::Zp;
+    type G1 = bn254::G1;
+    type G2 = bn254::G2;
+    type Gt = bn254::Gt;
+}
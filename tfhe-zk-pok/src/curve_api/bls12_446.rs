@@ -80,6 +80,7 @@ mod g1 {
         Hash,
         CanonicalSerialize,
         CanonicalDeserialize,
+        NotVersioned,
     )]
     #[repr(transparent)]
     pub struct G1 {
@@ -347,6 +348,7 @@ mod g2 {
         Hash,
         CanonicalSerialize,
         CanonicalDeserialize,
+        NotVersioned,
     )]
     #[repr(transparent)]
     pub struct G2 {
@@ -864,7 +866,7 @@ mod zp {
         t
     }
 
-    #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, Zeroize)]
+    #[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, Zeroize, NotVersioned)]
     #[repr(transparent)]
     pub struct Zp {
         #[serde(serialize_with = "ark_se", deserialize_with = "ark_de")]
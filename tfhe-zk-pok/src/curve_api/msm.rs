@@ -1,19 +1,15 @@
+use super::{CurveGroupOps, FieldOps};
 use ark_ec::short_weierstrass::Affine;
 use ark_ec::AffineRepr;
-use ark_ff::{AdditiveGroup, BigInteger, Field, Fp, PrimeField};
+use ark_ff::{AdditiveGroup, Field, Fp, PrimeField};
+use core::marker::PhantomData;
 use rayon::prelude::*;
 
-fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<Item = i64> + '_ {
-    let scalar = a.as_ref();
+fn make_digits(scalar: &[u64], w: usize, num_bits: usize) -> impl Iterator<Item = i64> + '_ {
     let radix: u64 = 1 << w;
     let window_mask: u64 = radix - 1;
 
     let mut carry = 0u64;
-    let num_bits = if num_bits == 0 {
-        a.num_bits() as usize
-    } else {
-        num_bits
-    };
     let digits_count = (num_bits + w - 1) / w;
 
     (0..digits_count).map(move |i| {
@@ -73,7 +69,7 @@ pub fn msm_wnaf_g1_446(
     let digits_count = (num_bits + c - 1) / c;
     let scalar_digits = scalars
         .into_par_iter()
-        .flat_map_iter(|s| make_digits(s, c, num_bits))
+        .flat_map_iter(|s| make_digits(s.as_ref(), c, num_bits))
         .collect::<Vec<_>>();
 
     let zero = G1Affine {
@@ -237,3 +233,174 @@ pub fn msm_wnaf_g1_446(
                 total
             })
 }
+
+/// Multiples of fixed bases, precomputed once to speed up the multi-scalar multiplications with
+/// these bases.
+///
+/// For each base `P` and window `j`, the table holds `2^(w * j) * P`, where `w` is the window size
+/// in bits. A multi-scalar multiplication then accumulates the signed digits of all the windows
+/// in a single set of `2^(w - 1)` buckets, without the doublings of the variable-base algorithm.
+///
+/// The window size trades memory for time: the table holds `ceil((num_bits + 1) / w)` points per
+/// base, and each multiplication sums `2^(w - 1)` buckets.
+#[derive(Clone)]
+pub struct FixedBaseMsmTable<Zp, G: CurveGroupOps<Zp>> {
+    window_bits: usize,
+    num_windows: usize,
+    num_bits: usize,
+    table: Vec<G::Affine>,
+    __marker: PhantomData<Zp>,
+}
+
+impl<Zp, G: CurveGroupOps<Zp>> core::fmt::Debug for FixedBaseMsmTable<Zp, G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedBaseMsmTable")
+            .field("window_bits", &self.window_bits)
+            .field("num_windows", &self.num_windows)
+            .field("num_bases", &(self.table.len() / self.num_windows))
+            .finish()
+    }
+}
+
+impl<Zp: FieldOps, G: CurveGroupOps<Zp>> FixedBaseMsmTable<Zp, G> {
+    pub const MAX_WINDOW_BITS: usize = 16;
+
+    /// Builds the tables of `bases` with windows of `window_bits` bits.
+    pub fn new(bases: &[G::Affine], window_bits: usize) -> Self {
+        assert!(
+            (1..=Self::MAX_WINDOW_BITS).contains(&window_bits),
+            "window_bits must be in [1, {}]",
+            Self::MAX_WINDOW_BITS,
+        );
+
+        // The largest scalar is the modulus minus one
+        let max_scalar = (-Zp::ONE).to_bigint();
+        let max_scalar = max_scalar.as_ref();
+        let num_bits = max_scalar
+            .iter()
+            .rposition(|&limb| limb != 0)
+            .map_or(0, |i| {
+                64 * i + (64 - max_scalar[i].leading_zeros() as usize)
+            });
+
+        // One more bit than the scalars, so that the signed digit of the last window stays in
+        // [-2^(w - 1), 2^(w - 1)]
+        let num_windows = (num_bits + 1).div_ceil(window_bits);
+
+        let table = bases
+            .par_iter()
+            .flat_map_iter(|&base| {
+                let mut cur = G::projective(base);
+                (0..num_windows).map(move |_| {
+                    let point = cur.normalize();
+                    for _ in 0..window_bits {
+                        cur = cur.double();
+                    }
+                    point
+                })
+            })
+            .collect();
+
+        Self {
+            window_bits,
+            num_windows,
+            num_bits,
+            table,
+            __marker: PhantomData,
+        }
+    }
+
+    pub fn num_bases(&self) -> usize {
+        self.table.len() / self.num_windows
+    }
+
+    pub fn window_bits(&self) -> usize {
+        self.window_bits
+    }
+
+    /// Computes `sum_i scalars[i] * bases[start + i]`.
+    #[track_caller]
+    pub fn msm(&self, start: usize, scalars: &[Zp]) -> G {
+        assert!(start + scalars.len() <= self.num_bases());
+
+        let w = self.window_bits;
+        let num_windows = self.num_windows;
+        let num_buckets = 1 << (w - 1);
+        let table = &self.table[start * num_windows..][..scalars.len() * num_windows];
+
+        let buckets = scalars
+            .par_iter()
+            .zip(table.par_chunks(num_windows))
+            .fold(
+                || vec![G::ZERO; num_buckets],
+                |mut buckets, (&scalar, points)| {
+                    let scalar = scalar.to_bigint();
+                    for (digit, &point) in
+                        make_digits(scalar.as_ref(), w, self.num_bits + 1).zip(points)
+                    {
+                        use core::cmp::Ordering;
+                        match digit.cmp(&0) {
+                            Ordering::Greater => {
+                                buckets[digit as usize - 1] += G::projective(point)
+                            }
+                            Ordering::Less => {
+                                buckets[(-digit) as usize - 1] -= G::projective(point)
+                            }
+                            Ordering::Equal => (),
+                        }
+                    }
+                    buckets
+                },
+            )
+            .reduce(
+                || vec![G::ZERO; num_buckets],
+                |mut lhs, rhs| {
+                    for (lhs, rhs) in lhs.iter_mut().zip(rhs) {
+                        *lhs += rhs;
+                    }
+                    lhs
+                },
+            );
+
+        // sum_k (k + 1) * buckets[k]
+        let mut running_sum = G::ZERO;
+        let mut res = G::ZERO;
+        for bucket in buckets.into_iter().rev() {
+            running_sum += bucket;
+            res += running_sum;
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve_api::{Bls12_446, Curve};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_fixed_base_msm() {
+        type Zp = <Bls12_446 as Curve>::Zp;
+        type G1 = <Bls12_446 as Curve>::G1;
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let n = 50;
+        let bases = (0..n)
+            .map(|_| CurveGroupOps::normalize(G1::GENERATOR.mul_scalar(Zp::rand(rng))))
+            .collect::<Vec<_>>();
+        let mut scalars = (0..n).map(|_| Zp::rand(rng)).collect::<Vec<_>>();
+        scalars[0] = Zp::ZERO;
+        scalars[1] = -Zp::ONE;
+
+        for window_bits in [1, 4, 7] {
+            let table = FixedBaseMsmTable::<Zp, G1>::new(&bases, window_bits);
+            for start in [0, 3] {
+                let expected =
+                    <G1 as CurveGroupOps<Zp>>::multi_mul_scalar(&bases[start..], &scalars[start..]);
+                assert_eq!(table.msm(start, &scalars[start..]), expected);
+            }
+        }
+    }
+}
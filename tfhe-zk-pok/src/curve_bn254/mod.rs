@@ -0,0 +1,535 @@
+//! The BN254 curve, also known as alt_bn128, whose pairings are available as precompiles on EVM
+//! chains.
+//!
+//! The definitions follow the `ark-bn254` crate of arkworks, so that they can be used with the
+//! arkworks version this crate depends on.
+//!
+//! This curve provides about 100 bits of security, less than [`crate::curve_446`].
+
+#![allow(non_local_definitions)]
+
+use ark_ec::bn::{Bn, BnConfig, TwistType};
+use ark_ff::fields::*;
+use ark_ff::MontFp;
+
+#[derive(MontConfig)]
+#[modulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617"]
+#[generator = "5"]
+#[small_subgroup_base = "3"]
+#[small_subgroup_power = "2"]
+pub struct FrConfig;
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+#[derive(MontConfig)]
+#[modulus = "21888242871839275222246405745257275088696311157297823662689037894645226208583"]
+#[generator = "3"]
+pub struct FqConfig;
+pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+pub type Fq2 = Fp2<Fq2Config>;
+
+pub struct Fq2Config;
+
+impl Fp2Config for Fq2Config {
+    type Fp = Fq;
+
+    /// NONRESIDUE = -1
+    const NONRESIDUE: Fq = MontFp!("-1");
+
+    /// Coefficients for the Frobenius automorphism.
+    const FROBENIUS_COEFF_FP2_C1: &'static [Fq] = &[
+        // NONRESIDUE**(((q^0) - 1) / 2)
+        Fq::ONE,
+        // NONRESIDUE**(((q^1) - 1) / 2)
+        MontFp!("-1"),
+    ];
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_in_place(fe: &mut Self::Fp) -> &mut Self::Fp {
+        fe.neg_in_place()
+    }
+}
+
+pub type Fq6 = Fp6<Fq6Config>;
+
+#[derive(Clone, Copy)]
+pub struct Fq6Config;
+
+impl Fp6Config for Fq6Config {
+    type Fp2Config = Fq2Config;
+
+    /// NONRESIDUE = U+9
+    const NONRESIDUE: Fq2 = Fq2::new(MontFp!("9"), Fq::ONE);
+
+    const FROBENIUS_COEFF_FP6_C1: &'static [Fq2] = &[
+        // Fp2::NONRESIDUE^(((q^0) - 1) / 3)
+        Fq2::new(Fq::ONE, Fq::ZERO),
+        // Fp2::NONRESIDUE^(((q^1) - 1) / 3)
+        Fq2::new(
+            MontFp!(
+                "21575463638280843010398324269430826099269044274347216827212613867836435027261"
+            ),
+            MontFp!(
+                "10307601595873709700152284273816112264069230130616436755625194854815875713954"
+            ),
+        ),
+        // Fp2::NONRESIDUE^(((q^2) - 1) / 3)
+        Fq2::new(
+            MontFp!(
+                "21888242871839275220042445260109153167277707414472061641714758635765020556616"
+            ),
+            Fq::ZERO,
+        ),
+        // Fp2::NONRESIDUE^(((q^3) - 1) / 3)
+        Fq2::new(
+            MontFp!("3772000881919853776433695186713858239009073593817195771773381919316419345261"),
+            MontFp!("2236595495967245188281701248203181795121068902605861227855261137820944008926"),
+        ),
+        // Fp2::NONRESIDUE^(((q^4) - 1) / 3)
+        Fq2::new(
+            MontFp!("2203960485148121921418603742825762020974279258880205651966"),
+            Fq::ZERO,
+        ),
+        // Fp2::NONRESIDUE^(((q^5) - 1) / 3)
+        Fq2::new(
+            MontFp!(
+                "18429021223477853657660792034369865839114504446431234726392080002137598044644"
+            ),
+            MontFp!("9344045779998320333812420223237981029506012124075525679208581902008406485703"),
+        ),
+    ];
+
+    const FROBENIUS_COEFF_FP6_C2: &'static [Fq2] = &[
+        // Fp2::NONRESIDUE^((2*(q^0) - 2) / 3)
+        Fq2::new(Fq::ONE, Fq::ZERO),
+        // Fp2::NONRESIDUE^((2*(q^1) - 2) / 3)
+        Fq2::new(
+            MontFp!("2581911344467009335267311115468803099551665605076196740867805258568234346338"),
+            MontFp!(
+                "19937756971775647987995932169929341994314640652964949448313374472400716661030"
+            ),
+        ),
+        // Fp2::NONRESIDUE^((2*(q^2) - 2) / 3)
+        Fq2::new(
+            MontFp!("2203960485148121921418603742825762020974279258880205651966"),
+            Fq::ZERO,
+        ),
+        // Fp2::NONRESIDUE^((2*(q^3) - 2) / 3)
+        Fq2::new(
+            MontFp!("5324479202449903542726783395506214481928257762400643279780343368557297135718"),
+            MontFp!(
+                "16208900380737693084919495127334387981393726419856888799917914180988844123039"
+            ),
+        ),
+        // Fp2::NONRESIDUE^((2*(q^4) - 2) / 3)
+        Fq2::new(
+            MontFp!(
+                "21888242871839275220042445260109153167277707414472061641714758635765020556616"
+            ),
+            Fq::ZERO,
+        ),
+        // Fp2::NONRESIDUE^((2*(q^5) - 2) / 3)
+        Fq2::new(
+            MontFp!(
+                "13981852324922362344252311234282257507216387789820983642040889267519694726527"
+            ),
+            MontFp!("7629828391165209371577384193250820201684255241773809077146787135900891633097"),
+        ),
+    ];
+
+    #[inline(always)]
+    fn mul_fp2_by_nonresidue_in_place(fe: &mut Fq2) -> &mut Fq2 {
+        // (c0+u*c1)*(9+u) = (9*c0-c1)+u*(9*c1+c0)
+        let mut f = *fe;
+        f.double_in_place().double_in_place().double_in_place();
+        let mut c0 = fe.c1;
+        Fq2Config::mul_fp_by_nonresidue_in_place(&mut c0);
+        c0 += &f.c0;
+        c0 += &fe.c0;
+        let c1 = f.c1 + fe.c1 + fe.c0;
+        *fe = Fq2::new(c0, c1);
+        fe
+    }
+}
+
+pub type Fq12 = Fp12<Fq12Config>;
+
+#[derive(Clone, Copy)]
+pub struct Fq12Config;
+
+impl Fp12Config for Fq12Config {
+    type Fp6Config = Fq6Config;
+
+    const NONRESIDUE: Fq6 = Fq6::new(Fq2::ZERO, Fq2::ONE, Fq2::ZERO);
+
+    const FROBENIUS_COEFF_FP12_C1: &'static [Fq2] = &[
+        // Fp2::NONRESIDUE^(((q^0) - 1) / 6)
+        Fq2::new(Fq::ONE, Fq::ZERO),
+        // Fp2::NONRESIDUE^(((q^1) - 1) / 6)
+        Fq2::new(
+            MontFp!("8376118865763821496583973867626364092589906065868298776909617916018768340080"),
+            MontFp!(
+                "16469823323077808223889137241176536799009286646108169935659301613961712198316"
+            ),
+        ),
+        // Fp2::NONRESIDUE^(((q^2) - 1) / 6)
+        Fq2::new(
+            MontFp!(
+                "21888242871839275220042445260109153167277707414472061641714758635765020556617"
+            ),
+            Fq::ZERO,
+        ),
+        // Fp2::NONRESIDUE^(((q^3) - 1) / 6)
+        Fq2::new(
+            MontFp!(
+                "11697423496358154304825782922584725312912383441159505038794027105778954184319"
+            ),
+            MontFp!("303847389135065887422783454877609941456349188919719272345083954437860409601"),
+        ),
+        // Fp2::NONRESIDUE^(((q^4) - 1) / 6)
+        Fq2::new(
+            MontFp!(
+                "21888242871839275220042445260109153167277707414472061641714758635765020556616"
+            ),
+            Fq::ZERO,
+        ),
+        // Fp2::NONRESIDUE^(((q^5) - 1) / 6)
+        Fq2::new(
+            MontFp!("3321304630594332808241809054958361220322477375291206261884409189760185844239"),
+            MontFp!("5722266937896532885780051958958348231143373700109372999374820235121374419868"),
+        ),
+        // Fp2::NONRESIDUE^(((q^6) - 1) / 6)
+        Fq2::new(MontFp!("-1"), Fq::ZERO),
+        // Fp2::NONRESIDUE^(((q^7) - 1) / 6)
+        Fq2::new(
+            MontFp!(
+                "13512124006075453725662431877630910996106405091429524885779419978626457868503"
+            ),
+            MontFp!("5418419548761466998357268504080738289687024511189653727029736280683514010267"),
+        ),
+        // Fp2::NONRESIDUE^(((q^8) - 1) / 6)
+        Fq2::new(
+            MontFp!("2203960485148121921418603742825762020974279258880205651966"),
+            Fq::ZERO,
+        ),
+        // Fp2::NONRESIDUE^(((q^9) - 1) / 6)
+        Fq2::new(
+            MontFp!(
+                "10190819375481120917420622822672549775783927716138318623895010788866272024264"
+            ),
+            MontFp!(
+                "21584395482704209334823622290379665147239961968378104390343953940207365798982"
+            ),
+        ),
+        // Fp2::NONRESIDUE^(((q^10) - 1) / 6)
+        Fq2::new(
+            MontFp!("2203960485148121921418603742825762020974279258880205651967"),
+            Fq::ZERO,
+        ),
+        // Fp2::NONRESIDUE^(((q^11) - 1) / 6)
+        Fq2::new(
+            MontFp!(
+                "18566938241244942414004596690298913868373833782006617400804628704885040364344"
+            ),
+            MontFp!(
+                "16165975933942742336466353786298926857552937457188450663314217659523851788715"
+            ),
+        ),
+    ];
+}
+
+pub struct Config;
+
+impl BnConfig for Config {
+    const X: &'static [u64] = &[4965661367192848881];
+    /// `x` is positive.
+    const X_IS_NEGATIVE: bool = false;
+    const ATE_LOOP_COUNT: &'static [i8] = &[
+        0, 0, 0, 1, 0, 1, 0, -1, 0, 0, -1, 0, 0, 0, 1, 0, 0, -1, 0, -1, 0, 0, 0, 1, 0, -1, 0, 0, 0,
+        0, -1, 0, 0, 1, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, -1, 0,
+        -1, 0, 0, 0, 1, 0, 1, 1,
+    ];
+
+    const TWIST_MUL_BY_Q_X: Fq2 = Fq2::new(
+        MontFp!("21575463638280843010398324269430826099269044274347216827212613867836435027261"),
+        MontFp!("10307601595873709700152284273816112264069230130616436755625194854815875713954"),
+    );
+    const TWIST_MUL_BY_Q_Y: Fq2 = Fq2::new(
+        MontFp!("2821565182194536844548159561693502659359617185244120367078079554186484126554"),
+        MontFp!("3505843767911556378687030309984248845540243509899259641013678093033130930403"),
+    );
+    const TWIST_TYPE: TwistType = TwistType::D;
+    type Fp = Fq;
+    type Fp2Config = Fq2Config;
+    type Fp6Config = Fq6Config;
+    type Fp12Config = Fq12Config;
+    type G1Config = g1::Config;
+    type G2Config = g2::Config;
+}
+
+pub type Bn254 = Bn<Config>;
+pub use g1::G1Affine;
+pub use g2::G2Affine;
+
+pub mod g1 {
+    use super::{Fq, Fr};
+    use ark_ec::models::CurveConfig;
+    use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+    use ark_ec::AdditiveGroup;
+    use ark_ff::{Field, MontFp, Zero};
+
+    #[derive(Clone, Default, PartialEq, Eq)]
+    pub struct Config;
+
+    pub type G1Affine = Affine<Config>;
+    pub type G1Projective = Projective<Config>;
+
+    impl CurveConfig for Config {
+        type BaseField = Fq;
+        type ScalarField = Fr;
+
+        /// COFACTOR = 1
+        const COFACTOR: &'static [u64] = &[0x1];
+
+        /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+        const COFACTOR_INV: Fr = Fr::ONE;
+    }
+
+    impl SWCurveConfig for Config {
+        /// COEFF_A = 0
+        const COEFF_A: Fq = Fq::ZERO;
+
+        /// COEFF_B = 3
+        const COEFF_B: Fq = MontFp!("3");
+
+        /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+        const GENERATOR: G1Affine = G1Affine::new_unchecked(G1_GENERATOR_X, G1_GENERATOR_Y);
+
+        #[inline(always)]
+        fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+            Self::BaseField::zero()
+        }
+
+        #[inline]
+        fn is_in_correct_subgroup_assuming_on_curve(_p: &G1Affine) -> bool {
+            // G1 = E(Fq) so if the point is on the curve, it is also in the subgroup.
+            true
+        }
+    }
+
+    /// G1_GENERATOR_X = 1
+    pub const G1_GENERATOR_X: Fq = Fq::ONE;
+
+    /// G1_GENERATOR_Y = 2
+    pub const G1_GENERATOR_Y: Fq = MontFp!("2");
+}
+
+pub mod g2 {
+    use super::{Fq, Fq2, Fr};
+    use ark_ec::models::CurveConfig;
+    use ark_ec::short_weierstrass::{Affine, Projective, SWCurveConfig};
+    use ark_ec::{AdditiveGroup, AffineRepr};
+    use ark_ff::{Field, MontFp, Zero};
+
+    pub type G2Affine = Affine<Config>;
+    pub type G2Projective = Projective<Config>;
+
+    #[derive(Clone, Default, PartialEq, Eq)]
+    pub struct Config;
+
+    impl CurveConfig for Config {
+        type BaseField = Fq2;
+        type ScalarField = Fr;
+
+        /// COFACTOR = (36 * X^4) + (36 * X^3) + (30 * X^2) + 6*X + 1
+        /// 21888242871839275222246405745257275088844257914179612981679871602714643921549
+        #[rustfmt::skip]
+        const COFACTOR: &'static [u64] = &[
+            0x345f2299c0f9fa8d,
+            0x06ceecda572a2489,
+            0xb85045b68181585e,
+            0x30644e72e131a029,
+        ];
+
+        /// COFACTOR_INV = COFACTOR^{-1} mod r
+        const COFACTOR_INV: Fr = MontFp!(
+            "10944121435919637613327163357776759465618812564592884533313067514031822496649"
+        );
+    }
+
+    impl SWCurveConfig for Config {
+        /// COEFF_A = [0, 0]
+        const COEFF_A: Fq2 = Fq2::ZERO;
+
+        /// COEFF_B = 3/(u+9)
+        /// (19485874751759354771024239261021720505790618469301721065564631296452457478373, 266929791119991161246907387137283842545076965332900288569378510910307636690)
+        const COEFF_B: Fq2 = Fq2::new(
+            MontFp!(
+                "19485874751759354771024239261021720505790618469301721065564631296452457478373"
+            ),
+            MontFp!("266929791119991161246907387137283842545076965332900288569378510910307636690"),
+        );
+
+        /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+        const GENERATOR: G2Affine = G2Affine::new_unchecked(G2_GENERATOR_X, G2_GENERATOR_Y);
+
+        #[inline(always)]
+        fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+            Self::BaseField::zero()
+        }
+
+        fn is_in_correct_subgroup_assuming_on_curve(point: &G2Affine) -> bool {
+            // Subgroup check from section 4.3 of https://eprint.iacr.org/2022/352.pdf.
+            //
+            // Checks that [p]P = [6X^2]P
+
+            let x_times_point = point.mul_bigint(SIX_X_SQUARED);
+            let p_times_point = p_power_endomorphism(point);
+            x_times_point.eq(&p_times_point)
+        }
+    }
+
+    pub const G2_GENERATOR_X: Fq2 = Fq2::new(G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
+    pub const G2_GENERATOR_Y: Fq2 = Fq2::new(G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
+
+    /// G2_GENERATOR_X_C0 =
+    /// 10857046999023057135944570762232829481370756359578518086990519993285655852781
+    pub const G2_GENERATOR_X_C0: Fq =
+        MontFp!("10857046999023057135944570762232829481370756359578518086990519993285655852781");
+
+    /// G2_GENERATOR_X_C1 =
+    /// 11559732032986387107991004021392285783925812861821192530917403151452391805634
+    pub const G2_GENERATOR_X_C1: Fq =
+        MontFp!("11559732032986387107991004021392285783925812861821192530917403151452391805634");
+
+    /// G2_GENERATOR_Y_C0 =
+    /// 8495653923123431417604973247489272438418190587263600148770280649306958101930
+    pub const G2_GENERATOR_Y_C0: Fq =
+        MontFp!("8495653923123431417604973247489272438418190587263600148770280649306958101930");
+
+    /// G2_GENERATOR_Y_C1 =
+    /// 4082367875863433681332203403145435568316851327593401208105741076214120093531
+    pub const G2_GENERATOR_Y_C1: Fq =
+        MontFp!("4082367875863433681332203403145435568316851327593401208105741076214120093531");
+
+    // PSI_X = (u+9)^((p-1)/3) = TWIST_MUL_BY_Q_X
+    const P_POWER_ENDOMORPHISM_COEFF_0: Fq2 = Fq2::new(
+        MontFp!("21575463638280843010398324269430826099269044274347216827212613867836435027261"),
+        MontFp!("10307601595873709700152284273816112264069230130616436755625194854815875713954"),
+    );
+
+    // PSI_Y = (u+9)^((p-1)/2) = TWIST_MUL_BY_Q_Y
+    const P_POWER_ENDOMORPHISM_COEFF_1: Fq2 = Fq2::new(
+        MontFp!("2821565182194536844548159561693502659359617185244120367078079554186484126554"),
+        MontFp!("3505843767911556378687030309984248845540243509899259641013678093033130930403"),
+    );
+
+    // Integer representation of 6x^2 = t - 1
+    const SIX_X_SQUARED: [u64; 2] = [17887900258952609094, 8020209761171036667];
+
+    /// psi(P) is the untwist-Frobenius-twist endomorphism on E'(Fq2)
+    fn p_power_endomorphism(p: &Affine<Config>) -> Affine<Config> {
+        // Maps (x,y) -> (x^p * (u+9)^((p-1)/3), y^p * (u+9)^((p-1)/2))
+
+        let mut res = *p;
+        res.x.frobenius_map_in_place(1);
+        res.y.frobenius_map_in_place(1);
+
+        res.x *= P_POWER_ENDOMORPHISM_COEFF_0;
+        res.y *= P_POWER_ENDOMORPHISM_COEFF_1;
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Known answer tests against the `ark-bn254` crate.
+    //!
+    //! `ark-bn254` is built on the upstream arkworks crates, whose traits are not the ones of this
+    //! crate, so the values are compared through their canonical serialization.
+
+    use super::*;
+    use ark_ec::pairing::Pairing;
+    use ark_ec::short_weierstrass::SWCurveConfig;
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{FftField, PrimeField};
+    use ark_serialize::CanonicalSerialize;
+
+    use ark_ec_upstream::pairing::Pairing as _;
+    use ark_ec_upstream::short_weierstrass::SWCurveConfig as _;
+    use ark_ec_upstream::AffineRepr as _;
+    use ark_ff_upstream::{FftField as _, PrimeField as _};
+
+    fn to_bytes(value: &impl CanonicalSerialize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.serialize_uncompressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_field_constants() {
+        assert_eq!(Fr::MODULUS.to_string(), ark_bn254::Fr::MODULUS.to_string());
+        assert_eq!(Fq::MODULUS.to_string(), ark_bn254::Fq::MODULUS.to_string());
+        assert_eq!(
+            to_bytes(&Fr::GENERATOR),
+            to_bytes(&ark_bn254::Fr::GENERATOR)
+        );
+        assert_eq!(
+            to_bytes(&Fr::TWO_ADIC_ROOT_OF_UNITY),
+            to_bytes(&ark_bn254::Fr::TWO_ADIC_ROOT_OF_UNITY)
+        );
+        assert_eq!(
+            to_bytes(&Fq::GENERATOR),
+            to_bytes(&ark_bn254::Fq::GENERATOR)
+        );
+    }
+
+    #[test]
+    fn test_curve_constants() {
+        assert_eq!(
+            to_bytes(&g1::Config::COEFF_B),
+            to_bytes(&ark_bn254::g1::Config::COEFF_B)
+        );
+        assert_eq!(
+            to_bytes(&g2::Config::COEFF_B),
+            to_bytes(&ark_bn254::g2::Config::COEFF_B)
+        );
+
+        assert_eq!(
+            to_bytes(&G1Affine::generator()),
+            to_bytes(&ark_bn254::G1Affine::generator())
+        );
+        assert_eq!(
+            to_bytes(&G2Affine::generator()),
+            to_bytes(&ark_bn254::G2Affine::generator())
+        );
+
+        // The generators of the curve API are defined separately
+        assert_eq!(
+            crate::curve_api::bn254::G1::GENERATOR.inner.into_affine(),
+            G1Affine::generator()
+        );
+        assert_eq!(
+            crate::curve_api::bn254::G2::GENERATOR.inner.into_affine(),
+            G2Affine::generator()
+        );
+    }
+
+    #[test]
+    fn test_pairing() {
+        let a = 0x1234_5678_9abc_def0u64;
+        let b = 0x0fed_cba9_8765_4321u64;
+
+        let pairing = Bn254::pairing(
+            G1Affine::generator() * Fr::from(a),
+            G2Affine::generator() * Fr::from(b),
+        );
+        let expected = ark_bn254::Bn254::pairing(
+            ark_bn254::G1Affine::generator() * ark_bn254::Fr::from(a),
+            ark_bn254::G2Affine::generator() * ark_bn254::Fr::from(b),
+        );
+
+        assert_eq!(to_bytes(&pairing), to_bytes(&expected));
+    }
+}
@@ -1,7 +1,12 @@
-pub use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+pub use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate,
+};
 
+pub mod backward_compatibility;
 pub mod curve_446;
 pub mod curve_api;
+pub mod curve_bn254;
 pub mod proofs;
+pub mod serialization;
 
 mod four_squares;
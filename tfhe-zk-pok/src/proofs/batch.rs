@@ -0,0 +1,176 @@
+//! Batch verification of pairing equations.
+//!
+//! The verification of a proof boils down to a few pairing equations of the form
+//! `sum_i e(p_i, q_i) = 0`. Equations coming from many proofs are combined into a single one by
+//! scaling each of them with a random scalar. The terms sharing one of their group elements across
+//! proofs, e.g. the generators or the public parameters, are then merged so that the number of
+//! pairings grows with the number of group elements specific to each proof only.
+
+use super::*;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Terms `(p, q)` of an equation `sum e(p, q) = 0`.
+pub(crate) type PairingEquation<G> = Vec<(<G as Curve>::G1, <G as Curve>::G2)>;
+
+/// Checks that the sum of the pairings of the terms is zero.
+pub(crate) fn check_equation<G: Curve>(terms: &[(G::G1, G::G2)]) -> bool {
+    let Some((&(p0, q0), rest)) = terms.split_first() else {
+        return true;
+    };
+
+    let (lhs, rhs) = rayon::join(
+        || G::Gt::pairing(-p0, q0),
+        || {
+            rest.par_iter()
+                .map(|&(p, q)| G::Gt::pairing(p, q))
+                .reduce_with(|x, y| x + y)
+                .unwrap_or_else(|| G::Gt::pairing(G::G1::ZERO, G::G2::GENERATOR))
+        },
+    );
+
+    lhs == rhs
+}
+
+struct Term<G: Curve> {
+    item: usize,
+    p: G::G1,
+    q: G::G2,
+    p_bytes: Vec<u8>,
+    q_bytes: Vec<u8>,
+    scalar: G::Zp,
+}
+
+/// Combines the terms with the random scalars of their equation and checks the resulting equation.
+fn check_combined<G: Curve>(terms: &[&Term<G>]) -> bool {
+    let mut by_q = HashMap::<&[u8], Vec<&Term<G>>>::new();
+    for &term in terms {
+        by_q.entry(term.q_bytes.as_slice()).or_default().push(term);
+    }
+
+    let mut combined = Vec::new();
+    let mut by_p = HashMap::<&[u8], Vec<&Term<G>>>::new();
+    for group in by_q.into_values() {
+        if group.len() > 1 {
+            let p = group
+                .iter()
+                .map(|term| term.p.mul_scalar(term.scalar))
+                .sum::<G::G1>();
+            combined.push((p, group[0].q));
+        } else {
+            by_p.entry(group[0].p_bytes.as_slice())
+                .or_default()
+                .push(group[0]);
+        }
+    }
+
+    for group in by_p.into_values() {
+        if group.len() > 1 {
+            let q = group
+                .iter()
+                .map(|term| term.q.mul_scalar(term.scalar))
+                .sum::<G::G2>();
+            combined.push((group[0].p, q));
+        } else {
+            combined.push((group[0].p.mul_scalar(group[0].scalar), group[0].q));
+        }
+    }
+
+    check_equation::<G>(&combined)
+}
+
+fn find_invalid_in<G: Curve>(items: &[usize], terms: &[&Term<G>]) -> Vec<usize> {
+    if check_combined(terms) {
+        return Vec::new();
+    }
+
+    if items.len() == 1 {
+        return items.to_vec();
+    }
+
+    let (left, right) = items.split_at(items.len() / 2);
+    let (left_terms, right_terms): (Vec<_>, Vec<_>) = terms
+        .iter()
+        .copied()
+        .partition(|term| left.binary_search(&term.item).is_ok());
+
+    let (mut left_invalid, right_invalid) = rayon::join(
+        || find_invalid_in(left, &left_terms),
+        || find_invalid_in(right, &right_terms),
+    );
+    left_invalid.extend(right_invalid);
+    left_invalid
+}
+
+/// Returns the indices of the items for which at least one equation does not hold, `None` marking
+/// items that were already found invalid.
+///
+/// All the equations are checked at once, and the items are split recursively only when the
+/// combined check fails.
+pub(crate) fn find_invalid<G: Curve>(items: Vec<Option<Vec<PairingEquation<G>>>>) -> Vec<usize> {
+    let mut invalid = Vec::new();
+    let mut valid_items = Vec::new();
+    let mut terms = Vec::new();
+
+    for (item, equations) in items.into_iter().enumerate() {
+        let Some(equations) = equations else {
+            invalid.push(item);
+            continue;
+        };
+        valid_items.push(item);
+        for (equation_index, equation) in equations.into_iter().enumerate() {
+            for (p, q) in equation {
+                terms.push((item, equation_index, p, q));
+            }
+        }
+    }
+
+    let mut terms = terms
+        .into_par_iter()
+        .map(|(item, equation_index, p, q)| {
+            (
+                equation_index,
+                Term {
+                    item,
+                    p,
+                    q,
+                    p_bytes: p.to_bytes().as_ref().to_vec(),
+                    q_bytes: q.to_bytes().as_ref().to_vec(),
+                    scalar: G::Zp::ZERO,
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // The scalars depend on all the terms so that they cannot be known when creating the proofs
+    let equation_ids = terms
+        .iter()
+        .map(|(equation_index, term)| (term.item, *equation_index))
+        .collect::<std::collections::BTreeSet<_>>();
+    let mut scalars = vec![G::Zp::ZERO; equation_ids.len()];
+    let terms_bytes = terms
+        .iter()
+        .flat_map(|(_, term)| [term.p_bytes.as_slice(), term.q_bytes.as_slice()])
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>();
+    G::Zp::hash_128bit(
+        &mut scalars,
+        &[b"tfhe-zk-pok-batch-verify".as_slice(), &terms_bytes],
+    );
+
+    let scalar_of = equation_ids
+        .into_iter()
+        .zip(scalars)
+        .collect::<HashMap<_, _>>();
+    for (equation_index, term) in &mut terms {
+        term.scalar = scalar_of[&(term.item, *equation_index)];
+    }
+
+    let terms = terms.iter().map(|(_, term)| term).collect::<Vec<_>>();
+    if !valid_items.is_empty() {
+        invalid.extend(find_invalid_in(&valid_items, &terms));
+    }
+    invalid.sort_unstable();
+    invalid
+}
@@ -0,0 +1,260 @@
+//! Multi-party generation of the public parameters.
+//!
+//! The public parameters contain the powers `g^(alpha^i)` and `g_hat^(alpha^i)` of a secret scalar
+//! `alpha`, anyone knowing `alpha` can forge proofs. In a ceremony, each participant multiplies
+//! `alpha` by a secret scalar `tau` of their own, by raising the i-th powers to `tau^i`, and
+//! publishes a proof that the update is well formed and that they know `tau`. As long as one of the
+//! participants discards their `tau`, nobody knows the final `alpha`.
+//!
+//! The proof of knowledge of `tau` is a Schnorr proof on `g^alpha`, and the structure of the
+//! updated parameters is checked with pairings.
+
+use super::*;
+use rayon::prelude::*;
+
+/// Proof that [`UpdatableParams::update`] was applied correctly by a participant knowing the
+/// update scalar.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::Zp: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::Zp: serde::Serialize"
+))]
+pub struct UpdateProof<G: Curve> {
+    big_r: G::G1,
+    s: G::Zp,
+}
+
+/// Public parameters that can be rerandomized by the participants of a ceremony.
+#[allow(clippy::result_unit_err)]
+pub trait UpdatableParams<G: Curve>: Sized {
+    /// Rerandomizes the parameters with a fresh secret scalar, which is dropped before returning.
+    fn update(&self, rng: &mut dyn RngCore) -> (Self, UpdateProof<G>);
+
+    /// Checks that `updated` was obtained from `self` by a participant knowing the update scalar,
+    /// and that it is well formed.
+    fn verify_update(&self, updated: &Self, proof: &UpdateProof<G>) -> Result<(), ()>;
+
+    /// Checks that the group elements are successive powers of the same scalar.
+    fn is_well_formed(&self) -> bool;
+}
+
+/// Verifies the full transcript of a ceremony, starting from `initial` and where each entry holds
+/// the parameters published by a participant along with their proof.
+///
+/// The last parameters of the transcript are the ones to use.
+#[allow(clippy::result_unit_err)]
+pub fn verify_transcript<G: Curve, P: UpdatableParams<G>>(
+    initial: &P,
+    updates: &[(P, UpdateProof<G>)],
+) -> Result<(), ()> {
+    if !initial.is_well_formed() {
+        return Err(());
+    }
+
+    let mut current = initial;
+    for (updated, proof) in updates {
+        current.verify_update(updated, proof)?;
+        current = updated;
+    }
+
+    Ok(())
+}
+
+fn same_g1<G: Curve>(x: G::G1, y: G::G1) -> bool {
+    x.to_bytes().as_ref() == y.to_bytes().as_ref()
+}
+
+fn schnorr_challenge<G: Curve>(base: G::G1, target: G::G1, big_r: G::G1) -> G::Zp {
+    let mut c = [G::Zp::ZERO];
+    G::Zp::hash(
+        &mut c,
+        &[
+            b"tfhe-zk-pok-crs-update",
+            base.to_bytes().as_ref(),
+            target.to_bytes().as_ref(),
+            big_r.to_bytes().as_ref(),
+        ],
+    );
+    c[0]
+}
+
+impl<G: Curve> GroupElements<G> {
+    pub(super) fn update(&self, rng: &mut dyn RngCore) -> (Self, UpdateProof<G>) {
+        let n = self.message_len;
+        let tau = G::Zp::rand(rng);
+
+        let mut tau_powers = Vec::with_capacity(2 * n);
+        let mut tau_cur = tau;
+        for _ in 0..2 * n {
+            tau_powers.push(tau_cur);
+            tau_cur = tau_cur * tau;
+        }
+
+        let (g_list, g_hat_list) = rayon::join(
+            || {
+                self.g_list
+                    .0
+                    .par_iter()
+                    .zip(tau_powers.par_iter())
+                    .map(|(&g, &tau_pow)| G::G1::projective(g).mul_scalar(tau_pow).normalize())
+                    .collect::<Vec<_>>()
+            },
+            || {
+                self.g_hat_list
+                    .0
+                    .par_iter()
+                    .zip(tau_powers.par_iter())
+                    .map(|(&g_hat, &tau_pow)| {
+                        G::G2::projective(g_hat).mul_scalar(tau_pow).normalize()
+                    })
+                    .collect::<Vec<_>>()
+            },
+        );
+
+        let base = G::G1::projective(self.g_list[1]);
+        let target = G::G1::projective(g_list[0]);
+        let k = G::Zp::rand(rng);
+        let big_r = base.mul_scalar(k);
+        let c = schnorr_challenge::<G>(base, target, big_r);
+        let s = k + c * tau;
+
+        (Self::from_vec(g_list, g_hat_list), UpdateProof { big_r, s })
+    }
+
+    pub(super) fn verify_update(&self, updated: &Self, proof: &UpdateProof<G>) -> Result<(), ()> {
+        if updated.message_len != self.message_len || !updated.is_well_formed() {
+            return Err(());
+        }
+
+        let &UpdateProof { big_r, s } = proof;
+        let base = G::G1::projective(self.g_list[1]);
+        let target = G::G1::projective(updated.g_list[1]);
+        let c = schnorr_challenge::<G>(base, target, big_r);
+
+        if same_g1::<G>(base.mul_scalar(s), big_r + target.mul_scalar(c)) {
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    pub(super) fn is_well_formed(&self) -> bool {
+        let n = self.message_len;
+        let g_list = &self.g_list;
+        let g_hat_list = &self.g_hat_list;
+
+        if n == 0 || g_list.0.len() != 2 * n || g_hat_list.0.len() != n {
+            return false;
+        }
+
+        let g = G::G1::GENERATOR;
+        let g_hat = G::G2::GENERATOR;
+        let g_1 = G::G1::projective(g_list[1]);
+        let g_hat_1 = G::G2::projective(g_hat_list[1]);
+
+        // The power n + 1 is not part of the parameters
+        if same_g1::<G>(g_1, G::G1::ZERO)
+            || !same_g1::<G>(G::G1::projective(g_list[n + 1]), G::G1::ZERO)
+        {
+            return false;
+        }
+
+        if G::Gt::pairing(g_1, g_hat) != G::Gt::pairing(g, g_hat_1) {
+            return false;
+        }
+
+        // Indices i such that g_list[i] and g_list[i + 1] are consecutive powers
+        let g_indices = (1..2 * n)
+            .filter(|&i| i != n && i != n + 1)
+            .collect::<Vec<_>>();
+        let g_lhs = g_indices.iter().map(|&i| g_list[i + 1]).collect::<Vec<_>>();
+        let g_rhs = g_indices.iter().map(|&i| g_list[i]).collect::<Vec<_>>();
+
+        let mut bytes = Vec::new();
+        for &g in &g_list.0 {
+            bytes.extend_from_slice(G::G1::projective(g).to_bytes().as_ref());
+        }
+        for &g_hat in &g_hat_list.0 {
+            bytes.extend_from_slice(G::G2::projective(g_hat).to_bytes().as_ref());
+        }
+
+        // The consecutive powers are checked all at once with a random linear combination
+        let mut r = vec![G::Zp::ZERO; g_indices.len() + n - 1];
+        G::Zp::hash_128bit(&mut r, &[b"tfhe-zk-pok-crs-well-formed", &bytes]);
+        let (r_g, r_g_hat) = r.split_at(g_indices.len());
+
+        let (g_ok, g_hat_ok) = rayon::join(
+            || {
+                let lhs = G::G1::multi_mul_scalar(&g_lhs, r_g);
+                let rhs = G::G1::multi_mul_scalar(&g_rhs, r_g);
+                G::Gt::pairing(lhs, g_hat) == G::Gt::pairing(rhs, g_hat_1)
+            },
+            || {
+                let lhs = G::G2::multi_mul_scalar(&g_hat_list.0[1..], r_g_hat);
+                let rhs = G::G2::multi_mul_scalar(&g_hat_list.0[..n - 1], r_g_hat);
+                G::Gt::pairing(g, lhs) == G::Gt::pairing(g_1, rhs)
+            },
+        );
+
+        // Powers n and n + 2 are on each side of the missing power
+        let gap_ok = n < 2 || {
+            let g_hat_2 = G::G2::projective(g_hat_list[2]);
+            G::Gt::pairing(G::G1::projective(g_list[n + 2]), g_hat)
+                == G::Gt::pairing(G::G1::projective(g_list[n]), g_hat_2)
+        };
+
+        g_ok && g_hat_ok && gap_ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn test_ceremony<G: Curve>() {
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let initial = range::crs_gen::<G>(16, rng);
+        assert!(initial.is_well_formed());
+
+        let mut transcript = Vec::new();
+        let mut current = initial.clone();
+        for _ in 0..3 {
+            let (updated, proof) = current.update(rng);
+            current.verify_update(&updated, &proof).unwrap();
+            transcript.push((updated.clone(), proof));
+            current = updated;
+        }
+
+        verify_transcript(&initial, &transcript).unwrap();
+
+        // The updated parameters can be used to prove and verify
+        let (public_commit, private_commit) = range::commit(9, 4, &current, rng);
+        let proof = range::prove((&current, &public_commit), &private_commit, rng);
+        range::verify(&proof, (&current, &public_commit)).unwrap();
+
+        // A proof does not verify for another update
+        let (other, _) = initial.update(rng);
+        assert!(initial.verify_update(&other, &transcript[0].1).is_err());
+
+        // Skipping a participant breaks the transcript
+        assert!(verify_transcript(&initial, &transcript[1..]).is_err());
+    }
+
+    #[test]
+    fn test_ceremony_bls12_446() {
+        test_ceremony::<crate::curve_api::Bls12_446>();
+    }
+
+    #[test]
+    fn test_ceremony_bls12_381() {
+        test_ceremony::<crate::curve_api::Bls12_381>();
+    }
+
+    #[test]
+    fn test_ceremony_bn254() {
+        test_ceremony::<crate::curve_api::Bn254>();
+    }
+}
@@ -0,0 +1,313 @@
+//! Proof of decryption of an LWE ciphertext.
+//!
+//! Given a compact public key `(a, b)` with `b = a * s + e_pk`, a ciphertext `(mask, body)` and a
+//! clear value `m`, the proof shows that the prover knows a binary secret key `s` such that the
+//! public key is well formed under `s`, and that `body - <mask, s> - delta * m` is smaller than the
+//! noise bound, i.e. that `m` is the decryption of the ciphertext under the key behind the public
+//! key.
+//!
+//! This is the statement of [`pke`] where the encryption randomness is the secret key `s`, `c1` is
+//! the body of the public key with `e1` its noise, the second public key component is replaced by
+//! the mask of the ciphertext and `c2` is `body - delta * m`. The decryption noise is usually much
+//! larger than the encryption noise, so the public parameters are generated with their own noise
+//! bound by [`crs_gen`].
+//!
+//! In [`pke`] the message is a private witness, only known to be in `[0, t)`, which would let the
+//! prover shift `c2` by a multiple of `q / t` and prove another clear value. The public parameters
+//! of the decryption proofs use `t = 1`: the message has no bits in the witness and its scaling
+//! factor `q / t` is zero modulo `q`, so the statement has no message term at all.
+
+use super::pke;
+use super::*;
+use core::marker::PhantomData;
+
+pub use pke::{Proof, PublicParams};
+
+/// Generates public parameters to prove decryptions of ciphertexts of dimension `d`, whose
+/// decryption noise is in `[-b, b)`.
+pub fn crs_gen<G: Curve>(d: usize, b: u64, q: u64, rng: &mut dyn RngCore) -> PublicParams<G> {
+    pke::crs_gen(d, 1, b, q, PLAINTEXT_MODULUS, rng)
+}
+
+/// Plaintext modulus of the public parameters, which removes the message from the statement
+const PLAINTEXT_MODULUS: u64 = 1;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PublicCommit<G: Curve> {
+    key_mask: Vec<i64>,
+    key_body: Vec<i64>,
+    mask: Vec<i64>,
+    body: i64,
+    __marker: PhantomData<G>,
+}
+
+impl<G: Curve> PublicCommit<G> {
+    /// Builds the statement from the public key, the ciphertext and its encoded clear value
+    /// `delta * m`.
+    pub fn new(
+        key_mask: Vec<i64>,
+        key_body: Vec<i64>,
+        mask: Vec<i64>,
+        body: i64,
+        encoded_clear: i64,
+    ) -> Self {
+        Self {
+            key_mask,
+            key_body,
+            mask,
+            body: body.wrapping_sub(encoded_clear),
+            __marker: PhantomData,
+        }
+    }
+
+    fn to_pke_commit(&self) -> pke::PublicCommit<G> {
+        pke::PublicCommit::new(
+            self.key_mask.clone(),
+            self.mask.clone(),
+            self.key_body.clone(),
+            vec![self.body],
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PrivateCommit<G: Curve> {
+    inner: pke::PrivateCommit<G>,
+}
+
+/// Commits to the decryption of a ciphertext.
+///
+/// `e_pk` is the noise of the public key and `e` the decryption noise of the ciphertext, that is
+/// `body - <mask, s> - delta * m`.
+#[allow(clippy::too_many_arguments)]
+pub fn commit<G: Curve>(
+    key_mask: Vec<i64>,
+    key_body: Vec<i64>,
+    mask: Vec<i64>,
+    body: i64,
+    encoded_clear: i64,
+    s: Vec<i64>,
+    e_pk: Vec<i64>,
+    e: i64,
+    public: &PublicParams<G>,
+    rng: &mut dyn RngCore,
+) -> (PublicCommit<G>, PrivateCommit<G>) {
+    let public_commit = PublicCommit::new(key_mask, key_body, mask, body, encoded_clear);
+    let (_, inner) = pke::commit(
+        public_commit.key_mask.clone(),
+        public_commit.mask.clone(),
+        public_commit.key_body.clone(),
+        vec![public_commit.body],
+        s,
+        e_pk,
+        vec![0],
+        vec![e],
+        public,
+        rng,
+    );
+
+    (public_commit, PrivateCommit { inner })
+}
+
+pub fn prove<G: Curve>(
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    private_commit: &PrivateCommit<G>,
+    metadata: &[u8],
+    load: ComputeLoad,
+    rng: &mut dyn RngCore,
+) -> Proof<G> {
+    pke::prove(
+        (public.0, &public.1.to_pke_commit()),
+        &private_commit.inner,
+        metadata,
+        load,
+        rng,
+    )
+}
+
+#[allow(clippy::result_unit_err)]
+pub fn verify<G: Curve>(
+    proof: &Proof<G>,
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    metadata: &[u8],
+) -> Result<(), ()> {
+    // Public parameters with another plaintext modulus would let the prover shift the decryption
+    if public.0.t != PLAINTEXT_MODULUS {
+        return Err(());
+    }
+
+    pke::verify(proof, (public.0, &public.1.to_pke_commit()), metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_decryption() {
+        let d = 512;
+        let b_pk = 512;
+        let b = 1u64 << 57;
+        let q = 0;
+        let delta = 1u64 << 58;
+
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let polymul_rev = |a: &[i64], b: &[i64]| -> Vec<i64> {
+            assert_eq!(a.len(), b.len());
+            let d = a.len();
+            let mut c = vec![0i64; d];
+
+            for i in 0..d {
+                for j in 0..d {
+                    if i + j < d {
+                        c[i + j] = c[i + j].wrapping_add(a[i].wrapping_mul(b[d - j - 1]));
+                    } else {
+                        c[i + j - d] = c[i + j - d].wrapping_sub(a[i].wrapping_mul(b[d - j - 1]));
+                    }
+                }
+            }
+
+            c
+        };
+
+        let key_mask = (0..d).map(|_| rng.gen::<i64>()).collect::<Vec<_>>();
+        let s = (0..d)
+            .map(|_| (rng.gen::<u64>() % 2) as i64)
+            .collect::<Vec<_>>();
+        let e_pk = (0..d)
+            .map(|_| (rng.gen::<u64>() % (2 * b_pk)) as i64 - b_pk as i64)
+            .collect::<Vec<_>>();
+        let key_body = polymul_rev(&key_mask, &s)
+            .into_iter()
+            .zip(e_pk.iter())
+            .map(|(x, e)| x.wrapping_add(*e))
+            .collect::<Vec<_>>();
+
+        let mask = (0..d).map(|_| rng.gen::<i64>()).collect::<Vec<_>>();
+        let e = (rng.gen::<u64>() % (2 * b)) as i64 - b as i64;
+        let m = 5u64;
+        let fake_m = 6u64;
+
+        let body = mask
+            .iter()
+            .zip(s.iter())
+            .fold(0i64, |acc, (&a, &s)| acc.wrapping_add(a.wrapping_mul(s)))
+            .wrapping_add(e)
+            .wrapping_add(delta.wrapping_mul(m) as i64);
+
+        let metadata = [1u8; 40];
+        let fake_metadata = [2u8; 40];
+
+        type Curve = crate::curve_api::Bls12_446;
+
+        let public_param = crs_gen::<Curve>(d, b, q, rng);
+
+        for (use_fake_m, use_fake_metadata_verify) in
+            itertools::iproduct!([false, true], [false, true])
+        {
+            let clear = if use_fake_m { fake_m } else { m };
+            let encoded_clear = delta.wrapping_mul(clear) as i64;
+            // The prover computes the noise from the claimed clear value, a wrong value gives a
+            // noise out of the bound
+            let noise = body
+                .wrapping_sub(
+                    mask.iter()
+                        .zip(s.iter())
+                        .fold(0i64, |acc, (&a, &s)| acc.wrapping_add(a.wrapping_mul(s))),
+                )
+                .wrapping_sub(encoded_clear);
+
+            let (public_commit, private_commit) = commit(
+                key_mask.clone(),
+                key_body.clone(),
+                mask.clone(),
+                body,
+                encoded_clear,
+                s.clone(),
+                e_pk.clone(),
+                noise,
+                &public_param,
+                rng,
+            );
+
+            let proof = prove(
+                (&public_param, &public_commit),
+                &private_commit,
+                &metadata,
+                ComputeLoad::Proof,
+                rng,
+            );
+
+            let verify_metadata = if use_fake_metadata_verify {
+                &fake_metadata
+            } else {
+                &metadata
+            };
+
+            assert_eq!(
+                verify(&proof, (&public_param, &public_commit), verify_metadata).is_err(),
+                use_fake_m || use_fake_metadata_verify
+            );
+        }
+
+        // A prover claiming a clear value shifted by q / 2 and putting a non zero message in the
+        // pke witness to compensate must be rejected
+        let shifted_clear = m + (1u64 << 63) / delta;
+        let encoded_clear = delta.wrapping_mul(shifted_clear) as i64;
+        let public_commit = PublicCommit::new(
+            key_mask.clone(),
+            key_body.clone(),
+            mask.clone(),
+            body,
+            encoded_clear,
+        );
+        for pke_m in [0, 1] {
+            let (_, inner) = pke::commit(
+                public_commit.key_mask.clone(),
+                public_commit.mask.clone(),
+                public_commit.key_body.clone(),
+                vec![public_commit.body],
+                s.clone(),
+                e_pk.clone(),
+                vec![pke_m],
+                vec![e],
+                &public_param,
+                rng,
+            );
+            let proof = prove(
+                (&public_param, &public_commit),
+                &PrivateCommit { inner },
+                &metadata,
+                ComputeLoad::Proof,
+                rng,
+            );
+            assert!(verify(&proof, (&public_param, &public_commit), &metadata).is_err());
+        }
+
+        // Public parameters with a non trivial plaintext modulus are rejected
+        let pke_public_param = pke::crs_gen::<Curve>(d, 1, b, q, 2, rng);
+        let (public_commit, private_commit) = commit(
+            key_mask,
+            key_body,
+            mask,
+            body,
+            delta.wrapping_mul(m) as i64,
+            s,
+            e_pk,
+            e,
+            &pke_public_param,
+            rng,
+        );
+        let proof = prove(
+            (&pke_public_param, &public_commit),
+            &private_commit,
+            &metadata,
+            ComputeLoad::Proof,
+            rng,
+        );
+        assert!(verify(&proof, (&pke_public_param, &public_commit), &metadata).is_err());
+    }
+}
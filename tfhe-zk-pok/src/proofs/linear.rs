@@ -0,0 +1,526 @@
+//! Proof of a public linear combination of LWE ciphertext lists.
+//!
+//! Given input lists `(c1_j, c2_j)` encrypted under the compact public key `(a, b)` and public
+//! weights `w_j`, the output list `(c1, c2)` is computed as `sum_j w_j * (c1_j, c2_j)` plus a fresh
+//! encryption of zero with randomness `r` and noise `(e1, e2)`. Without this rerandomization the
+//! verifier could simply recompute the output, and it would leak the link between the inputs and
+//! the output to anyone knowing the weights.
+//!
+//! The proof shows that `(c1, c2) - sum_j w_j * (c1_j, c2_j)` is a [`pke_v2`] encryption of zero,
+//! i.e. that the output holds the linear combination of the input messages and that its extra
+//! noise is bounded. In [`pke_v2`] the message is a private witness only known to be in `[0, t)`,
+//! so the public parameters generated by [`crs_gen`] use `t = 1`: the message has no bits in the
+//! witness and its scaling factor `q / t` is zero modulo `q`, which fixes it to zero. The inputs and the weights are bound to the proof through its metadata, so a
+//! proof is only valid for the combination it was computed for. The noise bound of the output is
+//! given by [`PublicCommit::output_noise_bound`], it uses the bound proven by [`pke_v2`], which is
+//! larger than the bound of the noise of an honest prover.
+
+use super::pke_v2;
+use super::*;
+use core::marker::PhantomData;
+
+pub use pke_v2::{Proof, PublicParams};
+
+/// Generates public parameters to prove linear combinations of ciphertext lists of dimension `d`
+/// with up to `k` bodies, whose rerandomization noise is bounded by `b`.
+pub fn crs_gen<G: Curve>(
+    d: usize,
+    k: usize,
+    b: u64,
+    q: u64,
+    rng: &mut dyn RngCore,
+) -> PublicParams<G> {
+    pke_v2::crs_gen(d, k, b, q, PLAINTEXT_MODULUS, rng)
+}
+
+/// Plaintext modulus of the public parameters, which removes the message from the statement
+const PLAINTEXT_MODULUS: u64 = 1;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PublicCommit<G: Curve> {
+    a: Vec<i64>,
+    b: Vec<i64>,
+    inputs: Vec<(Vec<i64>, Vec<i64>)>,
+    weights: Vec<i64>,
+    c1: Vec<i64>,
+    c2: Vec<i64>,
+    __marker: PhantomData<G>,
+}
+
+impl<G: Curve> PublicCommit<G> {
+    /// Builds the statement from the public key, the input lists with their weights and the
+    /// output list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one weight per input, or if the input and output lists do
+    /// not have the same dimension and number of bodies.
+    pub fn new(
+        a: Vec<i64>,
+        b: Vec<i64>,
+        inputs: Vec<(Vec<i64>, Vec<i64>)>,
+        weights: Vec<i64>,
+        c1: Vec<i64>,
+        c2: Vec<i64>,
+    ) -> Self {
+        assert_eq!(inputs.len(), weights.len());
+        for (input_c1, input_c2) in &inputs {
+            assert_eq!(input_c1.len(), c1.len());
+            assert_eq!(input_c2.len(), c2.len());
+        }
+
+        Self {
+            a,
+            b,
+            inputs,
+            weights,
+            c1,
+            c2,
+            __marker: PhantomData,
+        }
+    }
+
+    /// Bound on the absolute value of the noise terms of an output accepted by [`verify`], given
+    /// a bound on the noise of the inputs.
+    ///
+    /// The proof only shows that the norm of the noise of the encryption of zero used to
+    /// rerandomize the output is below the proven bound `B_bound` of the public parameters, not
+    /// below the bound `B` used to generate them, so the former bounds each of its terms.
+    ///
+    /// Returns `None` if the bound does not fit in a `u64`.
+    pub fn output_noise_bound(
+        &self,
+        public: &PublicParams<G>,
+        input_noise_bound: u64,
+    ) -> Option<u64> {
+        self.weights.iter().try_fold(public.B_bound, |acc, w| {
+            acc.checked_add(w.unsigned_abs().checked_mul(input_noise_bound)?)
+        })
+    }
+
+    /// Subtracts the linear combination of the inputs from the output modulo `q`, `q == 0`
+    /// standing for `2^64`.
+    fn output_minus_combination(&self, q: u64) -> (Vec<i64>, Vec<i64>) {
+        let reduce = |x: i128| -> i64 {
+            if q == 0 {
+                x as i64
+            } else {
+                x.rem_euclid(q as i128) as i64
+            }
+        };
+
+        let mut c1 = self.c1.clone();
+        let mut c2 = self.c2.clone();
+        for ((input_c1, input_c2), &w) in self.inputs.iter().zip(&self.weights) {
+            for (x, &y) in c1
+                .iter_mut()
+                .zip(input_c1)
+                .chain(c2.iter_mut().zip(input_c2))
+            {
+                *x = reduce(*x as i128 - w as i128 * y as i128);
+            }
+        }
+
+        (c1, c2)
+    }
+
+    fn to_pke_commit(&self, q: u64) -> pke_v2::PublicCommit<G> {
+        let (c1, c2) = self.output_minus_combination(q);
+        pke_v2::PublicCommit::new(self.a.clone(), self.b.clone(), c1, c2)
+    }
+
+    /// Appends the inputs and the weights to the user metadata, so that the proof cannot be
+    /// replayed for another linear combination giving the same output.
+    fn bind_metadata(&self, metadata: &[u8]) -> Vec<u8> {
+        let mut bound = Vec::with_capacity(metadata.len() + 8 * (2 + self.weights.len()));
+
+        bound.extend_from_slice(&(metadata.len() as u64).to_le_bytes());
+        bound.extend_from_slice(metadata);
+        bound.extend_from_slice(&(self.weights.len() as u64).to_le_bytes());
+        for ((input_c1, input_c2), &w) in self.inputs.iter().zip(&self.weights) {
+            bound.extend_from_slice(&w.to_le_bytes());
+            for x in input_c1.iter().chain(input_c2) {
+                bound.extend_from_slice(&x.to_le_bytes());
+            }
+        }
+
+        bound
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PrivateCommit<G: Curve> {
+    inner: pke_v2::PrivateCommit<G>,
+}
+
+/// Commits to a linear combination of ciphertext lists.
+///
+/// `r`, `e1` and `e2` are the randomness and the noise of the encryption of zero that was added
+/// to `sum_j weights[j] * inputs[j]` to get the output `(c1, c2)`.
+#[allow(clippy::too_many_arguments)]
+pub fn commit<G: Curve>(
+    a: Vec<i64>,
+    b: Vec<i64>,
+    inputs: Vec<(Vec<i64>, Vec<i64>)>,
+    weights: Vec<i64>,
+    c1: Vec<i64>,
+    c2: Vec<i64>,
+    r: Vec<i64>,
+    e1: Vec<i64>,
+    e2: Vec<i64>,
+    public: &PublicParams<G>,
+    rng: &mut dyn RngCore,
+) -> (PublicCommit<G>, PrivateCommit<G>) {
+    let public_commit = PublicCommit::new(a, b, inputs, weights, c1, c2);
+    let (c1, c2) = public_commit.output_minus_combination(public.q);
+    let m = vec![0; c2.len()];
+    let (_, inner) = pke_v2::commit(
+        public_commit.a.clone(),
+        public_commit.b.clone(),
+        c1,
+        c2,
+        r,
+        e1,
+        m,
+        e2,
+        public,
+        rng,
+    );
+
+    (public_commit, PrivateCommit { inner })
+}
+
+pub fn prove<G: Curve>(
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    private_commit: &PrivateCommit<G>,
+    metadata: &[u8],
+    load: ComputeLoad,
+    rng: &mut dyn RngCore,
+) -> Proof<G> {
+    pke_v2::prove(
+        (public.0, &public.1.to_pke_commit(public.0.q)),
+        &private_commit.inner,
+        &public.1.bind_metadata(metadata),
+        load,
+        rng,
+    )
+}
+
+#[allow(clippy::result_unit_err)]
+pub fn verify<G: Curve>(
+    proof: &Proof<G>,
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    metadata: &[u8],
+) -> Result<(), ()> {
+    // Public parameters with another plaintext modulus would let the prover add a message
+    if public.0.t != PLAINTEXT_MODULUS {
+        return Err(());
+    }
+
+    pke_v2::verify(
+        proof,
+        (public.0, &public.1.to_pke_commit(public.0.q)),
+        &public.1.bind_metadata(metadata),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_linear() {
+        let d = 512;
+        let k = 4;
+        let noise_bound = 1024u64;
+        let q = 0;
+        let t = 16;
+        let delta = 1u64 << 60;
+
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let polymul_rev = |a: &[i64], b: &[i64]| -> Vec<i64> {
+            assert_eq!(a.len(), b.len());
+            let d = a.len();
+            let mut c = vec![0i64; d];
+
+            for i in 0..d {
+                for j in 0..d {
+                    if i + j < d {
+                        c[i + j] = c[i + j].wrapping_add(a[i].wrapping_mul(b[d - j - 1]));
+                    } else {
+                        c[i + j - d] = c[i + j - d].wrapping_sub(a[i].wrapping_mul(b[d - j - 1]));
+                    }
+                }
+            }
+
+            c
+        };
+
+        let a = (0..d).map(|_| rng.gen::<i64>()).collect::<Vec<_>>();
+        let s = (0..d)
+            .map(|_| (rng.gen::<u64>() % 2) as i64)
+            .collect::<Vec<_>>();
+        let e = (0..d)
+            .map(|_| (rng.gen::<u64>() % (2 * noise_bound)) as i64 - noise_bound as i64)
+            .collect::<Vec<_>>();
+        let b = polymul_rev(&a, &s)
+            .into_iter()
+            .zip(e.iter())
+            .map(|(x, e)| x.wrapping_add(*e))
+            .collect::<Vec<_>>();
+
+        let encrypt_with_noise = |m: &[i64], r: &[i64], e1: &[i64], e2: &[i64]| {
+            let c1 = polymul_rev(&a, r)
+                .into_iter()
+                .zip(e1.iter())
+                .map(|(x, e1)| x.wrapping_add(*e1))
+                .collect::<Vec<_>>();
+
+            let mut c2 = vec![0i64; k];
+            for i in 0..k {
+                let mut dot = 0i64;
+                for j in 0..d {
+                    let b = if i + j < d {
+                        b[d - j - i - 1]
+                    } else {
+                        b[2 * d - j - i - 1].wrapping_neg()
+                    };
+
+                    dot = dot.wrapping_add(r[d - j - 1].wrapping_mul(b));
+                }
+
+                c2[i] = dot
+                    .wrapping_add(e2[i])
+                    .wrapping_add(delta.wrapping_mul(m[i] as u64) as i64);
+            }
+
+            (c1, c2)
+        };
+
+        let mut encrypt = |m: &[i64]| {
+            let r = (0..d)
+                .map(|_| (rng.gen::<u64>() % 2) as i64)
+                .collect::<Vec<_>>();
+            let e1 = (0..d)
+                .map(|_| (rng.gen::<u64>() % (2 * noise_bound)) as i64 - noise_bound as i64)
+                .collect::<Vec<_>>();
+            let e2 = (0..k)
+                .map(|_| (rng.gen::<u64>() % (2 * noise_bound)) as i64 - noise_bound as i64)
+                .collect::<Vec<_>>();
+
+            (encrypt_with_noise(m, &r, &e1, &e2), (r, e1, e2))
+        };
+
+        let messages = (0..3)
+            .map(|_| {
+                (0..k)
+                    .map(|_| (rng.gen::<u64>() % t) as i64)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let inputs = messages.iter().map(|m| encrypt(m).0).collect::<Vec<_>>();
+        let weights = vec![1, -2, 3];
+        let fake_weights = vec![1, -2, 4];
+
+        let ((zero_c1, zero_c2), (r, e1, e2)) = encrypt(&vec![0; k]);
+        let mut c1 = zero_c1;
+        let mut c2 = zero_c2;
+        for ((input_c1, input_c2), &w) in inputs.iter().zip(&weights) {
+            for (x, &y) in c1
+                .iter_mut()
+                .zip(input_c1)
+                .chain(c2.iter_mut().zip(input_c2))
+            {
+                *x = x.wrapping_add(w.wrapping_mul(y));
+            }
+        }
+
+        let metadata = [1u8; 40];
+        let fake_metadata = [2u8; 40];
+
+        type Curve = crate::curve_api::Bls12_446;
+
+        let public_param = crs_gen::<Curve>(d, k, noise_bound, q, rng);
+
+        let (public_commit, private_commit) = commit(
+            a.clone(),
+            b.clone(),
+            inputs.clone(),
+            weights.clone(),
+            c1.clone(),
+            c2.clone(),
+            r.clone(),
+            e1.clone(),
+            e2.clone(),
+            &public_param,
+            rng,
+        );
+
+        assert_eq!(
+            public_commit.output_noise_bound(&public_param, noise_bound),
+            Some(public_param.B_bound + 6 * noise_bound)
+        );
+        assert_eq!(
+            public_commit.output_noise_bound(&public_param, u64::MAX),
+            None
+        );
+
+        let proof = prove(
+            (&public_param, &public_commit),
+            &private_commit,
+            &metadata,
+            ComputeLoad::Proof,
+            rng,
+        );
+
+        for (use_fake_weights, use_fake_metadata_verify) in
+            itertools::iproduct!([false, true], [false, true])
+        {
+            let verify_commit = PublicCommit::new(
+                a.clone(),
+                b.clone(),
+                inputs.clone(),
+                if use_fake_weights {
+                    fake_weights.clone()
+                } else {
+                    weights.clone()
+                },
+                c1.clone(),
+                c2.clone(),
+            );
+
+            let verify_metadata = if use_fake_metadata_verify {
+                &fake_metadata
+            } else {
+                &metadata
+            };
+
+            assert_eq!(
+                verify(&proof, (&public_param, &verify_commit), verify_metadata).is_err(),
+                use_fake_weights || use_fake_metadata_verify
+            );
+        }
+
+        // An output with an extra message cannot be proven by putting the message in the witness,
+        // although the pke_v2 statement accepts it with public parameters having room for it
+        let mut shifted_c2 = c2.clone();
+        shifted_c2[0] = shifted_c2[0].wrapping_add(delta as i64);
+        let shifted_commit = PublicCommit::new(
+            a.clone(),
+            b.clone(),
+            inputs.clone(),
+            weights.clone(),
+            c1.clone(),
+            shifted_c2,
+        );
+        let mut shifted_m = vec![0i64; k];
+        shifted_m[0] = 1;
+
+        let message_public_param = pke_v2::crs_gen::<Curve>(d, k, noise_bound, q, t, rng);
+
+        for public_param in [&public_param, &message_public_param] {
+            let (pke_c1, pke_c2) = shifted_commit.output_minus_combination(public_param.q);
+            let (_, inner) = pke_v2::commit(
+                a.clone(),
+                b.clone(),
+                pke_c1,
+                pke_c2,
+                r.clone(),
+                e1.clone(),
+                shifted_m.clone(),
+                e2.clone(),
+                public_param,
+                rng,
+            );
+            let proof = prove(
+                (public_param, &shifted_commit),
+                &PrivateCommit { inner },
+                &metadata,
+                ComputeLoad::Proof,
+                rng,
+            );
+
+            assert!(verify(&proof, (public_param, &shifted_commit), &metadata).is_err());
+
+            if public_param.t == t {
+                assert!(pke_v2::verify(
+                    &proof,
+                    (public_param, &shifted_commit.to_pke_commit(public_param.q)),
+                    &shifted_commit.bind_metadata(&metadata),
+                )
+                .is_ok());
+            }
+        }
+
+        // Inputs at their maximal noise, and a rerandomization whose noise is all in its first
+        // term, at the largest norm accepted when proving
+        let zero_r = (0..d)
+            .map(|_| (rng.gen::<u64>() % 2) as i64)
+            .collect::<Vec<_>>();
+        let mut zero_e1 = vec![0i64; d];
+        zero_e1[0] = public_param.B as i64;
+        let zero_e2 = vec![0i64; k];
+        let (mut c1, mut c2) = encrypt_with_noise(&vec![0; k], &zero_r, &zero_e1, &zero_e2);
+
+        let mut r_combination = zero_r.clone();
+        let mut inputs = Vec::with_capacity(weights.len());
+        for (m, &w) in messages.iter().zip(&weights) {
+            let r = (0..d)
+                .map(|_| (rng.gen::<u64>() % 2) as i64)
+                .collect::<Vec<_>>();
+            let mut e1 = vec![0i64; d];
+            e1[0] = w.signum() * noise_bound as i64;
+            let (input_c1, input_c2) = encrypt_with_noise(m, &r, &e1, &vec![0; k]);
+
+            for (x, &y) in c1
+                .iter_mut()
+                .zip(&input_c1)
+                .chain(c2.iter_mut().zip(&input_c2))
+            {
+                *x = x.wrapping_add(w.wrapping_mul(y));
+            }
+            for (x, &y) in r_combination.iter_mut().zip(&r) {
+                *x = x.wrapping_add(w.wrapping_mul(y));
+            }
+            inputs.push((input_c1, input_c2));
+        }
+
+        let (public_commit, private_commit) = commit(
+            a.clone(),
+            b.clone(),
+            inputs,
+            weights.clone(),
+            c1.clone(),
+            c2,
+            zero_r,
+            zero_e1,
+            zero_e2,
+            &public_param,
+            rng,
+        );
+        let proof = prove(
+            (&public_param, &public_commit),
+            &private_commit,
+            &metadata,
+            ComputeLoad::Proof,
+            rng,
+        );
+        assert!(verify(&proof, (&public_param, &public_commit), &metadata).is_ok());
+
+        let output_noise = c1[0]
+            .wrapping_sub(polymul_rev(&a, &r_combination)[0])
+            .unsigned_abs();
+        assert_eq!(output_noise, public_param.B + 6 * noise_bound);
+        // The output is accepted with a noise above the bound of an honest rerandomization
+        assert!(output_noise > 7 * noise_bound);
+        assert!(
+            output_noise
+                <= public_commit
+                    .output_noise_bound(&public_param, noise_bound)
+                    .unwrap()
+        );
+    }
+}
@@ -1,9 +1,11 @@
+use crate::curve_api::msm::FixedBaseMsmTable;
 use crate::curve_api::{Curve, CurveGroupOps, FieldOps, PairingGroupOps};
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
 };
-use core::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut, Range};
 use rand::{Rng, RngCore};
+use std::sync::Arc;
 
 #[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize)]
 #[repr(transparent)]
@@ -76,9 +78,65 @@ impl<T: ?Sized + IndexMut<usize>> IndexMut<usize> for OneBased<T> {
 
 pub type Affine<Zp, Group> = <Group as CurveGroupOps<Zp>>::Affine;
 
-#[derive(
-    Clone, Debug, serde::Serialize, serde::Deserialize, CanonicalSerialize, CanonicalDeserialize,
-)]
+/// Fixed-base tables of the [`GroupElements`], shared between the clones of the parameters.
+///
+/// The tables can be rebuilt from the group elements, so they are not serialized.
+#[derive(Clone)]
+struct MsmTables<G: Curve>(
+    #[allow(clippy::type_complexity)]
+    Option<
+        Arc<(
+            FixedBaseMsmTable<G::Zp, G::G1>,
+            FixedBaseMsmTable<G::Zp, G::G2>,
+        )>,
+    >,
+);
+
+impl<G: Curve> Default for MsmTables<G> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<G: Curve> core::fmt::Debug for MsmTables<G> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("MsmTables")
+            .field(&self.0.as_ref().map(|tables| tables.0.window_bits()))
+            .finish()
+    }
+}
+
+impl<G: Curve> Valid for MsmTables<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl<G: Curve> CanonicalDeserialize for MsmTables<G> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        _reader: R,
+        _compress: Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self(None))
+    }
+}
+
+impl<G: Curve> CanonicalSerialize for MsmTables<G> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        _writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        Ok(())
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        0
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, CanonicalSerialize)]
 #[serde(bound(
     deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>",
     serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize"
@@ -87,6 +145,48 @@ struct GroupElements<G: Curve> {
     g_list: OneBased<Vec<Affine<G::Zp, G::G1>>>,
     g_hat_list: OneBased<Vec<Affine<G::Zp, G::G2>>>,
     message_len: usize,
+    #[serde(skip)]
+    msm_tables: MsmTables<G>,
+}
+
+impl<G: Curve> Valid for GroupElements<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // The lengths are checked first, as they are cheap compared to the subgroup checks
+        if self.g_list.0.len() != 2 * self.message_len
+            || self.g_hat_list.0.len() != self.message_len
+        {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.g_list.check()?;
+        self.g_hat_list.check()
+    }
+}
+
+impl<G: Curve> CanonicalDeserialize for GroupElements<G> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let g_list = OneBased::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let g_hat_list = OneBased::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let message_len = usize::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let msm_tables = MsmTables::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+
+        let group_elements = Self {
+            g_list,
+            g_hat_list,
+            message_len,
+            msm_tables,
+        };
+
+        if validate == Validate::Yes {
+            group_elements.check()?;
+        }
+
+        Ok(group_elements)
+    }
 }
 
 impl<G: Curve> GroupElements<G> {
@@ -132,15 +232,55 @@ impl<G: Curve> GroupElements<G> {
             g_list: OneBased::new(g_list),
             g_hat_list: OneBased::new(g_hat_list),
             message_len,
+            msm_tables: MsmTables::default(),
+        }
+    }
+
+    pub fn precompute_msm_tables(&mut self, window_bits: usize) {
+        let (g_table, g_hat_table) = rayon::join(
+            || FixedBaseMsmTable::new(&self.g_list.0, window_bits),
+            || FixedBaseMsmTable::new(&self.g_hat_list.0, window_bits),
+        );
+        self.msm_tables = MsmTables(Some(Arc::new((g_table, g_hat_table))));
+    }
+
+    pub fn has_msm_tables(&self) -> bool {
+        self.msm_tables.0.is_some()
+    }
+
+    /// Computes the multi-scalar multiplication of the `g_list` elements in the zero-based
+    /// `bases` range, with the precomputed tables if there are some.
+    #[track_caller]
+    pub fn g1_msm(&self, bases: Range<usize>, scalars: &[G::Zp]) -> G::G1 {
+        assert_eq!(bases.len(), scalars.len());
+        match &self.msm_tables.0 {
+            Some(tables) => tables.0.msm(bases.start, scalars),
+            None => G::G1::multi_mul_scalar(&self.g_list.0[bases], scalars),
+        }
+    }
+
+    /// Computes the multi-scalar multiplication of the `g_hat_list` elements in the zero-based
+    /// `bases` range, with the precomputed tables if there are some.
+    #[track_caller]
+    pub fn g2_msm(&self, bases: Range<usize>, scalars: &[G::Zp]) -> G::G2 {
+        assert_eq!(bases.len(), scalars.len());
+        match &self.msm_tables.0 {
+            Some(tables) => tables.1.msm(bases.start, scalars),
+            None => G::G2::multi_mul_scalar(&self.g_hat_list.0[bases], scalars),
         }
     }
 }
 
 pub const HASH_METADATA_LEN_BYTES: usize = 256;
 
+mod batch;
 pub mod binary;
+pub mod ceremony;
+pub mod decryption;
 pub mod index;
+pub mod linear;
 pub mod pke;
+pub mod pke_sk;
 pub mod pke_v2;
 pub mod range;
 pub mod rlwe;
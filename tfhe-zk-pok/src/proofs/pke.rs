@@ -1,8 +1,10 @@
 // TODO: refactor copy-pasted code in proof/verify
 
 use super::*;
+use crate::backward_compatibility::PkeProofVersions;
 use core::marker::PhantomData;
 use rayon::prelude::*;
+use tfhe_versionable::Versionize;
 
 fn bit_iter(x: u64, nbits: u32) -> impl Iterator<Item = bool> {
     (0..nbits).map(move |idx| ((x >> idx) & 1) != 0)
@@ -69,13 +71,88 @@ impl<G: Curve> PublicParams<G> {
     pub fn exclusive_max_noise(&self) -> u64 {
         self.b
     }
+
+    /// Checks that the group elements of the parameters are on the curve and in the prime order
+    /// subgroup, and that there are as many of them as expected.
+    ///
+    /// This is already done when the parameters are deserialized with [`Validate::Yes`], which is
+    /// what their serde and versioned implementations use. Parameters loaded from an untrusted
+    /// source with [`Validate::No`] must be validated before being used.
+    pub fn validate(&self) -> Result<(), SerializationError> {
+        if self.g_lists.message_len != self.n {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.check()
+    }
 }
 
-#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+impl<G: Curve> ceremony::UpdatableParams<G> for PublicParams<G> {
+    fn update(&self, rng: &mut dyn RngCore) -> (Self, ceremony::UpdateProof<G>) {
+        let (g_lists, proof) = self.g_lists.update(rng);
+        let mut updated = self.clone();
+        updated.g_lists = g_lists;
+        (updated, proof)
+    }
+
+    fn verify_update(&self, updated: &Self, proof: &ceremony::UpdateProof<G>) -> Result<(), ()> {
+        let Self {
+            g_lists,
+            big_d,
+            n,
+            d,
+            k,
+            b,
+            b_r,
+            q,
+            t,
+            hash,
+            hash_t,
+            hash_agg,
+            hash_lmap,
+            hash_z,
+            hash_w,
+        } = updated;
+
+        if (*big_d, *n, *d, *k, *b, *b_r, *q, *t)
+            != (
+                self.big_d, self.n, self.d, self.k, self.b, self.b_r, self.q, self.t,
+            )
+            || [hash, hash_t, hash_agg, hash_lmap, hash_z, hash_w]
+                != [
+                    &self.hash,
+                    &self.hash_t,
+                    &self.hash_agg,
+                    &self.hash_lmap,
+                    &self.hash_z,
+                    &self.hash_w,
+                ]
+        {
+            return Err(());
+        }
+
+        self.g_lists.verify_update(g_lists, proof)
+    }
+
+    fn is_well_formed(&self) -> bool {
+        self.g_lists.is_well_formed()
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+    Versionize,
+)]
 #[serde(bound(
     deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>",
     serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize"
 ))]
+#[versionize(PkeProofVersions)]
 pub struct Proof<G: Curve> {
     c_hat: G::G2,
     c_y: G::G1,
@@ -85,6 +162,17 @@ pub struct Proof<G: Curve> {
     pi_kzg: Option<G::G1>,
 }
 
+impl<G: Curve> Proof<G> {
+    /// Checks that the group elements of the proof are on the curve and in the prime order
+    /// subgroup.
+    ///
+    /// This is already done when the proof is deserialized with [`Validate::Yes`], which is what
+    /// its serde and versioned implementations use.
+    pub fn validate(&self) -> Result<(), SerializationError> {
+        self.check()
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct PublicCommit<G: Curve> {
     a: Vec<i64>,
@@ -200,6 +288,19 @@ pub fn prove<G: Curve>(
     load: ComputeLoad,
     rng: &mut dyn RngCore,
 ) -> Proof<G> {
+    prove_with_opening(public, private_commit, metadata, load, rng).0
+}
+
+/// Proves the encryption like [`prove`], also returning the opening of the commitment to the
+/// bits of the witness so that range commitments can be linked to the messages with
+/// [`prove_range_link`].
+pub fn prove_with_opening<G: Curve>(
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    private_commit: &PrivateCommit<G>,
+    metadata: &[u8],
+    load: ComputeLoad,
+    rng: &mut dyn RngCore,
+) -> (Proof<G>, range::BitsOpening<G>) {
     let &PublicParams {
         ref g_lists,
         big_d: big_d_max,
@@ -464,7 +565,7 @@ pub fn prove<G: Curve>(
     let pi =
         g.mul_scalar(poly[0]) + G::G1::multi_mul_scalar(&g_list.0[..poly.len() - 1], &poly[1..]);
 
-    if load == ComputeLoad::Proof {
+    let proof = if load == ComputeLoad::Proof {
         let c_hat_t = G::G2::multi_mul_scalar(&g_hat_list.0, &t.0);
         let scalars = (1..n + 1)
             .map(|i| {
@@ -586,7 +687,80 @@ pub fn prove<G: Curve>(
             c_h: None,
             pi_kzg: None,
         }
-    }
+    };
+
+    (proof, range::BitsOpening { gamma, bits: w.0 })
+}
+
+/// Proves that the value committed in `range_public` is made of the message bits selected in the
+/// `linked` proofs, see [`range::LinkProof`].
+///
+/// Each entry holds a proof made with [`prove_with_opening`] and its opening, the number of
+/// messages proven by the proof and the bits of these messages that are part of the value.
+pub fn prove_range_link<G: Curve>(
+    public: &PublicParams<G>,
+    linked: &[(
+        &Proof<G>,
+        &range::BitsOpening<G>,
+        usize,
+        &[range::MessageBits],
+    )],
+    range_public: (&range::PublicParams<G>, &range::PublicCommit<G>),
+    range_private_commit: &range::PrivateCommit<G>,
+    rng: &mut dyn RngCore,
+) -> range::LinkProof<G> {
+    let bit_commitments = linked
+        .iter()
+        .map(|&(proof, opening, num_messages, message_bits)| {
+            let weighted_bits = range::message_weighted_bits::<G>(
+                public.d,
+                public.t,
+                public.k,
+                num_messages,
+                message_bits,
+            )
+            .expect("the message bits are out of the messages of the proof");
+            (proof.c_hat, opening, weighted_bits)
+        })
+        .collect::<Vec<_>>();
+
+    range::prove_link(
+        &public.g_lists,
+        &bit_commitments,
+        range_public,
+        range_private_commit,
+        rng,
+    )
+}
+
+/// Verifies that the value committed in `range_public` is made of the message bits selected in
+/// the `linked` proofs.
+///
+/// The proofs must be verified on their own, this only checks the link between their commitments
+/// and the range commitment.
+#[allow(clippy::result_unit_err)]
+pub fn verify_range_link<G: Curve>(
+    public: &PublicParams<G>,
+    linked: &[(&Proof<G>, usize, &[range::MessageBits])],
+    range_public: (&range::PublicParams<G>, &range::PublicCommit<G>),
+    proof: &range::LinkProof<G>,
+) -> Result<(), ()> {
+    let bit_commitments = linked
+        .iter()
+        .map(|&(pke_proof, num_messages, message_bits)| {
+            range::message_weighted_bits::<G>(
+                public.d,
+                public.t,
+                public.k,
+                num_messages,
+                message_bits,
+            )
+            .map(|weighted_bits| (pke_proof.c_hat, weighted_bits))
+            .ok_or(())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    range::verify_link(&public.g_lists, &bit_commitments, range_public, proof)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -715,6 +889,49 @@ pub fn verify<G: Curve>(
     public: (&PublicParams<G>, &PublicCommit<G>),
     metadata: &[u8],
 ) -> Result<(), ()> {
+    let equations = pairing_equations(proof, public, metadata)?;
+
+    if equations
+        .iter()
+        .all(|equation| batch::check_equation::<G>(equation))
+    {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+/// Verifies many proofs made with the same public parameters, sharing the cost of the pairings
+/// between them.
+///
+/// Each entry of `proofs` holds a proof with its public commit and metadata. On failure, the
+/// indices of the invalid proofs are returned.
+pub fn verify_batch<G: Curve>(
+    public: &PublicParams<G>,
+    proofs: &[(&Proof<G>, &PublicCommit<G>, &[u8])],
+) -> Result<(), Vec<usize>> {
+    let equations = proofs
+        .par_iter()
+        .map(|&(proof, public_commit, metadata)| {
+            pairing_equations(proof, (public, public_commit), metadata).ok()
+        })
+        .collect();
+
+    let invalid = batch::find_invalid::<G>(equations);
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid)
+    }
+}
+
+/// Computes the pairing equations that hold if the proof is valid, or fails if the proof is
+/// inconsistent with the public parameters.
+fn pairing_equations<G: Curve>(
+    proof: &Proof<G>,
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    metadata: &[u8],
+) -> Result<Vec<batch::PairingEquation<G>>, ()> {
     let &Proof {
         c_hat,
         c_y,
@@ -723,7 +940,6 @@ pub fn verify<G: Curve>(
         c_h,
         pi_kzg,
     } = proof;
-    let e = G::Gt::pairing;
 
     let &PublicParams {
         ref g_lists,
@@ -885,17 +1101,15 @@ pub fn verify<G: Curve>(
             pow = pow * z;
         }
 
-        if e(pi, G::G2::GENERATOR)
-            != e(c_y.mul_scalar(delta_y) + c_h, c_hat)
-                - e(c_y.mul_scalar(delta_eq), c_hat_t)
-                - e(
-                    G::G1::projective(g_list[1]),
-                    G::G2::projective(g_hat_list[n]),
-                )
-                .mul_scalar(t_theta * delta_theta)
-        {
-            return Err(());
-        }
+        let equation0 = vec![
+            (c_y.mul_scalar(delta_y) + c_h, c_hat),
+            (-c_y.mul_scalar(delta_eq), c_hat_t),
+            (
+                -G::G1::projective(g_list[1]).mul_scalar(t_theta * delta_theta),
+                G::G2::projective(g_hat_list[n]),
+            ),
+            (-pi, G::G2::GENERATOR),
+        ];
 
         let mut w = G::Zp::ZERO;
         G::Zp::hash(
@@ -925,22 +1139,21 @@ pub fn verify<G: Curve>(
             ],
         );
 
-        if e(c_h - G::G1::GENERATOR.mul_scalar(p_h), G::G2::GENERATOR)
-            + e(G::G1::GENERATOR, c_hat_t - G::G2::GENERATOR.mul_scalar(p_t)).mul_scalar(w)
-            == e(
-                pi_kzg,
-                G::G2::projective(g_hat_list[1]) - G::G2::GENERATOR.mul_scalar(z),
-            )
-        {
-            Ok(())
-        } else {
-            Err(())
-        }
+        let equation1 = vec![
+            (
+                c_h - G::G1::GENERATOR.mul_scalar(p_h + w * p_t) + pi_kzg.mul_scalar(z),
+                G::G2::GENERATOR,
+            ),
+            (G::G1::GENERATOR, c_hat_t.mul_scalar(w)),
+            (-pi_kzg, G::G2::projective(g_hat_list[1])),
+        ];
+
+        Ok(vec![equation0, equation1])
     } else {
         // PERF: rewrite as multi_mul_scalar?
-        let (term0, term1) = rayon::join(
+        let (p0, q1) = rayon::join(
             || {
-                let p = c_y.mul_scalar(delta_y)
+                c_y.mul_scalar(delta_y)
                     + (1..n + 1)
                         .into_par_iter()
                         .map(|i| {
@@ -950,33 +1163,25 @@ pub fn verify<G: Curve>(
                             }
                             G::G1::projective(g_list[n + 1 - i]).mul_scalar(factor)
                         })
-                        .sum::<G::G1>();
-                let q = c_hat;
-                e(p, q)
+                        .sum::<G::G1>()
             },
             || {
-                let p = c_y;
-                let q = (1..n + 1)
+                (1..n + 1)
                     .into_par_iter()
                     .map(|i| G::G2::projective(g_hat_list[i]).mul_scalar(delta_eq * t[i]))
-                    .sum::<G::G2>();
-                e(p, q)
+                    .sum::<G::G2>()
             },
         );
-        let term2 = {
-            let p = G::G1::projective(g_list[1]);
-            let q = G::G2::projective(g_hat_list[n]);
-            e(p, q)
-        };
-
-        let lhs = e(pi, G::G2::GENERATOR);
-        let rhs = term0 - term1 - term2.mul_scalar(t_theta * delta_theta);
 
-        if lhs == rhs {
-            Ok(())
-        } else {
-            Err(())
-        }
+        Ok(vec![vec![
+            (p0, c_hat),
+            (-c_y, q1),
+            (
+                -G::G1::projective(g_list[1]).mul_scalar(t_theta * delta_theta),
+                G::G2::projective(g_hat_list[n]),
+            ),
+            (-pi, G::G2::GENERATOR),
+        ]])
     }
 }
 
@@ -1134,6 +1339,10 @@ mod tests {
             serialize_then_deserialize(&original_public_param, Compress::No).unwrap();
         let public_param_that_was_not_compressed =
             serialize_then_deserialize(&original_public_param, Compress::Yes).unwrap();
+        let batch_public_param = original_public_param.clone();
+
+        let mut batch = Vec::new();
+        let mut expected_invalid = Vec::new();
 
         for (
             public_param,
@@ -1198,15 +1407,244 @@ mod tests {
                     &metadata
                 };
 
+                let is_invalid = use_fake_e1
+                    || use_fake_e2
+                    || use_fake_r
+                    || use_fake_m
+                    || use_fake_metadata_verify;
+
                 assert_eq!(
                     verify(&proof, (&public_param, &public_commit), verify_metadata).is_err(),
-                    use_fake_e1
-                        || use_fake_e2
-                        || use_fake_r
-                        || use_fake_m
-                        || use_fake_metadata_verify
+                    is_invalid
                 );
+
+                if is_invalid {
+                    expected_invalid.push(batch.len());
+                }
+                batch.push((proof, public_commit.clone(), verify_metadata.to_vec()));
             }
         }
+
+        let batch = batch
+            .iter()
+            .map(|(proof, public_commit, metadata)| (proof, public_commit, metadata.as_slice()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            verify_batch(&batch_public_param, &batch).unwrap_err(),
+            expected_invalid
+        );
+
+        let valid_batch = batch
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !expected_invalid.contains(i))
+            .map(|(_, proof)| *proof)
+            .collect::<Vec<_>>();
+        assert!(verify_batch(&batch_public_param, &valid_batch).is_ok());
+    }
+
+    /// Encrypts `m` under a fresh key, returning `(a, b, c1, c2, r, e1, e2)`
+    #[allow(clippy::type_complexity)]
+    fn encrypt(
+        d: usize,
+        b_i: u64,
+        t: u64,
+        m: &[i64],
+        rng: &mut StdRng,
+    ) -> (
+        Vec<i64>,
+        Vec<i64>,
+        Vec<i64>,
+        Vec<i64>,
+        Vec<i64>,
+        Vec<i64>,
+        Vec<i64>,
+    ) {
+        let k = m.len();
+        let delta = ((1u128 << 64) / t as u128) as u64;
+
+        let polymul_rev = |a: &[i64], b: &[i64]| -> Vec<i64> {
+            let d = a.len();
+            let mut c = vec![0i64; d];
+            for i in 0..d {
+                for j in 0..d {
+                    if i + j < d {
+                        c[i + j] = c[i + j].wrapping_add(a[i].wrapping_mul(b[d - j - 1]));
+                    } else {
+                        c[i + j - d] = c[i + j - d].wrapping_sub(a[i].wrapping_mul(b[d - j - 1]));
+                    }
+                }
+            }
+            c
+        };
+        let noise = |len: usize, rng: &mut StdRng| {
+            (0..len)
+                .map(|_| (rng.gen::<u64>() % (2 * b_i)) as i64 - b_i as i64)
+                .collect::<Vec<_>>()
+        };
+
+        let a = (0..d).map(|_| rng.gen::<i64>()).collect::<Vec<_>>();
+        let s = (0..d)
+            .map(|_| (rng.gen::<u64>() % 2) as i64)
+            .collect::<Vec<_>>();
+        let r = (0..d)
+            .map(|_| (rng.gen::<u64>() % 2) as i64)
+            .collect::<Vec<_>>();
+        let e = noise(d, rng);
+        let e1 = noise(d, rng);
+        let e2 = noise(k, rng);
+
+        let b = polymul_rev(&a, &s)
+            .into_iter()
+            .zip(e.iter())
+            .map(|(x, e)| x.wrapping_add(*e))
+            .collect::<Vec<_>>();
+        let c1 = polymul_rev(&a, &r)
+            .into_iter()
+            .zip(e1.iter())
+            .map(|(x, e1)| x.wrapping_add(*e1))
+            .collect::<Vec<_>>();
+
+        let c2 = (0..k)
+            .map(|i| {
+                let mut dot = 0i64;
+                for j in 0..d {
+                    let b = if i + j < d {
+                        b[d - j - i - 1]
+                    } else {
+                        b[2 * d - j - i - 1].wrapping_neg()
+                    };
+                    dot = dot.wrapping_add(r[d - j - 1].wrapping_mul(b));
+                }
+                dot.wrapping_add(e2[i])
+                    .wrapping_add((delta * m[i] as u64) as i64)
+            })
+            .collect::<Vec<_>>();
+
+        (a, b, c1, c2, r, e1, e2)
+    }
+
+    #[test]
+    fn test_pke_range_link() {
+        let d = 256;
+        let k = 4;
+        let b_i = 512;
+        let q = 0;
+        let t = 16;
+        let upper_bound = 40;
+
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        type Curve = crate::curve_api::Bls12_446;
+
+        let public_param = crs_gen::<Curve>(d, k, b_i, q, t, rng);
+        let range_public_param = range::crs_gen::<Curve>(10, rng);
+        let metadata = [0u8; 4];
+
+        // The value is made of the 4 bits of the first message and of the 4 bits of the second
+        // message shifted by 4
+        let message_bits = [
+            range::MessageBits {
+                message_index: 0,
+                bits: 0..4,
+                weight: 1,
+            },
+            range::MessageBits {
+                message_index: 1,
+                bits: 0..4,
+                weight: 16,
+            },
+        ];
+
+        let mut prove_messages = |m: &[i64]| {
+            let (a, b, c1, c2, r, e1, e2) = encrypt(d, b_i, t, m, rng);
+            let (public_commit, private_commit) =
+                commit(a, b, c1, c2, r, e1, m.to_vec(), e2, &public_param, rng);
+            let (proof, opening) = prove_with_opening(
+                (&public_param, &public_commit),
+                &private_commit,
+                &metadata,
+                ComputeLoad::Proof,
+                rng,
+            );
+            assert!(verify(&proof, (&public_param, &public_commit), &metadata).is_ok());
+            (proof, opening)
+        };
+
+        // 3 + 2 * 16 = 35
+        let (proof, opening) = prove_messages(&[3, 2, 0, 0]);
+        // 3 + 9 * 16 = 147
+        let (out_of_range_proof, _) = prove_messages(&[3, 9, 0, 0]);
+
+        let (range_commit, range_private_commit) =
+            range::commit_upper_bound(35, upper_bound, &range_public_param, rng);
+        let range_proof = range::prove_upper_bound(
+            (&range_public_param, &range_commit),
+            &range_private_commit,
+            upper_bound,
+            rng,
+        );
+        let link_proof = prove_range_link(
+            &public_param,
+            &[(&proof, &opening, k, &message_bits)],
+            (&range_public_param, &range_commit),
+            &range_private_commit,
+            rng,
+        );
+
+        assert!(range::verify_upper_bound(
+            &range_proof,
+            (&range_public_param, &range_commit),
+            upper_bound
+        )
+        .is_ok());
+        assert!(verify_range_link(
+            &public_param,
+            &[(&proof, k, &message_bits)],
+            (&range_public_param, &range_commit),
+            &link_proof,
+        )
+        .is_ok());
+
+        // The range proof is valid on its own, but is not about the value encrypted by the other
+        // proof
+        assert!(verify_range_link(
+            &public_param,
+            &[(&out_of_range_proof, k, &message_bits)],
+            (&range_public_param, &range_commit),
+            &link_proof,
+        )
+        .is_err());
+
+        // Nor about other bits of the proven messages
+        let shifted_bits = [
+            range::MessageBits {
+                message_index: 1,
+                bits: 0..4,
+                weight: 1,
+            },
+            range::MessageBits {
+                message_index: 0,
+                bits: 0..4,
+                weight: 16,
+            },
+        ];
+        assert!(verify_range_link(
+            &public_param,
+            &[(&proof, k, &shifted_bits)],
+            (&range_public_param, &range_commit),
+            &link_proof,
+        )
+        .is_err());
+
+        // Bits of messages that are not part of the proof are rejected
+        assert!(verify_range_link(
+            &public_param,
+            &[(&proof, 1, &message_bits)],
+            (&range_public_param, &range_commit),
+            &link_proof,
+        )
+        .is_err());
     }
 }
@@ -0,0 +1,307 @@
+//! Proof of secret key encryption of a compact list of LWE ciphertexts, under the secret key of a
+//! given compact public key.
+//!
+//! The list is made of a random mask polynomial `a` of size `d`, the i-th ciphertext using the
+//! mask `a * X^i`, and of `k` bodies `c_i = <a * X^i, s> + e_i + delta * m_i` where `s` is the
+//! binary secret key. The compact public key `(a_pk, b_pk)` was generated from the same key, with
+//! `b_pk = a_pk * s + e_pk`.
+//!
+//! This is the statement of [`pke`] where the public key is `(a_pk, a)`, the encryption randomness
+//! is the secret key `s`, `c1` is the body `b_pk` of the public key, `e1` its noise `e_pk`, and
+//! `c2` holds the bodies. The same public parameters can hence be used for both kinds of
+//! encryption, and the proof binds the ciphertexts to the key of the public key: the same `s`
+//! satisfies both relations.
+
+use super::pke;
+use super::*;
+use core::marker::PhantomData;
+
+pub use pke::{prove_range_link, verify_range_link, Proof, PublicParams};
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PublicCommit<G: Curve> {
+    public_key_mask: Vec<i64>,
+    public_key_body: Vec<i64>,
+    mask: Vec<i64>,
+    bodies: Vec<i64>,
+    __marker: PhantomData<G>,
+}
+
+impl<G: Curve> PublicCommit<G> {
+    pub fn new(
+        public_key_mask: Vec<i64>,
+        public_key_body: Vec<i64>,
+        mask: Vec<i64>,
+        bodies: Vec<i64>,
+    ) -> Self {
+        Self {
+            public_key_mask,
+            public_key_body,
+            mask,
+            bodies,
+            __marker: PhantomData,
+        }
+    }
+
+    fn to_pke_commit(&self) -> pke::PublicCommit<G> {
+        pke::PublicCommit::new(
+            self.public_key_mask.clone(),
+            self.mask.clone(),
+            self.public_key_body.clone(),
+            self.bodies.clone(),
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PrivateCommit<G: Curve> {
+    inner: pke::PrivateCommit<G>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn commit<G: Curve>(
+    public_key_mask: Vec<i64>,
+    public_key_body: Vec<i64>,
+    mask: Vec<i64>,
+    bodies: Vec<i64>,
+    s: Vec<i64>,
+    public_key_noise: Vec<i64>,
+    m: Vec<i64>,
+    e: Vec<i64>,
+    public: &PublicParams<G>,
+    rng: &mut dyn RngCore,
+) -> (PublicCommit<G>, PrivateCommit<G>) {
+    let (_, inner) = pke::commit(
+        public_key_mask.clone(),
+        mask.clone(),
+        public_key_body.clone(),
+        bodies.clone(),
+        s,
+        public_key_noise,
+        m,
+        e,
+        public,
+        rng,
+    );
+
+    (
+        PublicCommit::new(public_key_mask, public_key_body, mask, bodies),
+        PrivateCommit { inner },
+    )
+}
+
+pub fn prove<G: Curve>(
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    private_commit: &PrivateCommit<G>,
+    metadata: &[u8],
+    load: ComputeLoad,
+    rng: &mut dyn RngCore,
+) -> Proof<G> {
+    pke::prove(
+        (public.0, &public.1.to_pke_commit()),
+        &private_commit.inner,
+        metadata,
+        load,
+        rng,
+    )
+}
+
+/// Proves the encryption like [`prove`], also returning the opening used to link range
+/// commitments to the messages with [`prove_range_link`].
+pub fn prove_with_opening<G: Curve>(
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    private_commit: &PrivateCommit<G>,
+    metadata: &[u8],
+    load: ComputeLoad,
+    rng: &mut dyn RngCore,
+) -> (Proof<G>, range::BitsOpening<G>) {
+    pke::prove_with_opening(
+        (public.0, &public.1.to_pke_commit()),
+        &private_commit.inner,
+        metadata,
+        load,
+        rng,
+    )
+}
+
+#[allow(clippy::result_unit_err)]
+pub fn verify<G: Curve>(
+    proof: &Proof<G>,
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    metadata: &[u8],
+) -> Result<(), ()> {
+    pke::verify(proof, (public.0, &public.1.to_pke_commit()), metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_pke_sk() {
+        let d = 2048;
+        let k = 320;
+        let b_i = 512;
+        let q = 0;
+        let t = 1024;
+
+        let delta = {
+            let q = if q == 0 { 1i128 << 64 } else { q as i128 };
+            (q / t as i128) as u64
+        };
+
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let polymul_rev = |a: &[i64], b: &[i64]| -> Vec<i64> {
+            assert_eq!(a.len(), b.len());
+            let d = a.len();
+            let mut c = vec![0i64; d];
+
+            for i in 0..d {
+                for j in 0..d {
+                    if i + j < d {
+                        c[i + j] = c[i + j].wrapping_add(a[i].wrapping_mul(b[d - j - 1]));
+                    } else {
+                        c[i + j - d] = c[i + j - d].wrapping_sub(a[i].wrapping_mul(b[d - j - 1]));
+                    }
+                }
+            }
+
+            c
+        };
+
+        let public_key_mask = (0..d).map(|_| rng.gen::<i64>()).collect::<Vec<_>>();
+        let mask = (0..d).map(|_| rng.gen::<i64>()).collect::<Vec<_>>();
+        let s = (0..d)
+            .map(|_| (rng.gen::<u64>() % 2) as i64)
+            .collect::<Vec<_>>();
+        let fake_s = (0..d)
+            .map(|_| (rng.gen::<u64>() % 2) as i64)
+            .collect::<Vec<_>>();
+        let public_key_noise = (0..d)
+            .map(|_| (rng.gen::<u64>() % (2 * b_i)) as i64 - b_i as i64)
+            .collect::<Vec<_>>();
+        let e = (0..k)
+            .map(|_| (rng.gen::<u64>() % (2 * b_i)) as i64 - b_i as i64)
+            .collect::<Vec<_>>();
+        let m = (0..k)
+            .map(|_| (rng.gen::<u64>() % t) as i64)
+            .collect::<Vec<_>>();
+        let fake_m = (0..k)
+            .map(|_| (rng.gen::<u64>() % t) as i64)
+            .collect::<Vec<_>>();
+
+        let public_key_body = |s: &[i64]| {
+            polymul_rev(&public_key_mask, s)
+                .into_iter()
+                .zip(public_key_noise.iter())
+                .map(|(x, e)| x.wrapping_add(*e))
+                .collect::<Vec<_>>()
+        };
+        let encrypt = |s: &[i64]| {
+            let mut bodies = vec![0i64; k];
+            for i in 0..k {
+                let mut dot = 0i64;
+                for j in 0..d {
+                    let a = if i + j < d {
+                        mask[d - j - i - 1]
+                    } else {
+                        mask[2 * d - j - i - 1].wrapping_neg()
+                    };
+
+                    dot = dot.wrapping_add(s[d - j - 1].wrapping_mul(a));
+                }
+
+                bodies[i] = dot
+                    .wrapping_add(e[i])
+                    .wrapping_add((delta * m[i] as u64) as i64);
+            }
+            bodies
+        };
+
+        let real_public_key_body = public_key_body(&s);
+        let other_public_key_body = public_key_body(&fake_s);
+        let bodies = encrypt(&s);
+
+        let metadata = [1u8; 40];
+        let fake_metadata = [2u8; 40];
+
+        type Curve = crate::curve_api::Bls12_446;
+
+        let public_param = pke::crs_gen::<Curve>(d, k, b_i, q, t, rng);
+
+        for (use_fake_s, use_fake_m, use_fake_metadata_verify, use_other_public_key) in
+            itertools::iproduct!([false, true], [false, true], [false, true], [false, true])
+        {
+            let (public_commit, private_commit) = commit(
+                public_key_mask.clone(),
+                if use_other_public_key {
+                    other_public_key_body.clone()
+                } else {
+                    real_public_key_body.clone()
+                },
+                mask.clone(),
+                bodies.clone(),
+                if use_fake_s {
+                    fake_s.clone()
+                } else {
+                    s.clone()
+                },
+                public_key_noise.clone(),
+                if use_fake_m {
+                    fake_m.clone()
+                } else {
+                    m.clone()
+                },
+                e.clone(),
+                &public_param,
+                rng,
+            );
+
+            let proof = prove(
+                (&public_param, &public_commit),
+                &private_commit,
+                &metadata,
+                ComputeLoad::Proof,
+                rng,
+            );
+
+            let verify_metadata = if use_fake_metadata_verify {
+                &fake_metadata
+            } else {
+                &metadata
+            };
+
+            // The ciphertexts are only accepted for the public key of their secret key
+            assert_eq!(
+                verify(&proof, (&public_param, &public_commit), verify_metadata).is_err(),
+                use_fake_s || use_fake_m || use_fake_metadata_verify || use_other_public_key
+            );
+        }
+
+        // A key encrypting the list and matching another public key is rejected
+        let other_bodies = encrypt(&fake_s);
+        let (public_commit, private_commit) = commit(
+            public_key_mask.clone(),
+            real_public_key_body.clone(),
+            mask.clone(),
+            other_bodies,
+            fake_s.clone(),
+            public_key_noise.clone(),
+            m.clone(),
+            e.clone(),
+            &public_param,
+            rng,
+        );
+        let proof = prove(
+            (&public_param, &public_commit),
+            &private_commit,
+            &metadata,
+            ComputeLoad::Proof,
+            rng,
+        );
+        assert!(verify(&proof, (&public_param, &public_commit), &metadata).is_err());
+    }
+}
@@ -2,9 +2,11 @@
 #![allow(non_snake_case)]
 
 use super::*;
+use crate::backward_compatibility::PkeV2ProofVersions;
 use crate::four_squares::*;
 use core::marker::PhantomData;
 use rayon::prelude::*;
+use tfhe_versionable::Versionize;
 
 fn bit_iter(x: u64, nbits: u32) -> impl Iterator<Item = bool> {
     (0..nbits).map(move |idx| ((x >> idx) & 1) != 0)
@@ -87,15 +89,129 @@ impl<G: Curve> PublicParams<G> {
     pub fn exclusive_max_noise(&self) -> u64 {
         self.B
     }
+
+    /// Checks that the group elements of the parameters are on the curve and in the prime order
+    /// subgroup, and that there are as many of them as expected.
+    ///
+    /// This is already done when the parameters are deserialized with [`Validate::Yes`], which is
+    /// what their serde and versioned implementations use. Parameters loaded from an untrusted
+    /// source with [`Validate::No`] must be validated before being used.
+    pub fn validate(&self) -> Result<(), SerializationError> {
+        if self.g_lists.message_len != self.n {
+            return Err(SerializationError::InvalidData);
+        }
+
+        self.check()
+    }
+
+    /// Precomputes fixed-base tables of the CRS elements, which are then used by [`prove`] for
+    /// its multi-scalar multiplications.
+    ///
+    /// The tables are built once for all the following proofs, and hold about
+    /// `Zp bits / window_bits` multiples of each element of the CRS: larger windows use less
+    /// memory but make each multiplication accumulate more buckets (`2^(window_bits - 1)`).
+    /// Windows of 8 to 12 bits are a good trade-off for CRS of a few thousand elements.
+    ///
+    /// The tables are shared by the clones of the parameters, are not serialized and are dropped
+    /// by [`ceremony::UpdatableParams::update`].
+    pub fn precompute_msm_tables(&mut self, window_bits: usize) {
+        self.g_lists.precompute_msm_tables(window_bits);
+    }
+
+    pub fn has_msm_tables(&self) -> bool {
+        self.g_lists.has_msm_tables()
+    }
+}
+
+impl<G: Curve> ceremony::UpdatableParams<G> for PublicParams<G> {
+    fn update(&self, rng: &mut dyn RngCore) -> (Self, ceremony::UpdateProof<G>) {
+        let (g_lists, proof) = self.g_lists.update(rng);
+        let mut updated = self.clone();
+        updated.g_lists = g_lists;
+        (updated, proof)
+    }
+
+    fn verify_update(&self, updated: &Self, proof: &ceremony::UpdateProof<G>) -> Result<(), ()> {
+        let Self {
+            g_lists,
+            D,
+            n,
+            d,
+            k,
+            B,
+            B_r,
+            B_bound,
+            m_bound,
+            q,
+            t,
+            hash,
+            hash_R,
+            hash_t,
+            hash_w,
+            hash_agg,
+            hash_lmap,
+            hash_phi,
+            hash_xi,
+            hash_z,
+            hash_chi,
+        } = updated;
+
+        if (*D, *n, *d, *k, *B, *B_r, *B_bound, *m_bound, *q, *t)
+            != (
+                self.D,
+                self.n,
+                self.d,
+                self.k,
+                self.B,
+                self.B_r,
+                self.B_bound,
+                self.m_bound,
+                self.q,
+                self.t,
+            )
+            || [
+                hash, hash_R, hash_t, hash_w, hash_agg, hash_lmap, hash_phi, hash_xi, hash_z,
+                hash_chi,
+            ] != [
+                &self.hash,
+                &self.hash_R,
+                &self.hash_t,
+                &self.hash_w,
+                &self.hash_agg,
+                &self.hash_lmap,
+                &self.hash_phi,
+                &self.hash_xi,
+                &self.hash_z,
+                &self.hash_chi,
+            ]
+        {
+            return Err(());
+        }
+
+        self.g_lists.verify_update(g_lists, proof)
+    }
+
+    fn is_well_formed(&self) -> bool {
+        self.g_lists.is_well_formed()
+    }
 }
 
 /// This represents a proof that the given ciphertext is a valid encryptions of the input messages
 /// with the provided public key.
-#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Clone,
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+    Versionize,
+)]
 #[serde(bound(
     deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>",
     serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize"
 ))]
+#[versionize(PkeV2ProofVersions)]
 pub struct Proof<G: Curve> {
     C_hat_e: G::G2,
     C_e: G::G1,
@@ -113,6 +229,17 @@ pub struct Proof<G: Curve> {
     C_hat_w: Option<G::G2>,
 }
 
+impl<G: Curve> Proof<G> {
+    /// Checks that the group elements of the proof are on the curve and in the prime order
+    /// subgroup.
+    ///
+    /// This is already done when the proof is deserialized with [`Validate::Yes`], which is what
+    /// its serde and versioned implementations use.
+    pub fn validate(&self) -> Result<(), SerializationError> {
+        self.check()
+    }
+}
+
 /// This is the public part of the commitment. `a` and `b` are the mask and body of the public key,
 /// `c1` and `c2` are the mask and body of the ciphertext.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -312,6 +439,19 @@ pub fn prove<G: Curve>(
     load: ComputeLoad,
     rng: &mut dyn RngCore,
 ) -> Proof<G> {
+    prove_with_opening(public, private_commit, metadata, load, rng).0
+}
+
+/// Proves the encryption like [`prove`], also returning the opening of the commitment to the
+/// bits of the witness so that range commitments can be linked to the messages with
+/// [`prove_range_link`].
+pub fn prove_with_opening<G: Curve>(
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    private_commit: &PrivateCommit<G>,
+    metadata: &[u8],
+    load: ComputeLoad,
+    rng: &mut dyn RngCore,
+) -> (Proof<G>, range::BitsOpening<G>) {
     _ = load;
     let (
         &PublicParams {
@@ -339,7 +479,6 @@ pub fn prove<G: Curve>(
         },
         PublicCommit { a, b, c1, c2, .. },
     ) = public;
-    let g_list = &*g_lists.g_list.0;
     let g_hat_list = &*g_lists.g_hat_list.0;
 
     let PrivateCommit { r, e1, m, e2, .. } = private_commit;
@@ -489,13 +628,12 @@ pub fn prove<G: Curve>(
         .chain(e2_zp.iter().copied())
         .chain(v_zp)
         .collect::<Box<[_]>>();
-    let C_hat_e =
-        g_hat.mul_scalar(gamma_hat_e) + G::G2::multi_mul_scalar(&g_hat_list[..d + k + 4], &scalars);
+    let C_hat_e = g_hat.mul_scalar(gamma_hat_e) + g_lists.g2_msm(0..d + k + 4, &scalars);
 
     let (C_e, C_r_tilde) = rayon::join(
         || {
             scalars.reverse();
-            g.mul_scalar(gamma_e) + G::G1::multi_mul_scalar(&g_list[n - (d + k + 4)..n], &scalars)
+            g.mul_scalar(gamma_e) + g_lists.g1_msm(n - (d + k + 4)..n, &scalars)
         },
         || {
             let scalars = r1_zp
@@ -503,7 +641,7 @@ pub fn prove<G: Curve>(
                 .chain(r2_zp.iter())
                 .copied()
                 .collect::<Box<[_]>>();
-            g.mul_scalar(gamma_r) + G::G1::multi_mul_scalar(&g_list[..d + k], &scalars)
+            g.mul_scalar(gamma_r) + g_lists.g1_msm(0..d + k, &scalars)
         },
     );
 
@@ -593,8 +731,8 @@ pub fn prove<G: Curve>(
         .collect::<Box<[_]>>();
 
     let C_R = g.mul_scalar(gamma_R)
-        + G::G1::multi_mul_scalar(
-            &g_list[..128],
+        + g_lists.g1_msm(
+            0..128,
             &w_R.iter()
                 .copied()
                 .map(G::Zp::from_i64)
@@ -692,8 +830,7 @@ pub fn prove<G: Curve>(
         .rev()
         .map(|(&y, &w)| if w { y } else { G::Zp::ZERO })
         .collect::<Box<[_]>>();
-    let C_y =
-        g.mul_scalar(gamma_y) + G::G1::multi_mul_scalar(&g_list[n - (D + 128 * m)..n], &scalars);
+    let C_y = g.mul_scalar(gamma_y) + g_lists.g1_msm(n - (D + 128 * m)..n, &scalars);
 
     let mut t = vec![G::Zp::ZERO; n];
     G::Zp::hash_128bit(
@@ -1056,7 +1193,7 @@ pub fn prove<G: Curve>(
     let pi = if P_pi.is_empty() {
         G::G1::ZERO
     } else {
-        g.mul_scalar(P_pi[0]) + G::G1::multi_mul_scalar(&g_list[..P_pi.len() - 1], &P_pi[1..])
+        g.mul_scalar(P_pi[0]) + g_lists.g1_msm(0..P_pi.len() - 1, &P_pi[1..])
     };
 
     let mut xi_scaled = xi.clone();
@@ -1088,7 +1225,7 @@ pub fn prove<G: Curve>(
         })
         .collect::<Box<[_]>>();
     scalars.reverse();
-    let C_h1 = G::G1::multi_mul_scalar(&g_list[n - (D + 128 * m)..n], &scalars);
+    let C_h1 = g_lists.g1_msm(n - (D + 128 * m)..n, &scalars);
 
     let mut scalars = (0..n)
         .map(|j| {
@@ -1115,35 +1252,32 @@ pub fn prove<G: Curve>(
         })
         .collect::<Box<[_]>>();
     scalars.reverse();
-    let C_h2 = G::G1::multi_mul_scalar(&g_list[..n], &scalars);
+    let C_h2 = g_lists.g1_msm(0..n, &scalars);
     let (C_hat_h3, C_hat_w) = match load {
         ComputeLoad::Proof => rayon::join(
             || {
-                Some(G::G2::multi_mul_scalar(
-                    &g_hat_list[n - (d + k)..n],
-                    &(0..d + k)
-                        .rev()
-                        .map(|j| {
-                            let mut acc = G::Zp::ZERO;
-                            for (i, &phi) in phi.iter().enumerate() {
-                                match R(i, d + k + 4 + j) {
-                                    0 => {}
-                                    1 => acc += phi,
-                                    -1 => acc -= phi,
-                                    _ => unreachable!(),
+                Some(
+                    g_lists.g2_msm(
+                        n - (d + k)..n,
+                        &(0..d + k)
+                            .rev()
+                            .map(|j| {
+                                let mut acc = G::Zp::ZERO;
+                                for (i, &phi) in phi.iter().enumerate() {
+                                    match R(i, d + k + 4 + j) {
+                                        0 => {}
+                                        1 => acc += phi,
+                                        -1 => acc -= phi,
+                                        _ => unreachable!(),
+                                    }
                                 }
-                            }
-                            delta_r * acc - delta_theta_q * theta[j]
-                        })
-                        .collect::<Box<[_]>>(),
-                ))
-            },
-            || {
-                Some(G::G2::multi_mul_scalar(
-                    &g_hat_list[..d + k + 4],
-                    &w[..d + k + 4],
-                ))
+                                delta_r * acc - delta_theta_q * theta[j]
+                            })
+                            .collect::<Box<[_]>>(),
+                    ),
+                )
             },
+            || Some(g_lists.g2_msm(0..d + k + 4, &w[..d + k + 4])),
         ),
         ComputeLoad::Verify => (None, None),
     };
@@ -1153,7 +1287,7 @@ pub fn prove<G: Curve>(
     let C_hat_h3_bytes = C_hat_h3_bytes.as_ref().map(|x| x.as_ref()).unwrap_or(&[]);
     let C_hat_w_bytes = C_hat_w_bytes.as_ref().map(|x| x.as_ref()).unwrap_or(&[]);
 
-    let C_hat_t = G::G2::multi_mul_scalar(g_hat_list, &t);
+    let C_hat_t = g_lists.g2_msm(0..n, &t);
 
     let mut z = G::Zp::ZERO;
     G::Zp::hash(
@@ -1343,9 +1477,9 @@ pub fn prove<G: Curve>(
         Q_kzg[j + 1] = G::Zp::ZERO;
     }
 
-    let pi_kzg = g.mul_scalar(q[0]) + G::G1::multi_mul_scalar(&g_list[..n - 1], &q[1..n]);
+    let pi_kzg = g.mul_scalar(q[0]) + g_lists.g1_msm(0..n - 1, &q[1..n]);
 
-    Proof {
+    let proof = Proof {
         C_hat_e,
         C_e,
         C_r_tilde,
@@ -1359,7 +1493,86 @@ pub fn prove<G: Curve>(
         C_hat_w,
         pi,
         pi_kzg,
-    }
+    };
+
+    (
+        proof,
+        range::BitsOpening {
+            gamma: gamma_bin,
+            bits: w_bin.into_vec(),
+        },
+    )
+}
+
+/// Proves that the value committed in `range_public` is made of the message bits selected in the
+/// `linked` proofs, see [`range::LinkProof`].
+///
+/// Each entry holds a proof made with [`prove_with_opening`] and its opening, the number of
+/// messages proven by the proof and the bits of these messages that are part of the value.
+pub fn prove_range_link<G: Curve>(
+    public: &PublicParams<G>,
+    linked: &[(
+        &Proof<G>,
+        &range::BitsOpening<G>,
+        usize,
+        &[range::MessageBits],
+    )],
+    range_public: (&range::PublicParams<G>, &range::PublicCommit<G>),
+    range_private_commit: &range::PrivateCommit<G>,
+    rng: &mut dyn RngCore,
+) -> range::LinkProof<G> {
+    let bit_commitments = linked
+        .iter()
+        .map(|&(proof, opening, num_messages, message_bits)| {
+            let weighted_bits = range::message_weighted_bits::<G>(
+                public.d,
+                public.t,
+                public.k,
+                num_messages,
+                message_bits,
+            )
+            .expect("the message bits are out of the messages of the proof");
+            (proof.C_hat_bin, opening, weighted_bits)
+        })
+        .collect::<Vec<_>>();
+
+    range::prove_link(
+        &public.g_lists,
+        &bit_commitments,
+        range_public,
+        range_private_commit,
+        rng,
+    )
+}
+
+/// Verifies that the value committed in `range_public` is made of the message bits selected in
+/// the `linked` proofs.
+///
+/// The proofs must be verified on their own, this only checks the link between their commitments
+/// and the range commitment.
+#[allow(clippy::result_unit_err)]
+pub fn verify_range_link<G: Curve>(
+    public: &PublicParams<G>,
+    linked: &[(&Proof<G>, usize, &[range::MessageBits])],
+    range_public: (&range::PublicParams<G>, &range::PublicCommit<G>),
+    proof: &range::LinkProof<G>,
+) -> Result<(), ()> {
+    let bit_commitments = linked
+        .iter()
+        .map(|&(pke_proof, num_messages, message_bits)| {
+            range::message_weighted_bits::<G>(
+                public.d,
+                public.t,
+                public.k,
+                num_messages,
+                message_bits,
+            )
+            .map(|weighted_bits| (pke_proof.C_hat_bin, weighted_bits))
+            .ok_or(())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    range::verify_link(&public.g_lists, &bit_commitments, range_public, proof)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1446,6 +1659,10 @@ fn compute_a_theta<G: Curve>(
         let a_theta = &mut a_theta[d..];
         let delta = G::Zp::from_u64(delta);
         let step = t.ilog2() as usize;
+        // With a plaintext modulus of 1 the witness has no message bits
+        if step == 0 {
+            return;
+        }
 
         a_theta
             .par_chunks_exact_mut(step)
@@ -1470,6 +1687,49 @@ pub fn verify<G: Curve>(
     public: (&PublicParams<G>, &PublicCommit<G>),
     metadata: &[u8],
 ) -> Result<(), ()> {
+    let equations = pairing_equations(proof, public, metadata)?;
+
+    if equations
+        .iter()
+        .all(|equation| batch::check_equation::<G>(equation))
+    {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+/// Verifies many proofs made with the same public parameters, sharing the cost of the pairings
+/// between them.
+///
+/// Each entry of `proofs` holds a proof with its public commit and metadata. On failure, the
+/// indices of the invalid proofs are returned.
+pub fn verify_batch<G: Curve>(
+    public: &PublicParams<G>,
+    proofs: &[(&Proof<G>, &PublicCommit<G>, &[u8])],
+) -> Result<(), Vec<usize>> {
+    let equations = proofs
+        .par_iter()
+        .map(|&(proof, public_commit, metadata)| {
+            pairing_equations(proof, (public, public_commit), metadata).ok()
+        })
+        .collect();
+
+    let invalid = batch::find_invalid::<G>(equations);
+    if invalid.is_empty() {
+        Ok(())
+    } else {
+        Err(invalid)
+    }
+}
+
+/// Computes the pairing equations that hold if the proof is valid, or fails if the proof is
+/// inconsistent with the public parameters.
+fn pairing_equations<G: Curve>(
+    proof: &Proof<G>,
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    metadata: &[u8],
+) -> Result<Vec<batch::PairingEquation<G>>, ()> {
     let &Proof {
         C_hat_e,
         C_e,
@@ -1485,7 +1745,6 @@ pub fn verify<G: Curve>(
         pi,
         pi_kzg,
     } = proof;
-    let pairing = G::Gt::pairing;
 
     let &PublicParams {
         ref g_lists,
@@ -1780,12 +2039,10 @@ pub fn verify<G: Curve>(
     let delta_theta_q =
         delta_theta * G::Zp::from_u128(if q == 0 { 1u128 << 64 } else { q as u128 });
 
-    let rhs = pairing(pi, g_hat);
-    let lhs = {
-        let lhs0 = pairing(C_y.mul_scalar(delta_y) + C_h1, C_hat_bin);
-        let lhs1 = pairing(C_e.mul_scalar(delta_l) + C_h2, C_hat_e);
-
-        let lhs2 = pairing(
+    let equation0 = vec![
+        (C_y.mul_scalar(delta_y) + C_h1, C_hat_bin),
+        (C_e.mul_scalar(delta_l) + C_h2, C_hat_e),
+        (
             C_r_tilde,
             match C_hat_h3 {
                 Some(C_hat_h3) => C_hat_h3,
@@ -1808,9 +2065,9 @@ pub fn verify<G: Curve>(
                         .collect::<Box<[_]>>(),
                 ),
             },
-        );
-        let lhs3 = pairing(
-            C_R,
+        ),
+        (
+            -C_R,
             G::G2::multi_mul_scalar(
                 &g_hat_list[n - 128..n],
                 &(0..128)
@@ -1818,27 +2075,22 @@ pub fn verify<G: Curve>(
                     .map(|j| delta_r * phi[j] + delta_dec * xi[j])
                     .collect::<Box<[_]>>(),
             ),
-        );
-        let lhs4 = pairing(
-            C_e.mul_scalar(delta_e),
+        ),
+        (
+            -C_e.mul_scalar(delta_e),
             match C_hat_w {
                 Some(C_hat_w) => C_hat_w,
                 None => G::G2::multi_mul_scalar(&g_hat_list[..d + k + 4], &w[..d + k + 4]),
             },
-        );
-        let lhs5 = pairing(C_y.mul_scalar(delta_eq), C_hat_t);
-        let lhs6 = pairing(
-            G::G1::projective(g_list[0]),
+        ),
+        (-C_y.mul_scalar(delta_eq), C_hat_t),
+        (
+            -G::G1::projective(g_list[0])
+                .mul_scalar(delta_theta * t_theta + delta_l * sqr(G::Zp::from_u64(B))),
             G::G2::projective(g_hat_list[n - 1]),
-        )
-        .mul_scalar(delta_theta * t_theta + delta_l * sqr(G::Zp::from_u64(B)));
-
-        lhs0 + lhs1 + lhs2 - lhs3 - lhs4 - lhs5 - lhs6
-    };
-
-    if lhs != rhs {
-        return Err(());
-    }
+        ),
+        (-pi, g_hat),
+    ];
 
     let mut z = G::Zp::ZERO;
     G::Zp::hash(
@@ -2014,12 +2266,14 @@ pub fn verify<G: Curve>(
     let chi3 = chi2 * chi;
     let chi4 = chi3 * chi;
 
-    let lhs = pairing(
-        C_h1 + C_h2.mul_scalar(chi) - g.mul_scalar(p_h1 + chi * p_h2),
-        g_hat,
-    ) + pairing(
-        g,
-        {
+    let equation1 = vec![
+        (
+            C_h1 + C_h2.mul_scalar(chi)
+                - g.mul_scalar(p_h1 + chi * p_h2 + p_t * chi2 + p_h3 * chi3 + p_w * chi4)
+                + pi_kzg.mul_scalar(z),
+            g_hat,
+        ),
+        (g, {
             let mut C_hat = C_hat_t.mul_scalar(chi2);
             if let Some(C_hat_h3) = C_hat_h3 {
                 C_hat += C_hat_h3.mul_scalar(chi3);
@@ -2028,17 +2282,11 @@ pub fn verify<G: Curve>(
                 C_hat += C_hat_w.mul_scalar(chi4);
             }
             C_hat
-        } - g_hat.mul_scalar(p_t * chi2 + p_h3 * chi3 + p_w * chi4),
-    );
-    let rhs = pairing(
-        pi_kzg,
-        G::G2::projective(g_hat_list[0]) - g_hat.mul_scalar(z),
-    );
-    if lhs != rhs {
-        Err(())
-    } else {
-        Ok(())
-    }
+        }),
+        (-pi_kzg, G::G2::projective(g_hat_list[0])),
+    ];
+
+    Ok(vec![equation0, equation1])
 }
 
 #[cfg(test)]
@@ -2047,8 +2295,7 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::{Rng, SeedableRng};
 
-    #[test]
-    fn test_pke() {
+    fn test_pke<G: Curve>() {
         let d = 2048;
         let k = 320;
         let B = 1048576;
@@ -2175,26 +2422,30 @@ mod tests {
             m_roundtrip[i] = result;
         }
 
-        type Curve = crate::curve_api::Bls12_446;
-
-        let serialize_then_deserialize =
-            |public_param: &PublicParams<Curve>,
-             compress: Compress|
-             -> Result<PublicParams<Curve>, SerializationError> {
-                let mut data = Vec::new();
-                public_param.serialize_with_mode(&mut data, compress)?;
+        let serialize_then_deserialize = |public_param: &PublicParams<G>,
+                                          compress: Compress|
+         -> Result<PublicParams<G>, SerializationError> {
+            let mut data = Vec::new();
+            public_param.serialize_with_mode(&mut data, compress)?;
 
-                PublicParams::deserialize_with_mode(data.as_slice(), compress, Validate::No)
-            };
+            PublicParams::deserialize_with_mode(data.as_slice(), compress, Validate::No)
+        };
 
         // To check management of bigger k_max from CRS during test
         let crs_k = k + 1 + (rng.gen::<usize>() % (d - k));
 
-        let original_public_param = crs_gen::<Curve>(d, crs_k, B, q, t, rng);
+        let original_public_param = crs_gen::<G>(d, crs_k, B, q, t, rng);
         let public_param_that_was_compressed =
             serialize_then_deserialize(&original_public_param, Compress::No).unwrap();
         let public_param_that_was_not_compressed =
             serialize_then_deserialize(&original_public_param, Compress::Yes).unwrap();
+        let batch_public_param = original_public_param.clone();
+        let mut public_param_with_msm_tables = original_public_param.clone();
+        public_param_with_msm_tables.precompute_msm_tables(8);
+        assert!(public_param_with_msm_tables.has_msm_tables());
+
+        let mut batch = Vec::new();
+        let mut expected_invalid = Vec::new();
 
         for (
             public_param,
@@ -2208,6 +2459,7 @@ mod tests {
                 original_public_param,
                 public_param_that_was_compressed,
                 public_param_that_was_not_compressed,
+                public_param_with_msm_tables,
             ],
             [false, true],
             [false, true],
@@ -2259,15 +2511,97 @@ mod tests {
                     &metadata
                 };
 
+                let is_invalid = use_fake_e1
+                    || use_fake_e2
+                    || use_fake_r
+                    || use_fake_m
+                    || use_fake_metadata_verify;
+
                 assert_eq!(
                     verify(&proof, (&public_param, &public_commit), verify_metadata).is_err(),
-                    use_fake_e1
-                        || use_fake_e2
-                        || use_fake_r
-                        || use_fake_m
-                        || use_fake_metadata_verify
+                    is_invalid
                 );
+
+                if is_invalid {
+                    expected_invalid.push(batch.len());
+                }
+                batch.push((proof, public_commit.clone(), verify_metadata.to_vec()));
             }
         }
+
+        let batch = batch
+            .iter()
+            .map(|(proof, public_commit, metadata)| (proof, public_commit, metadata.as_slice()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            verify_batch(&batch_public_param, &batch).unwrap_err(),
+            expected_invalid
+        );
+
+        let valid_batch = batch
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !expected_invalid.contains(i))
+            .map(|(_, proof)| *proof)
+            .collect::<Vec<_>>();
+        assert!(verify_batch(&batch_public_param, &valid_batch).is_ok());
+    }
+
+    #[test]
+    fn test_pke_bls12_446() {
+        test_pke::<crate::curve_api::Bls12_446>();
+    }
+
+    #[test]
+    fn test_pke_bn254() {
+        test_pke::<crate::curve_api::Bn254>();
+    }
+
+    #[test]
+    fn test_public_params_validation() {
+        type Curve = crate::curve_api::Bls12_446;
+
+        let rng = &mut StdRng::seed_from_u64(0);
+        let public_param = crs_gen::<Curve>(256, 16, 1 << 10, 0, 16, rng);
+        public_param.validate().unwrap();
+
+        let mut compressed = Vec::new();
+        public_param
+            .serialize_with_mode(&mut compressed, Compress::Yes)
+            .unwrap();
+        assert!(compressed.len() < public_param.serialized_size(Compress::No));
+
+        let serialized = serde_json::to_vec(&public_param).unwrap();
+        let deserialized: PublicParams<Curve> = serde_json::from_slice(&serialized).unwrap();
+        assert_eq!(deserialized.g_lists.g_list.0, public_param.g_lists.g_list.0);
+        assert_eq!(
+            deserialized.g_lists.g_hat_list.0,
+            public_param.g_lists.g_hat_list.0
+        );
+
+        // Lists that do not match the size of the parameters are rejected
+        let mut truncated = public_param.clone();
+        truncated.g_lists.g_hat_list.0.pop();
+        assert!(truncated.validate().is_err());
+
+        let mut data = Vec::new();
+        truncated
+            .serialize_with_mode(&mut data, Compress::Yes)
+            .unwrap();
+        assert!(PublicParams::<Curve>::deserialize_with_mode(
+            data.as_slice(),
+            Compress::Yes,
+            Validate::Yes
+        )
+        .is_err());
+
+        let unchecked = PublicParams::<Curve>::deserialize_with_mode(
+            data.as_slice(),
+            Compress::Yes,
+            Validate::No,
+        )
+        .unwrap();
+        assert!(unchecked.validate().is_err());
     }
 }
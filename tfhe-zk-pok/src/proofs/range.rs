@@ -1,12 +1,17 @@
 use super::*;
+use crate::backward_compatibility::{
+    LinkProofVersions, RangeProofVersions, RangePublicCommitVersions, UpperBoundProofVersions,
+};
+use tfhe_versionable::Versionize;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct PublicParams<G: Curve> {
     g_lists: GroupElements<G>,
     hash: [u8; HASH_METADATA_LEN_BYTES],
     hash_s: [u8; HASH_METADATA_LEN_BYTES],
     hash_t: [u8; HASH_METADATA_LEN_BYTES],
     hash_agg: [u8; HASH_METADATA_LEN_BYTES],
+    hash_link: [u8; HASH_METADATA_LEN_BYTES],
 }
 
 impl<G: Curve> PublicParams<G> {
@@ -17,6 +22,7 @@ impl<G: Curve> PublicParams<G> {
         hash_s: [u8; HASH_METADATA_LEN_BYTES],
         hash_t: [u8; HASH_METADATA_LEN_BYTES],
         hash_agg: [u8; HASH_METADATA_LEN_BYTES],
+        hash_link: [u8; HASH_METADATA_LEN_BYTES],
     ) -> Self {
         Self {
             g_lists: GroupElements::from_vec(g_list, g_hat_list),
@@ -24,8 +30,52 @@ impl<G: Curve> PublicParams<G> {
             hash_s,
             hash_t,
             hash_agg,
+            hash_link,
         }
     }
+
+    /// Maximum number of bits of the values that can be proven with these parameters.
+    pub fn max_num_bits(&self) -> usize {
+        self.g_lists.message_len
+    }
+}
+
+impl<G: Curve> ceremony::UpdatableParams<G> for PublicParams<G> {
+    fn update(&self, rng: &mut dyn RngCore) -> (Self, ceremony::UpdateProof<G>) {
+        let (g_lists, proof) = self.g_lists.update(rng);
+        let mut updated = self.clone();
+        updated.g_lists = g_lists;
+        (updated, proof)
+    }
+
+    fn verify_update(&self, updated: &Self, proof: &ceremony::UpdateProof<G>) -> Result<(), ()> {
+        let Self {
+            g_lists,
+            hash,
+            hash_s,
+            hash_t,
+            hash_agg,
+            hash_link,
+        } = updated;
+
+        if [hash, hash_s, hash_t, hash_agg, hash_link]
+            != [
+                &self.hash,
+                &self.hash_s,
+                &self.hash_t,
+                &self.hash_agg,
+                &self.hash_link,
+            ]
+        {
+            return Err(());
+        }
+
+        self.g_lists.verify_update(g_lists, proof)
+    }
+
+    fn is_well_formed(&self) -> bool {
+        self.g_lists.is_well_formed()
+    }
 }
 
 #[allow(dead_code)]
@@ -34,7 +84,12 @@ pub struct PrivateParams<G: Curve> {
     alpha: G::Zp,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize"
+))]
+#[versionize(RangePublicCommitVersions)]
 pub struct PublicCommit<G: Curve> {
     l: usize,
     v_hat: G::G2,
@@ -46,7 +101,12 @@ pub struct PrivateCommit<G: Curve> {
     r: G::Zp,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize"
+))]
+#[versionize(RangeProofVersions)]
 pub struct Proof<G: Curve> {
     c_y: G::G1,
     c_hat: G::G2,
@@ -61,6 +121,7 @@ pub fn crs_gen<G: Curve>(max_nbits: usize, rng: &mut dyn RngCore) -> PublicParam
         hash_s: core::array::from_fn(|_| rng.gen()),
         hash_t: core::array::from_fn(|_| rng.gen()),
         hash_agg: core::array::from_fn(|_| rng.gen()),
+        hash_link: core::array::from_fn(|_| rng.gen()),
     }
 }
 
@@ -69,10 +130,19 @@ pub fn commit<G: Curve>(
     l: usize,
     public: &PublicParams<G>,
     rng: &mut dyn RngCore,
+) -> (PublicCommit<G>, PrivateCommit<G>) {
+    let r = G::Zp::rand(rng);
+    commit_with_randomness(x, l, r, public)
+}
+
+fn commit_with_randomness<G: Curve>(
+    x: u64,
+    l: usize,
+    r: G::Zp,
+    public: &PublicParams<G>,
 ) -> (PublicCommit<G>, PrivateCommit<G>) {
     let g_hat = G::G2::GENERATOR;
 
-    let r = G::Zp::rand(rng);
     let v_hat = g_hat.mul_scalar(r)
         + G::G2::projective(public.g_lists.g_hat_list[1]).mul_scalar(G::Zp::from_u64(x));
 
@@ -92,6 +162,7 @@ pub fn prove<G: Curve>(
         hash_s,
         hash_t,
         hash_agg,
+        hash_link: _,
     } = public.0;
     let n = g_lists.message_len;
 
@@ -276,6 +347,7 @@ pub fn verify<G: Curve>(
         hash_s,
         hash_t,
         hash_agg,
+        hash_link: _,
     } = public.0;
     let n = g_lists.message_len;
 
@@ -374,6 +446,380 @@ pub fn verify<G: Curve>(
     }
 }
 
+/// Proof that a committed value `x` satisfies `0 <= x <= upper_bound`.
+///
+/// With `l` the number of bits of `upper_bound`, it is made of a proof that `x < 2^l` and of a
+/// proof that `upper_bound - x < 2^l`, the second commitment being derived from the first one so
+/// that the verifier can check that both values sum to `upper_bound`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize"
+))]
+#[versionize(UpperBoundProofVersions)]
+pub struct UpperBoundProof<G: Curve> {
+    proof: Proof<G>,
+    complement_commit: PublicCommit<G>,
+    complement_proof: Proof<G>,
+}
+
+/// Returns the number of bits `l` of the commitments proven by an [`UpperBoundProof`] for the
+/// given bound.
+pub fn upper_bound_num_bits(upper_bound: u64) -> usize {
+    (u64::BITS - upper_bound.leading_zeros()).max(1) as usize
+}
+
+/// Commits to `x` so that it can be proven to be at most `upper_bound` with
+/// [`prove_upper_bound`].
+pub fn commit_upper_bound<G: Curve>(
+    x: u64,
+    upper_bound: u64,
+    public: &PublicParams<G>,
+    rng: &mut dyn RngCore,
+) -> (PublicCommit<G>, PrivateCommit<G>) {
+    commit(x, upper_bound_num_bits(upper_bound), public, rng)
+}
+
+pub fn prove_upper_bound<G: Curve>(
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    private_commit: &PrivateCommit<G>,
+    upper_bound: u64,
+    rng: &mut dyn RngCore,
+) -> UpperBoundProof<G> {
+    let (public_params, public_commit) = public;
+    let &PrivateCommit { x, r } = private_commit;
+    assert!(x <= upper_bound);
+    let l = upper_bound_num_bits(upper_bound);
+    assert!(l <= public_params.max_num_bits());
+    assert_eq!(public_commit.l, l);
+
+    let (complement_commit, complement_private_commit) =
+        commit_with_randomness(upper_bound - x, l, -r, public_params);
+
+    let proof = prove(public, private_commit, rng);
+    let complement_proof = prove(
+        (public_params, &complement_commit),
+        &complement_private_commit,
+        rng,
+    );
+
+    UpperBoundProof {
+        proof,
+        complement_commit,
+        complement_proof,
+    }
+}
+
+#[allow(clippy::result_unit_err)]
+pub fn verify_upper_bound<G: Curve>(
+    proof: &UpperBoundProof<G>,
+    public: (&PublicParams<G>, &PublicCommit<G>),
+    upper_bound: u64,
+) -> Result<(), ()> {
+    let (public_params, public_commit) = public;
+    let UpperBoundProof {
+        proof,
+        complement_commit,
+        complement_proof,
+    } = proof;
+
+    let l = upper_bound_num_bits(upper_bound);
+    if l > public_params.max_num_bits() || public_commit.l != l || complement_commit.l != l {
+        return Err(());
+    }
+
+    // The randomness of the commitments cancel out, so they must sum to a commitment of the bound
+    let sum = public_commit.v_hat + complement_commit.v_hat;
+    let expected = G::G2::projective(public_params.g_lists.g_hat_list[1])
+        .mul_scalar(G::Zp::from_u64(upper_bound));
+    if sum.to_bytes().as_ref() != expected.to_bytes().as_ref() {
+        return Err(());
+    }
+
+    verify(proof, (public_params, public_commit))?;
+    verify(complement_proof, (public_params, complement_commit))
+}
+
+/// Opening of the commitment `g_hat^gamma * prod_j g_hat_j^bits_j` that a pke proof makes to the
+/// binary decomposition of its witness, returned by
+/// [`pke::prove_with_opening`](super::pke::prove_with_opening) and
+/// [`pke_v2::prove_with_opening`](super::pke_v2::prove_with_opening).
+///
+/// It reveals the witness, so it must stay with the prover. It is only used to link range
+/// commitments to the messages of the proof with a [`LinkProof`].
+#[derive(Clone, Debug)]
+pub struct BitsOpening<G: Curve> {
+    pub(crate) gamma: G::Zp,
+    pub(crate) bits: Vec<bool>,
+}
+
+/// Bits of a message proven by a pke proof that are part of a value linked to a range
+/// commitment.
+///
+/// The bits in `bits` of the message `message_index` are read as an integer, which is weighted by
+/// `weight` in the value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MessageBits {
+    pub message_index: usize,
+    pub bits: Range<u32>,
+    pub weight: u128,
+}
+
+/// Proof that the value committed in a range [`PublicCommit`] is the weighted sum of bits of
+/// messages proven by pke proofs, so that a range proof on the commitment holds for the value
+/// that is encrypted.
+///
+/// With `C_j` the commitments of the pke proofs to the bits `w_j` of their witnesses, `u_j` the
+/// weights of these bits in the value and `P_j = sum_i u_ji g_(n+1-i)`, the prover sends a
+/// commitment `X = g_1^x g^rho` to the value and a group element `pi` such that
+///
+/// `sum_j e(P_j, C_j) = e(X, g_hat_n) + e(pi, g_hat)`
+///
+/// which can only be computed if `x = sum_j <u_j, w_j>`, as `g_(n+1)` is not part of the public
+/// parameters of the pke proofs. A sigma protocol then shows that `X` and the range commitment
+/// `v_hat = g_hat^r h^x` open to the same `x`.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>, \
+    G::Zp: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize, G::Zp: serde::Serialize"
+))]
+#[versionize(LinkProofVersions)]
+pub struct LinkProof<G: Curve> {
+    x_commit: G::G1,
+    pi: G::G1,
+    challenge: G::Zp,
+    z_x: G::Zp,
+    z_rho: G::Zp,
+    z_r: G::Zp,
+}
+
+/// Bits of a pke witness, given by their one-based position in the committed vector, with their
+/// weight in a linked value.
+pub(crate) type WeightedBits<G> = Vec<(usize, <G as Curve>::Zp)>;
+
+/// Returns the weighted bits of the messages of a pke proof whose witness starts with the `d`
+/// coefficients of the encryption randomness followed by the `log2(t)` bits of each message.
+///
+/// Returns `None` if some bits are out of the messages proven by the proof.
+pub(crate) fn message_weighted_bits<G: Curve>(
+    d: usize,
+    t: u64,
+    k: usize,
+    num_messages: usize,
+    message_bits: &[MessageBits],
+) -> Option<WeightedBits<G>> {
+    let step = t.ilog2();
+    if num_messages > k {
+        return None;
+    }
+
+    let mut weighted_bits = Vec::new();
+    for MessageBits {
+        message_index,
+        bits,
+        weight,
+    } in message_bits
+    {
+        if *message_index >= num_messages || bits.start > bits.end || bits.end > step {
+            return None;
+        }
+
+        for bit in bits.clone() {
+            let position = d + message_index * step as usize + bit as usize + 1;
+            let weight = G::Zp::from_u128(*weight) * G::Zp::from_u64(1 << (bit - bits.start));
+            weighted_bits.push((position, weight));
+        }
+    }
+
+    Some(weighted_bits)
+}
+
+fn link_challenge<G: Curve>(
+    hash_link: &[u8; HASH_METADATA_LEN_BYTES],
+    bit_commitments: &[(G::G2, &WeightedBits<G>)],
+    v_hat: G::G2,
+    x_commit: G::G1,
+    pi: G::G1,
+    a_x: G::G1,
+    a_v: G::G2,
+) -> G::Zp {
+    let linked_bytes = &*bit_commitments
+        .iter()
+        .flat_map(|(commitment, weighted_bits)| {
+            let mut bytes = commitment.to_bytes().as_ref().to_vec();
+            for (position, weight) in weighted_bits.iter() {
+                bytes.extend_from_slice(&position.to_le_bytes());
+                bytes.extend_from_slice(weight.to_bytes().as_ref());
+            }
+            bytes
+        })
+        .collect::<Box<_>>();
+
+    let mut challenge = G::Zp::ZERO;
+    G::Zp::hash(
+        core::slice::from_mut(&mut challenge),
+        &[
+            hash_link,
+            linked_bytes,
+            v_hat.to_bytes().as_ref(),
+            x_commit.to_bytes().as_ref(),
+            pi.to_bytes().as_ref(),
+            a_x.to_bytes().as_ref(),
+            a_v.to_bytes().as_ref(),
+        ],
+    );
+    challenge
+}
+
+/// Proves that the value of the range commitment is the weighted sum of the bits opened by the
+/// openings of the commitments, which are made with the group elements `g_lists` of a pke proof.
+pub(crate) fn prove_link<G: Curve>(
+    g_lists: &GroupElements<G>,
+    bit_commitments: &[(G::G2, &BitsOpening<G>, WeightedBits<G>)],
+    range_public: (&PublicParams<G>, &PublicCommit<G>),
+    range_private_commit: &PrivateCommit<G>,
+    rng: &mut dyn RngCore,
+) -> LinkProof<G> {
+    let (range_params, range_commit) = range_public;
+    let &PrivateCommit { x, r } = range_private_commit;
+    let n = g_lists.message_len;
+
+    let g = G::G1::GENERATOR;
+    let g_hat = G::G2::GENERATOR;
+    let g_1 = G::G1::projective(g_lists.g_list[1]);
+    let g_n = G::G1::projective(g_lists.g_list[n]);
+    let h = G::G2::projective(range_params.g_lists.g_hat_list[1]);
+
+    let x_zp = G::Zp::from_u64(x);
+    let mut linked_x = G::Zp::ZERO;
+
+    // pi = sum_j sum_i u_ji (gamma_j g_(n+1-i) + sum_(k != i) w_jk g_(n+1-i+k)), the terms in
+    // g_(n+1) being the linked value
+    let mut scalars = vec![G::Zp::ZERO; 2 * n];
+    for (_, opening, weighted_bits) in bit_commitments {
+        assert!(opening.bits.len() <= n);
+        for &(i, u) in weighted_bits {
+            assert!((1..=n).contains(&i));
+            scalars[n - i] += u * opening.gamma;
+            for (k, _) in opening.bits.iter().enumerate().filter(|&(_, &bit)| bit) {
+                let k = k + 1;
+                if k == i {
+                    linked_x += u;
+                } else {
+                    scalars[n - i + k] += u;
+                }
+            }
+        }
+    }
+    assert!(
+        linked_x.to_bytes().as_ref() == x_zp.to_bytes().as_ref(),
+        "the committed value is not the one of the linked bits"
+    );
+
+    let rho = G::Zp::rand(rng);
+    let x_commit = g_1.mul_scalar(x_zp) + g.mul_scalar(rho);
+    let pi = g_lists.g1_msm(0..2 * n, &scalars) - g_n.mul_scalar(rho);
+
+    let (a, b, c) = (G::Zp::rand(rng), G::Zp::rand(rng), G::Zp::rand(rng));
+    let a_x = g_1.mul_scalar(a) + g.mul_scalar(b);
+    let a_v = g_hat.mul_scalar(c) + h.mul_scalar(a);
+
+    let commitments = bit_commitments
+        .iter()
+        .map(|(commitment, _, weighted_bits)| (*commitment, weighted_bits))
+        .collect::<Vec<_>>();
+    let challenge = link_challenge::<G>(
+        &range_params.hash_link,
+        &commitments,
+        range_commit.v_hat,
+        x_commit,
+        pi,
+        a_x,
+        a_v,
+    );
+
+    LinkProof {
+        x_commit,
+        pi,
+        challenge,
+        z_x: a + challenge * x_zp,
+        z_rho: b + challenge * rho,
+        z_r: c + challenge * r,
+    }
+}
+
+#[allow(clippy::result_unit_err)]
+pub(crate) fn verify_link<G: Curve>(
+    g_lists: &GroupElements<G>,
+    bit_commitments: &[(G::G2, WeightedBits<G>)],
+    range_public: (&PublicParams<G>, &PublicCommit<G>),
+    proof: &LinkProof<G>,
+) -> Result<(), ()> {
+    let (range_params, range_commit) = range_public;
+    let &LinkProof {
+        x_commit,
+        pi,
+        challenge,
+        z_x,
+        z_rho,
+        z_r,
+    } = proof;
+    let n = g_lists.message_len;
+
+    if bit_commitments
+        .iter()
+        .flat_map(|(_, weighted_bits)| weighted_bits)
+        .any(|&(i, _)| !(1..=n).contains(&i))
+    {
+        return Err(());
+    }
+
+    let g = G::G1::GENERATOR;
+    let g_hat = G::G2::GENERATOR;
+    let g_1 = G::G1::projective(g_lists.g_list[1]);
+    let h = G::G2::projective(range_params.g_lists.g_hat_list[1]);
+
+    let a_x = g_1.mul_scalar(z_x) + g.mul_scalar(z_rho) - x_commit.mul_scalar(challenge);
+    let a_v = g_hat.mul_scalar(z_r) + h.mul_scalar(z_x) - range_commit.v_hat.mul_scalar(challenge);
+
+    let commitments = bit_commitments
+        .iter()
+        .map(|(commitment, weighted_bits)| (*commitment, weighted_bits))
+        .collect::<Vec<_>>();
+    let expected_challenge = link_challenge::<G>(
+        &range_params.hash_link,
+        &commitments,
+        range_commit.v_hat,
+        x_commit,
+        pi,
+        a_x,
+        a_v,
+    );
+    if expected_challenge.to_bytes().as_ref() != challenge.to_bytes().as_ref() {
+        return Err(());
+    }
+
+    let mut terms = bit_commitments
+        .iter()
+        .map(|(commitment, weighted_bits)| {
+            let (bases, scalars): (Vec<_>, Vec<_>) = weighted_bits
+                .iter()
+                .map(|&(i, u)| (g_lists.g_list[n + 1 - i], u))
+                .unzip();
+            (G::G1::multi_mul_scalar(&bases, &scalars), *commitment)
+        })
+        .collect::<Vec<_>>();
+    terms.push((-x_commit, G::G2::projective(g_lists.g_hat_list[n])));
+    terms.push((-pi, g_hat));
+
+    if batch::check_equation::<G>(&terms) {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -393,4 +839,31 @@ mod tests {
         let verify = verify(&proof, (&public_params, &public_commit));
         assert!(verify.is_ok());
     }
+
+    #[test]
+    fn test_range_upper_bound() {
+        let rng = &mut StdRng::seed_from_u64(0);
+
+        let max_nbits = 10;
+        let upper_bound = 300;
+        let public_params = crs_gen::<crate::curve_api::Bls12_446>(max_nbits, rng);
+
+        let x = rng.gen::<u64>() % (upper_bound + 1);
+        let (public_commit, private_commit) =
+            commit_upper_bound(x, upper_bound, &public_params, rng);
+        let proof = prove_upper_bound(
+            (&public_params, &public_commit),
+            &private_commit,
+            upper_bound,
+            rng,
+        );
+        assert!(verify_upper_bound(&proof, (&public_params, &public_commit), upper_bound).is_ok());
+        assert!(
+            verify_upper_bound(&proof, (&public_params, &public_commit), upper_bound - 1).is_err()
+        );
+
+        // Commitment to a value above the bound, proven with a complement that does not match
+        let (fake_commit, _) = commit(upper_bound + 1, 9, &public_params, rng);
+        assert!(verify_upper_bound(&proof, (&public_params, &fake_commit), upper_bound).is_err());
+    }
 }
@@ -7,14 +7,14 @@ fn bit_iter(x: u64, nbits: u32) -> impl Iterator<Item = bool> {
     (0..nbits).map(move |idx| ((x >> idx) & 1) == 1)
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct PublicParams<G: Curve> {
     g_lists: GroupElements<G>,
-    d: usize,
-    big_n: usize,
-    big_m: usize,
-    b_i: u64,
-    q: u64,
+    pub d: usize,
+    pub big_n: usize,
+    pub big_m: usize,
+    pub b_i: u64,
+    pub q: u64,
     hash: [u8; HASH_METADATA_LEN_BYTES],
     hash_t: [u8; HASH_METADATA_LEN_BYTES],
     hash_agg: [u8; HASH_METADATA_LEN_BYTES],
@@ -63,20 +63,34 @@ pub struct PrivateParams<G: Curve> {
     alpha: G::Zp,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct PublicCommit<G: Curve> {
     a: Matrix<i64>,
     c: Vector<i64>,
     __marker: PhantomData<G>,
 }
 
+impl<G: Curve> PublicCommit<G> {
+    pub fn new(a: Matrix<i64>, c: Vector<i64>) -> Self {
+        Self {
+            a,
+            c,
+            __marker: PhantomData,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct PrivateCommit<G: Curve> {
     s: Vector<i64>,
     __marker: PhantomData<G>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(bound(
+    deserialize = "G: Curve, G::G1: serde::Deserialize<'de>, G::G2: serde::Deserialize<'de>",
+    serialize = "G: Curve, G::G1: serde::Serialize, G::G2: serde::Serialize"
+))]
 pub struct Proof<G: Curve> {
     c_hat: G::G2,
     c_y: G::G1,
@@ -114,13 +128,13 @@ pub fn crs_gen<G: Curve>(
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Vector<T> {
     pub data: Vec<T>,
     pub polynomial_size: usize,
     pub nrows: usize,
 }
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Matrix<T> {
     pub data: Vec<T>,
     pub polynomial_size: usize,
@@ -204,6 +218,7 @@ pub fn commit<G: Curve>(
 pub fn prove<G: Curve>(
     public: (&PublicParams<G>, &PublicCommit<G>),
     private_commit: &PrivateCommit<G>,
+    metadata: &[u8],
     load: ComputeLoad,
     rng: &mut dyn RngCore,
 ) -> Proof<G> {
@@ -268,16 +283,16 @@ pub fn prove<G: Curve>(
         }
 
         for ((ck, rk), old_ck) in zip(zip(c, r), &polymul) {
-            let q = if q == 0 { q as i128 } else { 1i128 << 64 };
+            let q = if q == 0 { 1i128 << 64 } else { q as i128 };
             let mut new_ck = old_ck.rem_euclid(q);
             if new_ck >= q / 2 {
                 new_ck -= q;
             }
             assert!((old_ck - new_ck) % q == 0);
-            assert!((*rk).unsigned_abs() < b_r);
 
             *ck = new_ck as i64;
             *rk = ((old_ck - new_ck) / q) as i64;
+            assert!((*rk).unsigned_abs() < b_r);
         }
     }
     let w_tilde = Iterator::chain(
@@ -329,7 +344,10 @@ pub fn prove<G: Curve>(
     .collect::<Box<_>>();
 
     let mut y = vec![G::Zp::ZERO; n];
-    G::Zp::hash(&mut y, &[hash, x_bytes, c_hat.to_bytes().as_ref()]);
+    G::Zp::hash(
+        &mut y,
+        &[hash, metadata, x_bytes, c_hat.to_bytes().as_ref()],
+    );
     let y = OneBased(y);
 
     let scalars = (n + 1 - big_d..n + 1)
@@ -342,6 +360,7 @@ pub fn prove<G: Curve>(
         &mut t,
         &[
             hash_t,
+            metadata,
             &(1..n + 1)
                 .flat_map(|i| y[i].to_bytes().as_ref().to_vec())
                 .collect::<Box<_>>(),
@@ -357,6 +376,7 @@ pub fn prove<G: Curve>(
         &mut theta_bar,
         &[
             hash_lmap,
+            metadata,
             x_bytes,
             c_hat.to_bytes().as_ref(),
             c_y.to_bytes().as_ref(),
@@ -426,7 +446,8 @@ pub fn prove<G: Curve>(
 
         for k in 0..b_step {
             let a_theta_jk = &mut a_theta_j[k..];
-            let mut c = -G::Zp::from_u64(1 << k) * G::Zp::from_u64(q);
+            let mut c = -G::Zp::from_u64(1 << k)
+                * G::Zp::from_u128(if q == 0 { 1u128 << 64 } else { q as u128 });
             if k + 1 == b_step {
                 c = -c;
             }
@@ -441,6 +462,7 @@ pub fn prove<G: Curve>(
         &mut delta,
         &[
             hash_agg,
+            metadata,
             x_bytes,
             c_hat.to_bytes().as_ref(),
             c_y.to_bytes().as_ref(),
@@ -509,6 +531,7 @@ pub fn prove<G: Curve>(
             core::array::from_mut(&mut z),
             &[
                 hash_z,
+                metadata,
                 x_bytes,
                 c_hat.to_bytes().as_ref(),
                 c_y.to_bytes().as_ref(),
@@ -549,6 +572,7 @@ pub fn prove<G: Curve>(
             core::array::from_mut(&mut w),
             &[
                 hash_w,
+                metadata,
                 x_bytes,
                 c_hat.to_bytes().as_ref(),
                 c_y.to_bytes().as_ref(),
@@ -614,6 +638,7 @@ pub fn prove<G: Curve>(
 pub fn verify<G: Curve>(
     proof: &Proof<G>,
     public: (&PublicParams<G>, &PublicCommit<G>),
+    metadata: &[u8],
 ) -> Result<(), ()> {
     let &Proof {
         c_hat,
@@ -675,6 +700,7 @@ pub fn verify<G: Curve>(
         &mut delta,
         &[
             hash_agg,
+            metadata,
             x_bytes,
             c_hat.to_bytes().as_ref(),
             c_y.to_bytes().as_ref(),
@@ -683,7 +709,10 @@ pub fn verify<G: Curve>(
     let [delta_eq, delta_y] = delta;
 
     let mut y = vec![G::Zp::ZERO; n];
-    G::Zp::hash(&mut y, &[hash, x_bytes, c_hat.to_bytes().as_ref()]);
+    G::Zp::hash(
+        &mut y,
+        &[hash, metadata, x_bytes, c_hat.to_bytes().as_ref()],
+    );
     let y = OneBased(y);
 
     let mut t = vec![G::Zp::ZERO; n];
@@ -691,6 +720,7 @@ pub fn verify<G: Curve>(
         &mut t,
         &[
             hash_t,
+            metadata,
             &(1..n + 1)
                 .flat_map(|i| y[i].to_bytes().as_ref().to_vec())
                 .collect::<Box<_>>(),
@@ -706,6 +736,7 @@ pub fn verify<G: Curve>(
         &mut theta_bar,
         &[
             hash_lmap,
+            metadata,
             x_bytes,
             c_hat.to_bytes().as_ref(),
             c_y.to_bytes().as_ref(),
@@ -775,7 +806,8 @@ pub fn verify<G: Curve>(
 
         for k in 0..b_step {
             let a_theta_jk = &mut a_theta_j[k..];
-            let mut c = -G::Zp::from_u64(1 << k) * G::Zp::from_u64(q);
+            let mut c = -G::Zp::from_u64(1 << k)
+                * G::Zp::from_u128(if q == 0 { 1u128 << 64 } else { q as u128 });
             if k + 1 == b_step {
                 c = -c;
             }
@@ -791,6 +823,7 @@ pub fn verify<G: Curve>(
             core::array::from_mut(&mut z),
             &[
                 hash_z,
+                metadata,
                 x_bytes,
                 c_hat.to_bytes().as_ref(),
                 c_y.to_bytes().as_ref(),
@@ -843,6 +876,7 @@ pub fn verify<G: Curve>(
             core::array::from_mut(&mut w),
             &[
                 hash_w,
+                metadata,
                 x_bytes,
                 c_hat.to_bytes().as_ref(),
                 c_y.to_bytes().as_ref(),
@@ -942,6 +976,9 @@ mod tests {
         let q = 1217;
         let b_i: u64 = 512;
 
+        let metadata = [1u8; 32];
+        let fake_metadata = [2u8; 32];
+
         let mut a = Matrix::new(d, big_m, big_n, 0i64);
         let mut c = Vector::new(d, big_n, 0i64);
         let mut s = Vector::new(d, big_m, 0i64);
@@ -985,7 +1022,7 @@ mod tests {
             }
 
             for (ck, old_ck) in core::iter::zip(c, &polymul) {
-                let q = if q == 0 { q as i128 } else { 1i128 << 64 };
+                let q = if q == 0 { 1i128 << 64 } else { q as i128 };
                 let mut new_ck = old_ck.rem_euclid(q);
                 if new_ck >= q / 2 {
                     new_ck -= q;
@@ -997,9 +1034,17 @@ mod tests {
         let public_params = crs_gen::<crate::curve_api::Bls12_446>(d, big_n, big_m, b_i, q, rng);
         let (public_commit, private_commit) = commit(a, c, s, &public_params, rng);
         for load in [ComputeLoad::Proof, ComputeLoad::Verify] {
-            let proof =
-                time(|| prove((&public_params, &public_commit), &private_commit, load, rng));
-            let verify = time(|| verify(&proof, (&public_params, &public_commit)));
+            let proof = time(|| {
+                prove(
+                    (&public_params, &public_commit),
+                    &private_commit,
+                    &metadata,
+                    load,
+                    rng,
+                )
+            });
+            assert!(verify(&proof, (&public_params, &public_commit), &fake_metadata).is_err());
+            let verify = time(|| verify(&proof, (&public_params, &public_commit), &metadata));
             assert!(verify.is_ok());
         }
     }
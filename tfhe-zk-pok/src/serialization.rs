@@ -0,0 +1,89 @@
+//! Compressed serialization of the public parameters.
+//!
+//! The public parameters hold a few thousand group elements, so they are serialized with their
+//! canonical encoding where the elements are compressed, which is about half the size of the
+//! uncompressed one. Parameters are validated when they are deserialized, so that they can be
+//! loaded from an untrusted source.
+
+use crate::backward_compatibility::SerializablePublicParamsVersions;
+use crate::curve_api::{ark_de, ark_se, Curve};
+use crate::proofs::{pke, pke_v2};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate,
+};
+use tfhe_versionable::{Unversionize, UnversionizeError, Versionize, VersionizeOwned};
+
+/// Serialized form of public parameters, holding their compressed canonical encoding.
+#[derive(serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(SerializablePublicParamsVersions)]
+pub struct SerializablePublicParams {
+    compressed: Vec<u8>,
+}
+
+impl SerializablePublicParams {
+    pub fn compress<T: CanonicalSerialize>(public_params: &T) -> Self {
+        let mut compressed = Vec::with_capacity(public_params.serialized_size(Compress::Yes));
+        public_params
+            .serialize_with_mode(&mut compressed, Compress::Yes)
+            .expect("Serialization into a Vec should not fail");
+
+        Self { compressed }
+    }
+
+    /// Decodes the public parameters, checking that their group elements are on the curve and in
+    /// the prime order subgroup.
+    pub fn decompress<T: CanonicalDeserialize>(&self) -> Result<T, SerializationError> {
+        T::deserialize_with_mode(self.compressed.as_slice(), Compress::Yes, Validate::Yes)
+    }
+}
+
+/// Implements serde and the versioning traits for public parameters through their compressed
+/// canonical encoding.
+macro_rules! impl_compressed_serialization {
+    ($public_params:ty) => {
+        impl<G: Curve> serde::Serialize for $public_params {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                ark_se(self, serializer)
+            }
+        }
+
+        impl<'de, G: Curve> serde::Deserialize<'de> for $public_params {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                ark_de(deserializer)
+            }
+        }
+
+        impl<G: Curve> Versionize for $public_params {
+            type Versioned<'vers> = <SerializablePublicParams as VersionizeOwned>::VersionedOwned;
+
+            fn versionize(&self) -> Self::Versioned<'_> {
+                SerializablePublicParams::compress(self).versionize_owned()
+            }
+        }
+
+        impl<G: Curve> VersionizeOwned for $public_params {
+            type VersionedOwned = <SerializablePublicParams as VersionizeOwned>::VersionedOwned;
+
+            fn versionize_owned(self) -> Self::VersionedOwned {
+                SerializablePublicParams::compress(&self).versionize_owned()
+            }
+        }
+
+        impl<G: Curve> Unversionize for $public_params {
+            fn unversionize(versioned: Self::VersionedOwned) -> Result<Self, UnversionizeError> {
+                SerializablePublicParams::unversionize(versioned)?
+                    .decompress()
+                    .map_err(|err| UnversionizeError::conversion("SerializablePublicParams", err))
+            }
+        }
+    };
+}
+
+impl_compressed_serialization!(pke::PublicParams<G>);
+impl_compressed_serialization!(pke_v2::PublicParams<G>);
@@ -8,8 +8,9 @@ use tfhe::keycache::NamedParam;
 use tfhe::shortint::parameters::classic::compact_pk::ALL_PARAMETER_VEC_COMPACT_PK;
 use tfhe::shortint::parameters::classic::gaussian::ALL_PARAMETER_VEC_GAUSSIAN;
 use tfhe::shortint::parameters::multi_bit::ALL_MULTI_BIT_PARAMETER_VEC;
+use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
 use tfhe::shortint::parameters::{
-    ShortintParameterSet, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    SanitizationParameters, ShortintParameterSet, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
 };
 
 pub trait ParamDetails<T: UnsignedInteger> {
@@ -72,6 +73,33 @@ impl ParamDetails<u64> for ShortintParameterSet {
     }
 }
 
+// The sanitization only has a GLWE key, its LWE entry is the GLWE key seen as an LWE key
+impl ParamDetails<u128> for SanitizationParameters {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.glwe_dimension
+            .to_equivalent_lwe_dimension(self.polynomial_size)
+    }
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn lwe_noise_distribution(&self) -> DynamicDistribution<u128> {
+        self.glwe_noise_distribution
+    }
+    fn glwe_noise_distribution(&self) -> DynamicDistribution<u128> {
+        self.glwe_noise_distribution
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn log_ciphertext_modulus(&self) -> usize {
+        128
+    }
+}
+
 ///Function to print in the lattice_estimator format the parameters
 /// Format:   LWE.Parameters(n=722, q=2^32, Xs=ND.UniformMod(2),
 /// Xe=ND.DiscreteGaussian(56139.60810663548), tag='test_lattice_estimator')
@@ -89,13 +117,13 @@ pub fn format_lwe_parameters_to_lattice_estimator<
                 param.log_ciphertext_modulus() as f64 + distrib.standard_dev().0.log2();
 
             format!(
-                "{}_LWE = LWE.Parameters(\n n = {},\n q ={},\n Xs=ND.UniformMod(2), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_lwe' \n)\n\n",
-                name, param.lwe_dimension().0, (1u128<<param.log_ciphertext_modulus() as u128), 2.0_f64.powf(modular_std_dev), name)
+                "{}_LWE = LWE.Parameters(\n n = {},\n q = 2^{},\n Xs=ND.UniformMod(2), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_lwe' \n)\n\n",
+                name, param.lwe_dimension().0, param.log_ciphertext_modulus(), 2.0_f64.powf(modular_std_dev), name)
         }
         DynamicDistribution::TUniform(distrib) => {
             format!(
-                "{}_LWE = LWE.Parameters(\n n = {},\n q ={},\n Xs=ND.Uniform(0,1), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_lwe' \n)\n\n",
-                name, param.lwe_dimension().0, (1u128<<param.log_ciphertext_modulus() as u128), tuniform_equivalent_gaussian_std_dev(&distrib), name)
+                "{}_LWE = LWE.Parameters(\n n = {},\n q = 2^{},\n Xs=ND.Uniform(0,1), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_lwe' \n)\n\n",
+                name, param.lwe_dimension().0, param.log_ciphertext_modulus(), tuniform_equivalent_gaussian_std_dev(&distrib), name)
         }
     }
 }
@@ -117,13 +145,13 @@ pub fn format_glwe_parameters_to_lattice_estimator<
                 param.log_ciphertext_modulus() as f64 + distrib.standard_dev().0.log2();
 
             format!(
-                "{}_GLWE = LWE.Parameters(\n n = {},\n q = {},\n Xs=ND.UniformMod(2), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_glwe' \n)\n\n",
-                name, param.glwe_dimension().0 * param.polynomial_size().0, (1u128<<param.log_ciphertext_modulus() as u128), 2.0_f64.powf(modular_std_dev), name)
+                "{}_GLWE = LWE.Parameters(\n n = {},\n q = 2^{},\n Xs=ND.UniformMod(2), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_glwe' \n)\n\n",
+                name, param.glwe_dimension().0 * param.polynomial_size().0, param.log_ciphertext_modulus(), 2.0_f64.powf(modular_std_dev), name)
         }
         DynamicDistribution::TUniform(distrib) => {
             format!(
-                "{}_GLWE = LWE.Parameters(\n n = {},\n q ={},\n Xs=ND.Uniform(0,1), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_glwe' \n)\n\n",
-                name, param.glwe_dimension().0 * param.polynomial_size().0, (1u128<<param.log_ciphertext_modulus() as u128), tuniform_equivalent_gaussian_std_dev(&distrib), name)
+                "{}_GLWE = LWE.Parameters(\n n = {},\n q = 2^{},\n Xs=ND.Uniform(0,1), \n Xe=ND.DiscreteGaussian({}),\n tag='{}_glwe' \n)\n\n",
+                name, param.glwe_dimension().0 * param.polynomial_size().0, param.log_ciphertext_modulus(), tuniform_equivalent_gaussian_std_dev(&distrib), name)
         }
     }
 }
@@ -207,6 +235,11 @@ fn main() {
         &multi_bit_pbs,
     );
 
+    write_all_params_in_file(
+        "shortint_sanitization_parameters_lattice_estimator.sage",
+        &[SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64],
+    );
+
     // TODO perform this gathering later
     // let wopbs = ALL_PARAMETER_VEC_WOPBS
     //     .iter()
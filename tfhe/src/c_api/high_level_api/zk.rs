@@ -95,6 +95,7 @@ pub unsafe extern "C" fn compact_pke_public_params_deserialize(
 pub struct CompactPkeCrs(pub(crate) crate::core_crypto::entities::CompactPkeCrs);
 
 impl_destroy_on_type!(CompactPkeCrs);
+impl_serialize_deserialize_on_type!(CompactPkeCrs);
 
 #[no_mangle]
 pub unsafe extern "C" fn compact_pke_crs_from_config(
@@ -1,8 +1,171 @@
+use std::fmt::{Debug, Display, Formatter};
+
 /// A trait for objects which can be checked to be conformant with a parameter set
 pub trait ParameterSetConformant {
     type ParameterSet;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool;
+
+    /// Checks the conformance of `self` with the parameter set
+    ///
+    /// On failure, the returned [ConformanceError] describes the first field found to be
+    /// mismatching. The default implementation only reports that the whole object is not
+    /// conformant.
+    fn check_conformance(
+        &self,
+        parameter_set: &Self::ParameterSet,
+    ) -> Result<(), ConformanceError> {
+        if self.is_conformant(parameter_set) {
+            Ok(())
+        } else {
+            Err(ConformanceError::invalid(
+                std::any::type_name::<Self>(),
+                "not conformant with the parameter set",
+            ))
+        }
+    }
+}
+
+/// Report of why an object is not conformant with a parameter set
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConformanceError {
+    /// A field does not have the value expected by the parameter set
+    Mismatch {
+        field: &'static str,
+        expected: String,
+        found: String,
+    },
+    /// The size of a list is not valid for its [ListSizeConstraint]
+    ListSize {
+        field: &'static str,
+        size: usize,
+        constraint: ListSizeConstraint,
+    },
+    /// A field is invalid on its own, e.g. its content is not consistent with its metadata
+    Invalid { field: &'static str, reason: String },
+    /// A sub-object is not conformant
+    InField {
+        field: &'static str,
+        error: Box<ConformanceError>,
+    },
+    /// An element of a list is not conformant
+    InElement {
+        field: &'static str,
+        index: usize,
+        error: Box<ConformanceError>,
+    },
+}
+
+impl ConformanceError {
+    pub fn mismatch<T: Debug + ?Sized>(field: &'static str, expected: &T, found: &T) -> Self {
+        Self::Mismatch {
+            field,
+            expected: format!("{expected:?}"),
+            found: format!("{found:?}"),
+        }
+    }
+
+    pub fn invalid(field: &'static str, reason: impl Into<String>) -> Self {
+        Self::Invalid {
+            field,
+            reason: reason.into(),
+        }
+    }
+
+    /// Wraps the error to indicate it comes from the given field of the checked object
+    pub fn in_field(self, field: &'static str) -> Self {
+        Self::InField {
+            field,
+            error: Box::new(self),
+        }
+    }
+
+    /// Wraps the error to indicate it comes from the element at `index` of the given list field
+    pub fn in_element(self, field: &'static str, index: usize) -> Self {
+        Self::InElement {
+            field,
+            index,
+            error: Box::new(self),
+        }
+    }
+
+    /// Returns the innermost error, i.e. the one naming the mismatching field
+    pub fn root_cause(&self) -> &Self {
+        match self {
+            Self::InField { error, .. } | Self::InElement { error, .. } => error.root_cause(),
+            Self::Mismatch { .. } | Self::ListSize { .. } | Self::Invalid { .. } => self,
+        }
+    }
+
+    /// Returns the name of the mismatching field
+    pub fn field(&self) -> &'static str {
+        match self.root_cause() {
+            Self::Mismatch { field, .. }
+            | Self::ListSize { field, .. }
+            | Self::Invalid { field, .. }
+            | Self::InField { field, .. }
+            | Self::InElement { field, .. } => field,
+        }
+    }
+}
+
+impl Display for ConformanceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mismatch {
+                field,
+                expected,
+                found,
+            } => write!(f, "{field} mismatch: expected {expected}, found {found}"),
+            Self::ListSize {
+                field,
+                size,
+                constraint,
+            } => write!(f, "{field} of size {size} does not respect {constraint}"),
+            Self::Invalid { field, reason } => write!(f, "invalid {field}: {reason}"),
+            Self::InField { field, error } => write!(f, "{field}: {error}"),
+            Self::InElement {
+                field,
+                index,
+                error,
+            } => write!(f, "{field}[{index}]: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ConformanceError {}
+
+/// Returns a [ConformanceError::Mismatch] if `found` is not equal to `expected`
+pub fn check_field<T: PartialEq + Debug + ?Sized>(
+    field: &'static str,
+    expected: &T,
+    found: &T,
+) -> Result<(), ConformanceError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(ConformanceError::mismatch(field, expected, found))
+    }
+}
+
+/// Checks the conformance of each element of a list, the error reports the index of the first
+/// non conformant element
+pub fn check_elements<'a, T>(
+    field: &'static str,
+    elements: impl IntoIterator<Item = &'a T>,
+    parameter_set: &T::ParameterSet,
+) -> Result<(), ConformanceError>
+where
+    T: ParameterSetConformant + 'a,
+{
+    elements
+        .into_iter()
+        .enumerate()
+        .try_for_each(|(index, element)| {
+            element
+                .check_conformance(parameter_set)
+                .map_err(|err| err.in_element(field, index))
+        })
 }
 
 /// A constraint on a list size
@@ -11,7 +174,7 @@ pub trait ParameterSetConformant {
 /// Moreover, `n` must be:
 /// - bigger or equal to `min_inclusive_group_count`
 /// - smaller of equal to `max_inclusive_group_count`
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ListSizeConstraint {
     min_inclusive_group_count: usize,
     max_inclusive_group_count: usize,
@@ -64,4 +227,27 @@ impl ListSizeConstraint {
             && size >= self.min_inclusive_group_count * self.group_size
             && size <= self.max_inclusive_group_count * self.group_size
     }
+
+    /// Returns a [ConformanceError::ListSize] naming `field` if `size` is not valid
+    pub fn check(&self, field: &'static str, size: usize) -> Result<(), ConformanceError> {
+        if self.is_valid(size) {
+            Ok(())
+        } else {
+            Err(ConformanceError::ListSize {
+                field,
+                size,
+                constraint: *self,
+            })
+        }
+    }
+}
+
+impl Display for ListSizeConstraint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "constraint (groups of {} elements, between {} and {} groups)",
+            self.group_size, self.min_inclusive_group_count, self.max_inclusive_group_count
+        )
+    }
 }
@@ -1,16 +1,28 @@
 //! Module containing primitives pertaining to [`GLWE ciphertext
 //! encryption`](`GlweCiphertext#glwe-encryption`).
 
+#[cfg(feature = "zk-pok")]
+use crate::core_crypto::algorithms::glwe_zero_knowledge_verification::{
+    glwe_ciphertext_statement, glwe_statement_matches_crs,
+};
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
 use crate::core_crypto::algorithms::slice_algorithms::{
     slice_wrapping_scalar_div_assign, slice_wrapping_scalar_mul_assign,
 };
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulusKind;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+#[cfg(feature = "zk-pok")]
+use crate::core_crypto::commons::generators::SecretRandomGenerator;
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Distribution, Uniform};
+#[cfg(feature = "zk-pok")]
+use crate::core_crypto::commons::math::random::{
+    BoundedDistribution, RandomGenerable, RandomGenerator, UniformBinary,
+};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+#[cfg(feature = "zk-pok")]
+use tfhe_zk_pok::proofs::rlwe::{commit as glwe_commit, prove as glwe_prove, Vector};
 
 /// Convenience function to share the core logic of the GLWE assign encryption between all functions
 /// needing it.
@@ -766,6 +778,302 @@ pub fn encrypt_glwe_ciphertext_list<
     }
 }
 
+/// Encrypt and generates a zero-knowledge proof of an input message list in an output
+/// [`GLWE ciphertext`](`GlweCiphertext`) using a GLWE public key.
+///
+/// The public key is a [`GLWE ciphertext`](`GlweCiphertext`) encrypting zero under a
+/// [`GLWE secret key`](`GlweSecretKey`), `(A_1, ..., A_k, B = sum_i A_i * S_i + E)`. The output is
+/// `(A_1 * r + e1_1, ..., A_k * r + e1_k, B * r + e2 + delta * m)` with a uniform binary polynomial
+/// `r`, which decrypts under the secret key of the public key with the noise
+/// `E * r + e2 - sum_i e1_i * S_i`. The messages are placed in the coefficients of the body, the
+/// ones not covered by the list being set to 0.
+///
+/// The proof shows that the ciphertext is such an encryption under the given public key, with `r`,
+/// the noises and the messages bounded by the bound of the CRS. It is verified with
+/// [`verify_glwe_ciphertext`] against the same public key and `delta`.
+///
+/// - The input message list must have a length smaller or equal to the [`PolynomialSize`] of the
+///   ciphertext, and its messages must be smaller than the bound of the CRS.
+///
+/// - The noise distribution must be bounded
+///
+/// - Only the native 64 bits modulus is supported
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(512);
+/// let glwe_noise_distribution = TUniform::new(9);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let delta_log = 60;
+/// let delta = 1u64 << delta_log;
+/// let plaintext_modulus = 1u64 << (64 - delta_log);
+///
+/// let metadata = [b'T', b'F', b'H', b'E', b'-', b'r', b's'];
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut random_generator = RandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let crs = GlwePkeCrs::new(
+///     glwe_dimension,
+///     polynomial_size,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     plaintext_modulus,
+///     &mut random_generator,
+/// )
+/// .unwrap();
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // The public key is an encryption of zero under the secret key
+/// let mut glwe_public_key = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe_public_key,
+///     &PlaintextList::new(0u64, PlaintextCount(polynomial_size.0)),
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let messages = (0..16u64).collect::<Vec<_>>();
+///
+/// // Create a new GlweCiphertext
+/// let mut glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// let proof = encrypt_and_prove_glwe_ciphertext_with_public_key(
+///     &glwe_public_key,
+///     &mut glwe,
+///     &messages,
+///     delta,
+///     glwe_noise_distribution,
+///     &mut secret_generator,
+///     &mut encryption_generator,
+///     &mut random_generator,
+///     crs.public_params(),
+///     &metadata,
+///     ZkComputeLoad::Proof,
+/// )
+/// .unwrap();
+///
+/// // verify the ciphertext with the proof
+/// assert!(verify_glwe_ciphertext(
+///     &glwe,
+///     &glwe_public_key,
+///     delta,
+///     &proof,
+///     crs.public_params(),
+///     &metadata
+/// )
+/// .is_valid());
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe, &mut output_plaintext_list);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// // Round the plaintexts
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = signed_decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Check we recovered the original messages
+/// assert_eq!(&messages, &output_plaintext_list.as_ref()[..messages.len()]);
+/// ```
+#[cfg(feature = "zk-pok")]
+#[allow(clippy::too_many_arguments)]
+pub fn encrypt_and_prove_glwe_ciphertext_with_public_key<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    NoiseDistribution,
+    SecretGen,
+    EncryptionGen,
+    G,
+>(
+    glwe_public_key: &GlweCiphertext<KeyCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    messages: &InputCont,
+    delta: Scalar,
+    noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    random_generator: &mut RandomGenerator<G>,
+    public_params: &GlwePkePublicParams,
+    metadata: &[u8],
+    load: ZkComputeLoad,
+) -> crate::Result<GlwePkeProof>
+where
+    Scalar:
+        Encryptable<Uniform, NoiseDistribution> + RandomGenerable<UniformBinary> + CastFrom<u64>,
+    Scalar::Signed: CastFrom<u64>,
+    i64: CastFrom<Scalar>,
+    u64: CastFrom<Scalar>,
+    NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+    G: ByteRandomGenerator,
+{
+    assert!(
+        output.glwe_size() == glwe_public_key.glwe_size(),
+        "Mismatch between GlweSize of output ciphertext and input public key. \
+        Got {:?} in output, and {:?} in public key.",
+        output.glwe_size(),
+        glwe_public_key.glwe_size()
+    );
+    assert!(
+        output.polynomial_size() == glwe_public_key.polynomial_size(),
+        "Mismatch between PolynomialSize of output ciphertext and input public key. \
+        Got {:?} in output, and {:?} in public key.",
+        output.polynomial_size(),
+        glwe_public_key.polynomial_size()
+    );
+
+    let polynomial_size = output.polynomial_size();
+    if messages.container_len() > polynomial_size.0 {
+        return Err(format!(
+            "A GLWE ciphertext holds at most {} messages, {} given",
+            polynomial_size.0,
+            messages.container_len()
+        )
+        .into());
+    }
+
+    if Scalar::BITS != 64 || !output.ciphertext_modulus().is_native_modulus() {
+        return Err("This operation only supports the native 64 bits modulus".into());
+    }
+
+    if !glwe_statement_matches_crs(output, glwe_public_key, public_params) {
+        return Err("Mismatched GLWE parameters between CRS and ciphertext".into());
+    }
+
+    let bound = public_params.b_i;
+    // The proof expect values between [-b, b) (aka -b..b)
+    if noise_distribution.contains(Scalar::Signed::cast_from(bound)) {
+        return Err(
+            "The given random distribution would create random values out \
+            of the expected bounds of given to the CRS"
+                .into(),
+        );
+    }
+    if messages
+        .as_ref()
+        .iter()
+        .any(|&m| u64::cast_from(m) >= bound)
+    {
+        return Err("The messages exceed the bound of the CRS".into());
+    }
+
+    let glwe_dimension = output.glwe_size().to_glwe_dimension().0;
+
+    let mut binary_random_polynomial = Polynomial::new(Scalar::ZERO, polynomial_size);
+    secret_generator.fill_slice_with_random_uniform_binary(binary_random_polynomial.as_mut());
+
+    let mut mask_noise = vec![Scalar::ZERO; glwe_dimension * polynomial_size.0];
+    encryption_generator
+        .fill_slice_with_random_noise_from_distribution(&mut mask_noise, noise_distribution);
+    let mut body_noise = vec![Scalar::ZERO; polynomial_size.0];
+    encryption_generator
+        .fill_slice_with_random_noise_from_distribution(&mut body_noise, noise_distribution);
+
+    let mut padded_messages = vec![Scalar::ZERO; polynomial_size.0];
+    padded_messages[..messages.container_len()].copy_from_slice(messages.as_ref());
+
+    let (key_mask, key_body) = glwe_public_key.get_mask_and_body();
+    let (mut mask, mut body) = output.get_mut_mask_and_body();
+
+    mask.as_mut().copy_from_slice(&mask_noise);
+    for (mut mask_polynomial, key_mask_polynomial) in mask
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(key_mask.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_add_mul_assign(
+            &mut mask_polynomial,
+            &key_mask_polynomial,
+            &binary_random_polynomial,
+        );
+    }
+
+    for ((b, &e), &m) in body
+        .as_mut()
+        .iter_mut()
+        .zip(body_noise.iter())
+        .zip(padded_messages.iter())
+    {
+        *b = e.wrapping_add(m.wrapping_mul(delta));
+    }
+    polynomial_wrapping_add_mul_assign(
+        &mut body.as_mut_polynomial(),
+        &key_body.as_polynomial(),
+        &binary_random_polynomial,
+    );
+
+    let (a, c) = glwe_ciphertext_statement(output, glwe_public_key, delta);
+
+    // The unknowns are r, then the noise of each mask polynomial, the noise of the body and the
+    // messages
+    let mut s = Vector::new(polynomial_size.0, glwe_dimension + 3, 0i64);
+    for (dst, &src) in s[1].iter_mut().zip(binary_random_polynomial.as_ref()) {
+        *dst = i64::cast_from(src);
+    }
+    for (i, noise) in mask_noise.chunks_exact(polynomial_size.0).enumerate() {
+        for (dst, &src) in s[i + 2].iter_mut().zip(noise) {
+            *dst = i64::cast_from(src);
+        }
+    }
+    for (dst, &src) in s[glwe_dimension + 2].iter_mut().zip(body_noise.iter()) {
+        *dst = i64::cast_from(src);
+    }
+    for (dst, &src) in s[glwe_dimension + 3].iter_mut().zip(padded_messages.iter()) {
+        *dst = i64::cast_from(src);
+    }
+
+    let (public_commit, private_commit) = glwe_commit(a, c, s, public_params, random_generator);
+
+    Ok(glwe_prove(
+        (public_params, &public_commit),
+        &private_commit,
+        metadata,
+        load,
+        random_generator,
+    ))
+}
+
 /// Decrypt a [`GLWE ciphertext`](`GlweCiphertext`) in a (scalar) plaintext list.
 ///
 /// See [`encrypt_glwe_ciphertext`] for usage.
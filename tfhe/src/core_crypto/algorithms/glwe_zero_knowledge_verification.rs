@@ -0,0 +1,116 @@
+use crate::core_crypto::entities::GlweCiphertext;
+use crate::core_crypto::prelude::{CastFrom, Container, UnsignedInteger};
+use crate::zk::{GlwePkeProof, GlwePkePublicParams, ZkVerificationOutCome};
+use tfhe_zk_pok::proofs::rlwe::{verify, Matrix, PublicCommit, Vector};
+
+/// Verifies with the given proof that a [`GlweCiphertext`] is a valid encryption of messages
+/// scaled by `delta` under the given GLWE public key.
+///
+/// See [`super::encrypt_and_prove_glwe_ciphertext_with_public_key`].
+pub fn verify_glwe_ciphertext<Scalar, Cont, KeyCont>(
+    glwe_ciphertext: &GlweCiphertext<Cont>,
+    glwe_public_key: &GlweCiphertext<KeyCont>,
+    delta: Scalar,
+    proof: &GlwePkeProof,
+    public_params: &GlwePkePublicParams,
+    metadata: &[u8],
+) -> ZkVerificationOutCome
+where
+    Scalar: UnsignedInteger,
+    i64: CastFrom<Scalar>,
+    Cont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    if Scalar::BITS != 64 || !glwe_ciphertext.ciphertext_modulus().is_native_modulus() {
+        return ZkVerificationOutCome::Invalid;
+    }
+    if !glwe_statement_matches_crs(glwe_ciphertext, glwe_public_key, public_params) {
+        return ZkVerificationOutCome::Invalid;
+    }
+    let (a, c) = glwe_ciphertext_statement(glwe_ciphertext, glwe_public_key, delta);
+    match verify(proof, (public_params, &PublicCommit::new(a, c)), metadata) {
+        Ok(_) => ZkVerificationOutCome::Valid,
+        Err(_) => ZkVerificationOutCome::Invalid,
+    }
+}
+
+pub(crate) fn glwe_statement_matches_crs<Scalar, Cont, KeyCont>(
+    glwe_ciphertext: &GlweCiphertext<Cont>,
+    glwe_public_key: &GlweCiphertext<KeyCont>,
+    public_params: &GlwePkePublicParams,
+) -> bool
+where
+    Scalar: UnsignedInteger,
+    Cont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    let glwe_size = glwe_ciphertext.glwe_size().0;
+
+    glwe_public_key.glwe_size() == glwe_ciphertext.glwe_size()
+        && glwe_public_key.polynomial_size() == glwe_ciphertext.polynomial_size()
+        && glwe_public_key.ciphertext_modulus() == glwe_ciphertext.ciphertext_modulus()
+        && public_params.q == 0
+        && public_params.big_n == glwe_size
+        && public_params.d == glwe_ciphertext.polynomial_size().0
+        && public_params.big_m == glwe_size + 2
+}
+
+/// Returns the matrix and the right-hand side of the relation proven for a GLWE ciphertext
+/// encrypted with a GLWE public key.
+///
+/// With `(A_1, ..., A_k, B)` the public key, the unknowns are the binary polynomial `r`, the noise
+/// `e1_i` of each mask polynomial, the noise `e2` of the body and the messages, so that
+/// `mask_i = A_i * r + e1_i` and `body = B * r + e2 + delta * m`.
+pub(crate) fn glwe_ciphertext_statement<Scalar, Cont, KeyCont>(
+    glwe_ciphertext: &GlweCiphertext<Cont>,
+    glwe_public_key: &GlweCiphertext<KeyCont>,
+    delta: Scalar,
+) -> (Matrix<i64>, Vector<i64>)
+where
+    Scalar: UnsignedInteger,
+    i64: CastFrom<Scalar>,
+    Cont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    let glwe_dimension = glwe_ciphertext.glwe_size().to_glwe_dimension().0;
+    let polynomial_size = glwe_ciphertext.polynomial_size().0;
+
+    let mut a = Matrix::new(
+        polynomial_size,
+        glwe_dimension + 3,
+        glwe_dimension + 1,
+        0i64,
+    );
+    let mut c = Vector::new(polynomial_size, glwe_dimension + 1, 0i64);
+
+    let (key_mask, key_body) = glwe_public_key.get_mask_and_body();
+    let (mask, body) = glwe_ciphertext.get_mask_and_body();
+
+    for (j, (key_mask_polynomial, mask_polynomial)) in key_mask
+        .as_polynomial_list()
+        .iter()
+        .zip(mask.as_polynomial_list().iter())
+        .enumerate()
+    {
+        let j = j + 1;
+        for (dst, &src) in a[(1, j)].iter_mut().zip(key_mask_polynomial.as_ref()) {
+            *dst = i64::cast_from(src);
+        }
+        a[(j + 1, j)][0] = 1;
+        for (dst, &src) in c[j].iter_mut().zip(mask_polynomial.as_ref()) {
+            *dst = i64::cast_from(src);
+        }
+    }
+
+    let body_row = glwe_dimension + 1;
+    for (dst, &src) in a[(1, body_row)].iter_mut().zip(key_body.as_ref()) {
+        *dst = i64::cast_from(src);
+    }
+    a[(glwe_dimension + 2, body_row)][0] = 1;
+    a[(glwe_dimension + 3, body_row)][0] = i64::cast_from(delta);
+    for (dst, &src) in c[body_row].iter_mut().zip(body.as_ref()) {
+        *dst = i64::cast_from(src);
+    }
+
+    (a, c)
+}
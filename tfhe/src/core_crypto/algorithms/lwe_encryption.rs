@@ -16,7 +16,13 @@ use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use rayon::prelude::*;
 #[cfg(feature = "zk-pok")]
-use tfhe_zk_pok::proofs::pke::{commit, prove};
+use tfhe_zk_pok::proofs::decryption::{commit as decryption_commit, prove as decryption_prove};
+#[cfg(feature = "zk-pok")]
+use tfhe_zk_pok::proofs::pke::{commit, prove, prove_with_opening};
+#[cfg(feature = "zk-pok")]
+use tfhe_zk_pok::proofs::pke_sk::{
+    commit as sk_commit, prove_with_opening as sk_prove_with_opening,
+};
 
 /// Convenience function to share the core logic of the LWE encryption between all functions needing
 /// it.
@@ -1840,8 +1846,8 @@ struct CompactPublicKeyRandomVectors<Scalar> {
 }
 
 #[cfg(feature = "zk-pok")]
-fn verify_zero_knowledge_preconditions<Scalar, KeyCont, MaskDistribution, BodyDistribution>(
-    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+fn verify_zero_knowledge_preconditions<Scalar, MaskDistribution, BodyDistribution>(
+    lwe_dimension: LweDimension,
     ciphertext_count: LweCiphertextCount,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     delta: Scalar,
@@ -1856,7 +1862,6 @@ where
     u64: CastFrom<Scalar> + CastInto<Scalar::Signed>,
     MaskDistribution: BoundedDistribution<Scalar::Signed>,
     BodyDistribution: BoundedDistribution<Scalar::Signed>,
-    KeyCont: Container<Element = Scalar>,
 {
     let exclusive_max = public_params.exclusive_max_noise();
     if Scalar::BITS < 64 && (1u64 << Scalar::BITS) >= exclusive_max {
@@ -1910,11 +1915,10 @@ where
         .into());
     }
 
-    if lwe_compact_public_key.lwe_dimension().0 > public_params.d {
+    if lwe_dimension.0 > public_params.d {
         return Err(format!(
             "CRS allows a LweDimension of at most {}, current dimension: {}",
-            public_params.d,
-            lwe_compact_public_key.lwe_dimension().0
+            public_params.d, lwe_dimension.0
         )
         .into());
     }
@@ -2266,7 +2270,7 @@ where
     G: ByteRandomGenerator,
 {
     verify_zero_knowledge_preconditions(
-        lwe_compact_public_key,
+        lwe_compact_public_key.lwe_dimension(),
         LweCiphertextCount(1),
         output.ciphertext_modulus(),
         delta,
@@ -2765,8 +2769,67 @@ where
     EncryptionGen: ByteRandomGenerator,
     G: ByteRandomGenerator,
 {
-    verify_zero_knowledge_preconditions(
+    encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key_with_opening(
         lwe_compact_public_key,
+        output,
+        messages,
+        delta,
+        mask_noise_distribution,
+        body_noise_distribution,
+        secret_generator,
+        encryption_generator,
+        random_generator,
+        public_params,
+        metadata,
+        load,
+    )
+    .map(|(proof, _)| proof)
+}
+
+#[cfg(feature = "zk-pok")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key_with_opening<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    MaskDistribution,
+    NoiseDistribution,
+    SecretGen,
+    EncryptionGen,
+    G,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    messages: &InputCont,
+    delta: Scalar,
+    mask_noise_distribution: MaskDistribution,
+    body_noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    random_generator: &mut RandomGenerator<G>,
+    public_params: &CompactPkePublicParams,
+    metadata: &[u8],
+    load: ZkComputeLoad,
+) -> crate::Result<(CompactPkeProof, CompactPkeProofOpening)>
+where
+    Scalar: Encryptable<MaskDistribution, NoiseDistribution>
+        + RandomGenerable<UniformBinary>
+        + CastFrom<u64>,
+    Scalar::Signed: CastFrom<u64>,
+    i64: CastFrom<Scalar>,
+    u64: CastFrom<Scalar> + CastInto<Scalar::Signed>,
+    MaskDistribution: BoundedDistribution<Scalar::Signed>,
+    NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+    G: ByteRandomGenerator,
+{
+    verify_zero_knowledge_preconditions(
+        lwe_compact_public_key.lwe_dimension(),
         output.lwe_ciphertext_count(),
         output.ciphertext_modulus(),
         delta,
@@ -2850,7 +2913,7 @@ where
         random_generator,
     );
 
-    Ok(prove(
+    Ok(prove_with_opening(
         (public_params, &public_commit),
         &private_commit,
         metadata,
@@ -3293,8 +3356,67 @@ where
     EncryptionGen: ByteRandomGenerator,
     G: ByteRandomGenerator,
 {
-    verify_zero_knowledge_preconditions(
+    par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key_with_opening(
         lwe_compact_public_key,
+        output,
+        messages,
+        delta,
+        mask_noise_distribution,
+        body_noise_distribution,
+        secret_generator,
+        encryption_generator,
+        random_generator,
+        public_params,
+        metadata,
+        load,
+    )
+    .map(|(proof, _)| proof)
+}
+
+#[cfg(feature = "zk-pok")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key_with_opening<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    MaskDistribution,
+    NoiseDistribution,
+    SecretGen,
+    EncryptionGen,
+    G,
+>(
+    lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    messages: &InputCont,
+    delta: Scalar,
+    mask_noise_distribution: MaskDistribution,
+    body_noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    random_generator: &mut RandomGenerator<G>,
+    public_params: &CompactPkePublicParams,
+    metadata: &[u8],
+    load: ZkComputeLoad,
+) -> crate::Result<(CompactPkeProof, CompactPkeProofOpening)>
+where
+    Scalar: Encryptable<MaskDistribution, NoiseDistribution>
+        + RandomGenerable<UniformBinary>
+        + CastFrom<u64>,
+    Scalar::Signed: CastFrom<u64>,
+    i64: CastFrom<Scalar>,
+    u64: CastFrom<Scalar> + CastInto<Scalar::Signed>,
+    MaskDistribution: BoundedDistribution<Scalar::Signed>,
+    NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+    G: ByteRandomGenerator,
+{
+    verify_zero_knowledge_preconditions(
+        lwe_compact_public_key.lwe_dimension(),
         output.lwe_ciphertext_count(),
         output.ciphertext_modulus(),
         delta,
@@ -3378,7 +3500,7 @@ where
         random_generator,
     );
 
-    Ok(prove(
+    Ok(prove_with_opening(
         (public_params, &public_commit),
         &private_commit,
         metadata,
@@ -3387,6 +3509,776 @@ where
     ))
 }
 
+fn encrypt_lwe_compact_ciphertext_list_with_secret_key_impl<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    NoiseDistribution,
+    EncryptionGen,
+>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) -> Vec<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    NoiseDistribution: Distribution,
+    EncryptionGen: ByteRandomGenerator,
+{
+    assert!(
+        output.lwe_size().to_lwe_dimension() == lwe_secret_key.lwe_dimension(),
+        "Mismatch between LweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output.lwe_size().to_lwe_dimension(),
+        lwe_secret_key.lwe_dimension()
+    );
+
+    assert!(
+        output.lwe_ciphertext_count().0 == encoded.plaintext_count().0,
+        "Mismatch between LweCiphertextCount of output ciphertext and \
+        PlaintextCount of input list. Got {:?} in output, and {:?} in input plaintext list.",
+        output.lwe_ciphertext_count(),
+        encoded.plaintext_count()
+    );
+
+    assert!(
+        output.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    let (mut output_mask_list, mut output_body_list) = output.get_mut_mask_and_body_list();
+
+    encryption_generator.fill_slice_with_random_uniform_mask(output_mask_list.as_mut());
+
+    let mut body_noise = vec![Scalar::ZERO; encoded.plaintext_count().0];
+    encryption_generator
+        .fill_slice_with_random_noise_from_distribution(&mut body_noise, noise_distribution);
+
+    let max_ciphertext_per_bin = lwe_secret_key.lwe_dimension().0;
+    output_mask_list
+        .iter()
+        .zip(
+            output_body_list
+                .chunks_mut(max_ciphertext_per_bin)
+                .zip(encoded.chunks(max_ciphertext_per_bin))
+                .zip(body_noise.as_slice().chunks(max_ciphertext_per_bin)),
+        )
+        .for_each(
+            |(output_mask, ((mut output_body_chunk, input_plaintext_chunk), body_noise))| {
+                // The i-th ciphertext of the bin has the mask a * X^i, so its body is
+                // rev(a convolved s)_i + Delta * m + e
+                let mut mask_convolved = vec![Scalar::ZERO; max_ciphertext_per_bin];
+
+                slice_semi_reverse_negacyclic_convolution(
+                    mask_convolved.as_mut_slice(),
+                    output_mask.as_ref(),
+                    lwe_secret_key.as_ref(),
+                );
+
+                output_body_chunk
+                    .iter_mut()
+                    .zip(
+                        mask_convolved
+                            .iter()
+                            .rev()
+                            .zip(input_plaintext_chunk.iter()),
+                    )
+                    .zip(body_noise)
+                    .for_each(|((dst, (&src, plaintext)), body_noise)| {
+                        *dst.data = src.wrapping_add(*body_noise).wrapping_add(*plaintext.0);
+                    });
+            },
+        );
+
+    body_noise
+}
+
+/// Encrypt an input plaintext list in an output [`LWE compact ciphertext
+/// list`](`LweCompactCiphertextList`) using an [`LWE secret key`](`LweSecretKey`).
+///
+/// The masks of the list are sampled uniformly, each of them being shared by up to
+/// [`LweDimension`] ciphertexts like for the encryption with an
+/// [`LWE compact public key`](`LweCompactPublicKey`).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(2048);
+/// let lwe_ciphertext_count = LweCiphertextCount(lwe_dimension.0 * 4);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let encoded_msg = msg << 60;
+/// let input_plaintext_list = PlaintextList::new(encoded_msg, PlaintextCount(lwe_ciphertext_count.0));
+///
+/// // Create a new LweCompactCiphertextList
+/// let mut output_compact_ct_list = LweCompactCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_lwe_compact_ciphertext_list_with_secret_key(
+///     &lwe_secret_key,
+///     &mut output_compact_ct_list,
+///     &input_plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_plaintext_list = input_plaintext_list.clone();
+/// output_plaintext_list.as_mut().fill(0u64);
+///
+/// let lwe_ciphertext_list = output_compact_ct_list.expand_into_lwe_ciphertext_list();
+///
+/// decrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &lwe_ciphertext_list,
+///     &mut output_plaintext_list,
+/// );
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// // Round the plaintexts
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = signed_decomposer.closest_representable(*x.0));
+///
+/// // Check we recovered the original messages
+/// assert_eq!(input_plaintext_list, output_plaintext_list);
+/// ```
+pub fn encrypt_lwe_compact_ciphertext_list_with_secret_key<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    NoiseDistribution,
+    EncryptionGen,
+>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    NoiseDistribution: Distribution,
+    EncryptionGen: ByteRandomGenerator,
+{
+    let _ = encrypt_lwe_compact_ciphertext_list_with_secret_key_impl(
+        lwe_secret_key,
+        output,
+        encoded,
+        noise_distribution,
+        encryption_generator,
+    );
+}
+
+/// Encrypt and generates a zero-knowledge proof of an input cleartext list in an output
+/// [`LWE compact ciphertext list`](`LweCompactCiphertextList`)
+/// using an [`LWE secret key`](`LweSecretKey`).
+///
+/// The mask of the list is sampled uniformly, the ciphertexts can be verified with the
+/// [`LWE compact public key`](`LweCompactPublicKey`) of the secret key using
+/// [`verify_lwe_compact_ciphertext_list_secret_key_encryption`] and the expanded list can be
+/// decrypted using the same [`LWE secret key`](`LweSecretKey`).
+///
+/// The proof shows that the ciphertexts are well formed under the binary secret key behind the
+/// public key: the same key satisfies the relation of the public key and the one of the
+/// ciphertexts.
+///
+/// - The input cleartext list must have a length smaller or equal the maximum number of message
+///   authorized by the CRS and the [`LweDimension`] of the key.
+///
+/// - The noise distribution must be bounded
+///
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(2048);
+/// let lwe_ciphertext_count = LweCiphertextCount(4);
+/// let glwe_noise_distribution = TUniform::new(9);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let delta_log = 60;
+/// let delta = 1u64 << delta_log;
+/// let plaintext_modulus = 1u64 << (64 - delta_log);
+///
+/// let metadata = [b'T', b'F', b'H', b'E', b'-', b'r', b's'];
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut random_generator = RandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let crs = CompactPkeCrs::new(
+///     lwe_dimension,
+///     lwe_ciphertext_count.0,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     plaintext_modulus,
+///     &mut random_generator,
+/// )
+/// .unwrap();
+///
+/// // Create the LweSecretKey and its LweCompactPublicKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+/// let lwe_compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+///     &lwe_secret_key,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let cleartexts = (0..lwe_ciphertext_count.0 as u64).collect::<Vec<_>>();
+///
+/// // Create a new LweCompactCiphertextList
+/// let mut output_compact_ct_list = LweCompactCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+///
+/// let proof = encrypt_and_prove_lwe_compact_ciphertext_list_with_secret_key(
+///     &lwe_secret_key,
+///     &lwe_compact_public_key,
+///     &mut output_compact_ct_list,
+///     &cleartexts,
+///     delta,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+///     &mut random_generator,
+///     crs.public_params(),
+///     &metadata,
+///     ZkComputeLoad::Proof,
+/// )
+/// .unwrap();
+///
+/// // verify the ciphertext list with the proof
+/// assert!(verify_lwe_compact_ciphertext_list_secret_key_encryption(
+///     &output_compact_ct_list,
+///     &lwe_compact_public_key,
+///     &proof,
+///     crs.public_params(),
+///     &metadata,
+/// )
+/// .is_valid());
+///
+/// let mut output_plaintext_list =
+///     PlaintextList::new(0u64, PlaintextCount(lwe_ciphertext_count.0));
+///
+/// let lwe_ciphertext_list = output_compact_ct_list.expand_into_lwe_ciphertext_list();
+///
+/// decrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &lwe_ciphertext_list,
+///     &mut output_plaintext_list,
+/// );
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// // Round the plaintexts
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = signed_decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Check we recovered the original messages
+/// assert_eq!(&cleartexts, output_plaintext_list.as_ref());
+/// ```
+#[cfg(feature = "zk-pok")]
+#[allow(clippy::too_many_arguments)]
+pub fn encrypt_and_prove_lwe_compact_ciphertext_list_with_secret_key<
+    Scalar,
+    KeyCont,
+    PublicKeyCont,
+    InputCont,
+    OutputCont,
+    NoiseDistribution,
+    EncryptionGen,
+    G,
+>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    lwe_compact_public_key: &LweCompactPublicKey<PublicKeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    messages: &InputCont,
+    delta: Scalar,
+    noise_distribution: NoiseDistribution,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    random_generator: &mut RandomGenerator<G>,
+    public_params: &CompactPkePublicParams,
+    metadata: &[u8],
+    load: ZkComputeLoad,
+) -> crate::Result<CompactPkeProof>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution> + CastFrom<u64>,
+    Scalar::Signed: CastFrom<u64>,
+    i64: CastFrom<Scalar>,
+    u64: CastFrom<Scalar> + CastInto<Scalar::Signed>,
+    NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    KeyCont: Container<Element = Scalar>,
+    PublicKeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    EncryptionGen: ByteRandomGenerator,
+    G: ByteRandomGenerator,
+{
+    encrypt_and_prove_lwe_compact_ciphertext_list_with_secret_key_with_opening(
+        lwe_secret_key,
+        lwe_compact_public_key,
+        output,
+        messages,
+        delta,
+        noise_distribution,
+        encryption_generator,
+        random_generator,
+        public_params,
+        metadata,
+        load,
+    )
+    .map(|(proof, _)| proof)
+}
+
+#[cfg(feature = "zk-pok")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn encrypt_and_prove_lwe_compact_ciphertext_list_with_secret_key_with_opening<
+    Scalar,
+    KeyCont,
+    PublicKeyCont,
+    InputCont,
+    OutputCont,
+    NoiseDistribution,
+    EncryptionGen,
+    G,
+>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    lwe_compact_public_key: &LweCompactPublicKey<PublicKeyCont>,
+    output: &mut LweCompactCiphertextList<OutputCont>,
+    messages: &InputCont,
+    delta: Scalar,
+    noise_distribution: NoiseDistribution,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+    random_generator: &mut RandomGenerator<G>,
+    public_params: &CompactPkePublicParams,
+    metadata: &[u8],
+    load: ZkComputeLoad,
+) -> crate::Result<(CompactPkeProof, CompactPkeProofOpening)>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution> + CastFrom<u64>,
+    Scalar::Signed: CastFrom<u64>,
+    i64: CastFrom<Scalar>,
+    u64: CastFrom<Scalar> + CastInto<Scalar::Signed>,
+    NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    KeyCont: Container<Element = Scalar>,
+    PublicKeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    EncryptionGen: ByteRandomGenerator,
+    G: ByteRandomGenerator,
+{
+    assert!(
+        output.lwe_ciphertext_count().0 == messages.container_len(),
+        "Mismatch between LweCiphertextCount of output ciphertext and \
+        length of input message list. Got {:?} in output, and {} in input message list.",
+        output.lwe_ciphertext_count(),
+        messages.container_len()
+    );
+
+    verify_zero_knowledge_preconditions(
+        lwe_secret_key.lwe_dimension(),
+        output.lwe_ciphertext_count(),
+        output.ciphertext_modulus(),
+        delta,
+        noise_distribution,
+        noise_distribution,
+        public_params,
+    )?;
+
+    // The proof is made for a single mask, all the ciphertexts must share it
+    if output.lwe_ciphertext_count().0 > lwe_secret_key.lwe_dimension().0 {
+        return Err(format!(
+            "Secret key proven encryption allows at most {} ciphertexts to be proven at once, \
+            {} contained in the list",
+            lwe_secret_key.lwe_dimension().0,
+            output.lwe_ciphertext_count().0
+        )
+        .into());
+    }
+
+    if lwe_compact_public_key.lwe_dimension() != lwe_secret_key.lwe_dimension() {
+        return Err(format!(
+            "Mismatch between the LweDimension of the compact public key {:?} and of the secret \
+            key {:?}",
+            lwe_compact_public_key.lwe_dimension(),
+            lwe_secret_key.lwe_dimension()
+        )
+        .into());
+    }
+
+    // The prover needs the noise of the public key, which can be recomputed from the secret key
+    let (key_mask, key_body) = lwe_compact_public_key.get_mask_and_body();
+    let mut key_noise = vec![Scalar::ZERO; lwe_secret_key.lwe_dimension().0];
+    slice_semi_reverse_negacyclic_convolution(
+        &mut key_noise,
+        key_mask.as_ref(),
+        lwe_secret_key.as_ref(),
+    );
+    key_noise
+        .iter_mut()
+        .zip(key_body.as_ref().iter())
+        .for_each(|(e, &b)| *e = b.wrapping_sub(*e));
+
+    let exclusive_max = Scalar::cast_from(public_params.exclusive_max_noise());
+    if !key_noise
+        .iter()
+        .all(|&noise| noise.wrapping_add(exclusive_max) < exclusive_max << 1)
+    {
+        return Err("The public key was not generated from this secret key".into());
+    }
+
+    let encoded = PlaintextList::from_container(
+        messages
+            .as_ref()
+            .iter()
+            .copied()
+            .map(|m| m * delta)
+            .collect::<Vec<_>>(),
+    );
+
+    let body_noise = encrypt_lwe_compact_ciphertext_list_with_secret_key_impl(
+        lwe_secret_key,
+        output,
+        &encoded,
+        noise_distribution,
+        encryption_generator,
+    );
+
+    let (mask, bodies) = output.get_mask_and_body_list();
+
+    let (public_commit, private_commit) = sk_commit(
+        key_mask
+            .as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        key_body
+            .as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        mask.as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        bodies
+            .as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        lwe_secret_key
+            .as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        key_noise
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        messages
+            .as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        body_noise
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        public_params,
+        random_generator,
+    );
+
+    Ok(sk_prove_with_opening(
+        (public_params, &public_commit),
+        &private_commit,
+        metadata,
+        load,
+        random_generator,
+    ))
+}
+
+/// Decrypt an [`LWE ciphertext`](`LweCiphertext`) encoding a message scaled by `delta` and prove
+/// with a zero-knowledge proof that the returned cleartext is its decryption.
+///
+/// The proof shows that the secret key is the one behind the given
+/// [`LWE compact public key`](`LweCompactPublicKey`), so that it can be checked by anyone knowing
+/// the public key with [`verify_lwe_ciphertext_decryption`]. The decryption noise must be in the
+/// `[-b, b)` bound of the public parameters, which should be generated with
+/// [`DecryptionCrs`](`crate::zk::DecryptionCrs`) so that any correctly decrypted ciphertext can be
+/// proven.
+///
+/// Only the native 64 bits modulus is supported.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+/// use tfhe::zk::{DecryptionCrs, ZkComputeLoad};
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(2048);
+/// let glwe_noise_distribution = TUniform::new(9);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let delta = 1u64 << 59;
+///
+/// let metadata = [b'T', b'F', b'H', b'E', b'-', b'r', b's'];
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut random_generator = RandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let crs =
+///     DecryptionCrs::new(lwe_dimension, ciphertext_modulus, delta, &mut random_generator).unwrap();
+///
+/// // Create the LweSecretKey and its LweCompactPublicKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+/// let lwe_compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+///     &lwe_secret_key,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let msg = 3u64;
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     Plaintext(msg * delta),
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let (cleartext, proof) = decrypt_and_prove_lwe_ciphertext(
+///     &lwe_secret_key,
+///     &lwe,
+///     &lwe_compact_public_key,
+///     delta,
+///     &mut random_generator,
+///     crs.public_params(),
+///     &metadata,
+///     ZkComputeLoad::Proof,
+/// )
+/// .unwrap();
+/// assert_eq!(cleartext.0, msg);
+///
+/// // Anyone knowing the public key can check the decryption
+/// assert!(verify_lwe_ciphertext_decryption(
+///     &lwe,
+///     &lwe_compact_public_key,
+///     cleartext,
+///     delta,
+///     &proof,
+///     crs.public_params(),
+///     &metadata,
+/// )
+/// .is_valid());
+///
+/// // The proof does not hold for another cleartext
+/// assert!(verify_lwe_ciphertext_decryption(
+///     &lwe,
+///     &lwe_compact_public_key,
+///     Cleartext(msg + 1),
+///     delta,
+///     &proof,
+///     crs.public_params(),
+///     &metadata,
+/// )
+/// .is_invalid());
+/// ```
+#[cfg(feature = "zk-pok")]
+#[allow(clippy::too_many_arguments)]
+pub fn decrypt_and_prove_lwe_ciphertext<Scalar, KeyCont, InputCont, PublicKeyCont, G>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    lwe_compact_public_key: &LweCompactPublicKey<PublicKeyCont>,
+    delta: Scalar,
+    random_generator: &mut RandomGenerator<G>,
+    public_params: &DecryptionPublicParams,
+    metadata: &[u8],
+    load: ZkComputeLoad,
+) -> crate::Result<(Cleartext<Scalar>, DecryptionProof)>
+where
+    Scalar: UnsignedInteger + CastFrom<u64>,
+    i64: CastFrom<Scalar>,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    PublicKeyCont: Container<Element = Scalar>,
+    G: ByteRandomGenerator,
+{
+    if Scalar::BITS != 64 || !lwe_ciphertext.ciphertext_modulus().is_native_modulus() {
+        return Err("Decryption proofs only support the native 64 bits modulus".into());
+    }
+
+    let lwe_dimension = lwe_secret_key.lwe_dimension();
+    if lwe_ciphertext.lwe_size().to_lwe_dimension() != lwe_dimension
+        || lwe_compact_public_key.lwe_dimension() != lwe_dimension
+    {
+        return Err(format!(
+            "Mismatched LweDimension, got {:?} in the secret key, {:?} in the ciphertext and {:?} \
+            in the public key",
+            lwe_dimension,
+            lwe_ciphertext.lwe_size().to_lwe_dimension(),
+            lwe_compact_public_key.lwe_dimension()
+        )
+        .into());
+    }
+
+    if public_params.q != 0 || public_params.d != lwe_dimension.0 {
+        return Err("The public parameters were not generated for this LweDimension".into());
+    }
+
+    let exclusive_max = Scalar::cast_from(public_params.exclusive_max_noise());
+    let is_in_bound = |noise: Scalar| noise.wrapping_add(exclusive_max) < exclusive_max << 1;
+
+    let phase = decrypt_lwe_ciphertext(lwe_secret_key, lwe_ciphertext).0;
+    let cleartext = phase.wrapping_add(delta >> 1) / delta;
+    let noise = phase.wrapping_sub(cleartext.wrapping_mul(delta));
+
+    if !is_in_bound(noise) {
+        return Err(
+            "The decryption noise is out of the bounds of the public parameters, \
+            the decryption cannot be proven"
+                .into(),
+        );
+    }
+
+    // The prover needs the noise of the public key, which can be recomputed from the secret key
+    let (key_mask, key_body) = (
+        lwe_compact_public_key.get_mask(),
+        lwe_compact_public_key.get_body(),
+    );
+    let mut key_noise = vec![Scalar::ZERO; lwe_dimension.0];
+    slice_semi_reverse_negacyclic_convolution(
+        &mut key_noise,
+        key_mask.as_ref(),
+        lwe_secret_key.as_ref(),
+    );
+    key_noise
+        .iter_mut()
+        .zip(key_body.as_ref().iter())
+        .for_each(|(e, &b)| *e = b.wrapping_sub(*e));
+
+    if !key_noise.iter().copied().all(is_in_bound) {
+        return Err("The public key was not generated from this secret key".into());
+    }
+
+    let (mask, body) = lwe_ciphertext.get_mask_and_body();
+
+    let (public_commit, private_commit) = decryption_commit(
+        key_mask
+            .as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        key_body
+            .as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        mask.as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        i64::cast_from(*body.data),
+        i64::cast_from(cleartext.wrapping_mul(delta)),
+        lwe_secret_key
+            .as_ref()
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        key_noise
+            .iter()
+            .copied()
+            .map(CastFrom::cast_from)
+            .collect::<Vec<_>>(),
+        i64::cast_from(noise),
+        public_params,
+        random_generator,
+    );
+
+    let proof = decryption_prove(
+        (public_params, &public_commit),
+        &private_commit,
+        metadata,
+        load,
+        random_generator,
+    );
+
+    Ok((Cleartext(cleartext), proof))
+}
+
 #[cfg(test)]
 mod test {
     use crate::core_crypto::commons::generators::DeterministicSeeder;
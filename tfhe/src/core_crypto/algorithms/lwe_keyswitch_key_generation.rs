@@ -3,11 +3,13 @@
 //! generation`](`SeededLweKeyswitchKey`).
 
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
 use crate::core_crypto::commons::math::decomposition::{
     DecompositionLevel, DecompositionTerm, DecompositionTermNonNative,
 };
-use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Distribution, Uniform};
+use crate::core_crypto::commons::math::random::{
+    ActivatedRandomGenerator, Distribution, RandomGenerable, Uniform, UniformBinary,
+};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -306,6 +308,250 @@ where
     new_lwe_keyswitch_key
 }
 
+/// Fill an [`LWE keyswitch key`](`LweKeyswitchKey`) with an actual keyswitching key constructed
+/// from an input [`LWE secret key`](`LweSecretKey`) and an output
+/// [`LWE compact public key`](`LweCompactPublicKey`).
+///
+/// The output secret key is not required: the key elements are encrypted with the compact public
+/// key, the resulting keyswitching key switches ciphertexts to the
+/// [`LWE secret key`](`LweSecretKey`) that was used to generate the public key. The keyswitching
+/// key has a larger noise than one generated with the output secret key, see
+/// [`par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key`].
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweKeyswitchKey creation
+/// let input_lwe_dimension = LweDimension(742);
+/// let output_lwe_dimension = LweDimension(2048);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(6);
+/// let decomp_level_count = DecompositionLevelCount(3);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let input_lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+/// let output_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+///     output_lwe_dimension,
+///     &mut secret_generator,
+/// );
+/// let output_compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+///     &output_lwe_secret_key,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut ksk = LweKeyswitchKey::new(
+///     0u64,
+///     decomp_base_log,
+///     decomp_level_count,
+///     input_lwe_dimension,
+///     output_lwe_dimension,
+///     ciphertext_modulus,
+/// );
+///
+/// par_generate_lwe_keyswitch_key_with_compact_public_key(
+///     &input_lwe_secret_key,
+///     &output_compact_public_key,
+///     &mut ksk,
+///     glwe_noise_distribution,
+///     glwe_noise_distribution,
+///     &mut secret_generator,
+///     &mut encryption_generator,
+/// );
+///
+/// // Keyswitch an encryption of 3 << 60 under the input key
+/// let plaintext = Plaintext(3u64 << 60);
+/// let input_ct = allocate_and_encrypt_new_lwe_ciphertext(
+///     &input_lwe_secret_key,
+///     plaintext,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+/// let mut output_ct =
+///     LweCiphertext::new(0u64, output_lwe_dimension.to_lwe_size(), ciphertext_modulus);
+/// keyswitch_lwe_ciphertext(&ksk, &input_ct, &mut output_ct);
+///
+/// let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &output_ct);
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+/// assert_eq!(
+///     signed_decomposer.closest_representable(decrypted.0),
+///     plaintext.0
+/// );
+/// ```
+pub fn par_generate_lwe_keyswitch_key_with_compact_public_key<
+    Scalar,
+    MaskDistribution,
+    NoiseDistribution,
+    InputKeyCont,
+    PublicKeyCont,
+    KSKeyCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    input_lwe_sk: &LweSecretKey<InputKeyCont>,
+    output_compact_public_key: &LweCompactPublicKey<PublicKeyCont>,
+    lwe_keyswitch_key: &mut LweKeyswitchKey<KSKeyCont>,
+    mask_noise_distribution: MaskDistribution,
+    body_noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) where
+    Scalar: Encryptable<MaskDistribution, NoiseDistribution>
+        + RandomGenerable<UniformBinary>
+        + Sync
+        + Send,
+    MaskDistribution: Distribution + Sync,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = Scalar>,
+    PublicKeyCont: Container<Element = Scalar>,
+    KSKeyCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ParallelByteRandomGenerator,
+{
+    assert!(
+        lwe_keyswitch_key.input_key_lwe_dimension() == input_lwe_sk.lwe_dimension(),
+        "The destination LweKeyswitchKey input LweDimension is not equal \
+    to the input LweSecretKey LweDimension. Destination: {:?}, input: {:?}",
+        lwe_keyswitch_key.input_key_lwe_dimension(),
+        input_lwe_sk.lwe_dimension()
+    );
+    assert!(
+        lwe_keyswitch_key.output_key_lwe_dimension() == output_compact_public_key.lwe_dimension(),
+        "The destination LweKeyswitchKey output LweDimension is not equal \
+    to the output LweCompactPublicKey LweDimension. Destination: {:?}, output: {:?}",
+        lwe_keyswitch_key.output_key_lwe_dimension(),
+        output_compact_public_key.lwe_dimension()
+    );
+    assert!(
+        lwe_keyswitch_key.ciphertext_modulus() == output_compact_public_key.ciphertext_modulus(),
+        "The destination LweKeyswitchKey CiphertextModulus is not equal \
+    to the output LweCompactPublicKey CiphertextModulus. Destination: {:?}, output: {:?}",
+        lwe_keyswitch_key.ciphertext_modulus(),
+        output_compact_public_key.ciphertext_modulus()
+    );
+
+    let decomp_base_log = lwe_keyswitch_key.decomposition_base_log();
+    let decomp_level_count = lwe_keyswitch_key.decomposition_level_count();
+    let ciphertext_modulus = lwe_keyswitch_key.ciphertext_modulus();
+    assert!(ciphertext_modulus.is_native_modulus());
+
+    // The plaintexts of all the key elements, in the order of the keyswitching key
+    let plaintexts = PlaintextList::from_container(
+        input_lwe_sk
+            .as_ref()
+            .iter()
+            .flat_map(|&input_key_element| {
+                (1..=decomp_level_count.0).rev().map(move |level| {
+                    DecompositionTerm::new(
+                        DecompositionLevel(level),
+                        decomp_base_log,
+                        input_key_element,
+                    )
+                    .to_recomposition_summand()
+                })
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    let mut compact_ct_list = LweCompactCiphertextList::new(
+        Scalar::ZERO,
+        output_compact_public_key.lwe_dimension().to_lwe_size(),
+        LweCiphertextCount(plaintexts.plaintext_count().0),
+        ciphertext_modulus,
+    );
+
+    par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key(
+        output_compact_public_key,
+        &mut compact_ct_list,
+        &plaintexts,
+        mask_noise_distribution,
+        body_noise_distribution,
+        secret_generator,
+        encryption_generator,
+    );
+
+    let output_lwe_size = lwe_keyswitch_key.output_lwe_size();
+    let mut keyswitch_key_ciphertexts = LweCiphertextList::from_container(
+        lwe_keyswitch_key.as_mut(),
+        output_lwe_size,
+        ciphertext_modulus,
+    );
+    par_expand_lwe_compact_ciphertext_list(&mut keyswitch_key_ciphertexts, &compact_ct_list);
+}
+
+/// Allocate a new [`LWE keyswitch key`](`LweKeyswitchKey`) and fill it with an actual keyswitching
+/// key constructed from an input [`LWE secret key`](`LweSecretKey`) and an output
+/// [`LWE compact public key`](`LweCompactPublicKey`).
+///
+/// See [`par_generate_lwe_keyswitch_key_with_compact_public_key`] for usage.
+pub fn par_allocate_and_generate_new_lwe_keyswitch_key_with_compact_public_key<
+    Scalar,
+    MaskDistribution,
+    NoiseDistribution,
+    InputKeyCont,
+    PublicKeyCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    input_lwe_sk: &LweSecretKey<InputKeyCont>,
+    output_compact_public_key: &LweCompactPublicKey<PublicKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    mask_noise_distribution: MaskDistribution,
+    body_noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) -> LweKeyswitchKeyOwned<Scalar>
+where
+    Scalar: Encryptable<MaskDistribution, NoiseDistribution>
+        + RandomGenerable<UniformBinary>
+        + Sync
+        + Send,
+    MaskDistribution: Distribution + Sync,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = Scalar>,
+    PublicKeyCont: Container<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ParallelByteRandomGenerator,
+{
+    let mut new_lwe_keyswitch_key = LweKeyswitchKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        input_lwe_sk.lwe_dimension(),
+        output_compact_public_key.lwe_dimension(),
+        output_compact_public_key.ciphertext_modulus(),
+    );
+
+    par_generate_lwe_keyswitch_key_with_compact_public_key(
+        input_lwe_sk,
+        output_compact_public_key,
+        &mut new_lwe_keyswitch_key,
+        mask_noise_distribution,
+        body_noise_distribution,
+        secret_generator,
+        encryption_generator,
+    );
+
+    new_lwe_keyswitch_key
+}
+
 /// Fill an [`LWE keyswitch key`](`SeededLweKeyswitchKey`) with an actual keyswitching key
 /// constructed from an input and an output key [`LWE secret key`](`LweSecretKey`).
 ///
@@ -1,7 +1,15 @@
 use crate::core_crypto::entities::{LweCompactCiphertextList, LweCompactPublicKey};
-use crate::core_crypto::prelude::{CastFrom, Container, LweCiphertext, UnsignedInteger};
-use crate::zk::{CompactPkeProof, CompactPkePublicParams, ZkVerificationOutCome};
-use tfhe_zk_pok::proofs::pke::{verify, PublicCommit};
+use crate::core_crypto::prelude::{CastFrom, Cleartext, Container, LweCiphertext, UnsignedInteger};
+use crate::zk::{
+    CompactPkeProof, CompactPkePublicParams, DecryptionProof, DecryptionPublicParams,
+    ZkVerificationOutCome,
+};
+use tfhe_zk_pok::curve_api::Bls12_446 as Curve;
+use tfhe_zk_pok::proofs::decryption::{
+    verify as decryption_verify, PublicCommit as DecryptionPublicCommit,
+};
+use tfhe_zk_pok::proofs::pke::{verify, verify_batch, PublicCommit};
+use tfhe_zk_pok::proofs::pke_sk::{verify as sk_verify, PublicCommit as SkPublicCommit};
 
 /// Verifies with the given proof that a [`LweCompactCiphertextList`]
 /// is valid.
@@ -21,7 +29,65 @@ where
     if Scalar::BITS > 64 {
         return ZkVerificationOutCome::Invalid;
     }
-    let public_commit = PublicCommit::new(
+    let public_commit = compact_list_public_commit(lwe_compact_list, compact_public_key);
+    match verify(proof, (public_params, &public_commit), metadata) {
+        Ok(_) => ZkVerificationOutCome::Valid,
+        Err(_) => ZkVerificationOutCome::Invalid,
+    }
+}
+
+/// Verifies many [`LweCompactCiphertextList`] with their proofs at once, which is faster than
+/// verifying them one by one.
+///
+/// Each entry holds a list with the public key used to encrypt it, its proof and the metadata
+/// given when proving. Returns the indices of the entries whose proof is invalid.
+#[allow(clippy::type_complexity)]
+pub fn verify_lwe_compact_ciphertext_list_batch<Scalar, ListCont, KeyCont>(
+    entries: &[(
+        &LweCompactCiphertextList<ListCont>,
+        &LweCompactPublicKey<KeyCont>,
+        &CompactPkeProof,
+        &[u8],
+    )],
+    public_params: &CompactPkePublicParams,
+) -> Vec<usize>
+where
+    Scalar: UnsignedInteger,
+    i64: CastFrom<Scalar>,
+    ListCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    if Scalar::BITS > 64 {
+        return (0..entries.len()).collect();
+    }
+    let public_commits = entries
+        .iter()
+        .map(|&(lwe_compact_list, compact_public_key, _, _)| {
+            compact_list_public_commit(lwe_compact_list, compact_public_key)
+        })
+        .collect::<Vec<_>>();
+    let proofs = entries
+        .iter()
+        .zip(public_commits.iter())
+        .map(|(&(_, _, proof, metadata), public_commit)| (proof, public_commit, metadata))
+        .collect::<Vec<_>>();
+
+    verify_batch(public_params, &proofs)
+        .err()
+        .unwrap_or_default()
+}
+
+fn compact_list_public_commit<Scalar, ListCont, KeyCont>(
+    lwe_compact_list: &LweCompactCiphertextList<ListCont>,
+    compact_public_key: &LweCompactPublicKey<KeyCont>,
+) -> PublicCommit<Curve>
+where
+    Scalar: UnsignedInteger,
+    i64: CastFrom<Scalar>,
+    ListCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    PublicCommit::new(
         compact_public_key
             .get_mask()
             .as_ref()
@@ -50,11 +116,7 @@ where
             .copied()
             .map(|x| i64::cast_from(x))
             .collect(),
-    );
-    match verify(proof, (public_params, &public_commit), metadata) {
-        Ok(_) => ZkVerificationOutCome::Valid,
-        Err(_) => ZkVerificationOutCome::Invalid,
-    }
+    )
 }
 
 pub fn verify_lwe_ciphertext<Scalar, Cont, KeyCont>(
@@ -102,3 +164,132 @@ where
         Err(_) => ZkVerificationOutCome::Invalid,
     }
 }
+
+/// Verifies with the given proof that a [`LweCompactCiphertextList`] encrypted with a secret key
+/// is valid, the secret key being the one behind the given [`LweCompactPublicKey`].
+///
+/// See [`encrypt_and_prove_lwe_compact_ciphertext_list_with_secret_key`](`super::encrypt_and_prove_lwe_compact_ciphertext_list_with_secret_key`).
+pub fn verify_lwe_compact_ciphertext_list_secret_key_encryption<Scalar, ListCont, KeyCont>(
+    lwe_compact_list: &LweCompactCiphertextList<ListCont>,
+    compact_public_key: &LweCompactPublicKey<KeyCont>,
+    proof: &CompactPkeProof,
+    public_params: &CompactPkePublicParams,
+    metadata: &[u8],
+) -> ZkVerificationOutCome
+where
+    Scalar: UnsignedInteger,
+    i64: CastFrom<Scalar>,
+    ListCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    if Scalar::BITS > 64 {
+        return ZkVerificationOutCome::Invalid;
+    }
+    // All the ciphertexts of a list proven with a secret key share the same mask
+    if lwe_compact_list.lwe_ciphertext_count().0 > lwe_compact_list.lwe_size().to_lwe_dimension().0
+    {
+        return ZkVerificationOutCome::Invalid;
+    }
+    if compact_public_key.lwe_dimension() != lwe_compact_list.lwe_size().to_lwe_dimension() {
+        return ZkVerificationOutCome::Invalid;
+    }
+    let public_commit = SkPublicCommit::new(
+        compact_public_key
+            .get_mask()
+            .as_ref()
+            .iter()
+            .copied()
+            .map(|x| i64::cast_from(x))
+            .collect(),
+        compact_public_key
+            .get_body()
+            .as_ref()
+            .iter()
+            .copied()
+            .map(|x| i64::cast_from(x))
+            .collect(),
+        lwe_compact_list
+            .get_mask_list()
+            .as_ref()
+            .iter()
+            .copied()
+            .map(|x| i64::cast_from(x))
+            .collect(),
+        lwe_compact_list
+            .get_body_list()
+            .as_ref()
+            .iter()
+            .copied()
+            .map(|x| i64::cast_from(x))
+            .collect(),
+    );
+    match sk_verify(proof, (public_params, &public_commit), metadata) {
+        Ok(_) => ZkVerificationOutCome::Valid,
+        Err(_) => ZkVerificationOutCome::Invalid,
+    }
+}
+
+/// Verifies with the given proof that `cleartext` is the decryption of an [`LweCiphertext`]
+/// encoding its message scaled by `delta`, under the secret key behind the given
+/// [`LweCompactPublicKey`].
+///
+/// A `cleartext` whose scaling by `delta` overflows is rejected, as it is congruent to a smaller
+/// one.
+///
+/// See [`decrypt_and_prove_lwe_ciphertext`](`super::decrypt_and_prove_lwe_ciphertext`).
+pub fn verify_lwe_ciphertext_decryption<Scalar, Cont, KeyCont>(
+    lwe_ciphertext: &LweCiphertext<Cont>,
+    compact_public_key: &LweCompactPublicKey<KeyCont>,
+    cleartext: Cleartext<Scalar>,
+    delta: Scalar,
+    proof: &DecryptionProof,
+    public_params: &DecryptionPublicParams,
+    metadata: &[u8],
+) -> ZkVerificationOutCome
+where
+    Scalar: UnsignedInteger,
+    i64: CastFrom<Scalar>,
+    Cont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    if Scalar::BITS != 64 || !lwe_ciphertext.ciphertext_modulus().is_native_modulus() {
+        return ZkVerificationOutCome::Invalid;
+    }
+    if delta == Scalar::ZERO || cleartext.0 > Scalar::MAX / delta {
+        return ZkVerificationOutCome::Invalid;
+    }
+    if lwe_ciphertext.lwe_size().to_lwe_dimension() != compact_public_key.lwe_dimension()
+        || compact_public_key.lwe_dimension().0 != public_params.d
+    {
+        return ZkVerificationOutCome::Invalid;
+    }
+    let public_commit = DecryptionPublicCommit::new(
+        compact_public_key
+            .get_mask()
+            .as_ref()
+            .iter()
+            .copied()
+            .map(|x| i64::cast_from(x))
+            .collect(),
+        compact_public_key
+            .get_body()
+            .as_ref()
+            .iter()
+            .copied()
+            .map(|x| i64::cast_from(x))
+            .collect(),
+        lwe_ciphertext
+            .get_mask()
+            .as_ref()
+            .iter()
+            .copied()
+            .map(|x| i64::cast_from(x))
+            .collect(),
+        i64::cast_from(*lwe_ciphertext.get_body().data),
+        i64::cast_from(cleartext.0.wrapping_mul(delta)),
+    );
+    match decryption_verify(proof, (public_params, &public_commit), metadata) {
+        Ok(_) => ZkVerificationOutCome::Valid,
+        Err(_) => ZkVerificationOutCome::Invalid,
+    }
+}
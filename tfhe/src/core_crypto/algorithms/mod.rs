@@ -8,6 +8,8 @@ pub mod glwe_encryption;
 pub mod glwe_linear_algebra;
 pub mod glwe_sample_extraction;
 pub mod glwe_secret_key_generation;
+#[cfg(feature = "zk-pok")]
+pub mod glwe_zero_knowledge_verification;
 pub mod lwe_bootstrap_key_conversion;
 pub mod lwe_bootstrap_key_generation;
 pub mod lwe_compact_ciphertext_list_expansion;
@@ -56,6 +58,8 @@ pub use glwe_encryption::*;
 pub use glwe_linear_algebra::*;
 pub use glwe_sample_extraction::*;
 pub use glwe_secret_key_generation::*;
+#[cfg(feature = "zk-pok")]
+pub use glwe_zero_knowledge_verification::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
 pub use lwe_compact_ciphertext_list_expansion::*;
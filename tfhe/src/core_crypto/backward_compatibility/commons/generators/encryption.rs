@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::commons::generators::EncryptionRandomGeneratorState;
+
+#[derive(VersionsDispatch)]
+pub enum EncryptionRandomGeneratorStateVersions {
+    V0(EncryptionRandomGeneratorState),
+}
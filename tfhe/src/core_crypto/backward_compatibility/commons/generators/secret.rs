@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::commons::generators::SecretRandomGeneratorState;
+
+#[derive(VersionsDispatch)]
+pub enum SecretRandomGeneratorStateVersions {
+    V0(SecretRandomGeneratorState),
+}
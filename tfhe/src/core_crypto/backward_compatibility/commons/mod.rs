@@ -1,4 +1,5 @@
 pub mod ciphertext_modulus;
 pub mod dispersion;
+pub mod generators;
 pub mod math;
 pub mod parameters;
@@ -9,7 +9,7 @@ use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{
     CiphertextModulus, EncryptionMaskByteCount, EncryptionMaskSampleCount,
 };
-use concrete_csprng::generators::ForkError;
+use concrete_csprng::generators::{ForkError, GeneratorState, StateError};
 use rayon::prelude::*;
 
 #[derive(Clone, Copy, Debug)]
@@ -107,6 +107,14 @@ impl<G: ByteRandomGenerator> MaskRandomGenerator<G> {
         self.gen.remaining_bytes()
     }
 
+    pub fn state(&self) -> Result<GeneratorState, StateError> {
+        self.gen.state()
+    }
+
+    pub fn restore(&mut self, state: GeneratorState) -> Result<(), StateError> {
+        self.gen.restore(state)
+    }
+
     // Fills the slice with random uniform values, using the mask generator.
     pub(crate) fn fill_slice_with_random_uniform_mask<Scalar>(&mut self, output: &mut [Scalar])
     where
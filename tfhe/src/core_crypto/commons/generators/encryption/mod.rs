@@ -5,6 +5,7 @@ pub(crate) mod noise_random_generator;
 #[cfg(test)]
 mod test;
 
+use crate::core_crypto::backward_compatibility::commons::generators::encryption::EncryptionRandomGeneratorStateVersions;
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, Distribution, ParallelByteRandomGenerator, RandomGenerable, Seed, Seeder,
     Uniform,
@@ -14,10 +15,12 @@ use crate::core_crypto::commons::parameters::{
     CiphertextModulus, EncryptionMaskByteCount, EncryptionMaskSampleCount,
     EncryptionNoiseByteCount, EncryptionNoiseSampleCount,
 };
-use concrete_csprng::generators::ForkError;
+use concrete_csprng::generators::{ForkError, GeneratorState, StateError};
 use mask_random_generator::{MaskRandomGenerator, MaskRandomGeneratorForkConfig};
 use noise_random_generator::{NoiseRandomGenerator, NoiseRandomGeneratorForkConfig};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
 
 pub const PER_SAMPLE_TARGET_FAILURE_PROBABILITY_LOG2: f64 = -128.;
 
@@ -86,6 +89,20 @@ impl EncryptionRandomGeneratorForkConfig {
     }
 }
 
+/// The state of an [`EncryptionRandomGenerator`], from which the generation can be resumed.
+///
+/// The state does not contain the seeds of the generator, it must be restored on a generator
+/// created with the same seed and the same seeder state, e.g. with a [`DeterministicSeeder`].
+///
+/// [`DeterministicSeeder`]: crate::core_crypto::commons::generators::DeterministicSeeder
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(EncryptionRandomGeneratorStateVersions)]
+pub struct EncryptionRandomGeneratorState {
+    // The states of the generators, in the versioned encoding of `concrete-csprng`
+    mask: Vec<u8>,
+    noise: Vec<u8>,
+}
+
 /// A random number generator which can be used to encrypt messages.
 pub struct EncryptionRandomGenerator<G: ByteRandomGenerator> {
     // A separate mask generator, only used to generate the mask elements.
@@ -110,6 +127,30 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.mask.remaining_bytes()
     }
 
+    /// Return the state of the mask and noise generators, to resume the generation later with
+    /// [`EncryptionRandomGenerator::restore`].
+    pub fn state(&self) -> Result<EncryptionRandomGeneratorState, StateError> {
+        Ok(EncryptionRandomGeneratorState {
+            mask: self.mask.state()?.to_bytes(),
+            noise: self.noise.state()?.to_bytes(),
+        })
+    }
+
+    /// Move the mask and noise generators to a state returned by
+    /// [`EncryptionRandomGenerator::state`].
+    ///
+    /// Both states are validated before either generator is moved, so if the state is invalid
+    /// an error is returned and the generator is left unchanged.
+    pub fn restore(&mut self, state: EncryptionRandomGeneratorState) -> Result<(), StateError> {
+        let mask = GeneratorState::from_bytes(&state.mask)?;
+        let noise = GeneratorState::from_bytes(&state.noise)?;
+        mask.validate()?;
+        noise.validate()?;
+
+        self.mask.restore(mask)?;
+        self.noise.restore(noise)
+    }
+
     pub fn try_fork_from_config(
         &mut self,
         fork_config: EncryptionRandomGeneratorForkConfig,
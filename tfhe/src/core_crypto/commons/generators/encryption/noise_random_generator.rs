@@ -9,7 +9,7 @@ use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{
     CiphertextModulus, EncryptionNoiseByteCount, EncryptionNoiseSampleCount,
 };
-use concrete_csprng::generators::ForkError;
+use concrete_csprng::generators::{ForkError, GeneratorState, StateError};
 use rayon::prelude::*;
 
 #[derive(Clone, Copy, Debug)]
@@ -111,6 +111,14 @@ impl<G: ByteRandomGenerator> NoiseRandomGenerator<G> {
         self.gen.remaining_bytes()
     }
 
+    pub fn state(&self) -> Result<GeneratorState, StateError> {
+        self.gen.state()
+    }
+
+    pub fn restore(&mut self, state: GeneratorState) -> Result<(), StateError> {
+        self.gen.restore(state)
+    }
+
     // Sample a noise value, using the random generator.
     pub(crate) fn random_noise_from_distribution<D, Scalar>(&mut self, distribution: D) -> Scalar
     where
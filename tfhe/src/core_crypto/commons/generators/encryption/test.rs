@@ -1,6 +1,8 @@
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::dispersion::StandardDev;
-use crate::core_crypto::commons::math::random::Gaussian;
+use crate::core_crypto::commons::generators::encryption::EncryptionRandomGeneratorState;
+use crate::core_crypto::commons::generators::{DeterministicSeeder, EncryptionRandomGenerator};
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Gaussian, Seed};
 use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{
     CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension,
@@ -10,6 +12,8 @@ use crate::core_crypto::commons::test_tools::{
     new_encryption_random_generator, new_secret_random_generator, normality_test_f64,
 };
 use crate::core_crypto::commons::traits::UnsignedTorus;
+use concrete_csprng::generators::GeneratorState;
+use tfhe_versionable::{Unversionize, Versionize};
 
 #[test]
 fn test_gaussian_sampling_margin_factor_does_not_panic() {
@@ -648,3 +652,69 @@ fn mask_gen_slice_native_custom_mod_u64() {
 fn mask_gen_slice_native_custom_mod_u128() {
     mask_gen_slice_custom_mod::<u128>(CiphertextModulus::new_native());
 }
+
+#[test]
+fn test_state_restore() {
+    let seed = Seed(0);
+    let gaussian = Gaussian::from_standard_dev(StandardDev(2.0f64.powi(-32)), 0.0);
+
+    let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
+    let mut gen = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seed, &mut seeder);
+    let mut mask = vec![0u64; 100];
+    gen.fill_slice_with_random_uniform_mask(&mut mask);
+    let _: u64 = gen.random_noise_from_distribution(gaussian);
+
+    // The state goes through versioned serialization, as a checkpoint saved to disk would
+    let state = bincode::serialize(&gen.state().unwrap().versionize()).unwrap();
+    gen.fill_slice_with_random_uniform_mask(&mut mask);
+    let noise: u64 = gen.random_noise_from_distribution(gaussian);
+
+    let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
+    let mut restored =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seed, &mut seeder);
+    let state = EncryptionRandomGeneratorState::unversionize(bincode::deserialize(&state).unwrap())
+        .unwrap();
+    restored.restore(state).unwrap();
+    let mut restored_mask = vec![0u64; 100];
+    restored.fill_slice_with_random_uniform_mask(&mut restored_mask);
+    let restored_noise: u64 = restored.random_noise_from_distribution(gaussian);
+
+    assert_eq!(restored_mask, mask);
+    assert_eq!(restored_noise, noise);
+}
+
+#[test]
+fn test_invalid_state_restore() {
+    let seed = Seed(0);
+    let gaussian = Gaussian::from_standard_dev(StandardDev(2.0f64.powi(-32)), 0.0);
+
+    let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
+    let mut gen = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seed, &mut seeder);
+    let initial_state = gen.state().unwrap();
+
+    let mut mask = vec![0u64; 100];
+    gen.fill_slice_with_random_uniform_mask(&mut mask);
+    let _: u64 = gen.random_noise_from_distribution(gaussian);
+    let state = gen.state().unwrap();
+
+    // A valid mask state with an invalid noise state must not move the mask generator
+    let initial_noise_state = GeneratorState::from_bytes(&initial_state.noise).unwrap();
+    let invalid_state = EncryptionRandomGeneratorState {
+        mask: initial_state.mask.clone(),
+        noise: GeneratorState {
+            table_index: initial_noise_state.bound,
+            bound: initial_noise_state.bound,
+        }
+        .to_bytes(),
+    };
+    assert!(gen.restore(invalid_state).is_err());
+    assert_eq!(gen.state().unwrap(), state);
+
+    // Same with a noise state that can not be decoded
+    let invalid_state = EncryptionRandomGeneratorState {
+        mask: initial_state.mask,
+        noise: Vec::new(),
+    };
+    assert!(gen.restore(invalid_state).is_err());
+    assert_eq!(gen.state().unwrap(), state);
+}
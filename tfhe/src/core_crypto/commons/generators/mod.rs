@@ -4,11 +4,11 @@
 mod encryption;
 pub use encryption::mask_random_generator::{MaskRandomGenerator, MaskRandomGeneratorForkConfig};
 pub use encryption::noise_random_generator::NoiseRandomGenerator;
-pub use encryption::EncryptionRandomGenerator;
 pub(crate) use encryption::EncryptionRandomGeneratorForkConfig;
+pub use encryption::{EncryptionRandomGenerator, EncryptionRandomGeneratorState};
 
 mod secret;
-pub use secret::SecretRandomGenerator;
+pub use secret::{SecretRandomGenerator, SecretRandomGeneratorState};
 
 mod seeder;
 pub use seeder::DeterministicSeeder;
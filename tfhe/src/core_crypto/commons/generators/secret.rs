@@ -1,9 +1,24 @@
 //! Module containing primitives pertaining to random generation in the context of secret key
 //! generation.
 
+use crate::core_crypto::backward_compatibility::commons::generators::secret::SecretRandomGeneratorStateVersions;
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
 };
+use concrete_csprng::generators::{GeneratorState, StateError};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// The state of a [`SecretRandomGenerator`], from which the generation can be resumed.
+///
+/// The state does not contain the seed of the generator, it must be restored on a generator
+/// created with the same seed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(SecretRandomGeneratorStateVersions)]
+pub struct SecretRandomGeneratorState(
+    // The state of the generator, in the versioned encoding of `concrete-csprng`
+    Vec<u8>,
+);
 
 /// A random number generator which can be used to generate secret keys.
 pub struct SecretRandomGenerator<G: ByteRandomGenerator>(RandomGenerator<G>);
@@ -19,6 +34,19 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
         self.0.remaining_bytes()
     }
 
+    /// Return the state of the generator, to resume the generation later with
+    /// [`SecretRandomGenerator::restore`].
+    pub fn state(&self) -> Result<SecretRandomGeneratorState, StateError> {
+        self.0
+            .state()
+            .map(|state| SecretRandomGeneratorState(state.to_bytes()))
+    }
+
+    /// Move the generator to a state returned by [`SecretRandomGenerator::state`].
+    pub fn restore(&mut self, state: SecretRandomGeneratorState) -> Result<(), StateError> {
+        self.0.restore(GeneratorState::from_bytes(&state.0)?)
+    }
+
     pub(crate) fn fill_slice_with_random_uniform_binary<Scalar>(&mut self, slice: &mut [Scalar])
     where
         Scalar: RandomGenerable<UniformBinary>,
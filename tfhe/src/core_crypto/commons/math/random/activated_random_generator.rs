@@ -1,19 +1,27 @@
-#[cfg(feature = "generator_x86_64_aesni")]
+#[cfg(all(feature = "generator_x86_64_aesni", not(feature = "generator_chacha")))]
 use concrete_csprng::generators::AesniRandomGenerator;
-#[cfg(feature = "generator_aarch64_aes")]
+#[cfg(feature = "generator_chacha")]
+use concrete_csprng::generators::ChachaRandomGenerator;
+#[cfg(all(feature = "generator_aarch64_aes", not(feature = "generator_chacha")))]
 use concrete_csprng::generators::NeonAesRandomGenerator;
 #[cfg(all(
     not(feature = "generator_x86_64_aesni"),
-    not(feature = "generator_aarch64_aes")
+    not(feature = "generator_aarch64_aes"),
+    not(feature = "generator_chacha")
 ))]
 use concrete_csprng::generators::SoftwareRandomGenerator;
 
-#[cfg(feature = "generator_x86_64_aesni")]
+// The ChaCha generator is only enabled by the generator_chacha feature, which none of the
+// target_arch features turn on, so it takes precedence over the aes generators when requested
+#[cfg(feature = "generator_chacha")]
+pub type ActivatedRandomGenerator = ChachaRandomGenerator;
+#[cfg(all(feature = "generator_x86_64_aesni", not(feature = "generator_chacha")))]
 pub type ActivatedRandomGenerator = AesniRandomGenerator;
-#[cfg(feature = "generator_aarch64_aes")]
+#[cfg(all(feature = "generator_aarch64_aes", not(feature = "generator_chacha")))]
 pub type ActivatedRandomGenerator = NeonAesRandomGenerator;
 #[cfg(all(
     not(feature = "generator_x86_64_aesni"),
-    not(feature = "generator_aarch64_aes")
+    not(feature = "generator_aarch64_aes"),
+    not(feature = "generator_chacha")
 ))]
 pub type ActivatedRandomGenerator = SoftwareRandomGenerator;
@@ -7,7 +7,9 @@ use crate::core_crypto::commons::math::random::{
 use crate::core_crypto::commons::math::torus::{UnsignedInteger, UnsignedTorus};
 use crate::core_crypto::commons::numeric::{CastInto, FloatingPoint};
 use crate::core_crypto::commons::parameters::CiphertextModulus;
-use concrete_csprng::generators::{BytesPerChild, ChildrenCount, ForkError};
+use concrete_csprng::generators::{
+    BytesPerChild, ChildrenCount, ForkError, GeneratorState, StateError,
+};
 use rayon::prelude::*;
 
 pub use concrete_csprng::generators::{
@@ -166,6 +168,36 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
             .map(|iter| iter.map(Self))
     }
 
+    /// Return the state of the generator, from which the generation can be resumed with
+    /// [`RandomGenerator::restore`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// generator.generate_next();
+    /// let state = generator.state().unwrap();
+    /// let next = generator.generate_next();
+    ///
+    /// let mut restored = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// restored.restore(state).unwrap();
+    /// assert_eq!(restored.generate_next(), next);
+    /// ```
+    pub fn state(&self) -> Result<GeneratorState, StateError> {
+        self.0.state()
+    }
+
+    /// Move the generator to a state returned by [`RandomGenerator::state`].
+    ///
+    /// The generator must have been created from the same seed as the one the state was taken
+    /// from, for the generation to continue deterministically.
+    pub fn restore(&mut self, state: GeneratorState) -> Result<(), StateError> {
+        self.0.restore(state)
+    }
+
     /// Generate a random scalar from the given distribution under the native modulus.
     ///
     /// # Example
@@ -1,7 +1,7 @@
 use tfhe_versionable::Versionize;
 
 use self::packed_integers::PackedIntegers;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::backward_compatibility::entities::compressed_modulus_switched_glwe_ciphertext::CompressedModulusSwitchedGlweCiphertextVersions;
 use crate::core_crypto::fft_impl::common::modulus_switch;
 use crate::core_crypto::prelude::*;
@@ -196,10 +196,14 @@ impl<Scalar: UnsignedInteger> ParameterSetConformant
 {
     type ParameterSet = GlweCiphertextConformanceParameters<Scalar>;
 
-    fn is_conformant(
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
         &self,
         lwe_ct_parameters: &GlweCiphertextConformanceParameters<Scalar>,
-    ) -> bool {
+    ) -> Result<(), ConformanceError> {
         let Self {
             packed_integers,
             glwe_dimension,
@@ -214,11 +218,32 @@ impl<Scalar: UnsignedInteger> ParameterSetConformant
 
         let len = number_bits_to_unpack.div_ceil(Scalar::BITS);
 
-        packed_integers.packed_coeffs.len() == len
-            && *glwe_dimension == lwe_ct_parameters.glwe_dim
-            && *polynomial_size == lwe_ct_parameters.polynomial_size
-            && lwe_ct_parameters.ct_modulus.is_power_of_two()
-            && *uncompressed_ciphertext_modulus == lwe_ct_parameters.ct_modulus
+        check_field(
+            "glwe_dimension",
+            &lwe_ct_parameters.glwe_dim,
+            glwe_dimension,
+        )?;
+        check_field(
+            "polynomial_size",
+            &lwe_ct_parameters.polynomial_size,
+            polynomial_size,
+        )?;
+        check_field(
+            "uncompressed_ciphertext_modulus",
+            &lwe_ct_parameters.ct_modulus,
+            uncompressed_ciphertext_modulus,
+        )?;
+        if !lwe_ct_parameters.ct_modulus.is_power_of_two() {
+            return Err(ConformanceError::invalid(
+                "uncompressed_ciphertext_modulus",
+                "modulus switched ciphertexts require a power of two modulus",
+            ));
+        }
+        check_field(
+            "packed_coeffs length",
+            &len,
+            &packed_integers.packed_coeffs.len(),
+        )
     }
 }
 
@@ -2,7 +2,7 @@ use tfhe_versionable::Versionize;
 
 use self::packed_integers::PackedIntegers;
 
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::backward_compatibility::entities::compressed_modulus_switched_lwe_ciphertext::CompressedModulusSwitchedLweCiphertextVersions;
 use crate::core_crypto::fft_impl::common::modulus_switch;
 use crate::core_crypto::prelude::*;
@@ -146,7 +146,14 @@ impl<Scalar: UnsignedInteger> ParameterSetConformant
 {
     type ParameterSet = LweCiphertextParameters<Scalar>;
 
-    fn is_conformant(&self, lwe_ct_parameters: &LweCiphertextParameters<Scalar>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        lwe_ct_parameters: &LweCiphertextParameters<Scalar>,
+    ) -> Result<(), ConformanceError> {
         let Self {
             packed_integers,
             lwe_dimension,
@@ -159,14 +166,32 @@ impl<Scalar: UnsignedInteger> ParameterSetConformant
 
         let len = number_bits_to_pack.div_ceil(Scalar::BITS);
 
-        packed_integers.packed_coeffs.len() == len
-            && *lwe_dimension == lwe_ct_parameters.lwe_dim
-            && lwe_ct_parameters.ct_modulus.is_power_of_two()
-            && *uncompressed_ciphertext_modulus == lwe_ct_parameters.ct_modulus
-            && matches!(
-                lwe_ct_parameters.ms_decompression_method,
-                MsDecompressionType::ClassicPbs
-            )
+        check_field("lwe_dimension", &lwe_ct_parameters.lwe_dim, lwe_dimension)?;
+        check_field(
+            "uncompressed_ciphertext_modulus",
+            &lwe_ct_parameters.ct_modulus,
+            uncompressed_ciphertext_modulus,
+        )?;
+        if !lwe_ct_parameters.ct_modulus.is_power_of_two() {
+            return Err(ConformanceError::invalid(
+                "uncompressed_ciphertext_modulus",
+                "modulus switched ciphertexts require a power of two modulus",
+            ));
+        }
+        if !matches!(
+            lwe_ct_parameters.ms_decompression_method,
+            MsDecompressionType::ClassicPbs
+        ) {
+            return Err(ConformanceError::invalid(
+                "ms_decompression_method",
+                "a classic ciphertext was provided but a multi bit PBS is expected",
+            ));
+        }
+        check_field(
+            "packed_coeffs length",
+            &len,
+            &packed_integers.packed_coeffs.len(),
+        )
     }
 }
 
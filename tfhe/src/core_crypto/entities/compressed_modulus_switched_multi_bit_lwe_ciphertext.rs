@@ -1,5 +1,5 @@
 use self::packed_integers::PackedIntegers;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::backward_compatibility::entities::compressed_modulus_switched_multi_bit_lwe_ciphertext::CompressedModulusSwitchedMultiBitLweCiphertextVersions;
 use crate::core_crypto::fft_impl::common::modulus_switch;
 use crate::core_crypto::prelude::*;
@@ -405,7 +405,14 @@ impl<Scalar: UnsignedInteger + CastInto<usize> + CastFrom<usize>> ParameterSetCo
 {
     type ParameterSet = LweCiphertextParameters<Scalar>;
 
-    fn is_conformant(&self, lwe_ct_parameters: &LweCiphertextParameters<Scalar>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        lwe_ct_parameters: &LweCiphertextParameters<Scalar>,
+    ) -> Result<(), ConformanceError> {
         let Self {
             body,
             packed_mask,
@@ -417,19 +424,48 @@ impl<Scalar: UnsignedInteger + CastInto<usize> + CastFrom<usize>> ParameterSetCo
 
         let lwe_dim = lwe_dimension.0;
 
-        body >> packed_mask.log_modulus.0 == 0
-            && packed_mask.is_conformant(&lwe_dim)
-            && packed_diffs
-                .as_ref()
-                .map_or(true, |packed_diffs| packed_diffs.is_conformant(&lwe_dim))
-            && *lwe_dimension == lwe_ct_parameters.lwe_dim
-            && lwe_ct_parameters.ct_modulus.is_power_of_two()
-            && match lwe_ct_parameters.ms_decompression_method {
-                MsDecompressionType::ClassicPbs => false,
-                MsDecompressionType::MultiBitPbs(expected_gouping_factor) => {
-                    expected_gouping_factor.0 == grouping_factor.0
-                }
+        check_field("lwe_dimension", &lwe_ct_parameters.lwe_dim, lwe_dimension)?;
+        check_field(
+            "uncompressed_ciphertext_modulus",
+            &lwe_ct_parameters.ct_modulus,
+            uncompressed_ciphertext_modulus,
+        )?;
+        if !lwe_ct_parameters.ct_modulus.is_power_of_two() {
+            return Err(ConformanceError::invalid(
+                "uncompressed_ciphertext_modulus",
+                "modulus switched ciphertexts require a power of two modulus",
+            ));
+        }
+        match lwe_ct_parameters.ms_decompression_method {
+            MsDecompressionType::ClassicPbs => {
+                return Err(ConformanceError::invalid(
+                    "grouping_factor",
+                    "a multi bit ciphertext was provided but a classic PBS is expected",
+                ));
             }
-            && *uncompressed_ciphertext_modulus == lwe_ct_parameters.ct_modulus
+            MsDecompressionType::MultiBitPbs(expected_gouping_factor) => {
+                check_field(
+                    "grouping_factor",
+                    &expected_gouping_factor.0,
+                    &grouping_factor.0,
+                )?;
+            }
+        }
+        if body >> packed_mask.log_modulus.0 != 0 {
+            return Err(ConformanceError::invalid(
+                "body",
+                "body is bigger than the switched modulus",
+            ));
+        }
+        packed_mask
+            .check_conformance(&lwe_dim)
+            .map_err(|err| err.in_field("packed_mask"))?;
+        if let Some(packed_diffs) = packed_diffs {
+            packed_diffs
+                .check_conformance(&lwe_dim)
+                .map_err(|err| err.in_field("packed_diffs"))?;
+        }
+
+        Ok(())
     }
 }
@@ -1,6 +1,6 @@
 //! Module containing the definition of the GlweCiphertext.
 
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use tfhe_versionable::Versionize;
 
 use crate::core_crypto::backward_compatibility::entities::glwe_ciphertext::GlweCiphertextVersions;
@@ -639,23 +639,45 @@ where
 {
     type ParameterSet = GlweCiphertextConformanceParameters<C::Element>;
 
-    fn is_conformant(
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
         &self,
         glwe_ct_parameters: &GlweCiphertextConformanceParameters<C::Element>,
-    ) -> bool {
+    ) -> Result<(), ConformanceError> {
         let Self {
             data,
             polynomial_size,
             ciphertext_modulus,
         } = self;
 
-        check_encrypted_content_respects_mod(self, glwe_ct_parameters.ct_modulus)
-            && data.container_len()
-                == glwe_ciphertext_size(
-                    glwe_ct_parameters.glwe_dim.to_glwe_size(),
-                    glwe_ct_parameters.polynomial_size,
-                )
-            && *polynomial_size == glwe_ct_parameters.polynomial_size
-            && *ciphertext_modulus == glwe_ct_parameters.ct_modulus
+        check_field(
+            "polynomial_size",
+            &glwe_ct_parameters.polynomial_size,
+            polynomial_size,
+        )?;
+        check_field(
+            "ciphertext_modulus",
+            &glwe_ct_parameters.ct_modulus,
+            ciphertext_modulus,
+        )?;
+        check_field(
+            "data length",
+            &glwe_ciphertext_size(
+                glwe_ct_parameters.glwe_dim.to_glwe_size(),
+                glwe_ct_parameters.polynomial_size,
+            ),
+            &data.container_len(),
+        )?;
+        if !check_encrypted_content_respects_mod(self, glwe_ct_parameters.ct_modulus) {
+            return Err(ConformanceError::invalid(
+                "data",
+                "content does not respect the ciphertext modulus",
+            ));
+        }
+
+        Ok(())
     }
 }
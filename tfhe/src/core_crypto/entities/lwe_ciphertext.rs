@@ -2,7 +2,7 @@
 
 use tfhe_versionable::Versionize;
 
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::backward_compatibility::entities::lwe_ciphertext::LweCiphertextVersions;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
@@ -760,15 +760,37 @@ where
 {
     type ParameterSet = LweCiphertextParameters<C::Element>;
 
-    fn is_conformant(&self, lwe_ct_parameters: &LweCiphertextParameters<C::Element>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        lwe_ct_parameters: &LweCiphertextParameters<C::Element>,
+    ) -> Result<(), ConformanceError> {
         let Self {
             data,
             ciphertext_modulus,
         } = self;
 
-        check_encrypted_content_respects_mod(data, lwe_ct_parameters.ct_modulus)
-            && self.lwe_size() == lwe_ct_parameters.lwe_dim.to_lwe_size()
-            && *ciphertext_modulus == lwe_ct_parameters.ct_modulus
+        check_field(
+            "lwe_size",
+            &lwe_ct_parameters.lwe_dim.to_lwe_size(),
+            &self.lwe_size(),
+        )?;
+        check_field(
+            "ciphertext_modulus",
+            &lwe_ct_parameters.ct_modulus,
+            ciphertext_modulus,
+        )?;
+        if !check_encrypted_content_respects_mod(data, lwe_ct_parameters.ct_modulus) {
+            return Err(ConformanceError::invalid(
+                "data",
+                "content does not respect the ciphertext modulus",
+            ));
+        }
+
+        Ok(())
     }
 }
 
@@ -3,7 +3,9 @@
 
 use tfhe_versionable::Versionize;
 
-use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+use crate::conformance::{
+    check_field, ConformanceError, ListSizeConstraint, ParameterSetConformant,
+};
 use crate::core_crypto::algorithms::{
     expand_lwe_compact_ciphertext_list, par_expand_lwe_compact_ciphertext_list,
 };
@@ -342,7 +344,14 @@ pub struct LweCiphertextListParameters<T: UnsignedInteger> {
 impl<T: UnsignedInteger> ParameterSetConformant for LweCompactCiphertextListOwned<T> {
     type ParameterSet = LweCiphertextListParameters<T>;
 
-    fn is_conformant(&self, param: &LweCiphertextListParameters<T>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        param: &LweCiphertextListParameters<T>,
+    ) -> Result<(), ConformanceError> {
         let Self {
             data,
             lwe_size,
@@ -352,15 +361,22 @@ impl<T: UnsignedInteger> ParameterSetConformant for LweCompactCiphertextListOwne
 
         param
             .lwe_ciphertext_count_constraint
-            .is_valid(lwe_ciphertext_count.0)
-            && data.len()
-                == lwe_compact_ciphertext_list_size(
-                    lwe_size.to_lwe_dimension(),
-                    *lwe_ciphertext_count,
-                )
-            && check_encrypted_content_respects_mod(self, param.ct_modulus)
-            && *lwe_size == param.lwe_dim.to_lwe_size()
-            && *ciphertext_modulus == param.ct_modulus
+            .check("lwe_ciphertext_count", lwe_ciphertext_count.0)?;
+        check_field("lwe_size", &param.lwe_dim.to_lwe_size(), lwe_size)?;
+        check_field("ciphertext_modulus", &param.ct_modulus, ciphertext_modulus)?;
+        check_field(
+            "data length",
+            &lwe_compact_ciphertext_list_size(lwe_size.to_lwe_dimension(), *lwe_ciphertext_count),
+            &data.len(),
+        )?;
+        if !check_encrypted_content_respects_mod(self, param.ct_modulus) {
+            return Err(ConformanceError::invalid(
+                "data",
+                "content does not respect the ciphertext modulus",
+            ));
+        }
+
+        Ok(())
     }
 }
 
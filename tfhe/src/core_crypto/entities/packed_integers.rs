@@ -1,6 +1,6 @@
 use tfhe_versionable::Versionize;
 
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::backward_compatibility::entities::packed_integers::PackedIntegersVersions;
 use crate::core_crypto::prelude::*;
 
@@ -171,7 +171,11 @@ impl<Scalar: UnsignedInteger> PackedIntegers<Scalar> {
 impl<Scalar: UnsignedInteger> ParameterSetConformant for PackedIntegers<Scalar> {
     type ParameterSet = usize;
 
-    fn is_conformant(&self, len: &usize) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(&self, len: &usize) -> Result<(), ConformanceError> {
         let Self {
             packed_coeffs,
             log_modulus,
@@ -182,6 +186,7 @@ impl<Scalar: UnsignedInteger> ParameterSetConformant for PackedIntegers<Scalar>
 
         let packed_len = number_packed_bits.div_ceil(Scalar::BITS);
 
-        *len == *initial_len && packed_coeffs.len() == packed_len
+        check_field("initial_len", len, initial_len)?;
+        check_field("packed_coeffs length", &packed_len, &packed_coeffs.len())
     }
 }
@@ -2,7 +2,7 @@
 
 use tfhe_versionable::Versionize;
 
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::backward_compatibility::entities::seeded_lwe_ciphertext::SeededLweCiphertextVersions;
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, CompressionSeed};
@@ -24,7 +24,14 @@ pub struct SeededLweCiphertext<Scalar: UnsignedInteger> {
 impl<T: UnsignedInteger> ParameterSetConformant for SeededLweCiphertext<T> {
     type ParameterSet = LweCiphertextParameters<T>;
 
-    fn is_conformant(&self, lwe_ct_parameters: &LweCiphertextParameters<T>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        lwe_ct_parameters: &LweCiphertextParameters<T>,
+    ) -> Result<(), ConformanceError> {
         let Self {
             data,
             lwe_size,
@@ -32,11 +39,27 @@ impl<T: UnsignedInteger> ParameterSetConformant for SeededLweCiphertext<T> {
             ciphertext_modulus,
         } = self;
 
-        check_encrypted_content_respects_mod::<T, &[T]>(
+        check_field(
+            "lwe_size",
+            &lwe_ct_parameters.lwe_dim.to_lwe_size(),
+            lwe_size,
+        )?;
+        check_field(
+            "ciphertext_modulus",
+            &lwe_ct_parameters.ct_modulus,
+            ciphertext_modulus,
+        )?;
+        if !check_encrypted_content_respects_mod::<T, &[T]>(
             &std::slice::from_ref(data),
             lwe_ct_parameters.ct_modulus,
-        ) && *lwe_size == lwe_ct_parameters.lwe_dim.to_lwe_size()
-            && *ciphertext_modulus == lwe_ct_parameters.ct_modulus
+        ) {
+            return Err(ConformanceError::invalid(
+                "data",
+                "content does not respect the ciphertext modulus",
+            ));
+        }
+
+        Ok(())
     }
 }
 
@@ -666,7 +666,12 @@ impl<'de, C: IntoContainerOwned<Element = c64>> serde::Deserialize<'de>
 {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         use std::marker::PhantomData;
-        struct SeqVisitor<C: IntoContainerOwned<Element = c64>>(PhantomData<fn() -> C>);
+        struct SeqVisitor<C: IntoContainerOwned<Element = c64>> {
+            // Set for the visitor given to `deserialize_newtype_struct`, whose `visit_seq` is only
+            // called directly by the inspection deserializer, see `SKIPPABLE_BULK_DATA`
+            skip_bulk_data: bool,
+            _marker: PhantomData<fn() -> C>,
+        }
 
         impl<'de, C: IntoContainerOwned<Element = c64>> serde::de::Visitor<'de> for SeqVisitor<C> {
             type Value = FourierPolynomialList<C>;
@@ -677,6 +682,16 @@ impl<'de, C: IntoContainerOwned<Element = c64>> serde::Deserialize<'de>
                 )
             }
 
+            fn visit_newtype_struct<D: serde::Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                deserializer.deserialize_seq(SeqVisitor {
+                    skip_bulk_data: false,
+                    _marker: PhantomData,
+                })
+            }
+
             fn visit_seq<A: serde::de::SeqAccess<'de>>(
                 self,
                 mut seq: A,
@@ -709,6 +724,35 @@ impl<'de, C: IntoContainerOwned<Element = c64>> serde::Deserialize<'de>
                     }
                 }
 
+                if self.skip_bulk_data {
+                    // The content is not needed, read each polynomial into the same buffer
+                    // instead of allocating the whole list
+                    if chunk_count != 0 {
+                        let fft = Fft::new(polynomial_size);
+                        let mut buf =
+                            vec![c64::default(); polynomial_size.to_fourier_polynomial_size().0];
+                        for i in 0..chunk_count {
+                            if seq
+                                .next_element_seed(FillFourier {
+                                    fft: fft.as_view(),
+                                    buf: &mut buf,
+                                })?
+                                .is_none()
+                            {
+                                return Err(serde::de::Error::invalid_length(
+                                    i,
+                                    &&*format!("sequence of {chunk_count} Fourier polynomials"),
+                                ));
+                            }
+                        }
+                    }
+
+                    return Ok(FourierPolynomialList {
+                        data: C::collect(std::iter::empty()),
+                        polynomial_size,
+                    });
+                }
+
                 let mut data = C::collect(
                     (0..(polynomial_size.to_fourier_polynomial_size().0 * chunk_count))
                         .map(|_| c64::default()),
@@ -739,7 +783,13 @@ impl<'de, C: IntoContainerOwned<Element = c64>> serde::Deserialize<'de>
             }
         }
 
-        deserializer.deserialize_seq(SeqVisitor::<C>(PhantomData))
+        deserializer.deserialize_newtype_struct(
+            crate::safe_deserialization::SKIPPABLE_BULK_DATA,
+            SeqVisitor::<C> {
+                skip_bulk_data: true,
+                _marker: PhantomData,
+            },
+        )
     }
 }
 
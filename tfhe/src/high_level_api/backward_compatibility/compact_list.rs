@@ -1,6 +1,8 @@
 use std::convert::Infallible;
 use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
 
+#[cfg(feature = "zk-pok")]
+use crate::ProvenCompactCiphertextList;
 use crate::{CompactCiphertextList, Tag};
 
 #[derive(Version)]
@@ -22,3 +24,9 @@ pub enum CompactCiphertextListVersions {
     V0(CompactCiphertextListV0),
     V1(CompactCiphertextList),
 }
+
+#[cfg(feature = "zk-pok")]
+#[derive(VersionsDispatch)]
+pub enum ProvenCompactCiphertextListVersions {
+    V0(ProvenCompactCiphertextList),
+}
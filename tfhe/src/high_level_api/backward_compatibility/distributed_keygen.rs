@@ -0,0 +1,19 @@
+use crate::high_level_api::distributed_keygen::{
+    KeyContribution, Opening, TrustedDealerPreprocessing,
+};
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum TrustedDealerPreprocessingVersions {
+    V0(TrustedDealerPreprocessing),
+}
+
+#[derive(VersionsDispatch)]
+pub enum OpeningVersions {
+    V0(Opening),
+}
+
+#[derive(VersionsDispatch)]
+pub enum KeyContributionVersions {
+    V0(KeyContribution),
+}
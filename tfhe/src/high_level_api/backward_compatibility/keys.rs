@@ -370,3 +370,43 @@ pub(in crate::high_level_api) enum IntegerCompressedCompactPublicKeyVersions {
 pub enum KeySwitchingKeyVersions {
     V0(KeySwitchingKey),
 }
+
+#[derive(VersionsDispatch)]
+pub enum KeyRotationKeyVersions {
+    V0(KeyRotationKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SanitizationKeyVersions {
+    V0(SanitizationKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingKeyVersions {
+    V0(NoiseSquashingKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingPrivateKeyVersions {
+    V0(NoiseSquashingPrivateKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum ProxyReencryptionKeyVersions {
+    V0(ProxyReencryptionKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum KeySetIdVersions {
+    V0(KeySetId),
+}
+
+#[derive(VersionsDispatch)]
+pub enum ContentHashVersions {
+    V0(ContentHash),
+}
+
+#[derive(VersionsDispatch)]
+pub enum KeyBundleVersions {
+    V0(KeyBundle),
+}
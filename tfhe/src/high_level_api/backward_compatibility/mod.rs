@@ -4,6 +4,9 @@ pub mod booleans;
 pub mod compact_list;
 pub mod compressed_ciphertext_list;
 pub mod config;
+pub mod distributed_keygen;
 pub mod integers;
 pub mod keys;
+pub mod squashed_noise;
 pub mod tag;
+pub mod threshold;
@@ -0,0 +1,26 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::{
+    CompressedSquashedNoiseCiphertextList, SquashedNoiseFheBool, SquashedNoiseFheInt,
+    SquashedNoiseFheUint,
+};
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseFheUintVersions {
+    V0(SquashedNoiseFheUint),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseFheIntVersions {
+    V0(SquashedNoiseFheInt),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseFheBoolVersions {
+    V0(SquashedNoiseFheBool),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedSquashedNoiseCiphertextListVersions {
+    V0(CompressedSquashedNoiseCiphertextList),
+}
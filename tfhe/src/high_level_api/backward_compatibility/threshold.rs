@@ -0,0 +1,24 @@
+use crate::high_level_api::threshold::{
+    KeyShare, NoiseSquashingKeyShare, PartialDecryption, SquashedNoisePartialDecryption,
+};
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum KeyShareVersions {
+    V0(KeyShare),
+}
+
+#[derive(VersionsDispatch)]
+pub enum PartialDecryptionVersions {
+    V0(PartialDecryption),
+}
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingKeyShareVersions {
+    V0(NoiseSquashingKeyShare),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoisePartialDecryptionVersions {
+    V0(SquashedNoisePartialDecryption),
+}
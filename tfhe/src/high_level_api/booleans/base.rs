@@ -1,6 +1,6 @@
 use super::inner::InnerBoolean;
 use crate::backward_compatibility::booleans::FheBoolVersions;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{ConformanceError, ParameterSetConformant};
 use crate::high_level_api::global_state;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::global_state::with_thread_local_cuda_streams;
@@ -17,7 +17,7 @@ use crate::named::Named;
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::parameters::CiphertextConformanceParams;
 use crate::shortint::PBSParameters;
-use crate::{Device, ServerKey, Tag};
+use crate::{CompactPublicKey, Device, ProxyReencryptionKey, SanitizationKey, ServerKey, Tag};
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
@@ -81,12 +81,16 @@ impl From<&ServerKey> for FheBoolConformanceParams {
 impl ParameterSetConformant for FheBool {
     type ParameterSet = FheBoolConformanceParams;
 
-    fn is_conformant(&self, params: &FheBoolConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(&self, params: &FheBoolConformanceParams) -> Result<(), ConformanceError> {
         let Self { ciphertext, tag: _ } = self;
 
         let BooleanBlock(block) = &*ciphertext.on_cpu();
 
-        block.is_conformant(&params.0)
+        block.check_conformance(&params.0)
     }
 }
 
@@ -177,6 +181,127 @@ impl FheBool {
     pub fn is_trivial(&self) -> bool {
         self.ciphertext.on_cpu().is_trivial()
     }
+
+    /// Re-randomizes the ciphertext, making it unlinkable to the ciphertexts it was computed from.
+    ///
+    /// A fresh encryption of zero, produced with the `public_key`, is added to the ciphertext
+    /// which is then refreshed with a PBS. If the public key uses dedicated parameters, the
+    /// server key set must hold the corresponding casting key.
+    ///
+    /// An error is returned if the parameters or the tag of the public key do not match the
+    /// server key. Public keys of the same parameters are only told apart by their tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheBool};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// let public_key = CompactPublicKey::new(&client_key);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    ///
+    /// let mut result = &a ^ &a;
+    /// result.rerandomize(&public_key).unwrap();
+    ///
+    /// let decrypted: bool = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, false);
+    /// ```
+    pub fn rerandomize(&mut self, public_key: &CompactPublicKey) -> crate::Result<()> {
+        public_key.rerandomize_blocks(std::slice::from_mut(&mut self.ciphertext.as_cpu_mut().0))
+    }
+
+    /// Sanitizes the ciphertext, the result encrypts the same value with a noise that does not
+    /// depend on the operations that produced it.
+    ///
+    /// The result is meant to be decrypted, its noise is too large for further computations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool, SanitizationKey};
+    ///
+    /// let config =
+    ///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let (client_key, server_key) = generate_keys(config);
+    /// let sanitization_key = SanitizationKey::new(
+    ///     &client_key,
+    ///     SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    /// let b = FheBool::encrypt(false, &client_key);
+    ///
+    /// let result = (&a | &b).sanitize(&sanitization_key);
+    ///
+    /// let decrypted: bool = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn sanitize(&self, sanitization_key: &SanitizationKey) -> Self {
+        sanitization_key.with_cpu_server_key(|server_key, tag| {
+            let block = self.ciphertext.on_cpu();
+            let inner = sanitization_key
+                .key
+                .key
+                .sanitize(&server_key.key, block.as_ref());
+            Self::new(BooleanBlock::new_unchecked(inner), tag.clone())
+        })
+    }
+
+    /// Re-encrypts the ciphertext to the key of the compact public key of `reencryption_key`,
+    /// using the server key set for the current thread, which must be the one of the recipient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::proxy_reencryption::PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::{
+    ///     generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheBool,
+    ///     ProxyReencryptionKey,
+    /// };
+    ///
+    /// let config =
+    ///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let (alice_client_key, _) = generate_keys(config);
+    /// let (bob_client_key, bob_server_key) = generate_keys(config);
+    /// let bob_public_key = CompactPublicKey::new(&bob_client_key);
+    ///
+    /// let reencryption_key = ProxyReencryptionKey::new(
+    ///     &alice_client_key,
+    ///     &bob_public_key,
+    ///     PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    /// set_server_key(bob_server_key);
+    ///
+    /// let a = FheBool::encrypt(true, &alice_client_key);
+    ///
+    /// let result = a.reencrypt(&reencryption_key).unwrap();
+    ///
+    /// let decrypted: bool = result.decrypt(&bob_client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn reencrypt(&self, reencryption_key: &ProxyReencryptionKey) -> crate::Result<Self> {
+        reencryption_key.with_cpu_server_key(|server_key, casting_mode| {
+            let block = self.ciphertext.on_cpu();
+            let inner = reencryption_key.key.key.reencrypt(
+                block.as_ref(),
+                &server_key.key,
+                casting_mode.into(),
+            )?;
+            Ok(Self::new(
+                BooleanBlock::new_unchecked(inner),
+                reencryption_key.tag.clone(),
+            ))
+        })
+    }
 }
 
 impl<Id> IfThenElse<FheUint<Id>> for FheBool
@@ -1,7 +1,7 @@
 use crate::backward_compatibility::booleans::{
     CompressedFheBoolVersions, InnerCompressedFheBoolVersions,
 };
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{ConformanceError, ParameterSetConformant};
 use crate::high_level_api::global_state::with_cpu_internal_keys;
 use crate::high_level_api::traits::Tagged;
 use crate::integer::BooleanBlock;
@@ -98,10 +98,14 @@ impl FheTryEncrypt<bool, ClientKey> for CompressedFheBool {
 impl ParameterSetConformant for CompressedFheBool {
     type ParameterSet = FheBoolConformanceParams;
 
-    fn is_conformant(&self, params: &FheBoolConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(&self, params: &FheBoolConformanceParams) -> Result<(), ConformanceError> {
         match &self.inner {
-            InnerCompressedFheBool::Seeded(seeded) => seeded.is_conformant(&params.0),
-            InnerCompressedFheBool::ModulusSwitched(ct) => ct.is_conformant(&params.0),
+            InnerCompressedFheBool::Seeded(seeded) => seeded.check_conformance(&params.0),
+            InnerCompressedFheBool::ModulusSwitched(ct) => ct.check_conformance(&params.0),
         }
     }
 }
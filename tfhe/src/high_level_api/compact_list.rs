@@ -1,7 +1,7 @@
 use tfhe_versionable::Versionize;
 
 use crate::backward_compatibility::compact_list::CompactCiphertextListVersions;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{ConformanceError, ParameterSetConformant};
 use crate::core_crypto::commons::math::random::{Deserialize, Serialize};
 use crate::core_crypto::prelude::Numeric;
 use crate::high_level_api::global_state;
@@ -16,11 +16,15 @@ use crate::integer::parameters::{
 use crate::named::Named;
 use crate::shortint::MessageModulus;
 #[cfg(feature = "zk-pok")]
-pub use zk::ProvenCompactCiphertextList;
+pub use zk::{
+    ProvenCompactCiphertextList, ProvenGlweCiphertextList, ProvenGlweCiphertextListBuilder,
+};
 
 #[cfg(feature = "zk-pok")]
-use crate::zk::{CompactPkePublicParams, ZkComputeLoad};
-use crate::{CompactPublicKey, Tag};
+use crate::zk::{
+    CompactPkePublicParams, GlwePkePublicParams, RangeConstraint, RangePublicParams, ZkComputeLoad,
+};
+use crate::{ClientKey, CompactPublicKey, ProxyReencryptionKey, Tag};
 
 impl crate::FheTypes {
     fn from_data_kind(data_kind: DataKind, message_modulus: MessageModulus) -> Option<Self> {
@@ -168,6 +172,27 @@ impl CompactCiphertextList {
             Some(_) => Err(crate::Error::new("Expected a CPU server key".to_string())),
         })
     }
+
+    /// Re-encrypts the list to the key of the compact public key of `reencryption_key`, and
+    /// expands it with the server key set for the current thread, which must be the one of the
+    /// recipient.
+    pub fn reencrypt(
+        &self,
+        reencryption_key: &ProxyReencryptionKey,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        reencryption_key.with_cpu_server_key(|server_key, casting_mode| {
+            self.inner
+                .reencrypt(
+                    reencryption_key.compact_list_key(),
+                    server_key,
+                    casting_mode,
+                )
+                .map(|inner| CompactCiphertextListExpander {
+                    inner,
+                    tag: reencryption_key.tag.clone(),
+                })
+        })
+    }
 }
 
 impl Tagged for CompactCiphertextList {
@@ -184,17 +209,27 @@ impl ParameterSetConformant for CompactCiphertextList {
     type ParameterSet = CompactCiphertextListConformanceParams;
 
     fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        parameter_set: &Self::ParameterSet,
+    ) -> Result<(), ConformanceError> {
         let Self { inner, tag: _ } = self;
 
-        inner.is_conformant(parameter_set)
+        inner.check_conformance(parameter_set)
     }
 }
 
 #[cfg(feature = "zk-pok")]
 mod zk {
-    use super::*;
+    use crate::backward_compatibility::compact_list::ProvenCompactCiphertextListVersions;
+    use crate::backward_compatibility::compact_list::ProvenCompactCiphertextListVersions;
+    use crate::GlwePublicKey;
 
-    #[derive(Clone, Serialize, Deserialize)]
+    #[derive(Clone, Serialize, Deserialize, Versionize)]
+    #[versionize(ProvenCompactCiphertextListVersions)]
     pub struct ProvenCompactCiphertextList {
         pub(crate) inner: crate::integer::ciphertext::ProvenCompactCiphertextList,
         pub(crate) tag: Tag,
@@ -241,20 +276,97 @@ mod zk {
             self.inner.verify(public_params, &pk.key.key, metadata)
         }
 
+        /// Verifies many lists at once, which is faster than verifying them one by one as the
+        /// cost of the pairings is shared between all the proofs.
+        ///
+        /// Each entry holds a list with the public key used to encrypt it and the metadata given
+        /// when proving. Returns the indices of the lists with at least one invalid proof, an
+        /// empty result meaning that all the lists are valid.
+        pub fn verify_batch(
+            lists: &[(&Self, &CompactPublicKey, &[u8])],
+            public_params: &CompactPkePublicParams,
+        ) -> Vec<usize> {
+            let integer_lists = lists
+                .iter()
+                .map(|&(list, pk, metadata)| (&list.inner, &pk.key.key, metadata))
+                .collect::<Vec<_>>();
+
+            crate::integer::ciphertext::ProvenCompactCiphertextList::verify_batch(
+                &integer_lists,
+                public_params,
+            )
+        }
+
         pub fn verify_and_expand(
             &self,
             public_params: &CompactPkePublicParams,
             pk: &CompactPublicKey,
             metadata: &[u8],
+        ) -> crate::Result<CompactCiphertextListExpander> {
+            self.expand_with(|unpacking_mode, casting_mode| {
+                self.inner.verify_and_expand(
+                    public_params,
+                    &pk.key.key,
+                    metadata,
+                    unpacking_mode,
+                    casting_mode,
+                )
+            })
+        }
+
+        /// Verifies the range proofs attached with
+        /// [`CompactCiphertextListBuilder::push_with_range_proof`].
+        ///
+        /// Each `(index, constraint)` of `expected_constraints` must be covered by a range proof
+        /// at least as tight as `constraint`.
+        ///
+        /// The range proofs are linked to the values committed in the encryption proofs, which
+        /// must be verified as well, see [`Self::verify_and_expand_with_range_proofs`].
+        pub fn verify_range_proofs(
+            &self,
+            public_params: &CompactPkePublicParams,
+            range_public_params: &RangePublicParams,
+            expected_constraints: &[(usize, RangeConstraint)],
+        ) -> crate::zk::ZkVerificationOutCome {
+            self.inner
+                .verify_range_proofs(public_params, range_public_params, expected_constraints)
+        }
+
+        pub fn verify_and_expand_with_range_proofs(
+            &self,
+            public_params: &CompactPkePublicParams,
+            range_public_params: &RangePublicParams,
+            pk: &CompactPublicKey,
+            metadata: &[u8],
+            expected_constraints: &[(usize, RangeConstraint)],
+        ) -> crate::Result<CompactCiphertextListExpander> {
+            self.expand_with(|unpacking_mode, casting_mode| {
+                self.inner.verify_and_expand_with_range_proofs(
+                    public_params,
+                    range_public_params,
+                    &pk.key.key,
+                    metadata,
+                    expected_constraints,
+                    unpacking_mode,
+                    casting_mode,
+                )
+            })
+        }
+
+        fn expand_with(
+            &self,
+            expand: impl FnOnce(
+                IntegerCompactCiphertextListUnpackingMode<'_>,
+                IntegerCompactCiphertextListCastingMode<'_>,
+            ) -> crate::Result<
+                crate::integer::ciphertext::CompactCiphertextListExpander,
+            >,
         ) -> crate::Result<CompactCiphertextListExpander> {
             // For WASM
             if !self.inner.is_packed() && !self.inner.needs_casting() {
                 // No ServerKey required, short circuit to avoid the global state call
                 return Ok(CompactCiphertextListExpander {
-                    inner: self.inner.verify_and_expand(
-                        public_params,
-                        &pk.key.key,
-                        metadata,
+                    inner: expand(
                         IntegerCompactCiphertextListUnpackingMode::NoUnpacking,
                         IntegerCompactCiphertextListCastingMode::NoCasting,
                     )?,
@@ -287,83 +399,161 @@ mod zk {
                         IntegerCompactCiphertextListCastingMode::NoCasting
                     };
 
-                    self.inner
-                        .verify_and_expand(
-                            public_params,
-                            &pk.key.key,
-                            metadata,
-                            unpacking_mode,
-                            casting_mode,
-                        )
-                        .map(|expander| CompactCiphertextListExpander {
+                    expand(unpacking_mode, casting_mode).map(|expander| {
+                        CompactCiphertextListExpander {
                             inner: expander,
                             tag: self.tag.clone(),
-                        })
+                        }
+                    })
                 }
                 #[cfg(feature = "gpu")]
                 Some(_) => Err(crate::Error::new("Expected a CPU server key".to_string())),
             })
         }
+    }
+
+    /// A list of values encrypted with a [`GlwePublicKey`] in GLWE ciphertexts, with the proofs
+    /// that the ciphertexts are well formed.
+    ///
+    /// The proofs show that the ciphertexts are encryptions under the [`GlwePublicKey`] given to
+    /// [`ProvenGlweCiphertextList::verify`]. The values are expanded without requiring a
+    /// [`ServerKey`](crate::ServerKey).
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct ProvenGlweCiphertextList {
+        pub(crate) inner: crate::integer::ciphertext::ProvenGlweCiphertextList,
+        pub(crate) tag: Tag,
+    }
+
+    impl Tagged for ProvenGlweCiphertextList {
+        fn tag(&self) -> &Tag {
+            &self.tag
+        }
+
+        fn tag_mut(&mut self) -> &mut Tag {
+            &mut self.tag
+        }
+    }
+    impl Named for ProvenGlweCiphertextList {
+        const NAME: &'static str = "high_level_api::ProvenGlweCiphertextList";
+    }
+
+    impl ProvenGlweCiphertextList {
+        pub fn builder(public_key: &GlwePublicKey) -> ProvenGlweCiphertextListBuilder {
+            ProvenGlweCiphertextListBuilder::new(public_key)
+        }
+
+        pub fn len(&self) -> usize {
+            self.inner.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        pub fn get_kind_of(&self, index: usize) -> Option<crate::FheTypes> {
+            self.inner.get_kind_of(index).and_then(|data_kind| {
+                crate::FheTypes::from_data_kind(data_kind, self.inner.glwe_list.message_modulus())
+            })
+        }
+
+        pub fn verify(
+            &self,
+            public_params: &GlwePkePublicParams,
+            public_key: &GlwePublicKey,
+            metadata: &[u8],
+        ) -> crate::zk::ZkVerificationOutCome {
+            self.inner.verify(public_params, &public_key.key, metadata)
+        }
+
+        pub fn verify_and_expand(
+            &self,
+            public_params: &GlwePkePublicParams,
+            public_key: &GlwePublicKey,
+            metadata: &[u8],
+        ) -> crate::Result<CompactCiphertextListExpander> {
+            Ok(CompactCiphertextListExpander {
+                inner: self
+                    .inner
+                    .verify_and_expand(public_params, &public_key.key, metadata)?,
+                tag: self.tag.clone(),
+            })
+        }
 
         #[doc(hidden)]
         /// This function allows to expand a ciphertext without verifying the associated proof.
         ///
         /// If you are here you were probably looking for it: use at your own risks.
-        pub fn expand_without_verification(&self) -> crate::Result<CompactCiphertextListExpander> {
-            // For WASM
-            if !self.inner.is_packed() && !self.inner.needs_casting() {
-                // No ServerKey required, short circuit to avoid the global state call
-                return Ok(CompactCiphertextListExpander {
-                    inner: self.inner.expand_without_verification(
-                        IntegerCompactCiphertextListUnpackingMode::NoUnpacking,
-                        IntegerCompactCiphertextListCastingMode::NoCasting,
-                    )?,
-                    tag: self.tag.clone(),
-                });
+        pub fn expand_without_verification(&self) -> CompactCiphertextListExpander {
+            CompactCiphertextListExpander {
+                inner: self.inner.expand_without_verification(),
+                tag: self.tag.clone(),
             }
+        }
+    }
 
-            global_state::try_with_internal_keys(|maybe_keys| match maybe_keys {
-                None => Err(crate::high_level_api::errors::UninitializedServerKey.into()),
-                Some(InternalServerKey::Cpu(cpu_key)) => {
-                    let unpacking_mode = if self.inner.is_packed() {
-                        IntegerCompactCiphertextListUnpackingMode::UnpackIfNecessary(
-                            cpu_key.pbs_key(),
-                        )
-                    } else {
-                        IntegerCompactCiphertextListUnpackingMode::NoUnpacking
-                    };
+    pub struct ProvenGlweCiphertextListBuilder {
+        inner: crate::integer::ciphertext::ProvenGlweCiphertextListBuilder,
+        tag: Tag,
+    }
 
-                    let casting_mode = if self.inner.needs_casting() {
-                        IntegerCompactCiphertextListCastingMode::CastIfNecessary(
-                            cpu_key.cpk_casting_key().ok_or_else(|| {
-                                crate::Error::new(
-                                    "No casting key found in ServerKey, \
-                                required to expand this CompactCiphertextList"
-                                        .to_string(),
-                                )
-                            })?,
-                        )
-                    } else {
-                        IntegerCompactCiphertextListCastingMode::NoCasting
-                    };
+    impl ProvenGlweCiphertextListBuilder {
+        pub fn new(public_key: &GlwePublicKey) -> Self {
+            Self {
+                inner: crate::integer::ciphertext::ProvenGlweCiphertextListBuilder::new(
+                    &public_key.key,
+                ),
+                tag: public_key.tag.clone(),
+            }
+        }
 
-                    self.inner
-                        .expand_without_verification(unpacking_mode, casting_mode)
-                        .map(|expander| CompactCiphertextListExpander {
-                            inner: expander,
-                            tag: self.tag.clone(),
-                        })
-                }
-                #[cfg(feature = "gpu")]
-                Some(_) => Err(crate::Error::new("Expected a CPU server key".to_string())),
-            })
+        pub fn push<T>(&mut self, value: T) -> &mut Self
+        where
+            T: Compactable,
+        {
+            self.inner.push(value);
+            self
+        }
+
+        pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+        where
+            T: Compactable,
+        {
+            self.inner.extend(values);
+            self
+        }
+
+        pub fn push_with_num_bits<T>(
+            &mut self,
+            number: T,
+            num_bits: usize,
+        ) -> crate::Result<&mut Self>
+        where
+            T: Compactable + Numeric,
+        {
+            let num_blocks = num_bits_to_strict_num_blocks(num_bits, self.inner.message_modulus())?;
+            self.inner.push_with_num_blocks(number, num_blocks);
+            Ok(self)
+        }
+
+        pub fn build_with_proof(
+            &self,
+            public_params: &GlwePkePublicParams,
+            metadata: &[u8],
+            compute_load: ZkComputeLoad,
+        ) -> crate::Result<ProvenGlweCiphertextList> {
+            self.inner
+                .build_with_proof(public_params, metadata, compute_load)
+                .map(|proved_list| ProvenGlweCiphertextList {
+                    inner: proved_list,
+                    tag: self.tag.clone(),
+                })
         }
     }
 }
 
 pub struct CompactCiphertextListExpander {
     pub(in crate::high_level_api) inner: crate::integer::ciphertext::CompactCiphertextListExpander,
-    tag: Tag,
+    pub(in crate::high_level_api) tag: Tag,
 }
 
 impl CompactCiphertextListExpander {
@@ -418,6 +608,52 @@ impl CompactCiphertextListBuilder {
         }
     }
 
+    /// Creates a builder encrypting with the secret key of the [`ClientKey`] from which `pk` is
+    /// derived.
+    ///
+    /// Lists built with a proof are verified against `pk` with
+    /// [`ProvenCompactCiphertextList::verify_and_expand`], the proofs also show that `pk` was
+    /// generated from the secret key used to encrypt.
+    pub fn new_with_secret_key(
+        client_key: &ClientKey,
+        pk: &CompactPublicKey,
+    ) -> crate::Result<Self> {
+        let pk = &pk.key.key;
+        let check_parameters = |parameters| {
+            if pk.key.parameters == parameters {
+                Ok(())
+            } else {
+                Err(crate::Error::new(String::from(
+                    "The CompactPublicKey does not use the parameters of the ClientKey",
+                )))
+            }
+        };
+
+        let inner = match &client_key.key.dedicated_compact_private_key {
+            Some((compact_private_key, _)) => {
+                check_parameters(compact_private_key.key.parameters())?;
+                crate::integer::ciphertext::CompactCiphertextListBuilder::new_with_secret_key(
+                    compact_private_key,
+                    pk,
+                )
+            }
+            None => {
+                let compact_private_key: crate::integer::CompactPrivateKey<&[u64]> =
+                    (&client_key.key.key).try_into()?;
+                check_parameters(compact_private_key.key.parameters())?;
+                crate::integer::ciphertext::CompactCiphertextListBuilder::new_with_secret_key(
+                    &compact_private_key,
+                    pk,
+                )
+            }
+        };
+
+        Ok(Self {
+            inner,
+            tag: client_key.tag.clone(),
+        })
+    }
+
     pub fn push<T>(&mut self, value: T) -> &mut Self
     where
         T: Compactable,
@@ -438,12 +674,29 @@ impl CompactCiphertextListBuilder {
     where
         T: Compactable + Numeric,
     {
-        let num_blocks =
-            num_bits_to_strict_num_blocks(num_bits, self.inner.pk.key.message_modulus())?;
+        let num_blocks = num_bits_to_strict_num_blocks(num_bits, self.inner.key.message_modulus())?;
         self.inner.push_with_num_blocks(number, num_blocks);
         Ok(self)
     }
 
+    /// Pushes a value along with a proof that its plaintext satisfies `constraint`.
+    ///
+    /// See [`ProvenCompactCiphertextList::verify_range_proofs`].
+    #[cfg(feature = "zk-pok")]
+    pub fn push_with_range_proof<T>(
+        &mut self,
+        value: T,
+        constraint: RangeConstraint,
+        range_public_params: &RangePublicParams,
+    ) -> crate::Result<&mut Self>
+    where
+        T: Compactable + Numeric + TryInto<u64>,
+    {
+        self.inner
+            .push_with_range_proof(value, constraint, range_public_params)?;
+        Ok(self)
+    }
+
     pub fn extend_with_num_bits<T>(
         &mut self,
         values: impl Iterator<Item = T>,
@@ -452,8 +705,7 @@ impl CompactCiphertextListBuilder {
     where
         T: Compactable + Numeric,
     {
-        let num_blocks =
-            num_bits_to_strict_num_blocks(num_bits, self.inner.pk.key.message_modulus())?;
+        let num_blocks = num_bits_to_strict_num_blocks(num_bits, self.inner.key.message_modulus())?;
         self.inner.extend_with_num_blocks(values, num_blocks);
         Ok(self)
     }
@@ -697,6 +949,191 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "zk-pok")]
+    #[test]
+    fn test_proven_compact_list_verify_batch() {
+        use crate::shortint::parameters::classic::tuniform::p_fail_2_minus_64::ks_pbs::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+        let config = crate::ConfigBuilder::with_custom_parameters(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        )
+        .build();
+
+        let ck = crate::ClientKey::generate(config);
+        let pk = crate::CompactPublicKey::new(&ck);
+        let other_ck = crate::ClientKey::generate(config);
+        let other_pk = crate::CompactPublicKey::new(&other_ck);
+
+        // Intentionally low so that lists hold multiple proofs
+        let crs = CompactPkeCrs::from_config(config, 32).unwrap();
+
+        let metadata = [b'h', b'l', b'a', b'p', b'i'];
+        let wrong_metadata = [b'w', b'r', b'o', b'n', b'g'];
+
+        let lists = [17u32, 42u32, 1234u32, 5u32].map(|value| {
+            ProvenCompactCiphertextList::builder(&pk)
+                .push(value)
+                .push(value as u64)
+                .build_with_proof_packed(crs.public_params(), &metadata, ZkComputeLoad::Proof)
+                .unwrap()
+        });
+
+        let valid_entries = lists
+            .iter()
+            .map(|list| (list, &pk, metadata.as_slice()))
+            .collect::<Vec<_>>();
+        assert!(
+            ProvenCompactCiphertextList::verify_batch(&valid_entries, crs.public_params())
+                .is_empty()
+        );
+
+        let entries = [
+            (&lists[0], &pk, metadata.as_slice()),
+            (&lists[1], &pk, wrong_metadata.as_slice()),
+            (&lists[2], &pk, metadata.as_slice()),
+            (&lists[3], &other_pk, metadata.as_slice()),
+        ];
+        assert_eq!(
+            ProvenCompactCiphertextList::verify_batch(&entries, crs.public_params()),
+            vec![1, 3]
+        );
+    }
+
+    #[cfg(feature = "zk-pok")]
+    #[test]
+    fn test_proven_compact_list_with_range_proofs() {
+        use crate::shortint::parameters::classic::tuniform::p_fail_2_minus_64::ks_pbs::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        use crate::zk::{RangeConstraint, RangeCrs, ZkVerificationOutCome};
+
+        let config = crate::ConfigBuilder::with_custom_parameters(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        )
+        .build();
+
+        let ck = crate::ClientKey::generate(config);
+        let pk = crate::CompactPublicKey::new(&ck);
+        let sks = crate::ServerKey::new(&ck);
+
+        set_server_key(sks);
+
+        let crs = CompactPkeCrs::from_config(config, 64).unwrap();
+        let range_crs = RangeCrs::new(32, &mut rand::thread_rng());
+
+        let metadata = [b'h', b'l', b'a', b'p', b'i'];
+        let balance_limit = 1000u64;
+
+        let mut builder = ProvenCompactCiphertextList::builder(&pk);
+        builder
+            .push(17u32)
+            .push_with_range_proof(
+                750u32,
+                RangeConstraint::UpperBound(balance_limit),
+                range_crs.public_params(),
+            )
+            .unwrap()
+            .push_with_range_proof(9u16, RangeConstraint::NumBits(4), range_crs.public_params())
+            .unwrap();
+
+        // Values not satisfying their constraint are rejected
+        assert!(builder
+            .push_with_range_proof(
+                1001u32,
+                RangeConstraint::UpperBound(balance_limit),
+                range_crs.public_params()
+            )
+            .is_err());
+        assert!(builder
+            .push_with_range_proof(
+                -1i32,
+                RangeConstraint::NumBits(4),
+                range_crs.public_params()
+            )
+            .is_err());
+
+        let compact_list = builder
+            .build_with_proof_packed(crs.public_params(), &metadata, ZkComputeLoad::Proof)
+            .unwrap();
+
+        let serialized = bincode::serialize(&compact_list).unwrap();
+        let compact_list: ProvenCompactCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+        let expected_constraints = [
+            (1, RangeConstraint::UpperBound(balance_limit)),
+            (2, RangeConstraint::NumBits(4)),
+        ];
+
+        // A tighter constraint than the proven one is not satisfied
+        assert!(matches!(
+            compact_list.verify_range_proofs(
+                crs.public_params(),
+                range_crs.public_params(),
+                &[(1, RangeConstraint::UpperBound(balance_limit - 1))]
+            ),
+            ZkVerificationOutCome::Invalid
+        ));
+        // Values without range proofs
+        assert!(matches!(
+            compact_list.verify_range_proofs(
+                crs.public_params(),
+                range_crs.public_params(),
+                &[(0, RangeConstraint::NumBits(32))]
+            ),
+            ZkVerificationOutCome::Invalid
+        ));
+
+        let expander = compact_list
+            .verify_and_expand_with_range_proofs(
+                crs.public_params(),
+                range_crs.public_params(),
+                &pk,
+                &metadata,
+                &expected_constraints,
+            )
+            .unwrap();
+
+        let a: FheUint32 = expander.get(0).unwrap().unwrap();
+        let b: FheUint32 = expander.get(1).unwrap().unwrap();
+        let c: FheUint16 = expander.get(2).unwrap().unwrap();
+
+        let a: u32 = a.decrypt(&ck);
+        assert_eq!(a, 17);
+        let b: u32 = b.decrypt(&ck);
+        assert_eq!(b, 750);
+        let c: u16 = c.decrypt(&ck);
+        assert_eq!(c, 9);
+        assert!(expander.get::<FheUint32>(3).is_none());
+
+        // A list encrypting a value above the limit, with the valid range proofs of the first list
+        let mut forged_list = ProvenCompactCiphertextList::builder(&pk)
+            .push(17u32)
+            .push(5000u32)
+            .push(9u16)
+            .build_with_proof_packed(crs.public_params(), &metadata, ZkComputeLoad::Proof)
+            .unwrap();
+        forged_list.inner.range_proofs = compact_list.inner.range_proofs.clone();
+
+        assert!(forged_list
+            .verify(crs.public_params(), &pk, &metadata)
+            .is_valid());
+        assert!(matches!(
+            forged_list.verify_range_proofs(
+                crs.public_params(),
+                range_crs.public_params(),
+                &expected_constraints
+            ),
+            ZkVerificationOutCome::Invalid
+        ));
+        assert!(forged_list
+            .verify_and_expand_with_range_proofs(
+                crs.public_params(),
+                range_crs.public_params(),
+                &pk,
+                &metadata,
+                &expected_constraints,
+            )
+            .is_err());
+    }
+
     #[cfg(feature = "zk-pok")]
     #[test]
     fn test_proven_compact_list_with_casting() {
@@ -785,4 +1222,137 @@ mod tests {
             assert!(unverified_expander.get::<FheBool>(4).is_none());
         }
     }
+
+    #[cfg(feature = "zk-pok")]
+    #[test]
+    fn test_proven_compact_list_with_secret_key() {
+        use crate::shortint::parameters::compact_public_key_only::p_fail_2_minus_64::ks_pbs::PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        use crate::shortint::parameters::key_switching::p_fail_2_minus_64::ks_pbs::PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+        let config = crate::ConfigBuilder::with_custom_parameters(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        )
+        .use_dedicated_compact_public_key_parameters((
+            PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+            PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        ))
+        .build();
+
+        let ck = crate::ClientKey::generate(config);
+        let sks = crate::ServerKey::new(&ck);
+
+        set_server_key(sks);
+
+        let crs = CompactPkeCrs::from_config(config, 32).unwrap();
+
+        let metadata = [b'h', b'l', b'a', b'p', b'i'];
+
+        let pk = crate::CompactPublicKey::new(&ck);
+        let other_pk = crate::CompactPublicKey::new(&crate::ClientKey::generate(config));
+
+        let compact_list = CompactCiphertextListBuilder::new_with_secret_key(&ck, &pk)
+            .unwrap()
+            .push(17u32)
+            .push(-1i64)
+            .push(false)
+            .build_with_proof_packed(crs.public_params(), &metadata, ZkComputeLoad::Proof)
+            .unwrap();
+
+        assert!(compact_list
+            .verify(crs.public_params(), &pk, &metadata)
+            .is_valid());
+        assert!(compact_list
+            .verify(crs.public_params(), &pk, b"wrong")
+            .is_invalid());
+        // The proofs are tied to the public key of the client key used to encrypt
+        assert!(compact_list
+            .verify(crs.public_params(), &other_pk, &metadata)
+            .is_invalid());
+        assert!(compact_list
+            .verify_and_expand(crs.public_params(), &other_pk, &metadata)
+            .is_err());
+
+        let expander = compact_list
+            .verify_and_expand(crs.public_params(), &pk, &metadata)
+            .unwrap();
+
+        let a: FheUint32 = expander.get(0).unwrap().unwrap();
+        let b: FheInt64 = expander.get(1).unwrap().unwrap();
+        let c: FheBool = expander.get(2).unwrap().unwrap();
+
+        let a: u32 = a.decrypt(&ck);
+        assert_eq!(a, 17);
+        let b: i64 = b.decrypt(&ck);
+        assert_eq!(b, -1);
+        let c = c.decrypt(&ck);
+        assert!(!c);
+    }
+
+    #[cfg(feature = "zk-pok")]
+    #[test]
+    fn test_proven_glwe_list() {
+        use crate::shortint::parameters::classic::tuniform::p_fail_2_minus_64::ks_pbs::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        use crate::zk::GlwePkeCrs;
+
+        let config = crate::ConfigBuilder::with_custom_parameters(
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        )
+        .build();
+
+        let ck = crate::ClientKey::generate(config);
+        let pk = crate::GlwePublicKey::new(&ck);
+        let crs = GlwePkeCrs::from_config(config).unwrap();
+
+        let metadata = [b'h', b'l', b'a', b'p', b'i'];
+
+        let list = ProvenGlweCiphertextList::builder(&pk)
+            .push(17u32)
+            .push(-1i64)
+            .push(true)
+            .push_with_num_bits(3u32, 2)
+            .unwrap()
+            .build_with_proof(crs.public_params(), &metadata, ZkComputeLoad::Proof)
+            .unwrap();
+
+        let serialized = bincode::serialize(&list).unwrap();
+        let list: ProvenGlweCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+        assert!(list.verify(crs.public_params(), &pk, &metadata).is_valid());
+        assert!(list.verify(crs.public_params(), &pk, b"wrong").is_invalid());
+        assert!(list
+            .verify_and_expand(crs.public_params(), &pk, b"wrong")
+            .is_err());
+
+        // The list was not encrypted with this public key
+        let other_ck = crate::ClientKey::generate(config);
+        let other_pk = crate::GlwePublicKey::new(&other_ck);
+        assert!(list
+            .verify(crs.public_params(), &other_pk, &metadata)
+            .is_invalid());
+
+        // No server key is needed to expand the list
+        let expander = list
+            .verify_and_expand(crs.public_params(), &pk, &metadata)
+            .unwrap();
+
+        let a: FheUint32 = expander.get(0).unwrap().unwrap();
+        let b: FheInt64 = expander.get(1).unwrap().unwrap();
+        let c: FheBool = expander.get(2).unwrap().unwrap();
+        let d: FheUint2 = expander.get(3).unwrap().unwrap();
+
+        let a: u32 = a.decrypt(&ck);
+        assert_eq!(a, 17);
+        let b: i64 = b.decrypt(&ck);
+        assert_eq!(b, -1);
+        assert!(c.decrypt(&ck));
+        let d: u8 = d.decrypt(&ck);
+        assert_eq!(d, 3);
+
+        // The list was not proven with this CRS
+        let other_crs = GlwePkeCrs::from_config(config).unwrap();
+        assert!(list
+            .verify(other_crs.public_params(), &pk, &metadata)
+            .is_invalid());
+    }
 }
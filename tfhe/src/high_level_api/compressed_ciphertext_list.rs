@@ -8,7 +8,7 @@ use crate::integer::ciphertext::{Compressible, DataKind, Expandable};
 use crate::named::Named;
 use crate::prelude::Tagged;
 use crate::shortint::Ciphertext;
-use crate::{FheBool, FheInt, FheUint, Tag};
+use crate::{CompactCiphertextListExpander, FheBool, FheInt, FheUint, ProxyReencryptionKey, Tag};
 
 impl<Id: FheUintId> Compressible for FheUint<Id> {
     fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
@@ -177,6 +177,26 @@ impl CompressedCiphertextList {
         })
     }
 
+    /// Re-encrypts the list to the key of the compact public key of `reencryption_key`, without
+    /// decompressing it, using the server key set for the current thread, which must be the one
+    /// of the recipient.
+    ///
+    /// The client key that generated `reencryption_key` must have compression keys.
+    pub fn reencrypt(
+        &self,
+        reencryption_key: &ProxyReencryptionKey,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        let key = reencryption_key.compressed_list_key()?;
+        reencryption_key.with_cpu_server_key(|server_key, casting_mode| {
+            self.inner
+                .reencrypt(key, server_key, casting_mode)
+                .map(|inner| CompactCiphertextListExpander {
+                    inner,
+                    tag: reencryption_key.tag.clone(),
+                })
+        })
+    }
+
     pub fn into_raw_parts(self) -> (crate::integer::ciphertext::CompressedCiphertextList, Tag) {
         let Self { inner, tag } = self;
         (inner, tag)
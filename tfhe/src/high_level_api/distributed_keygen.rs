@@ -0,0 +1,1264 @@
+//! Distributed generation of the public, server and noise squashing keys, without any party ever
+//! holding the secret keys.
+//!
+//! Every coordinate of the secret keys (the LWE key, the GLWE key and the GLWE key of the noise
+//! squashing) is additively secret shared between the N parties modulo `2^128`: no coalition of
+//! less than N parties learns anything about any coordinate of the keys. The shares are directly
+//! usable for [threshold](crate::threshold) decryption with [DkgParty::key_share] and
+//! [DkgParty::noise_squashing_key_share].
+//!
+//! Each party samples a random bit for each coordinate, the coordinate of the key is the XOR of
+//! the bits of all the parties. The XOR of the shared bits `x` and `y` is `x + y - 2xy`, the
+//! products are computed with Beaver triples, organized as a binary tree over the parties. The
+//! keyswitch key, the compact public key and the bootstrapping keys are then linear in the key
+//! shares and in the shares of the products of the LWE key with the GLWE keys, which are computed
+//! with one last round of Beaver triples: each party generates its contribution to the keys with
+//! its shares and a common public seed for the masks, the contributions of all parties are summed
+//! by [combine_key_contributions].
+//!
+//! # Trust assumption
+//!
+//! The Beaver triples are produced beforehand by a trusted dealer
+//! ([trusted_dealer_preprocessing]), which never sees anything about the keys, but learns all of
+//! them from the openings of the parties: the dealer **MUST NOT** collude with any of the
+//! parties, and the openings must be sent over private channels. The parties are assumed to be
+//! semi-honest, a party deviating from the protocol can make the keys incorrect.
+//!
+//! # Protocol
+//!
+//! The protocol is transport-agnostic, every message is a serializable struct:
+//! 1. the dealer sends its [TrustedDealerPreprocessing] to each party over a private channel,
+//! 2. in each round, each party sends its [Opening] ([DkgParty::opening]) to all the other
+//!    parties, and receives theirs ([DkgParty::receive_openings]), until there is no opening left,
+//!    which takes `ceil(log2(N)) + 1` rounds,
+//! 3. each party publishes its [KeyContribution], which are combined into the
+//!    [CompactPublicKey], the [ServerKey] and the [NoiseSquashingKey] by
+//!    [combine_key_contributions].
+//!
+//! Each party adds the noise of the parameters to the keys, the noise of the combined keys is
+//! hence N times larger (in variance) than the one of centrally generated keys: only
+//! [DistributedKeygenParameters] accounting for it are accepted, up to their maximum number of
+//! parties.
+//!
+//! ```rust
+//! use tfhe::distributed_keygen::{
+//!     combine_key_contributions, trusted_dealer_preprocessing, DkgParty,
+//! };
+//! use tfhe::prelude::*;
+//! use tfhe::shortint::parameters::distributed_keygen::DKG_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+//! use tfhe::threshold::{combine_unsigned, SquashedNoiseFhePartialDecrypt};
+//! use tfhe::{set_server_key, CompactCiphertextList, FheUint8};
+//!
+//! let parameters = DKG_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+//!
+//! let mut parties = trusted_dealer_preprocessing(parameters, 3)
+//!     .unwrap()
+//!     .into_iter()
+//!     .map(|preprocessing| DkgParty::new(parameters, preprocessing).unwrap())
+//!     .collect::<Vec<_>>();
+//!
+//! while let Some(openings) = parties
+//!     .iter()
+//!     .map(|party| party.opening().cloned())
+//!     .collect::<Option<Vec<_>>>()
+//! {
+//!     for party in &mut parties {
+//!         party.receive_openings(&openings).unwrap();
+//!     }
+//! }
+//!
+//! let contributions = parties
+//!     .iter()
+//!     .map(|party| party.key_contribution().unwrap())
+//!     .collect::<Vec<_>>();
+//! let (public_key, server_key, noise_squashing_key) =
+//!     combine_key_contributions(parameters, &contributions).unwrap();
+//! set_server_key(server_key);
+//!
+//! let list = CompactCiphertextList::builder(&public_key)
+//!     .push(27u8)
+//!     .build()
+//!     .expand()
+//!     .unwrap();
+//! let a: FheUint8 = list.get(0).unwrap().unwrap();
+//! let b = (&a + &a).squash_noise(&noise_squashing_key);
+//!
+//! let partial_decryptions = parties
+//!     .iter()
+//!     .map(|party| {
+//!         b.partial_decrypt(&party.noise_squashing_key_share().unwrap())
+//!             .unwrap()
+//!     })
+//!     .collect::<Vec<_>>();
+//! let clear: u8 = combine_unsigned(&partial_decryptions).unwrap();
+//! assert_eq!(clear, 54);
+//! ```
+
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{CompressionSeed, RandomGenerator, Seed, Seeder};
+use crate::core_crypto::prelude::*;
+use crate::high_level_api::backward_compatibility::distributed_keygen::{
+    KeyContributionVersions, OpeningVersions, TrustedDealerPreprocessingVersions,
+};
+use crate::high_level_api::threshold::{
+    squashed_noise_bound_log2, KeyShare, NoiseSquashingKeyShare,
+};
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::named::Named;
+use crate::shortint::ciphertext::MaxDegree;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{
+    CompactPublicKeyEncryptionParameters, DistributedKeygenParameters,
+};
+use crate::shortint::server_key::ShortintBootstrappingKey;
+use crate::shortint::EncryptionKeyChoice;
+use crate::{CompactPublicKey, Error, NoiseSquashingKey, ServerKey, Tag};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// The preprocessing dealt to one party, to be sent to it over a private channel.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(TrustedDealerPreprocessingVersions)]
+pub struct TrustedDealerPreprocessing {
+    party: usize,
+    num_parties: usize,
+    /// Seed of the public masks of the keys, common to all parties
+    common_seed: u128,
+    /// Seed of the shares of the Beaver triples of this party
+    seed: u128,
+    /// Corrections making the shares of the triples consistent, only dealt to the first party
+    product_corrections: Vec<u128>,
+}
+
+impl Named for TrustedDealerPreprocessing {
+    const NAME: &'static str = "high_level_api::distributed_keygen::TrustedDealerPreprocessing";
+}
+
+impl TrustedDealerPreprocessing {
+    pub fn party(&self) -> usize {
+        self.party
+    }
+}
+
+/// The message of a party for one round of the protocol, sent to all the other parties.
+///
+/// It contains the shares of the party masked by its shares of the Beaver triples of the round.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(OpeningVersions)]
+pub struct Opening {
+    party: usize,
+    round: usize,
+    values: Vec<u128>,
+}
+
+impl Named for Opening {
+    const NAME: &'static str = "high_level_api::distributed_keygen::Opening";
+}
+
+impl Opening {
+    pub fn party(&self) -> usize {
+        self.party
+    }
+
+    pub fn round(&self) -> usize {
+        self.round
+    }
+}
+
+/// Last message of the protocol, the contribution of a party to the public, server and noise
+/// squashing keys.
+///
+/// The contributions can be published, all of them are needed to build the keys.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(KeyContributionVersions)]
+pub struct KeyContribution {
+    party: usize,
+    num_parties: usize,
+    compact_public_key: SeededLweCompactPublicKeyOwned<u64>,
+    key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: SeededLweBootstrapKeyOwned<u64>,
+    noise_squashing_bootstrapping_key: SeededLweBootstrapKeyOwned<u128>,
+}
+
+impl Named for KeyContribution {
+    const NAME: &'static str = "high_level_api::distributed_keygen::KeyContribution";
+}
+
+impl KeyContribution {
+    pub fn party(&self) -> usize {
+        self.party
+    }
+}
+
+/// Checks that the parameters are supported by the distributed generation for `num_parties`,
+/// returns the parameters of the compact public key.
+fn check_parameters(
+    parameters: &DistributedKeygenParameters,
+    num_parties: usize,
+) -> crate::Result<CompactPublicKeyEncryptionParameters> {
+    if num_parties < 2 || num_parties > parameters.max_num_parties {
+        return Err(Error::new(format!(
+            "Distributed key generation needs between 2 and {} parties with these parameters, \
+            got {num_parties}",
+            parameters.max_num_parties
+        )));
+    }
+
+    let block_parameters = parameters.block_parameters;
+    if !block_parameters.ciphertext_modulus.is_native_modulus() {
+        return Err(Error::new(
+            "Distributed key generation only supports the native ciphertext modulus".to_string(),
+        ));
+    }
+    if !matches!(
+        block_parameters.encryption_key_choice,
+        EncryptionKeyChoice::Big
+    ) {
+        return Err(Error::new(
+            "Distributed key generation needs ciphertexts encrypted under the large key, to \
+            squash their noise"
+                .to_string(),
+        ));
+    }
+
+    let public_key_parameters: CompactPublicKeyEncryptionParameters =
+        block_parameters.try_into()?;
+    if !public_key_parameters
+        .encryption_lwe_dimension
+        .0
+        .is_power_of_two()
+    {
+        return Err(Error::new(
+            "The encryption LweDimension must be a power of two to generate a compact public key"
+                .to_string(),
+        ));
+    }
+
+    Ok(public_key_parameters)
+}
+
+/// Number of coordinates of each of the secret keys, which are shared as a single vector in this
+/// order.
+#[derive(Copy, Clone)]
+struct KeyLayout {
+    /// The LWE key, input of the bootstrapping keys
+    small: usize,
+    /// The GLWE key of the bootstrapping key, seen as an LWE key
+    large: usize,
+    /// The GLWE key of the noise squashing, seen as an LWE key
+    squashed: usize,
+}
+
+impl KeyLayout {
+    fn new(parameters: &DistributedKeygenParameters) -> Self {
+        let block_parameters = &parameters.block_parameters;
+        let noise_squashing_parameters = &parameters.noise_squashing_parameters;
+        Self {
+            small: block_parameters.lwe_dimension.0,
+            large: block_parameters
+                .glwe_dimension
+                .to_equivalent_lwe_dimension(block_parameters.polynomial_size)
+                .0,
+            squashed: noise_squashing_parameters
+                .glwe_dimension
+                .to_equivalent_lwe_dimension(noise_squashing_parameters.polynomial_size)
+                .0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.small + self.large + self.squashed
+    }
+
+    /// Number of coordinates multiplied with the coordinates of the LWE key
+    fn glwe_len(&self) -> usize {
+        self.large + self.squashed
+    }
+
+    /// Number of corrections dealt to the first party: one per coordinate of each of the
+    /// `num_parties - 1` XOR, and one per product of the LWE key with the GLWE keys.
+    fn num_product_corrections(&self, num_parties: usize) -> usize {
+        (num_parties - 1) * self.len() + self.small * self.glwe_len()
+    }
+}
+
+/// The random generators of the shares of the Beaver triples of a party.
+struct PreprocessingGenerators {
+    /// Generates the triples of the XOR, then the masks of the keys for the products
+    triples: RandomGenerator<ActivatedRandomGenerator>,
+    /// Generates the shares of the products of the masks of the keys
+    products: RandomGenerator<ActivatedRandomGenerator>,
+}
+
+impl PreprocessingGenerators {
+    fn new(seed: u128) -> Self {
+        let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(Seed(seed));
+        Self {
+            triples: RandomGenerator::new(seeder.seed()),
+            products: RandomGenerator::new(seeder.seed()),
+        }
+    }
+}
+
+fn random_vec(generator: &mut RandomGenerator<ActivatedRandomGenerator>, len: usize) -> Vec<u128> {
+    let mut values = vec![0u128; len];
+    generator.fill_slice_with_random_uniform(&mut values);
+    values
+}
+
+/// Shares of a Beaver triple of vectors, with `c = a * b` coordinate-wise.
+struct BeaverTriple {
+    a: Vec<u128>,
+    b: Vec<u128>,
+    c: Vec<u128>,
+}
+
+impl BeaverTriple {
+    /// Draws the next triple of `generator`, the first party adds `corrections` to its share of
+    /// `c`.
+    fn draw(
+        generator: &mut RandomGenerator<ActivatedRandomGenerator>,
+        corrections: &mut impl Iterator<Item = u128>,
+        len: usize,
+    ) -> Self {
+        let a = random_vec(generator, len);
+        let b = random_vec(generator, len);
+        let mut c = random_vec(generator, len);
+        for (c, correction) in c.iter_mut().zip(corrections) {
+            *c = c.wrapping_add(correction);
+        }
+        Self { a, b, c }
+    }
+}
+
+/// Seeds of the masks of the compact public key, keyswitch key, bootstrapping key and noise
+/// squashing bootstrapping key.
+fn mask_seeds(common_seed: u128) -> [CompressionSeed; 4] {
+    let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(Seed(common_seed));
+    std::array::from_fn(|_| seeder.seed().into())
+}
+
+/// Deals the Beaver triples needed by `num_parties` parties to generate keys for `parameters`,
+/// returns one message per party.
+///
+/// The dealer is trusted: it learns nothing about the keys from the protocol itself, but learns
+/// all of them if it colludes with any of the parties or sees their openings.
+pub fn trusted_dealer_preprocessing(
+    parameters: DistributedKeygenParameters,
+    num_parties: usize,
+) -> crate::Result<Vec<TrustedDealerPreprocessing>> {
+    check_parameters(&parameters, num_parties)?;
+    let layout = KeyLayout::new(&parameters);
+
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(new_seeder().seed());
+    let common_seed = generator.random_uniform::<u128>();
+    let seeds = (0..num_parties)
+        .map(|_| generator.random_uniform::<u128>())
+        .collect::<Vec<_>>();
+    let mut generators = seeds
+        .iter()
+        .map(|seed| PreprocessingGenerators::new(*seed))
+        .collect::<Vec<_>>();
+
+    let mut product_corrections = Vec::with_capacity(layout.num_product_corrections(num_parties));
+
+    // Each XOR of the tree consumes one triple, the tree of `num_parties` leaves has
+    // `num_parties - 1` of them
+    for _ in 1..num_parties {
+        let mut sum = BeaverTriple {
+            a: vec![0u128; layout.len()],
+            b: vec![0u128; layout.len()],
+            c: vec![0u128; layout.len()],
+        };
+        for generators in &mut generators {
+            let share = BeaverTriple::draw(
+                &mut generators.triples,
+                &mut std::iter::empty(),
+                layout.len(),
+            );
+            slice_wrapping_add_assign(&mut sum.a, &share.a);
+            slice_wrapping_add_assign(&mut sum.b, &share.b);
+            slice_wrapping_add_assign(&mut sum.c, &share.c);
+        }
+        product_corrections.extend(
+            sum.a
+                .iter()
+                .zip(sum.b.iter())
+                .zip(sum.c.iter())
+                .map(|((a, b), c)| a.wrapping_mul(*b).wrapping_sub(*c)),
+        );
+    }
+
+    let mut small_mask = vec![0u128; layout.small];
+    let mut glwe_mask = vec![0u128; layout.glwe_len()];
+    for generators in &mut generators {
+        slice_wrapping_add_assign(
+            &mut small_mask,
+            &random_vec(&mut generators.triples, layout.small),
+        );
+        slice_wrapping_add_assign(
+            &mut glwe_mask,
+            &random_vec(&mut generators.triples, layout.glwe_len()),
+        );
+    }
+    for small in &small_mask {
+        let mut products = vec![0u128; layout.glwe_len()];
+        for generators in &mut generators {
+            slice_wrapping_add_assign(
+                &mut products,
+                &random_vec(&mut generators.products, layout.glwe_len()),
+            );
+        }
+        product_corrections.extend(
+            glwe_mask
+                .iter()
+                .zip(products.iter())
+                .map(|(glwe, product)| small.wrapping_mul(*glwe).wrapping_sub(*product)),
+        );
+    }
+
+    let mut product_corrections = Some(product_corrections);
+    Ok(seeds
+        .into_iter()
+        .enumerate()
+        .map(|(party, seed)| TrustedDealerPreprocessing {
+            party,
+            num_parties,
+            common_seed,
+            seed,
+            product_corrections: product_corrections.take().unwrap_or_default(),
+        })
+        .collect())
+}
+
+enum Round {
+    /// XOR of the operands two by two, with one Beaver triple per XOR
+    Xor(Vec<BeaverTriple>),
+    /// Products of the LWE key with the GLWE keys, with the masks of the keys
+    Products {
+        small_mask: Vec<u128>,
+        glwe_mask: Vec<u128>,
+    },
+    /// The shares of the products of each coordinate of the LWE key with the GLWE keys, minus
+    /// the products of the shares of this party
+    Done { product_shares: Vec<u128> },
+}
+
+/// The state of one party of the distributed key generation.
+pub struct DkgParty {
+    parameters: DistributedKeygenParameters,
+    public_key_parameters: CompactPublicKeyEncryptionParameters,
+    layout: KeyLayout,
+    party: usize,
+    num_parties: usize,
+    common_seed: u128,
+    generators: PreprocessingGenerators,
+    product_corrections: std::vec::IntoIter<u128>,
+    /// Shares of the operands of the XOR tree, the last one holds the shares of the keys
+    operands: Vec<Vec<u128>>,
+    round_index: usize,
+    round: Round,
+    opening: Option<Opening>,
+}
+
+impl DkgParty {
+    /// Creates the state of a party and samples its random bits.
+    pub fn new(
+        parameters: DistributedKeygenParameters,
+        preprocessing: TrustedDealerPreprocessing,
+    ) -> crate::Result<Self> {
+        let TrustedDealerPreprocessing {
+            party,
+            num_parties,
+            common_seed,
+            seed,
+            product_corrections,
+        } = preprocessing;
+        let public_key_parameters = check_parameters(&parameters, num_parties)?;
+        let layout = KeyLayout::new(&parameters);
+
+        let expected_corrections = if party == 0 {
+            layout.num_product_corrections(num_parties)
+        } else {
+            0
+        };
+        if party >= num_parties || product_corrections.len() != expected_corrections {
+            return Err(Error::new(format!(
+                "The preprocessing dealt to party {party} does not match the parameters"
+            )));
+        }
+
+        let bits = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_binary_lwe_secret_key::<u128, _>(
+                LweDimension(layout.len()),
+                &mut engine.secret_generator,
+            )
+        });
+        // The bits of each party are the first shares of the XOR tree, a party holds zero shares
+        // of the bits of the other parties
+        let operands = (0..num_parties)
+            .map(|operand| {
+                if operand == party {
+                    bits.as_ref().to_vec()
+                } else {
+                    vec![0u128; layout.len()]
+                }
+            })
+            .collect();
+
+        let mut dkg_party = Self {
+            parameters,
+            public_key_parameters,
+            layout,
+            party,
+            num_parties,
+            common_seed,
+            generators: PreprocessingGenerators::new(seed),
+            product_corrections: product_corrections.into_iter(),
+            operands,
+            round_index: 0,
+            round: Round::Xor(Vec::new()),
+            opening: None,
+        };
+        dkg_party.start_round();
+
+        Ok(dkg_party)
+    }
+
+    pub fn party(&self) -> usize {
+        self.party
+    }
+
+    /// The opening of this party for the current round, to be sent to all the other parties,
+    /// `None` once the protocol is done.
+    pub fn opening(&self) -> Option<&Opening> {
+        self.opening.as_ref()
+    }
+
+    /// Receives the openings of all the parties (including this one) for the current round, and
+    /// moves to the next round.
+    pub fn receive_openings(&mut self, openings: &[Opening]) -> crate::Result<()> {
+        let Some(own_opening) = &self.opening else {
+            return Err(Error::new(
+                "The distributed key generation is already done".to_string(),
+            ));
+        };
+
+        let mut received = vec![false; self.num_parties];
+        let mut sums = vec![0u128; own_opening.values.len()];
+        for opening in openings {
+            if opening.round != self.round_index || opening.values.len() != sums.len() {
+                return Err(Error::new(format!(
+                    "The opening of party {} does not match round {}",
+                    opening.party, self.round_index
+                )));
+            }
+            match received.get_mut(opening.party) {
+                Some(seen) if !*seen => *seen = true,
+                _ => {
+                    return Err(Error::new(format!(
+                        "Duplicated or invalid opening of party {}",
+                        opening.party
+                    )))
+                }
+            }
+            slice_wrapping_add_assign(&mut sums, &opening.values);
+        }
+        if let Some(missing) = received.iter().position(|seen| !seen) {
+            return Err(Error::new(format!(
+                "Missing the opening of party {missing}"
+            )));
+        }
+
+        match std::mem::replace(&mut self.round, Round::Xor(Vec::new())) {
+            Round::Xor(triples) => self.finish_xor_round(&triples, &sums),
+            Round::Products {
+                small_mask,
+                glwe_mask,
+            } => self.finish_products_round(&small_mask, &glwe_mask, &sums),
+            Round::Done { .. } => unreachable!("an opening is only pending before the end"),
+        }
+        self.round_index += 1;
+        self.start_round();
+
+        Ok(())
+    }
+
+    /// Draws the Beaver triples of the round and computes the opening of this party.
+    fn start_round(&mut self) {
+        let len = self.layout.len();
+        let mut values = Vec::new();
+
+        if self.operands.len() > 1 {
+            let triples = self
+                .operands
+                .chunks_exact(2)
+                .map(|pair| {
+                    let triple = BeaverTriple::draw(
+                        &mut self.generators.triples,
+                        &mut self.product_corrections.by_ref().take(len),
+                        len,
+                    );
+                    values.extend(
+                        pair[0]
+                            .iter()
+                            .zip(triple.a.iter())
+                            .map(|(x, a)| x.wrapping_sub(*a)),
+                    );
+                    values.extend(
+                        pair[1]
+                            .iter()
+                            .zip(triple.b.iter())
+                            .map(|(y, b)| y.wrapping_sub(*b)),
+                    );
+                    triple
+                })
+                .collect();
+            self.round = Round::Xor(triples);
+        } else if matches!(self.round, Round::Xor(_)) {
+            let key_shares = &self.operands[0];
+            let small_mask = random_vec(&mut self.generators.triples, self.layout.small);
+            let glwe_mask = random_vec(&mut self.generators.triples, self.layout.glwe_len());
+            values.extend(
+                key_shares
+                    .iter()
+                    .zip(small_mask.iter().chain(glwe_mask.iter()))
+                    .map(|(share, mask)| share.wrapping_sub(*mask)),
+            );
+            self.round = Round::Products {
+                small_mask,
+                glwe_mask,
+            };
+        } else {
+            self.opening = None;
+            return;
+        }
+
+        self.opening = Some(Opening {
+            party: self.party,
+            round: self.round_index,
+            values,
+        });
+    }
+
+    /// Replaces each pair of operands by the shares of their XOR `x + y - 2xy`.
+    fn finish_xor_round(&mut self, triples: &[BeaverTriple], opened: &[u128]) {
+        let len = self.layout.len();
+        let is_first_party = self.party == 0;
+        let mut operands = std::mem::take(&mut self.operands).into_iter();
+        let mut xors = Vec::with_capacity(operands.len().div_ceil(2));
+
+        for (triple, opened) in triples.iter().zip(opened.chunks_exact(2 * len)) {
+            let (x, y) = (operands.next().unwrap(), operands.next().unwrap());
+            let (d, e) = opened.split_at(len);
+            let xor = (0..len)
+                .map(|i| {
+                    let mut product = triple.c[i]
+                        .wrapping_add(d[i].wrapping_mul(triple.b[i]))
+                        .wrapping_add(e[i].wrapping_mul(triple.a[i]));
+                    if is_first_party {
+                        product = product.wrapping_add(d[i].wrapping_mul(e[i]));
+                    }
+                    x[i].wrapping_add(y[i])
+                        .wrapping_sub(product.wrapping_mul(2))
+                })
+                .collect();
+            xors.push(xor);
+        }
+        // The last operand of an odd number of operands goes to the next round as is
+        xors.extend(operands);
+
+        self.operands = xors;
+    }
+
+    /// Computes the shares of the products of the LWE key with the GLWE keys.
+    fn finish_products_round(&mut self, small_mask: &[u128], glwe_mask: &[u128], opened: &[u128]) {
+        let is_first_party = self.party == 0;
+        let (small_key_shares, glwe_key_shares) = self.operands[0].split_at(self.layout.small);
+        let (d, e) = opened.split_at(self.layout.small);
+        let mut product_shares = Vec::with_capacity(self.layout.small * self.layout.glwe_len());
+
+        for ((small_share, small_mask), d) in small_key_shares.iter().zip(small_mask).zip(d) {
+            let products = random_vec(&mut self.generators.products, self.layout.glwe_len());
+            for (((product, glwe_share), glwe_mask), e) in
+                products.iter().zip(glwe_key_shares).zip(glwe_mask).zip(e)
+            {
+                let mut share = product
+                    .wrapping_add(d.wrapping_mul(*glwe_mask))
+                    .wrapping_add(e.wrapping_mul(*small_mask));
+                if is_first_party {
+                    share = share
+                        .wrapping_add(d.wrapping_mul(*e))
+                        .wrapping_add(self.product_corrections.next().unwrap());
+                }
+                // The product of the shares of this party is added by the bootstrapping key
+                // generation
+                product_shares.push(share.wrapping_sub(small_share.wrapping_mul(*glwe_share)));
+            }
+        }
+
+        self.round = Round::Done { product_shares };
+    }
+
+    /// The shares of the keys and of the products, once the protocol is done.
+    fn done_shares(&self) -> crate::Result<(&[u128], &[u128])> {
+        match &self.round {
+            Round::Done { product_shares } => Ok((&self.operands[0], product_shares)),
+            _ => Err(Error::new(
+                "The distributed key generation is not done yet".to_string(),
+            )),
+        }
+    }
+
+    /// The share of this party of the secret keys of the [ServerKey], for threshold decryption.
+    pub fn key_share(&self) -> crate::Result<KeyShare> {
+        let (key_shares, _) = self.done_shares()?;
+        // Shares modulo 2^128 are also shares modulo 2^64 once truncated
+        let truncate = |shares: &[u128]| -> LweSecretKeyOwned<u64> {
+            LweSecretKey::from_container(shares.iter().map(|share| *share as u64).collect())
+        };
+        let (small_key_share, glwe_key_shares) = key_shares.split_at(self.layout.small);
+
+        Ok(KeyShare::from_raw_parts(
+            self.party,
+            self.num_parties,
+            truncate(&glwe_key_shares[..self.layout.large]),
+            truncate(small_key_share),
+        ))
+    }
+
+    /// The share of this party of the secret key of the noise squashing, for threshold
+    /// decryption of the squashed ciphertexts.
+    pub fn noise_squashing_key_share(&self) -> crate::Result<NoiseSquashingKeyShare> {
+        let (key_shares, _) = self.done_shares()?;
+        let squashed_key_share = key_shares[self.layout.small + self.layout.large..].to_vec();
+
+        Ok(NoiseSquashingKeyShare::from_raw_parts(
+            self.party,
+            self.num_parties,
+            LweSecretKey::from_container(squashed_key_share),
+            squashed_noise_bound_log2(
+                self.parameters.block_parameters.lwe_dimension,
+                self.parameters.noise_squashing_parameters,
+                self.num_parties,
+            ),
+        ))
+    }
+
+    /// Generates the contribution of this party to the keys, once the protocol is done.
+    pub fn key_contribution(&self) -> crate::Result<KeyContribution> {
+        let (key_shares, product_shares) = self.done_shares()?;
+        let block_parameters = &self.parameters.block_parameters;
+        let noise_squashing_parameters = &self.parameters.noise_squashing_parameters;
+        let KeyLayout {
+            small,
+            large,
+            squashed,
+        } = self.layout;
+
+        // Shares modulo 2^128 are also shares modulo 2^64 once truncated
+        let truncate =
+            |shares: &[u128]| -> Vec<u64> { shares.iter().map(|share| *share as u64).collect() };
+        let (small_key_share, glwe_key_shares) = key_shares.split_at(small);
+        let (large_key_share, squashed_key_share) = glwe_key_shares.split_at(large);
+        let small_key_share_128 = LweSecretKey::from_container(small_key_share);
+        let small_key_share: LweSecretKeyOwned<u64> =
+            LweSecretKey::from_container(truncate(small_key_share));
+        let large_key_share: LweSecretKeyOwned<u64> =
+            LweSecretKey::from_container(truncate(large_key_share));
+        let glwe_key_share = GlweSecretKey::from_container(
+            large_key_share.as_ref(),
+            block_parameters.polynomial_size,
+        );
+        let squashed_key_share = GlweSecretKey::from_container(
+            squashed_key_share,
+            noise_squashing_parameters.polynomial_size,
+        );
+
+        let mut large_product_shares = Vec::with_capacity(small * large);
+        let mut squashed_product_shares = Vec::with_capacity(small * squashed);
+        for shares in product_shares.chunks_exact(self.layout.glwe_len()) {
+            let (large_shares, squashed_shares) = shares.split_at(large);
+            large_product_shares.extend(large_shares.iter().map(|share| *share as u64));
+            squashed_product_shares.extend_from_slice(squashed_shares);
+        }
+
+        let [public_key_seed, key_switching_key_seed, bootstrapping_key_seed, noise_squashing_seed] =
+            mask_seeds(self.common_seed);
+        let mut compact_public_key = SeededLweCompactPublicKeyOwned::new(
+            0u64,
+            large_key_share.lwe_dimension(),
+            public_key_seed,
+            block_parameters.ciphertext_modulus,
+        );
+        let mut key_switching_key = SeededLweKeyswitchKeyOwned::new(
+            0u64,
+            block_parameters.ks_base_log,
+            block_parameters.ks_level,
+            large_key_share.lwe_dimension(),
+            small_key_share.lwe_dimension(),
+            key_switching_key_seed,
+            block_parameters.ciphertext_modulus,
+        );
+        let mut bootstrapping_key = SeededLweBootstrapKeyOwned::new(
+            0u64,
+            block_parameters.glwe_dimension.to_glwe_size(),
+            block_parameters.polynomial_size,
+            block_parameters.pbs_base_log,
+            block_parameters.pbs_level,
+            block_parameters.lwe_dimension,
+            bootstrapping_key_seed,
+            block_parameters.ciphertext_modulus,
+        );
+        let mut noise_squashing_bootstrapping_key = SeededLweBootstrapKeyOwned::new(
+            0u128,
+            noise_squashing_parameters.glwe_dimension.to_glwe_size(),
+            noise_squashing_parameters.polynomial_size,
+            noise_squashing_parameters.decomp_base_log,
+            noise_squashing_parameters.decomp_level_count,
+            block_parameters.lwe_dimension,
+            noise_squashing_seed,
+            CiphertextModulus::new_native(),
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            generate_seeded_lwe_compact_public_key(
+                &large_key_share,
+                &mut compact_public_key,
+                self.public_key_parameters.encryption_noise_distribution,
+                &mut engine.seeder,
+            );
+            generate_seeded_lwe_keyswitch_key(
+                &large_key_share,
+                &small_key_share,
+                &mut key_switching_key,
+                block_parameters.lwe_noise_distribution,
+                &mut engine.seeder,
+            );
+            // Encrypts the products of the shares of this party, the shares of the products
+            // with the shares of the other parties are added below
+            par_generate_seeded_lwe_bootstrap_key(
+                &small_key_share,
+                &glwe_key_share,
+                &mut bootstrapping_key,
+                block_parameters.glwe_noise_distribution,
+                &mut engine.seeder,
+            );
+            par_generate_seeded_lwe_bootstrap_key(
+                &small_key_share_128,
+                &squashed_key_share,
+                &mut noise_squashing_bootstrapping_key,
+                noise_squashing_parameters.glwe_noise_distribution,
+                &mut engine.seeder,
+            );
+        });
+
+        add_product_shares(&mut bootstrapping_key, &large_product_shares);
+        add_product_shares(
+            &mut noise_squashing_bootstrapping_key,
+            &squashed_product_shares,
+        );
+
+        Ok(KeyContribution {
+            party: self.party,
+            num_parties: self.num_parties,
+            compact_public_key,
+            key_switching_key,
+            bootstrapping_key,
+            noise_squashing_bootstrapping_key,
+        })
+    }
+}
+
+/// Adds the shares of the products of the LWE key coordinates with the GLWE key to the bodies of
+/// the non-last rows of each level of the GGSW ciphertexts, where
+/// [generate_seeded_lwe_bootstrap_key] puts the product of the shares of this party.
+fn add_product_shares<Scalar: UnsignedTorus>(
+    bootstrapping_key: &mut SeededLweBootstrapKeyOwned<Scalar>,
+    product_shares: &[Scalar],
+) {
+    let polynomial_size = bootstrapping_key.polynomial_size().0;
+    let key_len = product_shares.len() / bootstrapping_key.input_lwe_dimension().0;
+    let decomp_base_log = bootstrapping_key.decomposition_base_log();
+    let ciphertext_modulus = bootstrapping_key.ciphertext_modulus();
+
+    for (mut ggsw, shares) in bootstrapping_key
+        .iter_mut()
+        .zip(product_shares.chunks_exact(key_len))
+    {
+        for (level_index, mut level_matrix) in ggsw.iter_mut().enumerate() {
+            let factor = ggsw_encryption_multiplicative_factor(
+                ciphertext_modulus,
+                DecompositionLevel(level_index + 1),
+                decomp_base_log,
+                Cleartext(Scalar::ONE),
+            );
+
+            for (mut row, shares) in level_matrix
+                .as_mut_seeded_glwe_list()
+                .iter_mut()
+                .zip(shares.chunks_exact(polynomial_size))
+            {
+                let mut body = row.get_mut_body();
+                for (coefficient, share) in body.as_mut().iter_mut().zip(shares.iter()) {
+                    *coefficient = coefficient.wrapping_add(share.wrapping_mul(factor));
+                }
+            }
+        }
+    }
+}
+
+impl KeyContribution {
+    /// Checks that the entities of the contribution have the dimensions of the parameters.
+    fn matches_parameters(
+        &self,
+        parameters: &DistributedKeygenParameters,
+        public_key_parameters: &CompactPublicKeyEncryptionParameters,
+    ) -> bool {
+        let Self {
+            party: _,
+            num_parties: _,
+            compact_public_key,
+            key_switching_key,
+            bootstrapping_key,
+            noise_squashing_bootstrapping_key,
+        } = self;
+        let block_parameters = &parameters.block_parameters;
+        let noise_squashing_parameters = &parameters.noise_squashing_parameters;
+
+        compact_public_key.lwe_dimension() == public_key_parameters.encryption_lwe_dimension
+            && compact_public_key.ciphertext_modulus() == block_parameters.ciphertext_modulus
+            && key_switching_key.decomposition_base_log() == block_parameters.ks_base_log
+            && key_switching_key.decomposition_level_count() == block_parameters.ks_level
+            && key_switching_key.input_key_lwe_dimension()
+                == block_parameters
+                    .glwe_dimension
+                    .to_equivalent_lwe_dimension(block_parameters.polynomial_size)
+            && key_switching_key.output_key_lwe_dimension() == block_parameters.lwe_dimension
+            && key_switching_key.ciphertext_modulus() == block_parameters.ciphertext_modulus
+            && bootstrapping_key.glwe_size() == block_parameters.glwe_dimension.to_glwe_size()
+            && bootstrapping_key.polynomial_size() == block_parameters.polynomial_size
+            && bootstrapping_key.decomposition_base_log() == block_parameters.pbs_base_log
+            && bootstrapping_key.decomposition_level_count() == block_parameters.pbs_level
+            && bootstrapping_key.input_lwe_dimension() == block_parameters.lwe_dimension
+            && bootstrapping_key.ciphertext_modulus() == block_parameters.ciphertext_modulus
+            && noise_squashing_bootstrapping_key.glwe_size()
+                == noise_squashing_parameters.glwe_dimension.to_glwe_size()
+            && noise_squashing_bootstrapping_key.polynomial_size()
+                == noise_squashing_parameters.polynomial_size
+            && noise_squashing_bootstrapping_key.decomposition_base_log()
+                == noise_squashing_parameters.decomp_base_log
+            && noise_squashing_bootstrapping_key.decomposition_level_count()
+                == noise_squashing_parameters.decomp_level_count
+            && noise_squashing_bootstrapping_key.input_lwe_dimension()
+                == block_parameters.lwe_dimension
+            && noise_squashing_bootstrapping_key
+                .ciphertext_modulus()
+                .is_native_modulus()
+    }
+}
+
+/// Combines the [KeyContribution]s of all the parties into the public, server and noise
+/// squashing keys.
+pub fn combine_key_contributions(
+    parameters: DistributedKeygenParameters,
+    contributions: &[KeyContribution],
+) -> crate::Result<(CompactPublicKey, ServerKey, NoiseSquashingKey)> {
+    let Some(first) = contributions.first() else {
+        return Err(Error::new("No key contribution to combine".to_string()));
+    };
+    let public_key_parameters = check_parameters(&parameters, first.num_parties)?;
+    let block_parameters = parameters.block_parameters;
+
+    let mut parties = vec![false; first.num_parties];
+    for contribution in contributions {
+        if contribution.num_parties != first.num_parties
+            || contribution.compact_public_key.compression_seed()
+                != first.compact_public_key.compression_seed()
+            || contribution.key_switching_key.compression_seed()
+                != first.key_switching_key.compression_seed()
+            || contribution.bootstrapping_key.compression_seed()
+                != first.bootstrapping_key.compression_seed()
+            || contribution
+                .noise_squashing_bootstrapping_key
+                .compression_seed()
+                != first.noise_squashing_bootstrapping_key.compression_seed()
+        {
+            return Err(Error::new(format!(
+                "Key contribution of party {} was not generated with the same parties and seed as \
+                the one of party {}",
+                contribution.party, first.party
+            )));
+        }
+        if !contribution.matches_parameters(&parameters, &public_key_parameters) {
+            return Err(Error::new(format!(
+                "Key contribution of party {} does not match the parameters",
+                contribution.party
+            )));
+        }
+        match parties.get_mut(contribution.party) {
+            Some(seen) if !*seen => *seen = true,
+            _ => {
+                return Err(Error::new(format!(
+                    "Duplicated or invalid key contribution of party {}",
+                    contribution.party
+                )))
+            }
+        }
+    }
+    if let Some(missing) = parties.iter().position(|seen| !seen) {
+        return Err(Error::new(format!(
+            "Missing the key contribution of party {missing}"
+        )));
+    }
+
+    let mut compact_public_key = first.compact_public_key.clone();
+    let mut key_switching_key = first.key_switching_key.clone();
+    let mut bootstrapping_key = first.bootstrapping_key.clone();
+    let mut noise_squashing_bootstrapping_key = first.noise_squashing_bootstrapping_key.clone();
+    for contribution in &contributions[1..] {
+        slice_wrapping_add_assign(
+            compact_public_key.as_mut(),
+            contribution.compact_public_key.as_ref(),
+        );
+        slice_wrapping_add_assign(
+            key_switching_key.as_mut(),
+            contribution.key_switching_key.as_ref(),
+        );
+        slice_wrapping_add_assign(
+            bootstrapping_key.as_mut(),
+            contribution.bootstrapping_key.as_ref(),
+        );
+        slice_wrapping_add_assign(
+            noise_squashing_bootstrapping_key.as_mut(),
+            contribution.noise_squashing_bootstrapping_key.as_ref(),
+        );
+    }
+
+    let compact_public_key = compact_public_key.decompress_into_lwe_compact_public_key();
+    let key_switching_key = key_switching_key.par_decompress_into_lwe_keyswitch_key();
+    let bootstrapping_key = bootstrapping_key.par_decompress_into_lwe_bootstrap_key();
+    let noise_squashing_bootstrapping_key =
+        noise_squashing_bootstrapping_key.par_decompress_into_lwe_bootstrap_key();
+
+    let mut fourier_bootstrapping_key = FourierLweBootstrapKeyOwned::new(
+        bootstrapping_key.input_lwe_dimension(),
+        bootstrapping_key.glwe_size(),
+        bootstrapping_key.polynomial_size(),
+        bootstrapping_key.decomposition_base_log(),
+        bootstrapping_key.decomposition_level_count(),
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(
+        &bootstrapping_key,
+        &mut fourier_bootstrapping_key,
+    );
+
+    let mut fourier_noise_squashing_bootstrapping_key = Fourier128LweBootstrapKey::new(
+        noise_squashing_bootstrapping_key.input_lwe_dimension(),
+        noise_squashing_bootstrapping_key.glwe_size(),
+        noise_squashing_bootstrapping_key.polynomial_size(),
+        noise_squashing_bootstrapping_key.decomposition_base_log(),
+        noise_squashing_bootstrapping_key.decomposition_level_count(),
+    );
+    convert_standard_lwe_bootstrap_key_to_fourier_128(
+        &noise_squashing_bootstrapping_key,
+        &mut fourier_noise_squashing_bootstrapping_key,
+    );
+
+    let shortint_key = crate::shortint::ServerKey::from_raw_parts(
+        key_switching_key,
+        ShortintBootstrappingKey::Classic(fourier_bootstrapping_key),
+        block_parameters.message_modulus,
+        block_parameters.carry_modulus,
+        MaxDegree::integer_radix_server_key(
+            block_parameters.message_modulus,
+            block_parameters.carry_modulus,
+        ),
+        block_parameters.max_noise_level,
+        block_parameters.ciphertext_modulus,
+        block_parameters.encryption_key_choice.into(),
+    );
+    let server_key = ServerKey::from_raw_parts(
+        IntegerServerKey::from_raw_parts(shortint_key),
+        None,
+        None,
+        None,
+        Tag::default(),
+    );
+
+    let noise_squashing_key = NoiseSquashingKey::from_raw_parts(
+        crate::integer::noise_squashing::NoiseSquashingKey::from_raw_parts(
+            crate::shortint::noise_squashing::NoiseSquashingKey::from_raw_parts(
+                fourier_noise_squashing_bootstrapping_key,
+                parameters.noise_squashing_parameters.storage_log_modulus,
+            ),
+        ),
+        Tag::default(),
+    );
+
+    let public_key = CompactPublicKey::from_raw_parts(
+        crate::integer::public_key::CompactPublicKey::from_raw_parts(
+            crate::shortint::CompactPublicKey::from_raw_parts(
+                compact_public_key,
+                public_key_parameters,
+            ),
+        ),
+        Tag::default(),
+    );
+
+    Ok((public_key, server_key, noise_squashing_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::shortint::parameters::distributed_keygen::DKG_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS;
+    use crate::threshold::{
+        combine_signed, combine_unsigned, FhePartialDecrypt, SquashedNoiseFhePartialDecrypt,
+    };
+    use crate::{set_server_key, CompactCiphertextList, FheInt16, FheUint8};
+
+    const PARAMETERS: DistributedKeygenParameters =
+        DKG_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+
+    fn new_parties(num_parties: usize) -> Vec<DkgParty> {
+        trusted_dealer_preprocessing(PARAMETERS, num_parties)
+            .unwrap()
+            .into_iter()
+            .map(|preprocessing| DkgParty::new(PARAMETERS, preprocessing).unwrap())
+            .collect()
+    }
+
+    fn run_protocol(num_parties: usize) -> (Vec<DkgParty>, Vec<KeyContribution>) {
+        let mut parties = new_parties(num_parties);
+        let mut num_rounds = 0;
+        while let Some(openings) = parties
+            .iter()
+            .map(|party| party.opening().cloned())
+            .collect::<Option<Vec<_>>>()
+        {
+            for party in &mut parties {
+                party.receive_openings(&openings).unwrap();
+            }
+            num_rounds += 1;
+        }
+        assert_eq!(
+            num_rounds,
+            num_parties.next_power_of_two().ilog2() as usize + 1
+        );
+
+        let contributions = parties
+            .iter()
+            .map(|party| party.key_contribution().unwrap())
+            .collect();
+
+        (parties, contributions)
+    }
+
+    #[test]
+    fn distributed_key_generation() {
+        let (parties, contributions) = run_protocol(3);
+
+        // The shares sum to binary keys
+        let mut key = vec![0u128; parties[0].layout.len()];
+        for party in &parties {
+            slice_wrapping_add_assign(&mut key, party.done_shares().unwrap().0);
+        }
+        assert!(key.iter().all(|coordinate| *coordinate <= 1));
+        assert!(key.iter().any(|coordinate| *coordinate == 1));
+
+        let (public_key, server_key, noise_squashing_key) =
+            combine_key_contributions(PARAMETERS, &contributions).unwrap();
+        set_server_key(server_key);
+        let key_shares = parties
+            .iter()
+            .map(|party| party.key_share().unwrap())
+            .collect::<Vec<_>>();
+        let noise_squashing_key_shares = parties
+            .iter()
+            .map(|party| party.noise_squashing_key_share().unwrap())
+            .collect::<Vec<_>>();
+
+        let list = CompactCiphertextList::builder(&public_key)
+            .push(27u8)
+            .push(100u8)
+            .push(-1234i16)
+            .build()
+            .expand()
+            .unwrap();
+
+        let a: FheUint8 = list.get(0).unwrap().unwrap();
+        let b: FheUint8 = list.get(1).unwrap().unwrap();
+        let c = &a * &b + &a;
+        let partial_decryptions = key_shares
+            .iter()
+            .map(|key_share| c.partial_decrypt(key_share).unwrap())
+            .collect::<Vec<_>>();
+        let clear: u8 = combine_unsigned(&partial_decryptions).unwrap();
+        assert_eq!(clear, 27u8.wrapping_mul(100).wrapping_add(27));
+
+        let c = c.squash_noise(&noise_squashing_key);
+        let partial_decryptions = noise_squashing_key_shares
+            .iter()
+            .map(|key_share| c.partial_decrypt(key_share).unwrap())
+            .collect::<Vec<_>>();
+        let clear: u8 = combine_unsigned(&partial_decryptions).unwrap();
+        assert_eq!(clear, 27u8.wrapping_mul(100).wrapping_add(27));
+
+        let d: FheInt16 = list.get(2).unwrap().unwrap();
+        let e = (-&d).squash_noise(&noise_squashing_key);
+        let partial_decryptions = noise_squashing_key_shares
+            .iter()
+            .map(|key_share| e.partial_decrypt(key_share).unwrap())
+            .collect::<Vec<_>>();
+        let clear: i16 = combine_signed(&partial_decryptions).unwrap();
+        assert_eq!(clear, 1234);
+    }
+
+    #[test]
+    fn distributed_key_generation_errors() {
+        assert!(trusted_dealer_preprocessing(PARAMETERS, 1).is_err());
+        assert!(trusted_dealer_preprocessing(PARAMETERS, PARAMETERS.max_num_parties + 1).is_err());
+        let small_key_parameters = DistributedKeygenParameters {
+            block_parameters: PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+            ..PARAMETERS
+        };
+        assert!(trusted_dealer_preprocessing(small_key_parameters, 2).is_err());
+
+        let mut preprocessing = trusted_dealer_preprocessing(PARAMETERS, 2).unwrap();
+        preprocessing[0].product_corrections.pop();
+        assert!(DkgParty::new(PARAMETERS, preprocessing[0].clone()).is_err());
+
+        let mut parties = new_parties(2);
+        let openings = parties
+            .iter()
+            .map(|party| party.opening().unwrap().clone())
+            .collect::<Vec<_>>();
+        assert!(parties[0].key_contribution().is_err());
+        assert!(parties[0].key_share().is_err());
+        assert!(parties[0].noise_squashing_key_share().is_err());
+        assert!(parties[0].receive_openings(&openings[..1]).is_err());
+        assert!(parties[0]
+            .receive_openings(&[openings[0].clone(), openings[0].clone()])
+            .is_err());
+        let mut truncated = openings[1].clone();
+        truncated.values.pop();
+        assert!(parties[0]
+            .receive_openings(&[openings[0].clone(), truncated])
+            .is_err());
+        parties[0].receive_openings(&openings).unwrap();
+        // The openings of a past round are rejected
+        assert!(parties[0].receive_openings(&openings).is_err());
+
+        let (_, contributions) = run_protocol(2);
+        assert!(combine_key_contributions(PARAMETERS, &contributions[..1]).is_err());
+        assert!(combine_key_contributions(
+            PARAMETERS,
+            &[contributions[0].clone(), contributions[0].clone()]
+        )
+        .is_err());
+    }
+}
@@ -2,7 +2,7 @@ use tfhe_versionable::Versionize;
 
 use super::inner::RadixCiphertext;
 use crate::backward_compatibility::integers::FheIntVersions;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{ConformanceError, ParameterSetConformant};
 use crate::high_level_api::global_state;
 use crate::high_level_api::integers::{FheUint, FheUintId, IntegerId};
 use crate::high_level_api::keys::InternalServerKey;
@@ -13,7 +13,9 @@ use crate::named::Named;
 use crate::prelude::CastFrom;
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::PBSParameters;
-use crate::{Device, FheBool, ServerKey, Tag};
+use crate::{
+    CompactPublicKey, Device, FheBool, ProxyReencryptionKey, SanitizationKey, ServerKey, Tag,
+};
 use std::marker::PhantomData;
 
 #[cfg(feature = "gpu")]
@@ -75,14 +77,21 @@ impl<Id: FheIntId> From<&ServerKey> for FheIntConformanceParams<Id> {
 impl<Id: FheIntId> ParameterSetConformant for FheInt<Id> {
     type ParameterSet = FheIntConformanceParams<Id>;
 
-    fn is_conformant(&self, params: &FheIntConformanceParams<Id>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &FheIntConformanceParams<Id>,
+    ) -> Result<(), ConformanceError> {
         let Self {
             ciphertext,
             id: _,
             tag: _,
         } = self;
 
-        ciphertext.on_cpu().is_conformant(&params.params)
+        ciphertext.on_cpu().check_conformance(&params.params)
     }
 }
 
@@ -646,6 +655,119 @@ where
         self.ciphertext.on_cpu().decrypt_trivial()
     }
 
+    /// Re-randomizes the ciphertext, making it unlinkable to the ciphertexts it was computed from.
+    ///
+    /// A fresh encryption of zero, produced with the `public_key`, is added to the ciphertext
+    /// which is then refreshed with a PBS. If the public key uses dedicated parameters, the
+    /// server key set must hold the corresponding casting key.
+    ///
+    /// An error is returned if the parameters or the tag of the public key do not match the
+    /// server key. Public keys of the same parameters are only told apart by their tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheInt16};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// let public_key = CompactPublicKey::new(&client_key);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    ///
+    /// let mut result = &a * &a;
+    /// result.rerandomize(&public_key).unwrap();
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 9i16);
+    /// ```
+    pub fn rerandomize(&mut self, public_key: &CompactPublicKey) -> crate::Result<()> {
+        public_key.rerandomize_blocks(&mut self.ciphertext.as_cpu_mut().blocks)
+    }
+
+    /// Sanitizes the ciphertext, the result encrypts the same value with a noise that does not
+    /// depend on the operations that produced it.
+    ///
+    /// The result is meant to be decrypted, its noise is too large for further computations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt16, SanitizationKey};
+    ///
+    /// let config =
+    ///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let (client_key, server_key) = generate_keys(config);
+    /// let sanitization_key = SanitizationKey::new(
+    ///     &client_key,
+    ///     SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt16::encrypt(-3i16, &client_key);
+    ///
+    /// let result = (&a * &a).sanitize(&sanitization_key);
+    ///
+    /// let decrypted: i16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 9i16);
+    /// ```
+    pub fn sanitize(&self, sanitization_key: &SanitizationKey) -> Self {
+        sanitization_key.with_cpu_server_key(|server_key, tag| {
+            let inner =
+                server_key.sanitize_parallelized(&*self.ciphertext.on_cpu(), &sanitization_key.key);
+            Self::new(inner, tag.clone())
+        })
+    }
+
+    /// Re-encrypts the ciphertext to the key of the compact public key of `reencryption_key`,
+    /// using the server key set for the current thread, which must be the one of the recipient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::proxy_reencryption::PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::{
+    ///     generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheInt16,
+    ///     ProxyReencryptionKey,
+    /// };
+    ///
+    /// let config =
+    ///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let (alice_client_key, _) = generate_keys(config);
+    /// let (bob_client_key, bob_server_key) = generate_keys(config);
+    /// let bob_public_key = CompactPublicKey::new(&bob_client_key);
+    ///
+    /// let reencryption_key = ProxyReencryptionKey::new(
+    ///     &alice_client_key,
+    ///     &bob_public_key,
+    ///     PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    /// set_server_key(bob_server_key);
+    ///
+    /// let a = FheInt16::encrypt(-1234i16, &alice_client_key);
+    ///
+    /// let result = a.reencrypt(&reencryption_key).unwrap();
+    ///
+    /// let decrypted: i16 = result.decrypt(&bob_client_key);
+    /// assert_eq!(decrypted, -1234i16);
+    /// ```
+    pub fn reencrypt(&self, reencryption_key: &ProxyReencryptionKey) -> crate::Result<Self> {
+        reencryption_key.with_cpu_server_key(|server_key, casting_mode| {
+            let inner = server_key.reencrypt_parallelized(
+                &*self.ciphertext.on_cpu(),
+                &reencryption_key.key,
+                casting_mode,
+            )?;
+            Ok(Self::new(inner, reencryption_key.tag.clone()))
+        })
+    }
+
     /// Reverse the bit of the signed integer
     ///
     /// # Example
@@ -3,7 +3,7 @@ use tfhe_versionable::Versionize;
 use crate::backward_compatibility::integers::{
     CompressedFheIntVersions, CompressedSignedRadixCiphertextVersions,
 };
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{ConformanceError, ParameterSetConformant};
 use crate::core_crypto::prelude::SignedNumeric;
 use crate::high_level_api::global_state::with_cpu_internal_keys;
 use crate::high_level_api::integers::signed::base::FheIntConformanceParams;
@@ -138,14 +138,21 @@ where
 impl<Id: FheIntId> ParameterSetConformant for CompressedFheInt<Id> {
     type ParameterSet = FheIntConformanceParams<Id>;
 
-    fn is_conformant(&self, params: &FheIntConformanceParams<Id>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &FheIntConformanceParams<Id>,
+    ) -> Result<(), ConformanceError> {
         let Self {
             ciphertext,
             id: _,
             tag: _,
         } = self;
 
-        ciphertext.is_conformant(&params.params)
+        ciphertext.check_conformance(&params.params)
     }
 }
 
@@ -162,10 +169,17 @@ pub enum CompressedSignedRadixCiphertext {
 
 impl ParameterSetConformant for CompressedSignedRadixCiphertext {
     type ParameterSet = RadixCiphertextConformanceParams;
-    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &RadixCiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         match self {
-            Self::Seeded(ct) => ct.is_conformant(params),
-            Self::ModulusSwitched(ct) => ct.is_conformant(params),
+            Self::Seeded(ct) => ct.check_conformance(params),
+            Self::ModulusSwitched(ct) => ct.check_conformance(params),
         }
     }
 }
@@ -3,7 +3,7 @@ use tfhe_versionable::Versionize;
 use crate::backward_compatibility::integers::{
     CompressedFheUintVersions, CompressedRadixCiphertextVersions,
 };
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{ConformanceError, ParameterSetConformant};
 use crate::core_crypto::prelude::UnsignedNumeric;
 use crate::high_level_api::global_state::with_cpu_internal_keys;
 use crate::high_level_api::integers::unsigned::base::{
@@ -140,14 +140,21 @@ where
 impl<Id: FheUintId> ParameterSetConformant for CompressedFheUint<Id> {
     type ParameterSet = FheUintConformanceParams<Id>;
 
-    fn is_conformant(&self, params: &FheUintConformanceParams<Id>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &FheUintConformanceParams<Id>,
+    ) -> Result<(), ConformanceError> {
         let Self {
             ciphertext,
             id: _,
             tag: _,
         } = self;
 
-        ciphertext.is_conformant(&params.params)
+        ciphertext.check_conformance(&params.params)
     }
 }
 
@@ -164,10 +171,17 @@ pub enum CompressedRadixCiphertext {
 
 impl ParameterSetConformant for CompressedRadixCiphertext {
     type ParameterSet = RadixCiphertextConformanceParams;
-    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &RadixCiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         match self {
-            Self::Seeded(ct) => ct.is_conformant(params),
-            Self::ModulusSwitched(ct) => ct.is_conformant(params),
+            Self::Seeded(ct) => ct.check_conformance(params),
+            Self::ModulusSwitched(ct) => ct.check_conformance(params),
         }
     }
 }
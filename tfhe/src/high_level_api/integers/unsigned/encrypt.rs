@@ -7,7 +7,13 @@ use crate::high_level_api::keys::InternalServerKey;
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
 #[cfg(feature = "gpu")]
 use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+#[cfg(feature = "zk-pok")]
+use crate::integer::RadixDecryptionProof;
 use crate::prelude::{FheDecrypt, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt};
+#[cfg(feature = "zk-pok")]
+use crate::zk::{DecryptionPublicParams, ZkComputeLoad, ZkVerificationOutCome};
+#[cfg(feature = "zk-pok")]
+use crate::CompactPublicKey;
 use crate::{ClientKey, CompressedPublicKey, FheUint, PublicKey};
 
 impl<Id, ClearType> FheDecrypt<ClearType> for FheUint<Id>
@@ -42,6 +48,92 @@ where
     }
 }
 
+#[cfg(feature = "zk-pok")]
+impl<Id> FheUint<Id>
+where
+    Id: FheUintId,
+{
+    /// Decrypts a [FheUint] and proves that the returned value is its decryption.
+    ///
+    /// The proof is made against the [CompactPublicKey] of the client key, which must not use
+    /// dedicated compact public key parameters. Anyone knowing the public key can then check the
+    /// decryption with [Self::verify_decryption].
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::zk::{DecryptionCrs, ZkComputeLoad};
+    /// use tfhe::{generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    /// let public_key = CompactPublicKey::new(&client_key);
+    /// let crs = DecryptionCrs::from_config(config).unwrap();
+    /// let metadata = [b'h', b'l', b'a', b'p', b'i'];
+    ///
+    /// let a = FheUint8::encrypt(100u8, &client_key);
+    /// let b = FheUint8::encrypt(27u8, &client_key);
+    /// let sum = a + b;
+    ///
+    /// let (decrypted, proof): (u8, _) = sum
+    ///     .decrypt_with_proof(
+    ///         &client_key,
+    ///         &public_key,
+    ///         crs.public_params(),
+    ///         &metadata,
+    ///         ZkComputeLoad::Proof,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(decrypted, 127u8);
+    ///
+    /// assert!(sum
+    ///     .verify_decryption(decrypted, &proof, crs.public_params(), &public_key, &metadata)
+    ///     .is_valid());
+    /// ```
+    pub fn decrypt_with_proof<Clear>(
+        &self,
+        key: &ClientKey,
+        public_key: &CompactPublicKey,
+        public_params: &DecryptionPublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+    ) -> crate::Result<(Clear, RadixDecryptionProof)>
+    where
+        Clear: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        key.key.key.decrypt_radix_with_proof(
+            &self.ciphertext.on_cpu(),
+            &public_key.key,
+            public_params,
+            metadata,
+            load,
+        )
+    }
+
+    /// Verifies that `clear` is the decryption of this [FheUint] with the proof returned by
+    /// [Self::decrypt_with_proof].
+    pub fn verify_decryption<Clear>(
+        &self,
+        clear: Clear,
+        proof: &RadixDecryptionProof,
+        public_params: &DecryptionPublicParams,
+        public_key: &CompactPublicKey,
+        metadata: &[u8],
+    ) -> ZkVerificationOutCome
+    where
+        Clear: DecomposableInto<u64> + UnsignedNumeric,
+    {
+        self.ciphertext.on_cpu().verify_decryption(
+            clear,
+            proof,
+            public_params,
+            &public_key.key,
+            metadata,
+        )
+    }
+}
+
 impl<Id, T> FheTryEncrypt<T, ClientKey> for FheUint<Id>
 where
     Id: FheUintId,
@@ -538,6 +538,67 @@ fn test_cpk_encrypt_cast_compute_hl() {
     assert_eq!(clear, (input_msg * multiplier) % modulus);
 }
 
+#[test]
+fn test_rerandomize_with_dedicated_cpk_hl() {
+    let param_pke_only = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    let param_fhe = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    let param_ksk = PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+    let (client_key, server_key) = generate_keys(
+        ConfigBuilder::with_custom_parameters(param_fhe)
+            .use_dedicated_compact_public_key_parameters((param_pke_only, param_ksk)),
+    );
+    set_server_key(server_key);
+
+    let pk = CompactPublicKey::new(&client_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u8>();
+    let clear_b = rng.gen::<i16>();
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let mut sum = &a + &a;
+    let (original, _, _) = sum.clone().into_raw_parts();
+    sum.rerandomize(&pk).unwrap();
+    let (rerandomized, _, _) = sum.clone().into_raw_parts();
+    assert_ne!(rerandomized, original);
+
+    let clear: u8 = (&sum + &a).decrypt(&client_key);
+    assert_eq!(clear, clear_a.wrapping_mul(3));
+
+    let mut b = FheInt16::encrypt(clear_b, &client_key);
+    b.rerandomize(&pk).unwrap();
+    let clear: i16 = b.decrypt(&client_key);
+    assert_eq!(clear, clear_b);
+
+    let mut c = a.eq(clear_a);
+    c.rerandomize(&pk).unwrap();
+    assert!(c.decrypt(&client_key));
+}
+
+#[test]
+fn test_rerandomize_with_mismatched_cpk_hl() {
+    let config = ConfigBuilder::default().build();
+
+    let mut client_key = ClientKey::generate(config);
+    client_key.tag_mut().set_u64(1);
+    set_server_key(crate::ServerKey::new(&client_key));
+
+    let mut other_client_key = ClientKey::generate(config);
+    other_client_key.tag_mut().set_u64(2);
+    let other_pk = CompactPublicKey::new(&other_client_key);
+
+    let mut a = FheUint8::encrypt(42u8, &client_key);
+    assert!(a.rerandomize(&other_pk).is_err());
+    let clear: u8 = a.decrypt(&client_key);
+    assert_eq!(clear, 42);
+
+    let pk = CompactPublicKey::new(&client_key);
+    a.rerandomize(&pk).unwrap();
+    let clear: u8 = a.decrypt(&client_key);
+    assert_eq!(clear, 42);
+}
+
 #[test]
 fn test_compressed_cpk_encrypt_cast_compute_hl() {
     let param_pke_only = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
@@ -590,3 +651,171 @@ fn test_compressed_cpk_encrypt_cast_compute_hl() {
     let clear: u64 = mul.decrypt(&client_key);
     assert_eq!(clear, (input_msg * multiplier) % modulus);
 }
+
+#[test]
+fn test_proxy_reencryption_hl() {
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::proxy_reencryption::PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::{CompressedCiphertextListBuilder, ProxyReencryptionKey};
+
+    let param_pke_only = PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    let param_fhe = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    let param_ksk = PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+    let config = ConfigBuilder::with_custom_parameters(param_fhe)
+        .use_dedicated_compact_public_key_parameters((param_pke_only, param_ksk))
+        .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+        .build();
+
+    let mut alice_client_key = ClientKey::generate(config);
+    alice_client_key.tag_mut().set_u64(1);
+    let alice_server_key = alice_client_key.generate_server_key();
+    let alice_public_key = CompactPublicKey::new(&alice_client_key);
+
+    let mut bob_client_key = ClientKey::generate(config);
+    bob_client_key.tag_mut().set_u64(2);
+    let bob_server_key = bob_client_key.generate_server_key();
+    let bob_public_key = CompactPublicKey::new(&bob_client_key);
+
+    let reencryption_key = ProxyReencryptionKey::new(
+        &alice_client_key,
+        &bob_public_key,
+        PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    );
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u8>();
+    let clear_b = rng.gen::<i16>();
+
+    set_server_key(alice_server_key);
+    let a = FheUint8::encrypt(clear_a, &alice_client_key);
+    let sum = &a + &a;
+    let b = FheInt16::encrypt(clear_b, &alice_client_key);
+    let c = a.eq(clear_a);
+    let compressed_list = CompressedCiphertextListBuilder::new()
+        .push(a.clone())
+        .push(b.clone())
+        .build()
+        .unwrap();
+    let compact_list = CompactCiphertextList::builder(&alice_public_key)
+        .push(clear_a)
+        .push(clear_b)
+        .build_packed();
+
+    set_server_key(bob_server_key);
+
+    let sum = sum.reencrypt(&reencryption_key).unwrap();
+    assert_eq!(sum.tag(), bob_client_key.tag());
+    let clear: u8 = (&sum + &a.reencrypt(&reencryption_key).unwrap()).decrypt(&bob_client_key);
+    assert_eq!(clear, clear_a.wrapping_mul(3));
+
+    let clear: i16 = b
+        .reencrypt(&reencryption_key)
+        .unwrap()
+        .decrypt(&bob_client_key);
+    assert_eq!(clear, clear_b);
+
+    assert!(c
+        .reencrypt(&reencryption_key)
+        .unwrap()
+        .decrypt(&bob_client_key));
+
+    for expander in [
+        compact_list.reencrypt(&reencryption_key).unwrap(),
+        compressed_list.reencrypt(&reencryption_key).unwrap(),
+    ] {
+        let a: FheUint8 = expander.get(0).unwrap().unwrap();
+        let b: FheInt16 = expander.get(1).unwrap().unwrap();
+        assert_eq!(a.tag(), bob_client_key.tag());
+
+        let clear: u8 = a.decrypt(&bob_client_key);
+        assert_eq!(clear, clear_a);
+        let clear: i16 = b.decrypt(&bob_client_key);
+        assert_eq!(clear, clear_b);
+    }
+}
+
+#[test]
+fn test_key_rotation_hl() {
+    use crate::shortint::parameters::key_switching::ShortintKeySwitchingParameters;
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::{CompressedCiphertextListBuilder, FheBool, KeyRotationKey, ServerKey};
+
+    let old_config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+        .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+        .build();
+    let mut old_client_key = ClientKey::generate(old_config);
+    old_client_key.tag_mut().set_u64(42);
+    let old_server_key = ServerKey::new(&old_client_key);
+
+    let new_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    let new_config = ConfigBuilder::with_custom_parameters(new_params)
+        .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+        .build();
+    let mut new_client_key = ClientKey::generate(new_config);
+    new_client_key.tag_mut().set_u64(42);
+    let new_server_key = ServerKey::new(&new_client_key);
+
+    let rotation_key = KeyRotationKey::new(
+        (&old_client_key, &old_server_key),
+        (&new_client_key, &new_server_key),
+        ShortintKeySwitchingParameters::new(
+            new_params.ks_base_log,
+            new_params.ks_level,
+            EncryptionKeyChoice::Small,
+        ),
+    )
+    .unwrap();
+
+    let mut rng = rand::thread_rng();
+    let clears_a = [rng.gen::<u8>(), rng.gen::<u8>(), rng.gen::<u8>()];
+    let clear_b = rng.gen::<i16>();
+
+    set_server_key(old_server_key);
+    let a = clears_a
+        .iter()
+        .map(|&clear| FheUint8::encrypt(clear, &old_client_key))
+        .collect::<Vec<_>>();
+    let b = FheInt16::encrypt(clear_b, &old_client_key);
+    let c = a[0].eq(clears_a[0]);
+    let lists = (0..2)
+        .map(|i| {
+            CompressedCiphertextListBuilder::new()
+                .push(a[i].clone())
+                .push(b.clone())
+                .push(c.clone())
+                .build()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let rotated_a = rotation_key.rotate_all(&a);
+    let rotated_b = rotation_key.keyswitch(&b);
+    let rotated_c = rotation_key.keyswitch(&c);
+    let rotated_lists = rotation_key.rotate_compressed_lists(&lists).unwrap();
+
+    set_server_key(new_server_key);
+
+    for (rotated, clear) in rotated_a.iter().zip(clears_a) {
+        assert_eq!(rotated.tag(), a[0].tag());
+        let decrypted: u8 = (rotated + 1u8).decrypt(&new_client_key);
+        assert_eq!(decrypted, clear.wrapping_add(1));
+    }
+    let decrypted: i16 = rotated_b.decrypt(&new_client_key);
+    assert_eq!(decrypted, clear_b);
+    assert!(rotated_c.decrypt(&new_client_key));
+
+    for (i, list) in rotated_lists.iter().enumerate() {
+        assert_eq!(list.tag(), lists[i].tag());
+
+        let a: FheUint8 = list.get(0).unwrap().unwrap();
+        let b: FheInt16 = list.get(1).unwrap().unwrap();
+        let c: FheBool = list.get(2).unwrap().unwrap();
+
+        let decrypted: u8 = a.decrypt(&new_client_key);
+        assert_eq!(decrypted, clears_a[i]);
+        let decrypted: i16 = b.decrypt(&new_client_key);
+        assert_eq!(decrypted, clear_b);
+        assert!(c.decrypt(&new_client_key));
+    }
+}
@@ -0,0 +1,841 @@
+//! This module defines the [KeyBundle], a self-describing container for the keys of a key set.
+//!
+//! The different parts of a key set (client key, server key, public key, CRS) are usually
+//! shipped to different parties. Serializing them separately loses the information that they
+//! were generated together. A [KeyBundle] keeps this information:
+//!
+//! - a [KeySetId] identifying the key set,
+//! - the name of the parameter set used to generate the keys,
+//! - the [Tag] of the keys,
+//! - a [ContentHash] of the client key and of each bundled part, the hashes of the public parts
+//!   being bound to the hash of the client key.
+//!
+//! A bundle can be exported selectively (e.g. only its public parts, see
+//! [KeyBundleSelection::PUBLIC_ONLY]), the hashes of the exported parts remain verifiable.
+//!
+//! The hashes are not keyed, anyone building a bundle can compute them, so they only detect
+//! accidental mixes and modifications of the parts. When the client key is part of the bundle,
+//! the compact public key is also checked to be generated from it, and the dimensions of the
+//! server key are checked against it. Checking that the server key was generated from the client
+//! key requires running bootstraps, it is only done by [KeyBundle::check_key_links]. A bundle
+//! holding only public parts carries no guarantee that these parts were generated from the same
+//! client key.
+//!
+//! The bundle is meant to be serialized with
+//! [safe_serialize_versioned](crate::safe_serialize_versioned) and loaded with
+//! [safe_deserialize_conformant_versioned](crate::safe_deserialization::safe_deserialize_conformant_versioned)
+//! which checks the integrity of the bundle and its conformance with a [Config].
+use tfhe_versionable::Versionize;
+
+use super::{ClientKey, CompactPublicKey, CompressedServerKey};
+use crate::backward_compatibility::keys::{
+    ContentHashVersions, KeyBundleVersions, KeySetIdVersions,
+};
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
+use crate::high_level_api::config::Config;
+use crate::named::Named;
+use crate::prelude::Tagged;
+#[cfg(feature = "zk-pok")]
+use crate::zk::{
+    CanonicalDeserialize, CanonicalSerialize, CompactPkePublicParams, Compress, Validate,
+};
+use crate::{Error, Tag};
+
+const CONTENT_HASH_LEN: usize = 32;
+
+// Domain separators, so that hashes of different parts can never be confused
+const CLIENT_KEY_DOMAIN: &[u8] = b"TFHE_KeyBundle_ClientKey";
+const KEY_SET_ID_DOMAIN: &[u8] = b"TFHE_KeyBundle_KeySetId";
+const COMPRESSED_SERVER_KEY_DOMAIN: &[u8] = b"TFHE_KeyBundle_CompressedServerKey";
+const COMPACT_PUBLIC_KEY_DOMAIN: &[u8] = b"TFHE_KeyBundle_CompactPublicKey";
+const CRS_DOMAIN: &[u8] = b"TFHE_KeyBundle_Crs";
+
+/// Identifier of a key set
+///
+/// It is derived from the client key, so all the parts generated from the same client key share
+/// the same id.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, Versionize,
+)]
+#[versionize(KeySetIdVersions)]
+pub struct KeySetId(pub u128);
+
+/// SHA3 based hash of the content of a part of a [KeyBundle]
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, Versionize,
+)]
+#[versionize(ContentHashVersions)]
+pub struct ContentHash(pub [u8; CONTENT_HASH_LEN]);
+
+impl ContentHash {
+    fn compute(parts: &[&[u8]]) -> Self {
+        use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+        let mut hasher = sha3::Shake256::default();
+        for part in parts {
+            // Prefix each part by its length so that the concatenation is not ambiguous
+            hasher.update(&(part.len() as u64).to_le_bytes());
+            hasher.update(part);
+        }
+
+        let mut reader = hasher.finalize_xof();
+        let mut hash = [0u8; CONTENT_HASH_LEN];
+        reader.read(&mut hash);
+        Self(hash)
+    }
+
+    fn of_versionized<T: Versionize>(
+        domain: &[u8],
+        parts: &[&[u8]],
+        value: &T,
+    ) -> crate::Result<Self> {
+        let bytes =
+            bincode::serialize(&value.versionize()).map_err(|err| Error::new(err.to_string()))?;
+        let mut all_parts = Vec::with_capacity(parts.len() + 2);
+        all_parts.push(domain);
+        all_parts.extend_from_slice(parts);
+        all_parts.push(bytes.as_slice());
+        Ok(Self::compute(&all_parts))
+    }
+}
+
+impl KeySetId {
+    fn from_client_key_hash(client_key_hash: &ContentHash) -> Self {
+        let hash = ContentHash::compute(&[KEY_SET_ID_DOMAIN, &client_key_hash.0]);
+        let mut id = [0u8; 16];
+        id.copy_from_slice(&hash.0[..16]);
+        Self(u128::from_le_bytes(id))
+    }
+}
+
+/// Selects the parts of a [KeyBundle] to export, see [KeyBundle::export]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyBundleSelection {
+    pub client_key: bool,
+    pub compressed_server_key: bool,
+    pub compact_public_key: bool,
+    pub crs: bool,
+}
+
+impl KeyBundleSelection {
+    /// Selects every part of the bundle
+    pub const ALL: Self = Self {
+        client_key: true,
+        compressed_server_key: true,
+        compact_public_key: true,
+        crs: true,
+    };
+
+    /// Selects every part of the bundle except the client key
+    pub const PUBLIC_ONLY: Self = Self {
+        client_key: false,
+        compressed_server_key: true,
+        compact_public_key: true,
+        crs: true,
+    };
+}
+
+/// A versioned, self-describing container for the keys of a key set
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::safe_deserialization::safe_deserialize_conformant_versioned;
+/// use tfhe::{
+///     safe_serialize_versioned, ClientKey, CompactPublicKey, CompressedServerKey, ConfigBuilder,
+///     KeyBundle, KeyBundleSelection,
+/// };
+///
+/// let config = ConfigBuilder::default().build();
+/// let client_key = ClientKey::generate(config);
+/// let compressed_server_key = CompressedServerKey::new(&client_key);
+/// let public_key = CompactPublicKey::new(&client_key);
+///
+/// let bundle = KeyBundle::new("PARAM_MESSAGE_2_CARRY_2_KS_PBS", client_key)
+///     .unwrap()
+///     .with_compressed_server_key(compressed_server_key)
+///     .unwrap()
+///     .with_compact_public_key(public_key)
+///     .unwrap();
+///
+/// // Only ship the public parts to the server
+/// let public_bundle = bundle.export(KeyBundleSelection::PUBLIC_ONLY);
+/// assert!(public_bundle.client_key().is_none());
+///
+/// let mut buffer = vec![];
+/// safe_serialize_versioned(&public_bundle, &mut buffer, 1 << 30).unwrap();
+///
+/// let loaded: KeyBundle =
+///     safe_deserialize_conformant_versioned(buffer.as_slice(), 1 << 30, &config).unwrap();
+/// assert_eq!(loaded.key_set_id(), bundle.key_set_id());
+/// ```
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(KeyBundleVersions)]
+pub struct KeyBundle {
+    key_set_id: KeySetId,
+    parameter_set_name: String,
+    tag: Tag,
+    client_key_hash: ContentHash,
+    client_key: Option<ClientKey>,
+    compressed_server_key: Option<(CompressedServerKey, ContentHash)>,
+    compact_public_key: Option<(CompactPublicKey, ContentHash)>,
+    // The CRS is stored in its compressed canonical serialization form
+    // so that the format of the bundle does not depend on the `zk-pok` feature
+    crs: Option<(Vec<u8>, ContentHash)>,
+}
+
+impl KeyBundle {
+    /// Creates a new bundle for the key set of the given client key
+    ///
+    /// `parameter_set_name` is a free form name describing the parameters of the key set,
+    /// e.g. the name of the parameter constant.
+    ///
+    /// Returns an error if the client key could not be serialized to be hashed.
+    pub fn new(
+        parameter_set_name: impl Into<String>,
+        client_key: ClientKey,
+    ) -> crate::Result<Self> {
+        let client_key_hash = ContentHash::of_versionized(CLIENT_KEY_DOMAIN, &[], &client_key)?;
+
+        Ok(Self {
+            key_set_id: KeySetId::from_client_key_hash(&client_key_hash),
+            parameter_set_name: parameter_set_name.into(),
+            tag: client_key.tag.clone(),
+            client_key_hash,
+            client_key: Some(client_key),
+            compressed_server_key: None,
+            compact_public_key: None,
+            crs: None,
+        })
+    }
+
+    /// Adds a compressed server key to the bundle
+    ///
+    /// Returns an error if the tag of the key does not match the tag of the bundle, or if the
+    /// bundle holds the client key and the dimensions of the server key do not match it. Use
+    /// [Self::check_key_links] to check that the server key was generated from the client key.
+    pub fn with_compressed_server_key(mut self, key: CompressedServerKey) -> crate::Result<Self> {
+        self.check_tag(key.tag(), "CompressedServerKey")?;
+        if let Some(client_key) = &self.client_key {
+            check_compressed_server_key_dimensions(client_key, &key)?;
+        }
+        let hash = self.part_hash(COMPRESSED_SERVER_KEY_DOMAIN, &key)?;
+        self.compressed_server_key = Some((key, hash));
+        Ok(self)
+    }
+
+    /// Adds a compact public key to the bundle
+    ///
+    /// Returns an error if the tag of the key does not match the tag of the bundle, or if the
+    /// bundle holds the client key and the public key was not generated from it.
+    pub fn with_compact_public_key(mut self, key: CompactPublicKey) -> crate::Result<Self> {
+        self.check_tag(key.tag(), "CompactPublicKey")?;
+        if let Some(client_key) = &self.client_key {
+            check_compact_public_key_link(client_key, &key)?;
+        }
+        let hash = self.part_hash(COMPACT_PUBLIC_KEY_DOMAIN, &key)?;
+        self.compact_public_key = Some((key, hash));
+        Ok(self)
+    }
+
+    /// Adds the public parameters of a CRS to the bundle
+    #[cfg(feature = "zk-pok")]
+    pub fn with_crs_public_params(
+        mut self,
+        public_params: &CompactPkePublicParams,
+    ) -> crate::Result<Self> {
+        let mut bytes = vec![];
+        public_params
+            .serialize_with_mode(&mut bytes, Compress::Yes)
+            .map_err(|err| Error::new(err.to_string()))?;
+        let hash = self.part_hash(CRS_DOMAIN, &bytes)?;
+        self.crs = Some((bytes, hash));
+        Ok(self)
+    }
+
+    /// Returns a new bundle containing only the selected parts
+    ///
+    /// The identifiers and hashes of the exported bundle are the same as the ones of `self`.
+    pub fn export(&self, selection: KeyBundleSelection) -> Self {
+        Self {
+            key_set_id: self.key_set_id,
+            parameter_set_name: self.parameter_set_name.clone(),
+            tag: self.tag.clone(),
+            client_key_hash: self.client_key_hash,
+            client_key: self.client_key.clone().filter(|_| selection.client_key),
+            compressed_server_key: self
+                .compressed_server_key
+                .clone()
+                .filter(|_| selection.compressed_server_key),
+            compact_public_key: self
+                .compact_public_key
+                .clone()
+                .filter(|_| selection.compact_public_key),
+            crs: self.crs.clone().filter(|_| selection.crs),
+        }
+    }
+
+    pub fn key_set_id(&self) -> KeySetId {
+        self.key_set_id
+    }
+
+    pub fn parameter_set_name(&self) -> &str {
+        &self.parameter_set_name
+    }
+
+    /// Returns the hash of the client key this bundle was created from
+    ///
+    /// It is available even if the client key is not part of the bundle.
+    pub fn client_key_hash(&self) -> ContentHash {
+        self.client_key_hash
+    }
+
+    pub fn client_key(&self) -> Option<&ClientKey> {
+        self.client_key.as_ref()
+    }
+
+    pub fn compressed_server_key(&self) -> Option<&CompressedServerKey> {
+        self.compressed_server_key.as_ref().map(|(key, _)| key)
+    }
+
+    pub fn compact_public_key(&self) -> Option<&CompactPublicKey> {
+        self.compact_public_key.as_ref().map(|(key, _)| key)
+    }
+
+    /// Returns the public params of the CRS stored in the bundle, if any
+    #[cfg(feature = "zk-pok")]
+    pub fn crs_public_params(&self) -> crate::Result<Option<CompactPkePublicParams>> {
+        self.crs
+            .as_ref()
+            .map(|(bytes, _)| {
+                CompactPkePublicParams::deserialize_with_mode(
+                    bytes.as_slice(),
+                    Compress::Yes,
+                    Validate::Yes,
+                )
+                .map_err(|err| Error::new(err.to_string()))
+            })
+            .transpose()
+    }
+
+    /// Checks that the content of the bundle matches its identifiers and hashes
+    ///
+    /// This detects parts that were modified or that come from another key set. As the hashes
+    /// are not keyed, this does not authenticate the origin of the bundle.
+    ///
+    /// If the bundle holds the client key, the compact public key is also checked to be generated
+    /// from it and the dimensions of the server key are checked against it. These checks are
+    /// cheap, see [Self::check_key_links] for the complete check of the server key.
+    pub fn check_integrity(&self) -> crate::Result<()> {
+        if KeySetId::from_client_key_hash(&self.client_key_hash) != self.key_set_id {
+            return Err(Error::new(
+                "KeyBundle key set id does not match the client key hash".to_string(),
+            ));
+        }
+
+        if let Some(client_key) = &self.client_key {
+            self.check_tag(client_key.tag(), "ClientKey")?;
+            let hash = ContentHash::of_versionized(CLIENT_KEY_DOMAIN, &[], client_key)?;
+            if hash != self.client_key_hash {
+                return Err(Error::new(
+                    "KeyBundle client key does not match its hash".to_string(),
+                ));
+            }
+        }
+
+        if let Some((key, expected_hash)) = &self.compressed_server_key {
+            self.check_tag(key.tag(), "CompressedServerKey")?;
+            self.check_part_hash(
+                COMPRESSED_SERVER_KEY_DOMAIN,
+                key,
+                expected_hash,
+                "CompressedServerKey",
+            )?;
+        }
+
+        if let Some((key, expected_hash)) = &self.compact_public_key {
+            self.check_tag(key.tag(), "CompactPublicKey")?;
+            self.check_part_hash(
+                COMPACT_PUBLIC_KEY_DOMAIN,
+                key,
+                expected_hash,
+                "CompactPublicKey",
+            )?;
+        }
+
+        if let Some((bytes, expected_hash)) = &self.crs {
+            self.check_part_hash(CRS_DOMAIN, bytes, expected_hash, "CRS")?;
+        }
+
+        if let Some(client_key) = &self.client_key {
+            if let Some((key, _)) = &self.compressed_server_key {
+                check_compressed_server_key_dimensions(client_key, key)?;
+            }
+            if let Some((key, _)) = &self.compact_public_key {
+                check_compact_public_key_link(client_key, key)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the public keys of the bundle were generated from its client key
+    ///
+    /// On top of the checks of [Self::check_integrity], the server key is decompressed and used to
+    /// bootstrap every value of the plaintext space encrypted with the client key. This is
+    /// expensive and is not done when the bundle is loaded or built.
+    ///
+    /// Returns an error if the bundle does not hold the client key.
+    pub fn check_key_links(&self) -> crate::Result<()> {
+        let Some(client_key) = &self.client_key else {
+            return Err(Error::new(
+                "KeyBundle key links can only be checked if the bundle holds the client key"
+                    .to_string(),
+            ));
+        };
+
+        self.check_integrity()?;
+
+        if let Some((key, _)) = &self.compressed_server_key {
+            check_compressed_server_key_link(client_key, key)?;
+        }
+
+        Ok(())
+    }
+
+    fn part_hash<T: Versionize>(&self, domain: &[u8], part: &T) -> crate::Result<ContentHash> {
+        // Binding the key set id and the client key hash in the hash of each public part links
+        // the part to the client key it was generated from
+        ContentHash::of_versionized(
+            domain,
+            &[&self.key_set_id.0.to_le_bytes(), &self.client_key_hash.0],
+            part,
+        )
+    }
+
+    fn check_part_hash<T: Versionize>(
+        &self,
+        domain: &[u8],
+        part: &T,
+        expected_hash: &ContentHash,
+        part_name: &str,
+    ) -> crate::Result<()> {
+        if self.part_hash(domain, part)? == *expected_hash {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "KeyBundle {part_name} does not match its hash"
+            )))
+        }
+    }
+
+    fn check_tag(&self, tag: &Tag, part_name: &str) -> crate::Result<()> {
+        if *tag == self.tag {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "KeyBundle {part_name} tag does not match the tag of the bundle"
+            )))
+        }
+    }
+}
+
+impl Tagged for KeyBundle {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl Named for KeyBundle {
+    const NAME: &'static str = "high_level_api::KeyBundle";
+}
+
+impl ParameterSetConformant for KeyBundle {
+    type ParameterSet = Config;
+
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(&self, config: &Config) -> Result<(), ConformanceError> {
+        self.check_integrity()
+            .map_err(|err| ConformanceError::invalid("integrity", err.to_string()))?;
+
+        if let Some(client_key) = self.client_key.as_ref() {
+            check_client_key_conformance(client_key, config)
+                .map_err(|err| err.in_field("client_key"))?;
+        }
+
+        if let Some((server_key, _)) = self.compressed_server_key.as_ref() {
+            check_compressed_server_key_conformance(server_key, config)
+                .map_err(|err| err.in_field("compressed_server_key"))?;
+        }
+
+        if let Some((public_key, _)) = self.compact_public_key.as_ref() {
+            let params = config
+                .inner
+                .public_key_encryption_parameters()
+                .map_err(|err| ConformanceError::invalid("compact_public_key", err.to_string()))?;
+            check_field("parameters", &params, &public_key.key.key.key.parameters)
+                .map_err(|err| err.in_field("compact_public_key"))?;
+        }
+
+        #[cfg(feature = "zk-pok")]
+        if let Some(public_params) = self
+            .crs_public_params()
+            .map_err(|err| ConformanceError::invalid("crs", err.to_string()))?
+        {
+            check_crs_conformance(&public_params, config).map_err(|err| err.in_field("crs"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that the public params of the CRS can prove encryptions with the compact public key
+/// parameters of the config
+///
+/// The maximum number of messages of a proof is not part of the parameters and is not checked.
+#[cfg(feature = "zk-pok")]
+fn check_crs_conformance(
+    public_params: &CompactPkePublicParams,
+    config: &Config,
+) -> Result<(), ConformanceError> {
+    let params = config
+        .inner
+        .public_key_encryption_parameters()
+        .map_err(|err| ConformanceError::invalid("parameters", err.to_string()))?;
+
+    // Same computation as in CompactPkeCrs::from_shortint_params, the plaintext modulus takes
+    // into account the bit of padding
+    let plaintext_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64 * 2;
+    let (lwe_dimension, _, noise_bound, q, plaintext_modulus) =
+        crate::zk::CompactPkeCrs::prepare_crs_parameters(
+            params.encryption_lwe_dimension,
+            public_params.k,
+            params.encryption_noise_distribution,
+            params.ciphertext_modulus,
+            plaintext_modulus,
+        )
+        .map_err(|err| ConformanceError::invalid("parameters", err.to_string()))?;
+
+    check_field("lwe_dimension", &lwe_dimension.0, &public_params.d)?;
+    check_field("noise_bound", &noise_bound, &public_params.b)?;
+    check_field("ciphertext_modulus", &q, &public_params.q)?;
+    check_field("plaintext_modulus", &plaintext_modulus, &public_params.t)
+}
+
+/// Checks that the compact public key was generated from the client key
+///
+/// The public key is an encryption of zero under the secret key, its body minus the product of
+/// its mask with the secret key is its noise, which must be small enough to decrypt. For a key
+/// generated from another secret key, this difference is uniform and every one of its
+/// coefficients would have to be small by chance.
+fn check_compact_public_key_link(
+    client_key: &ClientKey,
+    public_key: &CompactPublicKey,
+) -> crate::Result<()> {
+    use crate::core_crypto::algorithms::slice_algorithms::slice_semi_reverse_negacyclic_convolution;
+
+    let private_key: crate::integer::CompactPrivateKey<&[u64]> =
+        match &client_key.key.dedicated_compact_private_key {
+            Some((private_key, _)) => private_key.into(),
+            None => (&client_key.key.key).try_into()?,
+        };
+    let public_key = &public_key.key.key.key;
+
+    if private_key.key().parameters() != public_key.parameters {
+        return Err(Error::new(
+            "KeyBundle CompactPublicKey parameters do not match the client key".to_string(),
+        ));
+    }
+
+    let params = public_key.parameters;
+    let full_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64;
+    let delta = (1u64 << 63) / full_modulus;
+
+    let (mask, body) = public_key.key.get_mask_and_body();
+    let mut noise = vec![0u64; body.as_ref().len()];
+    slice_semi_reverse_negacyclic_convolution(
+        &mut noise,
+        mask.as_ref(),
+        private_key.key().key().as_ref(),
+    );
+
+    let is_linked = noise.iter().zip(body.as_ref()).all(|(product, body)| {
+        let noise = body.wrapping_sub(*product) as i64;
+        noise.unsigned_abs() < delta / 2
+    });
+
+    if is_linked {
+        Ok(())
+    } else {
+        Err(Error::new(
+            "KeyBundle CompactPublicKey was not generated from the client key".to_string(),
+        ))
+    }
+}
+
+/// Checks that the dimensions of the compressed server key match the ones of the client key
+fn check_compressed_server_key_dimensions(
+    client_key: &ClientKey,
+    server_key: &CompressedServerKey,
+) -> crate::Result<()> {
+    let client_key = &client_key.key.key.key;
+    let server_key = &server_key.integer_key.key.key;
+
+    let large_dimension = client_key.large_lwe_secret_key().lwe_dimension();
+    let small_dimension = client_key.small_lwe_secret_key().lwe_dimension();
+    if server_key.message_modulus != client_key.parameters.message_modulus()
+        || server_key.carry_modulus != client_key.parameters.carry_modulus()
+        || server_key.key_switching_key.input_key_lwe_dimension() != large_dimension
+        || server_key.key_switching_key.output_key_lwe_dimension() != small_dimension
+        || server_key.bootstrapping_key.input_lwe_dimension() != small_dimension
+        || server_key.bootstrapping_key.output_lwe_dimension() != large_dimension
+    {
+        return Err(Error::new(
+            "KeyBundle CompressedServerKey dimensions do not match the client key".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the compressed server key was generated from the client key, by bootstrapping
+/// every value of the plaintext space encrypted with the client key
+///
+/// This only checks the keys used to bootstrap, the compression and casting keys are not checked.
+fn check_compressed_server_key_link(
+    client_key: &ClientKey,
+    server_key: &CompressedServerKey,
+) -> crate::Result<()> {
+    // Bootstrapping with keys of the wrong dimensions would panic
+    check_compressed_server_key_dimensions(client_key, server_key)?;
+
+    let client_key = &client_key.key.key.key;
+    let server_key = server_key.integer_key.key.key.decompress();
+
+    let full_modulus = (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+    let identity = server_key.generate_lookup_table(|x| x);
+    let is_linked = (0..full_modulus).all(|message| {
+        let ct = client_key.unchecked_encrypt(message);
+        let result = server_key.apply_lookup_table(&ct, &identity);
+        client_key.decrypt_message_and_carry(&result) == message
+    });
+
+    if is_linked {
+        Ok(())
+    } else {
+        Err(Error::new(
+            "KeyBundle CompressedServerKey was not generated from the client key".to_string(),
+        ))
+    }
+}
+
+fn check_client_key_conformance(
+    client_key: &ClientKey,
+    config: &Config,
+) -> Result<(), ConformanceError> {
+    check_field(
+        "block_parameters",
+        &config.inner.block_parameters,
+        &client_key.key.block_parameters(),
+    )?;
+    check_field(
+        "compression_key",
+        &config.inner.compression_parameters.is_some(),
+        &client_key.key.compression_key.is_some(),
+    )?;
+    check_field(
+        "dedicated_compact_private_key",
+        &config
+            .inner
+            .dedicated_compact_public_key_parameters
+            .is_some(),
+        &client_key.key.dedicated_compact_private_key.is_some(),
+    )
+}
+
+fn check_compressed_server_key_conformance(
+    server_key: &CompressedServerKey,
+    config: &Config,
+) -> Result<(), ConformanceError> {
+    let block_parameters = config.inner.block_parameters;
+    let integer_key = &server_key.integer_key;
+    let shortint_key = &integer_key.key.key;
+
+    check_field(
+        "message_modulus",
+        &block_parameters.message_modulus(),
+        &shortint_key.message_modulus,
+    )?;
+    check_field(
+        "carry_modulus",
+        &block_parameters.carry_modulus(),
+        &shortint_key.carry_modulus,
+    )?;
+    check_field(
+        "ciphertext_modulus",
+        &block_parameters.ciphertext_modulus(),
+        &shortint_key.ciphertext_modulus,
+    )?;
+    check_field(
+        "compression_key",
+        &config.inner.compression_parameters.is_some(),
+        &integer_key.compression_key.is_some(),
+    )?;
+    check_field(
+        "cpk_key_switching_key_material",
+        &config
+            .inner
+            .dedicated_compact_public_key_parameters
+            .is_some(),
+        &integer_key.cpk_key_switching_key_material.is_some(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::COMPACT_PUBLIC_KEY_DOMAIN;
+    use crate::prelude::*;
+    use crate::safe_deserialization::safe_deserialize_conformant_versioned;
+    use crate::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS;
+    use crate::{
+        safe_serialize_versioned, ClientKey, CompactPublicKey, CompressedServerKey, ConfigBuilder,
+        KeyBundle, KeyBundleSelection,
+    };
+
+    #[test]
+    fn test_key_bundle() {
+        let config = ConfigBuilder::default().build();
+        let mut client_key = ClientKey::generate(config);
+        client_key.tag_mut().set_u64(42);
+
+        let bundle = KeyBundle::new("PARAM_MESSAGE_2_CARRY_2_KS_PBS", client_key.clone())
+            .unwrap()
+            .with_compressed_server_key(CompressedServerKey::new(&client_key))
+            .unwrap()
+            .with_compact_public_key(CompactPublicKey::new(&client_key))
+            .unwrap();
+
+        assert!(bundle.check_integrity().is_ok());
+        assert!(bundle.check_key_links().is_ok());
+        assert!(bundle.is_conformant(&config));
+        assert_eq!(bundle.tag().as_u64(), 42);
+        assert_eq!(
+            bundle.parameter_set_name(),
+            "PARAM_MESSAGE_2_CARRY_2_KS_PBS"
+        );
+
+        let public_bundle = bundle.export(KeyBundleSelection::PUBLIC_ONLY);
+        assert!(public_bundle.client_key().is_none());
+        assert!(public_bundle.compressed_server_key().is_some());
+        assert!(public_bundle.compact_public_key().is_some());
+        assert_eq!(public_bundle.key_set_id(), bundle.key_set_id());
+        assert_eq!(public_bundle.client_key_hash(), bundle.client_key_hash());
+
+        let mut buffer = vec![];
+        safe_serialize_versioned(&public_bundle, &mut buffer, 1 << 32).unwrap();
+
+        let loaded: KeyBundle =
+            safe_deserialize_conformant_versioned(buffer.as_slice(), 1 << 32, &config).unwrap();
+        assert_eq!(loaded.key_set_id(), bundle.key_set_id());
+
+        // Wrong parameters
+        let other_config =
+            ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS).build();
+        assert_eq!(
+            loaded.check_conformance(&other_config).unwrap_err().field(),
+            "message_modulus"
+        );
+        assert!(safe_deserialize_conformant_versioned::<KeyBundle>(
+            buffer.as_slice(),
+            1 << 32,
+            &other_config
+        )
+        .is_err());
+
+        // A public key from another key set must be rejected
+        let other_client_key = ClientKey::generate(config);
+        let mut mixed_bundle = bundle.export(KeyBundleSelection::PUBLIC_ONLY);
+        let mut other_public_key = CompactPublicKey::new(&other_client_key);
+        other_public_key.tag_mut().set_u64(42);
+        mixed_bundle.compact_public_key.as_mut().unwrap().0 = other_public_key.clone();
+        assert!(mixed_bundle.check_integrity().is_err());
+        assert!(!mixed_bundle.is_conformant(&config));
+
+        // Even with the right hash, a public key from another key set must be rejected when the
+        // client key is in the bundle
+        let mut forged_bundle = bundle.clone();
+        let forged_hash = forged_bundle
+            .part_hash(COMPACT_PUBLIC_KEY_DOMAIN, &other_public_key)
+            .unwrap();
+        forged_bundle.compact_public_key = Some((other_public_key.clone(), forged_hash));
+        assert!(forged_bundle.check_integrity().is_err());
+        assert!(
+            KeyBundle::new("PARAM_MESSAGE_2_CARRY_2_KS_PBS", client_key.clone())
+                .unwrap()
+                .with_compact_public_key(other_public_key)
+                .is_err()
+        );
+
+        // A server key from another key set has the right dimensions, it is only detected by
+        // the complete check
+        let mut other_server_key = CompressedServerKey::new(&other_client_key);
+        other_server_key.tag_mut().set_u64(42);
+        let mixed_bundle = KeyBundle::new("PARAM_MESSAGE_2_CARRY_2_KS_PBS", client_key.clone())
+            .unwrap()
+            .with_compressed_server_key(other_server_key)
+            .unwrap();
+        assert!(mixed_bundle.check_integrity().is_ok());
+        assert!(mixed_bundle.check_key_links().is_err());
+        assert!(public_bundle.check_key_links().is_err());
+
+        let mut other_params_key = CompressedServerKey::new(&ClientKey::generate(
+            ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS),
+        ));
+        other_params_key.tag_mut().set_u64(42);
+        assert!(
+            KeyBundle::new("PARAM_MESSAGE_2_CARRY_2_KS_PBS", client_key.clone())
+                .unwrap()
+                .with_compressed_server_key(other_params_key)
+                .is_err()
+        );
+
+        // Tags must match
+        assert!(KeyBundle::new("PARAM_MESSAGE_2_CARRY_2_KS_PBS", client_key)
+            .unwrap()
+            .with_compact_public_key(CompactPublicKey::new(&other_client_key))
+            .is_err());
+    }
+
+    #[cfg(feature = "zk-pok")]
+    #[test]
+    fn test_key_bundle_crs_conformance() {
+        use crate::zk::CompactPkeCrs;
+
+        let config = ConfigBuilder::default().build();
+        let client_key = ClientKey::generate(config);
+        let crs = CompactPkeCrs::from_config(config, 2).unwrap();
+
+        let bundle = KeyBundle::new("PARAM_MESSAGE_2_CARRY_2_KS_PBS", client_key)
+            .unwrap()
+            .with_crs_public_params(crs.public_params())
+            .unwrap()
+            .export(KeyBundleSelection {
+                client_key: false,
+                compressed_server_key: false,
+                compact_public_key: false,
+                crs: true,
+            });
+        assert!(bundle.is_conformant(&config));
+
+        let other_config =
+            ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS).build();
+        assert!(!bundle.is_conformant(&other_config));
+    }
+}
@@ -5,7 +5,9 @@
 use super::{CompressedServerKey, ServerKey};
 use crate::high_level_api::backward_compatibility::keys::ClientKeyVersions;
 use crate::high_level_api::config::Config;
-use crate::high_level_api::keys::{CompactPrivateKey, IntegerClientKey};
+use crate::high_level_api::keys::{
+    CompactPrivateKey, DerivationPath, DerivedKeyKind, IntegerClientKey, MasterSecret,
+};
 use crate::integer::compression_keys::CompressionPrivateKeys;
 use crate::named::Named;
 use crate::prelude::Tagged;
@@ -70,6 +72,45 @@ impl ClientKey {
         }
     }
 
+    /// Derives the key of the given path from a master secret.
+    ///
+    /// Every private key of the set (including the compression and dedicated compact public key
+    /// private keys enabled in the config) is reproducible from the master secret and the path,
+    /// and keys of distinct paths are independent.
+    ///
+    /// ```rust
+    /// use tfhe::{ClientKey, ConfigBuilder, DerivationPath, MasterSecret};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let master_secret = MasterSecret::generate();
+    /// let path: DerivationPath = "m/42/2024".parse().unwrap();
+    ///
+    /// let cks1 = ClientKey::derive(config, &master_secret, &path);
+    /// let cks2 = ClientKey::derive(config, &master_secret, &path);
+    /// let cks3 = ClientKey::derive(config, &master_secret, &path.child(0));
+    ///
+    /// assert_eq!(
+    ///     bincode::serialize(&cks1).unwrap(),
+    ///     bincode::serialize(&cks2).unwrap()
+    /// );
+    /// assert_ne!(
+    ///     bincode::serialize(&cks1).unwrap(),
+    ///     bincode::serialize(&cks3).unwrap()
+    /// );
+    /// ```
+    pub fn derive<C: Into<Config>>(
+        config: C,
+        master_secret: &MasterSecret,
+        path: &DerivationPath,
+    ) -> Self {
+        let config: Config = config.into();
+        let mut engine = master_secret.derive_engine(path, DerivedKeyKind::Client);
+        Self {
+            key: IntegerClientKey::with_engine(config.inner, &mut engine),
+            tag: Tag::default(),
+        }
+    }
+
     pub fn into_raw_parts(
         self,
     ) -> (
@@ -0,0 +1,205 @@
+//! Hierarchical deterministic derivation of keys from a single [MasterSecret].
+//!
+//! Each [DerivationPath] designates an independent set of keys. The seed of a path is computed
+//! by walking a binary tree of forks of the `concrete-csprng` generator (one level per bit of the
+//! path indices), the node reached is then forked once more into one seed per kind of key. Seeds
+//! of different paths, and of different kinds of keys for the same path, are thus independent,
+//! while each of them can be recomputed from the master secret alone.
+
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
+use crate::core_crypto::seeders::new_seeder;
+use crate::shortint::engine::ShortintEngine;
+use crate::Error;
+use concrete_csprng::seeders::{Seed, Seeder};
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+/// The secret from which all the keys of a hierarchy are derived.
+///
+/// This is the only piece of information that needs to be backed up to regenerate the keys,
+/// it **MUST** be kept as secret as the client keys it derives.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MasterSecret(Seed);
+
+impl MasterSecret {
+    /// Generates a new master secret using the platform's seeder.
+    pub fn generate() -> Self {
+        Self(new_seeder().seed())
+    }
+
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(Seed(u128::from_le_bytes(bytes)))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 16] {
+        self.0 .0.to_le_bytes()
+    }
+
+    /// Returns the seed used to generate the keys of the given kind for `path`.
+    fn derive_seed(&self, path: &DerivationPath, kind: DerivedKeyKind) -> Seed {
+        let node = path.indices().iter().fold(self.0, |seed, index| {
+            (0..u32::BITS).rev().fold(seed, |seed, bit| {
+                fork_seed(seed, ((index >> bit) & 1) as usize)
+            })
+        });
+
+        fork_seed(node, TREE_BRANCHES + kind as usize)
+    }
+
+    /// Returns an engine whose generators are all seeded from the seed of the given kind of keys
+    /// for `path`, every key generated with it is thus reproducible.
+    pub(in crate::high_level_api) fn derive_engine(
+        &self,
+        path: &DerivationPath,
+        kind: DerivedKeyKind,
+    ) -> ShortintEngine {
+        let mut seeder =
+            DeterministicSeeder::<ActivatedRandomGenerator>::new(self.derive_seed(path, kind));
+        ShortintEngine::new_from_seeder(&mut seeder)
+    }
+}
+
+impl Debug for MasterSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MasterSecret(..)")
+    }
+}
+
+/// The kinds of keys derived for each path, each one is generated from a distinct seed.
+#[derive(Copy, Clone, Debug)]
+pub(in crate::high_level_api) enum DerivedKeyKind {
+    Client = 0,
+    Server = 1,
+    KeySwitching = 2,
+}
+
+/// Number of children of a node of the derivation tree leading to other nodes.
+const TREE_BRANCHES: usize = 2;
+/// Number of children of a node of the derivation tree leading to key seeds.
+const KEY_KINDS: usize = 3;
+
+/// Returns the seed of the `child`-th generator forked from a generator seeded with `seed`.
+fn fork_seed(seed: Seed, child: usize) -> Seed {
+    const SEED_BYTES: usize = std::mem::size_of::<u128>();
+
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(seed);
+    let mut child_generator = generator
+        .try_fork(TREE_BRANCHES + KEY_KINDS, SEED_BYTES)
+        .expect("a fresh generator can always be forked")
+        .nth(child)
+        .expect("child index out of the fork bounds");
+
+    let mut bytes = [0u8; SEED_BYTES];
+    for byte in &mut bytes {
+        *byte = child_generator.generate_next();
+    }
+    Seed(u128::from_le_bytes(bytes))
+}
+
+/// Identifies a set of derived keys, written as `m/<index>/<index>/...`, e.g. `m/42/2024`.
+///
+/// The empty path (`m`) designates the keys derived directly from the master secret.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    pub fn new(indices: Vec<u32>) -> Self {
+        Self(indices)
+    }
+
+    /// Returns the path of the `index`-th child of this path.
+    pub fn child(&self, index: u32) -> Self {
+        let mut indices = self.0.clone();
+        indices.push(index);
+        Self(indices)
+    }
+
+    pub fn indices(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl From<&[u32]> for DerivationPath {
+    fn from(indices: &[u32]) -> Self {
+        Self(indices.to_vec())
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("m")?;
+        for index in &self.0 {
+            write!(f, "/{index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut components = path.split('/');
+        if components.next() != Some("m") {
+            return Err(Error::new(format!(
+                "Derivation path '{path}' must start with 'm'"
+            )));
+        }
+
+        components
+            .map(|component| {
+                component.parse().map_err(|_| {
+                    Error::new(format!(
+                        "Invalid index '{component}' in derivation path '{path}'"
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derivation_path_parsing() {
+        let path: DerivationPath = "m/42/2024".parse().unwrap();
+        assert_eq!(path.indices(), &[42, 2024]);
+        assert_eq!(path.to_string(), "m/42/2024");
+        assert_eq!(path, DerivationPath::default().child(42).child(2024));
+
+        let master: DerivationPath = "m".parse().unwrap();
+        assert!(master.indices().is_empty());
+
+        assert!("42/2024".parse::<DerivationPath>().is_err());
+        assert!("m/-1".parse::<DerivationPath>().is_err());
+        assert!("m//1".parse::<DerivationPath>().is_err());
+    }
+
+    #[test]
+    fn derived_seeds_are_separated() {
+        let master_secret = MasterSecret::from_bytes([7; 16]);
+        let path = DerivationPath::new(vec![1, 2]);
+
+        let seed = master_secret.derive_seed(&path, DerivedKeyKind::Client);
+        assert_eq!(
+            seed,
+            master_secret.derive_seed(&path, DerivedKeyKind::Client)
+        );
+
+        let others = [
+            master_secret.derive_seed(&path, DerivedKeyKind::Server),
+            master_secret.derive_seed(&path, DerivedKeyKind::KeySwitching),
+            master_secret.derive_seed(&DerivationPath::new(vec![2, 1]), DerivedKeyKind::Client),
+            master_secret.derive_seed(&DerivationPath::new(vec![1]), DerivedKeyKind::Client),
+            master_secret.derive_seed(&path.child(0), DerivedKeyKind::Client),
+            MasterSecret::from_bytes([8; 16]).derive_seed(&path, DerivedKeyKind::Client),
+        ];
+        for other in others {
+            assert_ne!(seed, other);
+        }
+    }
+}
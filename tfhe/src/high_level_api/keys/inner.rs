@@ -5,8 +5,12 @@ use crate::integer::compression_keys::{
     CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, CompressionPrivateKeys,
     DecompressionKey,
 };
+use crate::integer::key_switching_key::{
+    CompressedKeySwitchingKeyBuildHelper, KeySwitchingKeyBuildHelper,
+};
 use crate::integer::public_key::CompactPublicKey;
 use crate::integer::CompressedCompactPublicKey;
+use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::list_compression::CompressionParameters;
 use crate::shortint::MessageModulus;
 use crate::Error;
@@ -95,25 +99,38 @@ pub(crate) struct IntegerClientKey {
 
 impl IntegerClientKey {
     pub(crate) fn with_seed(config: IntegerConfig, seed: Seed) -> Self {
+        let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
+        let mut engine = ShortintEngine::new_from_seeder(&mut seeder);
+        Self::with_engine(config, &mut engine)
+    }
+
+    /// Generates every private key enabled in the config with the given engine, so that they are
+    /// all reproducible from the engine's seed.
+    pub(crate) fn with_engine(config: IntegerConfig, engine: &mut ShortintEngine) -> Self {
         assert!(
             (config.block_parameters.message_modulus().0) == 2 || config.block_parameters.message_modulus().0 == 4,
             "This API only supports parameters for which the MessageModulus is 2 or 4 (1 or 2 bits per block)",
         );
-        let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
-        let cks = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder)
-            .new_client_key(config.block_parameters.into());
-
-        let key = crate::integer::ClientKey::from(cks);
+        let cks = engine.new_client_key(config.block_parameters.into());
 
         let compression_key = config
             .compression_parameters
-            .map(|params| key.new_compression_private_key(params));
+            .map(|params| CompressionPrivateKeys {
+                key: engine.new_compression_private_key(&cks, params),
+            });
+
+        let dedicated_compact_private_key =
+            config.dedicated_compact_public_key_parameters.map(|p| {
+                (
+                    crate::integer::CompactPrivateKey {
+                        key: engine.new_compact_private_key(p.0),
+                    },
+                    p.1,
+                )
+            });
 
-        let dedicated_compact_private_key = config
-            .dedicated_compact_public_key_parameters
-            .map(|p| (crate::integer::CompactPrivateKey::new(p.0), p.1));
         Self {
-            key,
+            key: crate::integer::ClientKey::from(cks),
             dedicated_compact_private_key,
             compression_key,
         }
@@ -219,30 +236,47 @@ pub struct IntegerServerKey {
 
 impl IntegerServerKey {
     pub(in crate::high_level_api) fn new(client_key: &IntegerClientKey) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| Self::with_engine(client_key, engine))
+    }
+
+    /// Generates the server key with the given engine, so that it is reproducible from the
+    /// engine's seed.
+    pub(in crate::high_level_api) fn with_engine(
+        client_key: &IntegerClientKey,
+        engine: &mut ShortintEngine,
+    ) -> Self {
         let cks = &client_key.key;
 
         let (compression_key, decompression_key) = client_key.compression_key.as_ref().map_or_else(
             || (None, None),
             |a| {
                 let (compression_key, decompression_key) =
-                    cks.new_compression_decompression_keys(a);
-                (Some(compression_key), Some(decompression_key))
+                    engine.new_compression_decompression_keys(&cks.key, &a.key);
+                (
+                    Some(CompressionKey {
+                        key: compression_key,
+                    }),
+                    Some(DecompressionKey {
+                        key: decompression_key,
+                    }),
+                )
             },
         );
 
-        let base_integer_key = crate::integer::ServerKey::new_radix_server_key(cks);
+        let base_integer_key =
+            crate::integer::ServerKey::new_radix_server_key_with_engine(cks, engine);
 
         let cpk_key_switching_key_material =
             client_key
                 .dedicated_compact_private_key
                 .as_ref()
                 .map(|(private_key, ksk_params)| {
-                    let build_helper =
-                        crate::integer::key_switching_key::KeySwitchingKeyBuildHelper::new(
-                            (private_key, None),
-                            (cks, &base_integer_key),
-                            *ksk_params,
-                        );
+                    let build_helper = KeySwitchingKeyBuildHelper::new_with_engine(
+                        (private_key, None),
+                        (cks, &base_integer_key),
+                        *ksk_params,
+                        engine,
+                    );
 
                     build_helper.into()
                 });
@@ -287,20 +321,31 @@ pub struct IntegerCompressedServerKey {
 
 impl IntegerCompressedServerKey {
     pub(in crate::high_level_api) fn new(client_key: &IntegerClientKey) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| Self::with_engine(client_key, engine))
+    }
+
+    /// Generates the compressed server key with the given engine, so that it is reproducible
+    /// from the engine's seed.
+    pub(in crate::high_level_api) fn with_engine(
+        client_key: &IntegerClientKey,
+        engine: &mut ShortintEngine,
+    ) -> Self {
         let cks = &client_key.key;
 
-        let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key(cks);
+        let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key_with_engine(
+            cks, engine,
+        );
 
         let cpk_key_switching_key_material =
             client_key
                 .dedicated_compact_private_key
                 .as_ref()
                 .map(|(private_key, ksk_params)| {
-                    let build_helper =
-                    crate::integer::key_switching_key::CompressedKeySwitchingKeyBuildHelper::new(
+                    let build_helper = CompressedKeySwitchingKeyBuildHelper::new_with_engine(
                         (private_key, None),
                         (cks, &key),
                         *ksk_params,
+                        engine,
                     );
 
                     build_helper.into()
@@ -311,11 +356,20 @@ impl IntegerCompressedServerKey {
                 .compression_key
                 .as_ref()
                 .map_or((None, None), |compression_private_key| {
-                    let (compression_keys, decompression_keys) = client_key
-                        .key
-                        .new_compressed_compression_decompression_keys(compression_private_key);
+                    let (compression_keys, decompression_keys) = engine
+                        .new_compressed_compression_decompression_keys(
+                            &cks.key,
+                            &compression_private_key.key,
+                        );
 
-                    (Some(compression_keys), Some(decompression_keys))
+                    (
+                        Some(CompressedCompressionKey {
+                            key: compression_keys,
+                        }),
+                        Some(CompressedDecompressionKey {
+                            key: decompression_keys,
+                        }),
+                    )
                 });
 
         Self {
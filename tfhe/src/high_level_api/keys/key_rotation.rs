@@ -0,0 +1,199 @@
+use rayon::prelude::*;
+use tfhe_versionable::Versionize;
+
+use crate::backward_compatibility::keys::KeyRotationKeyVersions;
+use crate::integer::compression_keys::{CompressionKey, DecompressionKey};
+use crate::named::Named;
+use crate::prelude::{FheKeyswitch, Tagged};
+use crate::shortint::parameters::key_switching::ShortintKeySwitchingParameters;
+use crate::{ClientKey, CompressedCiphertextList, KeySwitchingKey, ServerKey};
+
+/// Key converting the ciphertexts of a key set to another one, typically to keep stored data
+/// usable when keys are rotated.
+///
+/// The new key set may use different parameters, as long as the message modulus stays the same.
+///
+/// Ciphertexts are converted by a [`KeySwitchingKey`], the rotation key adds the conversion of
+/// compressed lists. Converted ciphertexts and lists keep their [`Tag`](crate::Tag).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::key_switching::ShortintKeySwitchingParameters;
+/// use tfhe::shortint::parameters::list_compression::{
+///     COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS, COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+/// };
+/// use tfhe::shortint::parameters::{
+///     EncryptionKeyChoice, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+///     PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+/// };
+/// use tfhe::{
+///     set_server_key, ClientKey, CompressedCiphertextListBuilder, ConfigBuilder, FheUint32,
+///     KeyRotationKey, ServerKey,
+/// };
+///
+/// let old_config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+///     .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+///     .build();
+/// let old_client_key = ClientKey::generate(old_config);
+/// let old_server_key = ServerKey::new(&old_client_key);
+///
+/// let new_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+/// let new_config = ConfigBuilder::with_custom_parameters(new_params)
+///     .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+///     .build();
+/// let new_client_key = ClientKey::generate(new_config);
+/// let new_server_key = ServerKey::new(&new_client_key);
+///
+/// set_server_key(old_server_key.clone());
+/// let list = CompressedCiphertextListBuilder::new()
+///     .push(FheUint32::encrypt(17u32, &old_client_key))
+///     .build()
+///     .unwrap();
+///
+/// let rotation_key = KeyRotationKey::new(
+///     (&old_client_key, &old_server_key),
+///     (&new_client_key, &new_server_key),
+///     ShortintKeySwitchingParameters::new(
+///         new_params.ks_base_log,
+///         new_params.ks_level,
+///         EncryptionKeyChoice::Small,
+///     ),
+/// )
+/// .unwrap();
+/// let rotated = rotation_key.rotate_compressed_list(&list).unwrap();
+///
+/// set_server_key(new_server_key);
+/// let a: FheUint32 = rotated.get(0).unwrap().unwrap();
+/// let decrypted: u32 = a.decrypt(&new_client_key);
+/// assert_eq!(decrypted, 17);
+/// ```
+#[derive(serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(KeyRotationKeyVersions)]
+pub struct KeyRotationKey {
+    key: KeySwitchingKey,
+    /// Decompression key of the old key set
+    decompression_key: Option<DecompressionKey>,
+    /// Compression key of the new key set
+    compression_key: Option<CompressionKey>,
+}
+
+impl KeyRotationKey {
+    /// Creates the key converting ciphertexts from `key_pair_from` to `key_pair_to`.
+    ///
+    /// Compressed lists can only be converted if compression is enabled in both key sets.
+    pub fn new(
+        key_pair_from: (&ClientKey, &ServerKey),
+        key_pair_to: (&ClientKey, &ServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> crate::Result<Self> {
+        let message_modulus_from = key_pair_from.0.key.block_parameters().message_modulus();
+        let message_modulus_to = key_pair_to.0.key.block_parameters().message_modulus();
+
+        if message_modulus_from != message_modulus_to {
+            return Err(crate::Error::new(format!(
+                "Key rotation requires both key sets to have the same message modulus, \
+                got {message_modulus_from:?} and {message_modulus_to:?}"
+            )));
+        }
+
+        Ok(Self {
+            key: KeySwitchingKey::with_parameters(key_pair_from, key_pair_to, params),
+            decompression_key: key_pair_from.1.key.decompression_key.clone(),
+            compression_key: key_pair_to.1.key.compression_key.clone(),
+        })
+    }
+
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        KeySwitchingKey,
+        Option<DecompressionKey>,
+        Option<CompressionKey>,
+    ) {
+        let Self {
+            key,
+            decompression_key,
+            compression_key,
+        } = self;
+        (key, decompression_key, compression_key)
+    }
+
+    pub fn from_raw_parts(
+        key: KeySwitchingKey,
+        decompression_key: Option<DecompressionKey>,
+        compression_key: Option<CompressionKey>,
+    ) -> Self {
+        Self {
+            key,
+            decompression_key,
+            compression_key,
+        }
+    }
+
+    /// Converts a compressed list to the new key set, the result is compressed with the
+    /// compression key of the new key set.
+    pub fn rotate_compressed_list(
+        &self,
+        list: &CompressedCiphertextList,
+    ) -> crate::Result<CompressedCiphertextList> {
+        let (Some(decompression_key), Some(compression_key)) =
+            (&self.decompression_key, &self.compression_key)
+        else {
+            return Err(crate::Error::new(
+                "Compressed lists can only be rotated between key sets with compression enabled"
+                    .to_string(),
+            ));
+        };
+
+        let inner = list
+            .inner
+            .rotate(decompression_key, &self.key.key, compression_key)?;
+
+        Ok(CompressedCiphertextList {
+            inner,
+            tag: list.tag.clone(),
+        })
+    }
+
+    /// Converts compressed lists to the new key set in parallel, see
+    /// [`Self::rotate_compressed_list`].
+    pub fn rotate_compressed_lists(
+        &self,
+        lists: &[CompressedCiphertextList],
+    ) -> crate::Result<Vec<CompressedCiphertextList>> {
+        lists
+            .par_iter()
+            .map(|list| self.rotate_compressed_list(list))
+            .collect()
+    }
+
+    /// Converts ciphertexts to the new key set in parallel.
+    pub fn rotate_all<T>(&self, ciphertexts: &[T]) -> Vec<T>
+    where
+        Self: FheKeyswitch<T>,
+        T: Send + Sync,
+    {
+        ciphertexts
+            .par_iter()
+            .map(|ct| self.keyswitch(ct))
+            .collect()
+    }
+}
+
+impl<T> FheKeyswitch<T> for KeyRotationKey
+where
+    KeySwitchingKey: FheKeyswitch<T>,
+    T: Tagged,
+{
+    fn keyswitch(&self, input: &T) -> T {
+        let mut output = self.key.keyswitch(input);
+        *output.tag_mut() = input.tag().clone();
+        output
+    }
+}
+
+impl Named for KeyRotationKey {
+    const NAME: &'static str = "high_level_api::KeyRotationKey";
+}
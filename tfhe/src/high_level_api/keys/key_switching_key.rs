@@ -2,6 +2,8 @@ use tfhe_versionable::Versionize;
 
 use crate::backward_compatibility::keys::KeySwitchingKeyVersions;
 use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::{DerivationPath, DerivedKeyKind, MasterSecret};
+use crate::integer::key_switching_key::KeySwitchingKeyBuildHelper;
 use crate::integer::BooleanBlock;
 use crate::named::Named;
 use crate::prelude::FheKeyswitch;
@@ -23,7 +25,7 @@ impl std::error::Error for IncompatibleParameters {}
 #[derive(serde::Deserialize, serde::Serialize, Versionize)]
 #[versionize(KeySwitchingKeyVersions)]
 pub struct KeySwitchingKey {
-    key: crate::integer::key_switching_key::KeySwitchingKey,
+    pub(in crate::high_level_api) key: crate::integer::key_switching_key::KeySwitchingKey,
     tag_in: Tag,
     tag_out: Tag,
 }
@@ -33,21 +35,58 @@ impl KeySwitchingKey {
         key_pair_from: (&ClientKey, &ServerKey),
         key_pair_to: (&ClientKey, &ServerKey),
     ) -> Result<Self, IncompatibleParameters> {
-        let params_from = key_pair_from.0.key.block_parameters();
-        let params_to = key_pair_to.0.key.block_parameters();
+        let params = Self::same_parameters(key_pair_from.0, key_pair_to.0)?;
+
+        Ok(Self::with_parameters(key_pair_from, key_pair_to, params))
+    }
+
+    /// Returns the parameters to switch between two key sets using the same parameters
+    fn same_parameters(
+        client_key_from: &ClientKey,
+        client_key_to: &ClientKey,
+    ) -> Result<ShortintKeySwitchingParameters, IncompatibleParameters> {
+        let params_from = client_key_from.key.block_parameters();
+        let params_to = client_key_to.key.block_parameters();
 
         if params_to != params_from {
             return Err(IncompatibleParameters);
         }
 
         // params_to == params_from, so we can use the parameters from params_to
-        let params = ShortintKeySwitchingParameters {
+        Ok(ShortintKeySwitchingParameters {
             ks_base_log: params_to.ks_base_log(),
             ks_level: params_to.ks_level(),
             destination_key: params_to.encryption_key_choice(),
-        };
+        })
+    }
 
-        Ok(Self::with_parameters(key_pair_from, key_pair_to, params))
+    /// Creates the key switching key between two key sets deterministically from a master secret
+    /// and a path, see [ClientKey::derive].
+    ///
+    /// The path identifies the key switching key itself, it is usually the path of one of the two
+    /// client keys.
+    pub fn derive(
+        master_secret: &MasterSecret,
+        path: &DerivationPath,
+        key_pair_from: (&ClientKey, &ServerKey),
+        key_pair_to: (&ClientKey, &ServerKey),
+    ) -> Result<Self, IncompatibleParameters> {
+        let params = Self::same_parameters(key_pair_from.0, key_pair_to.0)?;
+        let mut engine = master_secret.derive_engine(path, DerivedKeyKind::KeySwitching);
+
+        let key = KeySwitchingKeyBuildHelper::new_with_engine(
+            (&key_pair_from.0.key.key, Some(&key_pair_from.1.key.key)),
+            (&key_pair_to.0.key.key, &key_pair_to.1.key.key),
+            params,
+            &mut engine,
+        )
+        .into();
+
+        Ok(Self {
+            key,
+            tag_in: key_pair_from.0.tag.clone(),
+            tag_out: key_pair_to.0.tag.clone(),
+        })
     }
 
     pub fn with_parameters(
@@ -2,14 +2,29 @@ mod client;
 mod public;
 mod server;
 
+mod bundle;
+mod derivation;
 mod inner;
+mod key_rotation;
 mod key_switching_key;
+mod noise_squashing;
+mod proxy_reencryption;
+mod sanitization;
 
 use crate::high_level_api::config::Config;
+pub use bundle::{ContentHash, KeyBundle, KeyBundleSelection, KeySetId};
 pub use client::ClientKey;
+pub(in crate::high_level_api) use derivation::DerivedKeyKind;
+pub use derivation::{DerivationPath, MasterSecret};
 pub(crate) use inner::CompactPrivateKey;
+pub use key_rotation::KeyRotationKey;
 pub use key_switching_key::KeySwitchingKey;
-pub use public::{CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey};
+pub use noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+pub use proxy_reencryption::ProxyReencryptionKey;
+pub use public::{
+    CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, GlwePublicKey, PublicKey,
+};
+pub use sanitization::SanitizationKey;
 #[cfg(feature = "gpu")]
 pub use server::CudaServerKey;
 pub(crate) use server::InternalServerKey;
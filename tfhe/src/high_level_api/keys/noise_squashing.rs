@@ -0,0 +1,105 @@
+use tfhe_versionable::Versionize;
+
+use crate::backward_compatibility::keys::{
+    NoiseSquashingKeyVersions, NoiseSquashingPrivateKeyVersions,
+};
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::named::Named;
+use crate::shortint::parameters::NoiseSquashingParameters;
+use crate::{ClientKey, Tag};
+
+/// Key decrypting the ciphertexts squashed by a [`NoiseSquashingKey`].
+///
+/// Its secret key is independent from the one of the [`ClientKey`], and is typically secret
+/// shared between the parties of a decryption protocol.
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(NoiseSquashingPrivateKeyVersions)]
+pub struct NoiseSquashingPrivateKey {
+    pub(in crate::high_level_api) key: crate::integer::noise_squashing::NoiseSquashingPrivateKey,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Generates a new key, tagged with the tag of `client_key`.
+    pub fn new(client_key: &ClientKey, params: NoiseSquashingParameters) -> Self {
+        Self {
+            key: crate::integer::noise_squashing::NoiseSquashingPrivateKey::new(params),
+            tag: client_key.tag.clone(),
+        }
+    }
+
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::integer::noise_squashing::NoiseSquashingPrivateKey,
+        Tag,
+    ) {
+        let Self { key, tag } = self;
+        (key, tag)
+    }
+
+    pub fn from_raw_parts(
+        key: crate::integer::noise_squashing::NoiseSquashingPrivateKey,
+        tag: Tag,
+    ) -> Self {
+        Self { key, tag }
+    }
+}
+
+impl Named for NoiseSquashingPrivateKey {
+    const NAME: &'static str = "high_level_api::NoiseSquashingPrivateKey";
+}
+
+/// Key used to squash the noise of ciphertexts, bootstrapping them to 128 bits ciphertexts with a
+/// tiny noise, see [`NoiseSquashingParameters`].
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(NoiseSquashingKeyVersions)]
+pub struct NoiseSquashingKey {
+    pub(in crate::high_level_api) key: crate::integer::noise_squashing::NoiseSquashingKey,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl NoiseSquashingKey {
+    pub fn new(
+        client_key: &ClientKey,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> Self {
+        Self {
+            key: crate::integer::noise_squashing::NoiseSquashingKey::new(
+                &client_key.key.key,
+                &noise_squashing_private_key.key,
+            ),
+            tag: client_key.tag.clone(),
+        }
+    }
+
+    pub fn into_raw_parts(self) -> (crate::integer::noise_squashing::NoiseSquashingKey, Tag) {
+        let Self { key, tag } = self;
+        (key, tag)
+    }
+
+    pub fn from_raw_parts(
+        key: crate::integer::noise_squashing::NoiseSquashingKey,
+        tag: Tag,
+    ) -> Self {
+        Self { key, tag }
+    }
+
+    pub(in crate::high_level_api) fn with_cpu_server_key<R>(
+        &self,
+        func: impl FnOnce(&crate::integer::ServerKey, &Tag) -> R,
+    ) -> R {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => func(cpu_key.pbs_key(), &cpu_key.tag),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support noise squashing yet")
+            }
+        })
+    }
+}
+
+impl Named for NoiseSquashingKey {
+    const NAME: &'static str = "high_level_api::NoiseSquashingKey";
+}
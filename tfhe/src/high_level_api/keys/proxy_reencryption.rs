@@ -0,0 +1,132 @@
+use tfhe_versionable::Versionize;
+
+use crate::backward_compatibility::keys::ProxyReencryptionKeyVersions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::parameters::IntegerCompactCiphertextListCastingMode;
+use crate::named::Named;
+use crate::shortint::parameters::ProxyReencryptionParameters;
+use crate::{ClientKey, CompactPublicKey, Tag};
+
+/// Key re-encrypting the ciphertexts of a [`ClientKey`] to the key of another user's
+/// [`CompactPublicKey`], without decrypting them.
+///
+/// The re-encrypted ciphertexts are usable with the server key of the recipient, and get the tag
+/// of its public key.
+///
+/// The key is generated by the owner of the ciphertexts and must not be given to the recipient:
+/// being the owner's secret key encrypted under the recipient's key, it would reveal it.
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(ProxyReencryptionKeyVersions)]
+pub struct ProxyReencryptionKey {
+    pub(in crate::high_level_api) key: crate::integer::proxy_reencryption::ProxyReencryptionKey,
+    /// Key for compact lists, when they use a dedicated compact public key
+    pub(in crate::high_level_api) compact_list_key:
+        Option<crate::integer::proxy_reencryption::ProxyReencryptionKey>,
+    /// Key for compressed lists, when compression is enabled
+    pub(in crate::high_level_api) compressed_list_key:
+        Option<crate::integer::proxy_reencryption::ProxyReencryptionKey>,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl ProxyReencryptionKey {
+    pub fn new(
+        client_key: &ClientKey,
+        public_key: &CompactPublicKey,
+        params: ProxyReencryptionParameters,
+    ) -> Self {
+        let target_key = &public_key.key.key;
+        let integer_client_key = &client_key.key;
+
+        Self {
+            key: integer_client_key
+                .key
+                .new_proxy_reencryption_key(target_key, params),
+            compact_list_key: integer_client_key
+                .dedicated_compact_private_key
+                .as_ref()
+                .map(|(private_key, _)| private_key.new_proxy_reencryption_key(target_key, params)),
+            compressed_list_key: integer_client_key
+                .compression_key
+                .as_ref()
+                .map(|private_key| private_key.new_proxy_reencryption_key(target_key, params)),
+            tag: public_key.tag.clone(),
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::integer::proxy_reencryption::ProxyReencryptionKey,
+        Option<crate::integer::proxy_reencryption::ProxyReencryptionKey>,
+        Option<crate::integer::proxy_reencryption::ProxyReencryptionKey>,
+        Tag,
+    ) {
+        let Self {
+            key,
+            compact_list_key,
+            compressed_list_key,
+            tag,
+        } = self;
+        (key, compact_list_key, compressed_list_key, tag)
+    }
+
+    pub fn from_raw_parts(
+        key: crate::integer::proxy_reencryption::ProxyReencryptionKey,
+        compact_list_key: Option<crate::integer::proxy_reencryption::ProxyReencryptionKey>,
+        compressed_list_key: Option<crate::integer::proxy_reencryption::ProxyReencryptionKey>,
+        tag: Tag,
+    ) -> Self {
+        Self {
+            key,
+            compact_list_key,
+            compressed_list_key,
+            tag,
+        }
+    }
+
+    pub(in crate::high_level_api) fn compact_list_key(
+        &self,
+    ) -> &crate::integer::proxy_reencryption::ProxyReencryptionKey {
+        self.compact_list_key.as_ref().unwrap_or(&self.key)
+    }
+
+    pub(in crate::high_level_api) fn compressed_list_key(
+        &self,
+    ) -> crate::Result<&crate::integer::proxy_reencryption::ProxyReencryptionKey> {
+        self.compressed_list_key.as_ref().ok_or_else(|| {
+            crate::Error::new(
+                "The proxy re-encryption key was generated without compression keys".to_string(),
+            )
+        })
+    }
+
+    /// Calls `func` with the server key set for the current thread, which must be the one of the
+    /// recipient, and the casting mode matching its compact public key.
+    pub(in crate::high_level_api) fn with_cpu_server_key<R>(
+        &self,
+        func: impl FnOnce(
+            &crate::integer::ServerKey,
+            IntegerCompactCiphertextListCastingMode<'_>,
+        ) -> crate::Result<R>,
+    ) -> crate::Result<R> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let casting_mode = cpu_key.cpk_casting_key().map_or(
+                    IntegerCompactCiphertextListCastingMode::NoCasting,
+                    IntegerCompactCiphertextListCastingMode::CastIfNecessary,
+                );
+                func(cpu_key.pbs_key(), casting_mode)
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(crate::Error::new(
+                "Cuda devices do not support proxy re-encryption yet".to_string(),
+            )),
+        })
+    }
+}
+
+impl Named for ProxyReencryptionKey {
+    const NAME: &'static str = "high_level_api::ProxyReencryptionKey";
+}
@@ -13,6 +13,9 @@
 //! transfer sizes.
 //! - [CompressedPublicKey]
 //! - [CompressedCompactPublicKey]
+//!
+//! The [GlwePublicKey] encrypts the values of a
+//! [ProvenGlweCiphertextList](crate::ProvenGlweCiphertextList).
 use tfhe_versionable::Versionize;
 
 use super::ClientKey;
@@ -20,9 +23,14 @@ use crate::backward_compatibility::keys::{
     CompactPublicKeyVersions, CompressedCompactPublicKeyVersions, CompressedPublicKeyVersions,
     PublicKeyVersions,
 };
-use crate::high_level_api::keys::{IntegerCompactPublicKey, IntegerCompressedCompactPublicKey};
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::{
+    IntegerCompactPublicKey, IntegerCompressedCompactPublicKey, InternalServerKey,
+};
+use crate::integer::ciphertext::IntegerCompactCiphertextListCastingMode;
 use crate::named::Named;
 use crate::prelude::Tagged;
+use crate::shortint::parameters::CompactCiphertextListExpansionKind;
 use crate::shortint::MessageModulus;
 use crate::{Error, Tag};
 
@@ -165,6 +173,56 @@ impl CompactPublicKey {
             tag,
         }
     }
+
+    /// Re-randomizes the blocks using the server key set in the current thread.
+    ///
+    /// The casting key of the server key is used to keyswitch the encryptions of zero when this
+    /// key uses dedicated parameters.
+    ///
+    /// The tag of this key must match the tag of the server key, as a public key generated from
+    /// another client key would corrupt the ciphertexts.
+    pub(in crate::high_level_api) fn rerandomize_blocks(
+        &self,
+        blocks: &mut [crate::shortint::Ciphertext],
+    ) -> crate::Result<()> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                if cpu_key.tag != self.tag {
+                    return Err(Error::new(
+                        "The tag of the CompactPublicKey does not match the tag of the ServerKey"
+                            .to_string(),
+                    ));
+                }
+
+                let casting_mode = if self.key.key.key.parameters.expansion_kind
+                    == CompactCiphertextListExpansionKind::RequiresCasting
+                {
+                    IntegerCompactCiphertextListCastingMode::CastIfNecessary(
+                        cpu_key.cpk_casting_key().ok_or_else(|| {
+                            Error::new(
+                                "No casting key found in ServerKey, \
+                                required to rerandomize with this CompactPublicKey"
+                                    .to_string(),
+                            )
+                        })?,
+                    )
+                } else {
+                    IntegerCompactCiphertextListCastingMode::NoCasting
+                };
+
+                crate::integer::ciphertext::rerandomize_blocks(
+                    blocks,
+                    &self.key.key,
+                    cpu_key.pbs_key(),
+                    casting_mode,
+                )
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(Error::new(
+                "Cuda devices do not support rerandomization yet".to_string(),
+            )),
+        })
+    }
 }
 
 impl Tagged for CompactPublicKey {
@@ -240,3 +298,57 @@ impl Tagged for CompressedCompactPublicKey {
 impl Named for CompressedCompactPublicKey {
     const NAME: &'static str = "high_level_api::CompressedCompactPublicKey";
 }
+
+/// Public key encrypting values in GLWE ciphertexts, see
+/// [ProvenGlweCiphertextList](crate::ProvenGlweCiphertextList).
+///
+/// The key is a GLWE encryption of zero under the GLWE secret key of the client key, the proofs
+/// of the lists are made and verified against it.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct GlwePublicKey {
+    pub(in crate::high_level_api) key: crate::integer::GlwePublicKey,
+    pub(crate) tag: Tag,
+}
+
+impl GlwePublicKey {
+    /// Creates a GlwePublicKey, derived from the given client key
+    ///
+    /// # Panic
+    ///
+    /// This will panic if parameters are not compatible
+    pub fn new(client_key: &ClientKey) -> Self {
+        Self {
+            key: crate::integer::GlwePublicKey::new(&client_key.key.key),
+            tag: client_key.tag.clone(),
+        }
+    }
+
+    pub fn try_new(client_key: &ClientKey) -> Result<Self, Error> {
+        crate::integer::GlwePublicKey::try_new(&client_key.key.key).map(|key| Self {
+            key,
+            tag: client_key.tag.clone(),
+        })
+    }
+
+    pub fn into_raw_parts(self) -> (crate::integer::GlwePublicKey, Tag) {
+        (self.key, self.tag)
+    }
+
+    pub fn from_raw_parts(key: crate::integer::GlwePublicKey, tag: Tag) -> Self {
+        Self { key, tag }
+    }
+}
+
+impl Tagged for GlwePublicKey {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+impl Named for GlwePublicKey {
+    const NAME: &'static str = "high_level_api::GlwePublicKey";
+}
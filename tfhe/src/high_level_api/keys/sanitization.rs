@@ -0,0 +1,55 @@
+use tfhe_versionable::Versionize;
+
+use crate::backward_compatibility::keys::SanitizationKeyVersions;
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::named::Named;
+use crate::shortint::parameters::SanitizationParameters;
+use crate::{ClientKey, Tag};
+
+/// Key used to sanitize ciphertexts before they are sent for decryption.
+///
+/// A sanitized ciphertext encrypts the same value, with a noise that does not depend on the
+/// operations that produced it, see
+/// [`SanitizationParameters`](crate::shortint::parameters::SanitizationParameters).
+#[derive(Clone, serde::Deserialize, serde::Serialize, Versionize)]
+#[versionize(SanitizationKeyVersions)]
+pub struct SanitizationKey {
+    pub(in crate::high_level_api) key: crate::integer::sanitization::SanitizationKey,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl SanitizationKey {
+    pub fn new(client_key: &ClientKey, params: SanitizationParameters) -> Self {
+        Self {
+            key: client_key.key.key.new_sanitization_key(params),
+            tag: client_key.tag.clone(),
+        }
+    }
+
+    pub fn into_raw_parts(self) -> (crate::integer::sanitization::SanitizationKey, Tag) {
+        let Self { key, tag } = self;
+        (key, tag)
+    }
+
+    pub fn from_raw_parts(key: crate::integer::sanitization::SanitizationKey, tag: Tag) -> Self {
+        Self { key, tag }
+    }
+
+    pub(in crate::high_level_api) fn with_cpu_server_key<R>(
+        &self,
+        func: impl FnOnce(&crate::integer::ServerKey, &Tag) -> R,
+    ) -> R {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => func(cpu_key.pbs_key(), &cpu_key.tag),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => {
+                panic!("Cuda devices do not support sanitization yet")
+            }
+        })
+    }
+}
+
+impl Named for SanitizationKey {
+    const NAME: &'static str = "high_level_api::SanitizationKey";
+}
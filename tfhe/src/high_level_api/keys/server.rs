@@ -4,7 +4,9 @@ use super::ClientKey;
 use crate::backward_compatibility::keys::{CompressedServerKeyVersions, ServerKeyVersions};
 #[cfg(feature = "gpu")]
 use crate::core_crypto::gpu::{synchronize_devices, CudaStreams};
-use crate::high_level_api::keys::{IntegerCompressedServerKey, IntegerServerKey};
+use crate::high_level_api::keys::{
+    DerivationPath, DerivedKeyKind, IntegerCompressedServerKey, IntegerServerKey, MasterSecret,
+};
 use crate::integer::compression_keys::{
     CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, DecompressionKey,
 };
@@ -39,6 +41,16 @@ impl ServerKey {
         }
     }
 
+    /// Generates the server key of `keys` deterministically from the master secret and the path
+    /// used to derive them, see [ClientKey::derive].
+    pub fn derive(keys: &ClientKey, master_secret: &MasterSecret, path: &DerivationPath) -> Self {
+        let mut engine = master_secret.derive_engine(path, DerivedKeyKind::Server);
+        Self {
+            key: Arc::new(IntegerServerKey::with_engine(&keys.key, &mut engine)),
+            tag: keys.tag.clone(),
+        }
+    }
+
     pub fn into_raw_parts(
         self,
     ) -> (
@@ -194,6 +206,16 @@ impl CompressedServerKey {
         }
     }
 
+    /// Generates the compressed server key of `keys` deterministically from the master secret
+    /// and the path used to derive them, see [ClientKey::derive].
+    pub fn derive(keys: &ClientKey, master_secret: &MasterSecret, path: &DerivationPath) -> Self {
+        let mut engine = master_secret.derive_engine(path, DerivedKeyKind::Server);
+        Self {
+            integer_key: IntegerCompressedServerKey::with_engine(&keys.key, &mut engine),
+            tag: keys.tag.clone(),
+        }
+    }
+
     pub fn into_raw_parts(
         self,
     ) -> (
@@ -33,7 +33,9 @@ pub use integers::{CompressedFheInt, CompressedFheUint, FheInt, FheUint, Integer
 pub use keys::CudaServerKey;
 pub use keys::{
     generate_keys, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
-    CompressedServerKey, KeySwitchingKey, PublicKey, ServerKey,
+    CompressedServerKey, ContentHash, DerivationPath, GlwePublicKey, KeyBundle, KeyBundleSelection,
+    KeyRotationKey, KeySetId, KeySwitchingKey, MasterSecret, NoiseSquashingKey,
+    NoiseSquashingPrivateKey, ProxyReencryptionKey, PublicKey, SanitizationKey, ServerKey,
 };
 
 #[cfg(test)]
@@ -51,13 +53,19 @@ expand_pub_use_fhe_type!(
 );
 
 pub use crate::integer::parameters::CompactCiphertextListConformanceParams;
-#[cfg(feature = "zk-pok")]
-pub use compact_list::ProvenCompactCiphertextList;
 pub use compact_list::{
     CompactCiphertextList, CompactCiphertextListBuilder, CompactCiphertextListExpander,
 };
+#[cfg(feature = "zk-pok")]
+pub use compact_list::{
+    ProvenCompactCiphertextList, ProvenGlweCiphertextList, ProvenGlweCiphertextListBuilder,
+};
 pub use compressed_ciphertext_list::{CompressedCiphertextList, CompressedCiphertextListBuilder};
 pub use safe_serialize::{safe_serialize, safe_serialize_versioned};
+pub use squashed_noise::{
+    CompressedSquashedNoiseCiphertextList, CompressedSquashedNoiseCiphertextListBuilder,
+    SquashedNoiseFheBool, SquashedNoiseFheInt, SquashedNoiseFheUint,
+};
 
 pub use tag::Tag;
 
@@ -68,13 +76,16 @@ mod errors;
 mod global_state;
 mod integers;
 mod keys;
+mod squashed_noise;
 mod traits;
 mod utils;
 
 pub mod array;
 pub mod backward_compatibility;
 mod compact_list;
+pub mod distributed_keygen;
 mod tag;
+pub mod threshold;
 
 pub(in crate::high_level_api) mod details;
 /// The tfhe prelude.
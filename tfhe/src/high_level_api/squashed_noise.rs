@@ -0,0 +1,438 @@
+//! Ciphertexts whose noise was squashed by a [`NoiseSquashingKey`].
+//!
+//! They are 128 bits ciphertexts with a tiny noise compared to their modulus, meant for
+//! decryption protocols like MPC decryption. They can only be decrypted, with the
+//! [`NoiseSquashingPrivateKey`] of the key, and stored in a
+//! [`CompressedSquashedNoiseCiphertextList`].
+
+use tfhe_versionable::Versionize;
+
+use crate::backward_compatibility::squashed_noise::{
+    CompressedSquashedNoiseCiphertextListVersions, SquashedNoiseFheBoolVersions,
+    SquashedNoiseFheIntVersions, SquashedNoiseFheUintVersions,
+};
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::integer::block_decomposition::RecomposableFrom;
+use crate::integer::ciphertext::{
+    DataKind, SquashedNoiseBooleanBlock, SquashedNoiseCompressible, SquashedNoiseExpandable,
+    SquashedNoiseRadixCiphertext, SquashedNoiseSignedRadixCiphertext,
+};
+use crate::integer::client_key::RecomposableSignedInteger;
+use crate::named::Named;
+use crate::prelude::Tagged;
+use crate::shortint::ciphertext::SquashedNoiseCiphertext;
+use crate::{FheBool, FheInt, FheUint, NoiseSquashingKey, NoiseSquashingPrivateKey, Tag};
+use serde::{Deserialize, Serialize};
+
+/// A [`FheUint`] whose noise was squashed, see [`FheUint::squash_noise`].
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseFheUintVersions)]
+pub struct SquashedNoiseFheUint {
+    pub(in crate::high_level_api) inner: SquashedNoiseRadixCiphertext,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl SquashedNoiseFheUint {
+    pub fn decrypt<T>(&self, key: &NoiseSquashingPrivateKey) -> T
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        key.key.decrypt_radix(&self.inner)
+    }
+
+    pub fn into_raw_parts(self) -> (SquashedNoiseRadixCiphertext, Tag) {
+        let Self { inner, tag } = self;
+        (inner, tag)
+    }
+
+    pub fn from_raw_parts(inner: SquashedNoiseRadixCiphertext, tag: Tag) -> Self {
+        Self { inner, tag }
+    }
+}
+
+/// A [`FheInt`] whose noise was squashed, see [`FheInt::squash_noise`].
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseFheIntVersions)]
+pub struct SquashedNoiseFheInt {
+    pub(in crate::high_level_api) inner: SquashedNoiseSignedRadixCiphertext,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl SquashedNoiseFheInt {
+    pub fn decrypt<T>(&self, key: &NoiseSquashingPrivateKey) -> T
+    where
+        T: RecomposableSignedInteger,
+    {
+        key.key.decrypt_signed_radix(&self.inner)
+    }
+
+    pub fn into_raw_parts(self) -> (SquashedNoiseSignedRadixCiphertext, Tag) {
+        let Self { inner, tag } = self;
+        (inner, tag)
+    }
+
+    pub fn from_raw_parts(inner: SquashedNoiseSignedRadixCiphertext, tag: Tag) -> Self {
+        Self { inner, tag }
+    }
+}
+
+/// A [`FheBool`] whose noise was squashed, see [`FheBool::squash_noise`].
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseFheBoolVersions)]
+pub struct SquashedNoiseFheBool {
+    pub(in crate::high_level_api) inner: SquashedNoiseBooleanBlock,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl SquashedNoiseFheBool {
+    pub fn decrypt(&self, key: &NoiseSquashingPrivateKey) -> bool {
+        key.key.decrypt_bool(&self.inner)
+    }
+
+    pub fn into_raw_parts(self) -> (SquashedNoiseBooleanBlock, Tag) {
+        let Self { inner, tag } = self;
+        (inner, tag)
+    }
+
+    pub fn from_raw_parts(inner: SquashedNoiseBooleanBlock, tag: Tag) -> Self {
+        Self { inner, tag }
+    }
+}
+
+macro_rules! impl_named_and_tagged {
+    ($($name:ident),*) => {
+        $(
+            impl Named for $name {
+                const NAME: &'static str = concat!("high_level_api::", stringify!($name));
+            }
+
+            impl Tagged for $name {
+                fn tag(&self) -> &Tag {
+                    &self.tag
+                }
+
+                fn tag_mut(&mut self) -> &mut Tag {
+                    &mut self.tag
+                }
+            }
+        )*
+    };
+}
+
+impl_named_and_tagged!(
+    SquashedNoiseFheUint,
+    SquashedNoiseFheInt,
+    SquashedNoiseFheBool,
+    CompressedSquashedNoiseCiphertextList
+);
+
+impl<Id: FheUintId> FheUint<Id> {
+    /// Squashes the noise of the ciphertext, the result can only be decrypted with the
+    /// [`NoiseSquashingPrivateKey`] of `noise_squashing_key`.
+    ///
+    /// Squashed ciphertexts can be stored in a [`CompressedSquashedNoiseCiphertextList`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::{
+    ///     generate_keys, set_server_key, CompressedSquashedNoiseCiphertextListBuilder,
+    ///     ConfigBuilder, FheUint16, NoiseSquashingKey, NoiseSquashingPrivateKey,
+    ///     SquashedNoiseFheBool, SquashedNoiseFheUint,
+    /// };
+    ///
+    /// let config =
+    ///     ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let (client_key, server_key) = generate_keys(config);
+    /// let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+    ///     &client_key,
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    /// let noise_squashing_key = NoiseSquashingKey::new(&client_key, &noise_squashing_private_key);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(300u16, &client_key);
+    /// let b = FheUint16::encrypt(12u16, &client_key);
+    ///
+    /// let squashed_sum = (&a + &b).squash_noise(&noise_squashing_key);
+    /// let squashed_gt = a.gt(&b).squash_noise(&noise_squashing_key);
+    ///
+    /// let list = CompressedSquashedNoiseCiphertextListBuilder::new()
+    ///     .push(squashed_sum)
+    ///     .push(squashed_gt)
+    ///     .build(&noise_squashing_key);
+    ///
+    /// let sum: SquashedNoiseFheUint = list.get(0).unwrap().unwrap();
+    /// let gt: SquashedNoiseFheBool = list.get(1).unwrap().unwrap();
+    ///
+    /// let decrypted: u16 = sum.decrypt(&noise_squashing_private_key);
+    /// assert_eq!(decrypted, 312);
+    /// assert!(gt.decrypt(&noise_squashing_private_key));
+    /// ```
+    pub fn squash_noise(&self, noise_squashing_key: &NoiseSquashingKey) -> SquashedNoiseFheUint {
+        noise_squashing_key.with_cpu_server_key(|server_key, tag| SquashedNoiseFheUint {
+            inner: noise_squashing_key
+                .key
+                .squash_radix_ciphertext_noise(&*self.ciphertext.on_cpu(), server_key),
+            tag: tag.clone(),
+        })
+    }
+}
+
+impl<Id: FheIntId> FheInt<Id> {
+    /// Squashes the noise of the ciphertext, see [`FheUint::squash_noise`].
+    pub fn squash_noise(&self, noise_squashing_key: &NoiseSquashingKey) -> SquashedNoiseFheInt {
+        noise_squashing_key.with_cpu_server_key(|server_key, tag| SquashedNoiseFheInt {
+            inner: noise_squashing_key
+                .key
+                .squash_signed_radix_ciphertext_noise(&*self.ciphertext.on_cpu(), server_key),
+            tag: tag.clone(),
+        })
+    }
+}
+
+impl FheBool {
+    /// Squashes the noise of the ciphertext, see [`FheUint::squash_noise`].
+    pub fn squash_noise(&self, noise_squashing_key: &NoiseSquashingKey) -> SquashedNoiseFheBool {
+        noise_squashing_key.with_cpu_server_key(|server_key, tag| SquashedNoiseFheBool {
+            inner: noise_squashing_key
+                .key
+                .squash_boolean_block_noise(&*self.ciphertext.on_cpu(), server_key),
+            tag: tag.clone(),
+        })
+    }
+}
+
+fn num_bits_of(blocks: &[SquashedNoiseCiphertext], kind: DataKind) -> usize {
+    blocks.first().map_or(0, |block| {
+        block.message_modulus.0.ilog2() as usize * kind.num_blocks()
+    })
+}
+
+impl SquashedNoiseCompressible for SquashedNoiseFheUint {
+    fn compress_into(self, messages: &mut Vec<SquashedNoiseCiphertext>) -> DataKind {
+        self.inner.compress_into(messages)
+    }
+}
+
+impl SquashedNoiseCompressible for SquashedNoiseFheInt {
+    fn compress_into(self, messages: &mut Vec<SquashedNoiseCiphertext>) -> DataKind {
+        self.inner.compress_into(messages)
+    }
+}
+
+impl SquashedNoiseCompressible for SquashedNoiseFheBool {
+    fn compress_into(self, messages: &mut Vec<SquashedNoiseCiphertext>) -> DataKind {
+        self.inner.compress_into(messages)
+    }
+}
+
+// The list is responsible for setting the correct tag
+impl SquashedNoiseExpandable for SquashedNoiseFheUint {
+    fn from_expanded_blocks(
+        blocks: Vec<SquashedNoiseCiphertext>,
+        kind: DataKind,
+    ) -> crate::Result<Self> {
+        let num_bits = num_bits_of(&blocks, kind);
+        SquashedNoiseRadixCiphertext::from_expanded_blocks(blocks, kind)
+            .map(|inner| Self {
+                inner,
+                tag: Tag::default(),
+            })
+            .map_err(|_| {
+                crate::Error::new(format!(
+                    "Tried to expand a SquashedNoiseFheUint while a {kind:?} of {num_bits} bits \
+                    is stored in this slot"
+                ))
+            })
+    }
+}
+
+impl SquashedNoiseExpandable for SquashedNoiseFheInt {
+    fn from_expanded_blocks(
+        blocks: Vec<SquashedNoiseCiphertext>,
+        kind: DataKind,
+    ) -> crate::Result<Self> {
+        let num_bits = num_bits_of(&blocks, kind);
+        SquashedNoiseSignedRadixCiphertext::from_expanded_blocks(blocks, kind)
+            .map(|inner| Self {
+                inner,
+                tag: Tag::default(),
+            })
+            .map_err(|_| {
+                crate::Error::new(format!(
+                    "Tried to expand a SquashedNoiseFheInt while a {kind:?} of {num_bits} bits \
+                    is stored in this slot"
+                ))
+            })
+    }
+}
+
+impl SquashedNoiseExpandable for SquashedNoiseFheBool {
+    fn from_expanded_blocks(
+        blocks: Vec<SquashedNoiseCiphertext>,
+        kind: DataKind,
+    ) -> crate::Result<Self> {
+        SquashedNoiseBooleanBlock::from_expanded_blocks(blocks, kind).map(|inner| Self {
+            inner,
+            tag: Tag::default(),
+        })
+    }
+}
+
+pub struct CompressedSquashedNoiseCiphertextListBuilder {
+    inner: crate::integer::ciphertext::CompressedSquashedNoiseCiphertextListBuilder,
+}
+
+impl CompressedSquashedNoiseCiphertextListBuilder {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            inner: crate::integer::ciphertext::CompressedSquashedNoiseCiphertextListBuilder::new(),
+        }
+    }
+
+    pub fn push<T>(&mut self, value: T) -> &mut Self
+    where
+        T: SquashedNoiseCompressible,
+    {
+        self.inner.push(value);
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: SquashedNoiseCompressible,
+    {
+        self.inner.extend(values);
+        self
+    }
+
+    pub fn build(
+        &self,
+        noise_squashing_key: &NoiseSquashingKey,
+    ) -> CompressedSquashedNoiseCiphertextList {
+        CompressedSquashedNoiseCiphertextList {
+            inner: self.inner.build(&noise_squashing_key.key),
+            tag: noise_squashing_key.tag.clone(),
+        }
+    }
+}
+
+/// A list of squashed ciphertexts, stored with a reduced modulus, see
+/// [`NoiseSquashingParameters`](crate::shortint::parameters::NoiseSquashingParameters).
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedSquashedNoiseCiphertextListVersions)]
+pub struct CompressedSquashedNoiseCiphertextList {
+    pub(in crate::high_level_api) inner:
+        crate::integer::ciphertext::CompressedSquashedNoiseCiphertextList,
+    pub(in crate::high_level_api) tag: Tag,
+}
+
+impl CompressedSquashedNoiseCiphertextList {
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get<T>(&self, index: usize) -> crate::Result<Option<T>>
+    where
+        T: SquashedNoiseExpandable + Tagged,
+    {
+        let mut ct = self.inner.get::<T>(index);
+        if let Ok(Some(ct_ref)) = &mut ct {
+            ct_ref.tag_mut().set_data(self.tag.data());
+        }
+        ct
+    }
+
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::integer::ciphertext::CompressedSquashedNoiseCiphertextList,
+        Tag,
+    ) {
+        let Self { inner, tag } = self;
+        (inner, tag)
+    }
+
+    pub fn from_raw_parts(
+        inner: crate::integer::ciphertext::CompressedSquashedNoiseCiphertextList,
+        tag: Tag,
+    ) -> Self {
+        Self { inner, tag }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::{
+        generate_keys, set_server_key, CompressedSquashedNoiseCiphertextList,
+        CompressedSquashedNoiseCiphertextListBuilder, ConfigBuilder, FheBool, FheInt32, FheUint8,
+        NoiseSquashingKey, NoiseSquashingPrivateKey, SquashedNoiseFheBool, SquashedNoiseFheInt,
+        SquashedNoiseFheUint,
+    };
+
+    #[test]
+    fn test_squash_noise_and_compress() {
+        let config =
+            ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let (client_key, server_key) = generate_keys(config);
+        let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+            &client_key,
+            NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+        let noise_squashing_key = NoiseSquashingKey::new(&client_key, &noise_squashing_private_key);
+        set_server_key(server_key);
+
+        let a = FheUint8::encrypt(200u8, &client_key);
+        let b = FheInt32::encrypt(-123_456i32, &client_key);
+        let c = FheBool::encrypt(true, &client_key);
+
+        let squashed_a = (&a + 50u8).squash_noise(&noise_squashing_key);
+        let squashed_b = (&b * 2i32).squash_noise(&noise_squashing_key);
+        let squashed_c = (!&c).squash_noise(&noise_squashing_key);
+
+        let dec: u8 = squashed_a.decrypt(&noise_squashing_private_key);
+        assert_eq!(dec, 250u8);
+        let dec: i32 = squashed_b.decrypt(&noise_squashing_private_key);
+        assert_eq!(dec, -246_912i32);
+        assert!(!squashed_c.decrypt(&noise_squashing_private_key));
+
+        let list = CompressedSquashedNoiseCiphertextListBuilder::new()
+            .push(squashed_a)
+            .push(squashed_b)
+            .push(squashed_c)
+            .build(&noise_squashing_key);
+
+        let serialized = bincode::serialize(&list).unwrap();
+        let list: CompressedSquashedNoiseCiphertextList =
+            bincode::deserialize(&serialized).unwrap();
+        assert_eq!(list.len(), 3);
+
+        let a: SquashedNoiseFheUint = list.get(0).unwrap().unwrap();
+        let b: SquashedNoiseFheInt = list.get(1).unwrap().unwrap();
+        let c: SquashedNoiseFheBool = list.get(2).unwrap().unwrap();
+        assert_eq!(a.tag(), list.tag());
+
+        let dec: u8 = a.decrypt(&noise_squashing_private_key);
+        assert_eq!(dec, 250u8);
+        let dec: i32 = b.decrypt(&noise_squashing_private_key);
+        assert_eq!(dec, -246_912i32);
+        assert!(!c.decrypt(&noise_squashing_private_key));
+
+        assert!(list.get::<SquashedNoiseFheInt>(0).is_err());
+        assert!(list.get::<SquashedNoiseFheUint>(2).is_err());
+        assert!(list.get::<SquashedNoiseFheBool>(3).unwrap().is_none());
+    }
+}
@@ -116,6 +116,71 @@ fn test_with_seed() {
     assert_ne!(&cks1_serialized, &cks4_serialized);
 }
 
+#[test]
+fn test_derive() {
+    use crate::shortint::parameters::compact_public_key_only::p_fail_2_minus_64::ks_pbs::PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::key_switching::p_fail_2_minus_64::ks_pbs::PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::{DerivationPath, KeySwitchingKey, MasterSecret, ServerKey};
+
+    // Every kind of key is enabled so that all of them are checked to be reproducible
+    let config =
+        ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+            .use_dedicated_compact_public_key_parameters((
+                PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+                PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+            ))
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+            .build();
+    let master_secret = MasterSecret::generate();
+    let path: DerivationPath = "m/3/1".parse().unwrap();
+
+    let cks1 = ClientKey::derive(config, &master_secret, &path);
+    let cks2 = ClientKey::derive(config, &master_secret, &path);
+    let cks3 = ClientKey::derive(config, &master_secret, &path.child(0));
+    let cks4 = ClientKey::derive(
+        config,
+        &MasterSecret::from_bytes(master_secret.to_bytes()),
+        &path,
+    );
+
+    let cks1_serialized = bincode::serialize(&cks1).unwrap();
+    assert_eq!(cks1_serialized, bincode::serialize(&cks2).unwrap());
+    assert_ne!(cks1_serialized, bincode::serialize(&cks3).unwrap());
+    assert_eq!(cks1_serialized, bincode::serialize(&cks4).unwrap());
+
+    let compressed_sks1 = CompressedServerKey::derive(&cks1, &master_secret, &path);
+    let compressed_sks2 = CompressedServerKey::derive(&cks2, &master_secret, &path);
+    assert_eq!(
+        bincode::serialize(&compressed_sks1).unwrap(),
+        bincode::serialize(&compressed_sks2).unwrap()
+    );
+
+    let sks1 = ServerKey::derive(&cks1, &master_secret, &path);
+    let sks2 = ServerKey::derive(&cks2, &master_secret, &path);
+    assert_eq!(
+        bincode::serialize(&sks1).unwrap(),
+        bincode::serialize(&sks2).unwrap()
+    );
+
+    let sks3 = ServerKey::derive(&cks3, &master_secret, &path.child(0));
+    let ksk1 =
+        KeySwitchingKey::derive(&master_secret, &path, (&cks1, &sks1), (&cks3, &sks3)).unwrap();
+    let ksk2 =
+        KeySwitchingKey::derive(&master_secret, &path, (&cks2, &sks2), (&cks3, &sks3)).unwrap();
+    assert_eq!(
+        bincode::serialize(&ksk1).unwrap(),
+        bincode::serialize(&ksk2).unwrap()
+    );
+
+    let a = FheUint8::encrypt(27u8, &cks1);
+    let b = FheUint8::encrypt(100u8, &cks1);
+    let r = crate::high_level_api::with_server_key_as_context(sks1, move || a + b);
+    let d: u8 = r.decrypt(&cks2);
+    assert_eq!(d, 127);
+}
+
 #[test]
 fn test_with_context() {
     let config = ConfigBuilder::default().build();
@@ -0,0 +1,1206 @@
+//! Threshold decryption, where the secret key is split between several parties.
+//!
+//! The secret keys of a [ClientKey] are split with an additive (N-out-of-N) secret sharing over
+//! the ciphertext modulus: each of the N parties receives a [KeyShare], and the sum of the shares
+//! is the original key. No single party (nor any coalition of less than N parties) learns
+//! anything about the key from its shares.
+//!
+//! To decrypt a ciphertext, each party computes a [PartialDecryption] with its share, adding a
+//! smudging noise to it, so that the partial decryptions do not leak the share. All the N
+//! partial decryptions are then combined to recover the clear value.
+//!
+//! Shamir (t-out-of-N) sharing is not offered, as secrets live in the ring of integers modulo
+//! 2^64, which is not a field.
+//!
+//! # Supported ciphertexts
+//!
+//! [FheUint], [FheInt], [FheBool] and the elements of a [CompressedCiphertextList] are decrypted
+//! with the shares of the large (GLWE) and small (LWE) keys of the [ClientKey], the one used
+//! depending on the [PBSOrder] of the blocks.
+//!
+//! The ciphertexts whose noise was squashed by a [NoiseSquashingKey] ([SquashedNoiseFheUint],
+//! [SquashedNoiseFheInt], [SquashedNoiseFheBool] and the elements of a
+//! [CompressedSquashedNoiseCiphertextList]) are decrypted with the shares of the
+//! [NoiseSquashingPrivateKey], the [NoiseSquashingKeyShare]s, into
+//! [SquashedNoisePartialDecryption]s. The partial decryptions of both kinds are combined by the
+//! same functions.
+//!
+//! # Smudging
+//!
+//! The sum of the partial decryptions is `m·Δ + e + Σ e_i`, where `e` is the noise of the
+//! ciphertext, which depends on the secret keys, and `e_i` the smudging noise of party `i`. With
+//! `2^b` a bound on `|e|` and `e_i` drawn uniformly in `[-2^s, 2^s)`, the statistical distance
+//! between `e + e_i` and `e_i` is at most `2^(b-s)`: the partial decryption of an honest party
+//! hides `e` up to this distance, even if all the other parties collude.
+//!
+//! The `2^64` modulus of the regular ciphertexts leaves little room between their noise and `Δ`:
+//! their smudging noise is bounded such that the total smudging noise of the N parties stays
+//! under `Δ / 2^SMUDGING_MARGIN_LOG2` ([SMUDGING_MARGIN_LOG2]), which keeps the decryption
+//! correct but only gives a distance of a few bits after a PBS. Their partial decryptions
+//! **MUST** hence only be sent to parties trusted not to exploit them to recover the key shares.
+//!
+//! The noise of the squashed ciphertexts is tiny compared to their `2^128` modulus. Their
+//! smudging noise is drawn with `s = b + λ`, `λ =` [SMUDGING_STATISTICAL_SECURITY_LOG2], for a
+//! statistical distance of at most `2^-λ`. `2^b` is 16 times the standard deviation of the noise
+//! of the squashed ciphertexts (compressed or not), computed from the parameters of the noise
+//! squashing when sharing the key. The decryption is correct if `|e| + N·2^(b+λ) < Δ/2`, which
+//! holds when
+//!
+//! ```text
+//! b + λ + ceil(log2(N)) + 2 <= log2(Δ)
+//! ```
+//!
+//! The partial decryption of a squashed ciphertext not meeting this constraint is rejected. With
+//! [NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64], `b = 65` and `log2(Δ) = 123`
+//! for 2 bits of message and 2 bits of carry, up to 2^16 parties are supported.
+//!
+//! [NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64]: crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64
+//!
+//! ```rust
+//! use tfhe::prelude::*;
+//! use tfhe::threshold::{combine_unsigned, share_client_key, FhePartialDecrypt};
+//! use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+//!
+//! let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+//! set_server_key(server_key);
+//!
+//! let a = FheUint8::encrypt(27u8, &client_key);
+//! let b = FheUint8::encrypt(100u8, &client_key);
+//! let c = a + b;
+//!
+//! // The client key is split between 3 parties, then dropped
+//! let key_shares = share_client_key(&client_key, 3).unwrap();
+//! drop(client_key);
+//!
+//! // Each party partially decrypts `c` with its share
+//! let partial_decryptions = key_shares
+//!     .iter()
+//!     .map(|key_share| c.partial_decrypt(key_share))
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//!
+//! let clear: u8 = combine_unsigned(&partial_decryptions).unwrap();
+//! assert_eq!(clear, 127);
+//! ```
+//!
+//! With squashed ciphertexts:
+//!
+//! ```rust
+//! use tfhe::prelude::*;
+//! use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+//! use tfhe::threshold::{
+//!     combine_unsigned, share_noise_squashing_private_key, SquashedNoiseFhePartialDecrypt,
+//! };
+//! use tfhe::{
+//!     generate_keys, set_server_key, ConfigBuilder, FheUint8, NoiseSquashingKey,
+//!     NoiseSquashingPrivateKey,
+//! };
+//!
+//! let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+//! let (client_key, server_key) = generate_keys(config);
+//! let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+//!     &client_key,
+//!     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+//! );
+//! let noise_squashing_key = NoiseSquashingKey::new(&client_key, &noise_squashing_private_key);
+//! set_server_key(server_key);
+//!
+//! let a = FheUint8::encrypt(27u8, &client_key);
+//! let b = FheUint8::encrypt(100u8, &client_key);
+//! let c = (a + b).squash_noise(&noise_squashing_key);
+//!
+//! // The noise squashing private key is split between 3 parties, then dropped
+//! let key_shares =
+//!     share_noise_squashing_private_key(&noise_squashing_private_key, &noise_squashing_key, 3)
+//!         .unwrap();
+//! drop(noise_squashing_private_key);
+//!
+//! // Each party partially decrypts `c` with its share
+//! let partial_decryptions = key_shares
+//!     .iter()
+//!     .map(|key_share| c.partial_decrypt(key_share))
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//!
+//! let clear: u8 = combine_unsigned(&partial_decryptions).unwrap();
+//! assert_eq!(clear, 127);
+//! ```
+
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_dot_product;
+use crate::core_crypto::commons::math::random::{
+    ActivatedRandomGenerator, RandomGenerable, RandomGenerator, Uniform,
+};
+use crate::core_crypto::prelude::{
+    DynamicDistribution, LweDimension, LweSecretKey, LweSecretKeyOwned, UnsignedInteger,
+    UnsignedNumeric,
+};
+use crate::core_crypto::seeders::new_seeder;
+use crate::high_level_api::backward_compatibility::threshold::{
+    KeyShareVersions, NoiseSquashingKeyShareVersions, PartialDecryptionVersions,
+    SquashedNoisePartialDecryptionVersions,
+};
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::integer::ciphertext::{
+    blocks_per_squashed_block, DataKind, Expandable, SquashedNoiseExpandable,
+};
+use crate::integer::client_key::{sign_extend_partial_number, RecomposableSignedInteger};
+use crate::named::Named;
+use crate::prelude::Tagged;
+use crate::shortint::ciphertext::{Ciphertext, SquashedNoiseCiphertext};
+use crate::shortint::parameters::NoiseSquashingParameters;
+use crate::shortint::{CarryModulus, MessageModulus, PBSOrder};
+use crate::{
+    ClientKey, CompressedCiphertextList, CompressedSquashedNoiseCiphertextList, Error, FheBool,
+    FheInt, FheUint, NoiseSquashingKey, NoiseSquashingPrivateKey, SquashedNoiseFheBool,
+    SquashedNoiseFheInt, SquashedNoiseFheUint, Tag,
+};
+use concrete_csprng::seeders::Seeder;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// The total smudging noise added by the parties to the regular ciphertexts is at most
+/// `Δ / 2^SMUDGING_MARGIN_LOG2`.
+pub const SMUDGING_MARGIN_LOG2: u32 = 5;
+
+/// The smudging noise of each party hides the noise of the squashed ciphertexts up to a
+/// statistical distance of `2^-SMUDGING_STATISTICAL_SECURITY_LOG2`.
+///
+/// This is lower than the `2^-64` of the noise flooding of the sanitization and of the multi-key
+/// decryption: the smudging noise has to fit between the noise of the squashed ciphertexts and
+/// the scaling factor. With [NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64] the
+/// noise is bounded by `2^65` and `Δ = 2^123`, a `2^-64` statistical distance would need
+/// `65 + 64 + 2 = 131` bits even for a single party. 40 bits is the usual statistical security
+/// parameter of MPC protocols, and leaves room for up to `2^16` parties.
+///
+/// [NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64]: crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64
+pub const SMUDGING_STATISTICAL_SECURITY_LOG2: u32 = 40;
+
+/// The noise of the squashed ciphertexts is bounded by `2^4 = 16` times its standard deviation,
+/// which it exceeds with a probability below `2^-180`.
+const NOISE_BOUND_STD_DEV_FACTOR_LOG2: f64 = 4.0;
+
+/// The share of a [ClientKey] held by one party.
+///
+/// This key **MUST NOT** be sent to the other parties, nor to the server.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(KeyShareVersions)]
+pub struct KeyShare {
+    party: usize,
+    num_parties: usize,
+    /// Share of the GLWE secret key, seen as an LWE secret key
+    large_key_share: LweSecretKeyOwned<u64>,
+    /// Share of the LWE secret key used as the output of the keyswitch
+    small_key_share: LweSecretKeyOwned<u64>,
+}
+
+impl Named for KeyShare {
+    const NAME: &'static str = "high_level_api::threshold::KeyShare";
+}
+
+/// Splits the secret keys of `client_key` into `num_parties` additive shares.
+pub fn share_client_key(
+    client_key: &ClientKey,
+    num_parties: usize,
+) -> crate::Result<Vec<KeyShare>> {
+    check_num_parties(num_parties)?;
+
+    let shortint_key = &client_key.key.key.key;
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(new_seeder().seed());
+    let large_key_shares = share_secret_key(
+        shortint_key.large_lwe_secret_key().as_ref(),
+        num_parties,
+        &mut generator,
+    );
+    let small_key_shares = share_secret_key(
+        shortint_key.small_lwe_secret_key().as_ref(),
+        num_parties,
+        &mut generator,
+    );
+
+    Ok(large_key_shares
+        .into_iter()
+        .zip(small_key_shares)
+        .enumerate()
+        .map(|(party, (large_key_share, small_key_share))| KeyShare {
+            party,
+            num_parties,
+            large_key_share,
+            small_key_share,
+        })
+        .collect())
+}
+
+fn check_num_parties(num_parties: usize) -> crate::Result<()> {
+    if num_parties < 2 {
+        return Err(Error::new(format!(
+            "Threshold decryption needs at least 2 parties, got {num_parties}"
+        )));
+    }
+    Ok(())
+}
+
+/// The first `num_parties - 1` shares are uniformly random, the last one completes the sum.
+fn share_secret_key<Scalar: UnsignedInteger + RandomGenerable<Uniform>>(
+    secret_key: &[Scalar],
+    num_parties: usize,
+    generator: &mut RandomGenerator<ActivatedRandomGenerator>,
+) -> Vec<LweSecretKeyOwned<Scalar>> {
+    let mut last_share = secret_key.to_vec();
+    let mut shares = Vec::with_capacity(num_parties);
+
+    for _ in 1..num_parties {
+        let mut share = vec![Scalar::ZERO; secret_key.len()];
+        generator.fill_slice_with_random_uniform(&mut share);
+        for (last, share) in last_share.iter_mut().zip(share.iter()) {
+            *last = last.wrapping_sub(*share);
+        }
+        shares.push(LweSecretKey::from_container(share));
+    }
+    shares.push(LweSecretKey::from_container(last_share));
+
+    shares
+}
+
+/// Draws a smudging noise uniformly in `[-2^bound_log2, 2^bound_log2)`.
+fn smudging_noise<Scalar: UnsignedInteger + RandomGenerable<Uniform>>(
+    generator: &mut RandomGenerator<ActivatedRandomGenerator>,
+    bound_log2: u32,
+) -> Scalar {
+    let bound = Scalar::ONE << bound_log2 as usize;
+    (generator.random_uniform::<Scalar>() & ((bound << 1) - Scalar::ONE)).wrapping_sub(bound)
+}
+
+impl KeyShare {
+    pub(in crate::high_level_api) fn from_raw_parts(
+        party: usize,
+        num_parties: usize,
+        large_key_share: LweSecretKeyOwned<u64>,
+        small_key_share: LweSecretKeyOwned<u64>,
+    ) -> Self {
+        Self {
+            party,
+            num_parties,
+            large_key_share,
+            small_key_share,
+        }
+    }
+
+    /// Index of the party holding this share, in `0..num_parties`.
+    pub fn party(&self) -> usize {
+        self.party
+    }
+
+    pub fn num_parties(&self) -> usize {
+        self.num_parties
+    }
+
+    /// Partially decrypts every element of a list.
+    ///
+    /// The elements are decompressed using the decompression key of the server key set with
+    /// [crate::set_server_key].
+    pub fn partial_decrypt_list(
+        &self,
+        list: &CompressedCiphertextList,
+    ) -> crate::Result<Vec<PartialDecryption>> {
+        (0..list.len())
+            .map(|index| {
+                let element = list.get::<ExpandedBlocks>(index)?.ok_or_else(|| {
+                    Error::new(format!("No element at index {index} in the list"))
+                })?;
+                self.partial_decrypt_blocks(&element.blocks, element.kind)
+            })
+            .collect()
+    }
+
+    fn partial_decrypt_blocks(
+        &self,
+        blocks: &[Ciphertext],
+        kind: DataKind,
+    ) -> crate::Result<PartialDecryption> {
+        let (message_modulus, carry_modulus) = blocks
+            .first()
+            .map_or((MessageModulus(1), CarryModulus(1)), |block| {
+                (block.message_modulus, block.carry_modulus)
+            });
+
+        let smudging_bound_log2 = delta_log2(message_modulus, carry_modulus)
+            .saturating_sub(SMUDGING_MARGIN_LOG2)
+            .saturating_sub(self.num_parties.next_power_of_two().ilog2());
+
+        let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(new_seeder().seed());
+
+        let blocks = blocks
+            .iter()
+            .map(|block| {
+                if !block.ct.ciphertext_modulus().is_native_modulus() {
+                    return Err(Error::new(
+                        "Threshold decryption only supports the native ciphertext modulus"
+                            .to_string(),
+                    ));
+                }
+                let key_share = match block.pbs_order {
+                    PBSOrder::KeyswitchBootstrap => &self.large_key_share,
+                    PBSOrder::BootstrapKeyswitch => &self.small_key_share,
+                };
+                if block.ct.lwe_size().to_lwe_dimension() != key_share.lwe_dimension() {
+                    return Err(Error::new(
+                        "The ciphertext is not encrypted under the key of the key share"
+                            .to_string(),
+                    ));
+                }
+                let mask_key_dot_product =
+                    slice_wrapping_dot_product(block.ct.get_mask().as_ref(), key_share.as_ref());
+
+                let partial = smudging_noise::<u64>(&mut generator, smudging_bound_log2)
+                    .wrapping_sub(mask_key_dot_product);
+                // Only one of the parties adds the body
+                Ok(if self.party == 0 {
+                    partial.wrapping_add(*block.ct.get_body().data)
+                } else {
+                    partial
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(PartialDecryption {
+            party: self.party,
+            num_parties: self.num_parties,
+            kind,
+            message_modulus,
+            carry_modulus,
+            blocks,
+        })
+    }
+}
+
+/// log2 of the scaling factor of the messages, the padding bit being accounted for.
+fn delta_log2(message_modulus: MessageModulus, carry_modulus: CarryModulus) -> u32 {
+    63 - (message_modulus.0 * carry_modulus.0).ilog2()
+}
+
+/// log2 of the scaling factor of the squashed messages, the padding bit being accounted for.
+fn squashed_delta_log2(message_modulus: MessageModulus, carry_modulus: CarryModulus) -> u32 {
+    127 - (message_modulus.0 * carry_modulus.0).ilog2()
+}
+
+/// The contribution of one party to the decryption of a ciphertext.
+///
+/// It can be sent to whoever is allowed to learn the clear value, which combines the
+/// contributions of all parties with [combine_unsigned], [combine_signed] or [combine_bool].
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(PartialDecryptionVersions)]
+pub struct PartialDecryption {
+    party: usize,
+    num_parties: usize,
+    kind: DataKind,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    blocks: Vec<u64>,
+}
+
+impl Named for PartialDecryption {
+    const NAME: &'static str = "high_level_api::threshold::PartialDecryption";
+}
+
+impl PartialDecryption {
+    pub fn party(&self) -> usize {
+        self.party
+    }
+}
+
+/// Trait to partially decrypt a ciphertext with a [KeyShare].
+pub trait FhePartialDecrypt {
+    fn partial_decrypt(&self, key_share: &KeyShare) -> crate::Result<PartialDecryption>;
+}
+
+impl<Id: FheUintId> FhePartialDecrypt for FheUint<Id> {
+    fn partial_decrypt(&self, key_share: &KeyShare) -> crate::Result<PartialDecryption> {
+        let ciphertext = self.ciphertext.on_cpu();
+        key_share.partial_decrypt_blocks(
+            &ciphertext.blocks,
+            DataKind::Unsigned(ciphertext.blocks.len()),
+        )
+    }
+}
+
+impl<Id: FheIntId> FhePartialDecrypt for FheInt<Id> {
+    fn partial_decrypt(&self, key_share: &KeyShare) -> crate::Result<PartialDecryption> {
+        let ciphertext = self.ciphertext.on_cpu();
+        key_share.partial_decrypt_blocks(
+            &ciphertext.blocks,
+            DataKind::Signed(ciphertext.blocks.len()),
+        )
+    }
+}
+
+impl FhePartialDecrypt for FheBool {
+    fn partial_decrypt(&self, key_share: &KeyShare) -> crate::Result<PartialDecryption> {
+        let ciphertext = self.ciphertext.on_cpu();
+        key_share.partial_decrypt_blocks(std::slice::from_ref(&ciphertext.0), DataKind::Boolean)
+    }
+}
+
+/// The blocks of an element of a [CompressedCiphertextList], whatever its kind.
+struct ExpandedBlocks {
+    blocks: Vec<Ciphertext>,
+    kind: DataKind,
+    tag: Tag,
+}
+
+impl Expandable for ExpandedBlocks {
+    fn from_expanded_blocks(blocks: Vec<Ciphertext>, kind: DataKind) -> crate::Result<Self> {
+        Ok(Self {
+            blocks,
+            kind,
+            tag: Tag::default(),
+        })
+    }
+}
+
+impl Tagged for ExpandedBlocks {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+/// The share of a [NoiseSquashingPrivateKey] held by one party.
+///
+/// This key **MUST NOT** be sent to the other parties, nor to the server.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingKeyShareVersions)]
+pub struct NoiseSquashingKeyShare {
+    party: usize,
+    num_parties: usize,
+    /// Share of the GLWE secret key of the noise squashing, seen as an LWE secret key
+    key_share: LweSecretKeyOwned<u128>,
+    /// log2 of the bound on the noise of the squashed ciphertexts
+    noise_bound_log2: u32,
+}
+
+impl Named for NoiseSquashingKeyShare {
+    const NAME: &'static str = "high_level_api::threshold::NoiseSquashingKeyShare";
+}
+
+/// Splits the secret key of `noise_squashing_private_key` into `num_parties` additive shares, to
+/// decrypt the ciphertexts squashed by `noise_squashing_key`.
+pub fn share_noise_squashing_private_key(
+    noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    noise_squashing_key: &NoiseSquashingKey,
+    num_parties: usize,
+) -> crate::Result<Vec<NoiseSquashingKeyShare>> {
+    check_num_parties(num_parties)?;
+
+    let private_key = &noise_squashing_private_key.key.key;
+    let bootstrapping_key = &noise_squashing_key.key.key.bootstrapping_key;
+    let secret_key = private_key
+        .post_noise_squashing_secret_key
+        .as_lwe_secret_key();
+    if bootstrapping_key.output_lwe_dimension() != secret_key.lwe_dimension() {
+        return Err(Error::new(
+            "The noise squashing key does not match the noise squashing private key".to_string(),
+        ));
+    }
+
+    let noise_bound_log2 = squashed_noise_bound_log2(
+        bootstrapping_key.input_lwe_dimension(),
+        private_key.params,
+        1,
+    );
+    let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(new_seeder().seed());
+
+    Ok(
+        share_secret_key(secret_key.as_ref(), num_parties, &mut generator)
+            .into_iter()
+            .enumerate()
+            .map(|(party, key_share)| NoiseSquashingKeyShare {
+                party,
+                num_parties,
+                key_share,
+                noise_bound_log2,
+            })
+            .collect(),
+    )
+}
+
+/// Returns log2 of the bound on the noise of the ciphertexts squashed with `params`, see
+/// [NoiseSquashingParameters] for the noise analysis.
+///
+/// The noise of the bootstrapping key is the sum of `num_key_contributions` independent noises
+/// drawn from the distribution of the parameters.
+pub(in crate::high_level_api) fn squashed_noise_bound_log2(
+    input_lwe_dimension: LweDimension,
+    params: NoiseSquashingParameters,
+    num_key_contributions: usize,
+) -> u32 {
+    let modulus_square = 2f64.powi(256);
+    let key_noise_variance = match params.glwe_noise_distribution {
+        DynamicDistribution::Gaussian(gaussian) => gaussian.std * gaussian.std * modulus_square,
+        DynamicDistribution::TUniform(t_uniform) => {
+            (2f64.powi(2 * t_uniform.bound_log2() as i32 + 1) + 1.0) / 6.0
+        }
+    } * num_key_contributions as f64;
+
+    let n = input_lwe_dimension.0 as f64;
+    let k = params.glwe_dimension.0 as f64;
+    let big_n = params.polynomial_size.0 as f64;
+    let base = 2f64.powi(params.decomp_base_log.0 as i32);
+    let level = params.decomp_level_count.0 as f64;
+
+    let pbs_variance = n * level * (k + 1.0) * big_n * base * base / 12.0 * key_noise_variance
+        + n * (1.0 + k * big_n / 2.0) * modulus_square / (12.0 * base.powf(2.0 * level));
+    let storage_variance = (1.0 + k * big_n / 2.0) * modulus_square
+        / (12.0 * 2f64.powi(2 * params.storage_log_modulus.0 as i32));
+
+    ((pbs_variance + storage_variance).sqrt().log2() + NOISE_BOUND_STD_DEV_FACTOR_LOG2).ceil()
+        as u32
+}
+
+impl NoiseSquashingKeyShare {
+    pub(in crate::high_level_api) fn from_raw_parts(
+        party: usize,
+        num_parties: usize,
+        key_share: LweSecretKeyOwned<u128>,
+        noise_bound_log2: u32,
+    ) -> Self {
+        Self {
+            party,
+            num_parties,
+            key_share,
+            noise_bound_log2,
+        }
+    }
+
+    /// Index of the party holding this share, in `0..num_parties`.
+    pub fn party(&self) -> usize {
+        self.party
+    }
+
+    pub fn num_parties(&self) -> usize {
+        self.num_parties
+    }
+
+    /// log2 of the bound on the noise of the ciphertexts this share decrypts.
+    pub fn noise_bound_log2(&self) -> u32 {
+        self.noise_bound_log2
+    }
+
+    /// Partially decrypts every element of a list.
+    pub fn partial_decrypt_list(
+        &self,
+        list: &CompressedSquashedNoiseCiphertextList,
+    ) -> crate::Result<Vec<SquashedNoisePartialDecryption>> {
+        (0..list.len())
+            .map(|index| {
+                let element = list.get::<SquashedBlocks>(index)?.ok_or_else(|| {
+                    Error::new(format!("No element at index {index} in the list"))
+                })?;
+                self.partial_decrypt_blocks(&element.blocks, element.kind)
+            })
+            .collect()
+    }
+
+    fn partial_decrypt_blocks(
+        &self,
+        blocks: &[SquashedNoiseCiphertext],
+        kind: DataKind,
+    ) -> crate::Result<SquashedNoisePartialDecryption> {
+        let (message_modulus, carry_modulus) = blocks
+            .first()
+            .map_or((MessageModulus(1), CarryModulus(1)), |block| {
+                (block.message_modulus, block.carry_modulus)
+            });
+
+        let delta_log2 = squashed_delta_log2(message_modulus, carry_modulus);
+        let smudging_bound_log2 = self.noise_bound_log2 + SMUDGING_STATISTICAL_SECURITY_LOG2;
+        if smudging_bound_log2 + self.num_parties.next_power_of_two().ilog2() + 2 > delta_log2 {
+            return Err(Error::new(format!(
+                "The smudging noise of {} parties, bounded by 2^{smudging_bound_log2} each, does \
+                not fit below a scaling factor of 2^{delta_log2}",
+                self.num_parties
+            )));
+        }
+
+        let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(new_seeder().seed());
+
+        let blocks = blocks
+            .iter()
+            .map(|block| {
+                if block.ct.lwe_size().to_lwe_dimension() != self.key_share.lwe_dimension() {
+                    return Err(Error::new(
+                        "The ciphertext is not encrypted under the key of the key share"
+                            .to_string(),
+                    ));
+                }
+                let mask_key_dot_product = slice_wrapping_dot_product(
+                    block.ct.get_mask().as_ref(),
+                    self.key_share.as_ref(),
+                );
+
+                let partial = smudging_noise::<u128>(&mut generator, smudging_bound_log2)
+                    .wrapping_sub(mask_key_dot_product);
+                // Only one of the parties adds the body
+                Ok(if self.party == 0 {
+                    partial.wrapping_add(*block.ct.get_body().data)
+                } else {
+                    partial
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(SquashedNoisePartialDecryption {
+            party: self.party,
+            num_parties: self.num_parties,
+            kind,
+            message_modulus,
+            carry_modulus,
+            blocks,
+        })
+    }
+}
+
+/// The contribution of one party to the decryption of a squashed ciphertext.
+///
+/// It can be sent to whoever is allowed to learn the clear value, which combines the
+/// contributions of all parties with [combine_unsigned], [combine_signed] or [combine_bool].
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoisePartialDecryptionVersions)]
+pub struct SquashedNoisePartialDecryption {
+    party: usize,
+    num_parties: usize,
+    kind: DataKind,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    blocks: Vec<u128>,
+}
+
+impl Named for SquashedNoisePartialDecryption {
+    const NAME: &'static str = "high_level_api::threshold::SquashedNoisePartialDecryption";
+}
+
+impl SquashedNoisePartialDecryption {
+    pub fn party(&self) -> usize {
+        self.party
+    }
+}
+
+/// Trait to partially decrypt a squashed ciphertext with a [NoiseSquashingKeyShare].
+pub trait SquashedNoiseFhePartialDecrypt {
+    fn partial_decrypt(
+        &self,
+        key_share: &NoiseSquashingKeyShare,
+    ) -> crate::Result<SquashedNoisePartialDecryption>;
+}
+
+impl SquashedNoiseFhePartialDecrypt for SquashedNoiseFheUint {
+    fn partial_decrypt(
+        &self,
+        key_share: &NoiseSquashingKeyShare,
+    ) -> crate::Result<SquashedNoisePartialDecryption> {
+        key_share.partial_decrypt_blocks(
+            &self.inner.packed_blocks,
+            DataKind::Unsigned(self.inner.original_block_count),
+        )
+    }
+}
+
+impl SquashedNoiseFhePartialDecrypt for SquashedNoiseFheInt {
+    fn partial_decrypt(
+        &self,
+        key_share: &NoiseSquashingKeyShare,
+    ) -> crate::Result<SquashedNoisePartialDecryption> {
+        key_share.partial_decrypt_blocks(
+            &self.inner.packed_blocks,
+            DataKind::Signed(self.inner.original_block_count),
+        )
+    }
+}
+
+impl SquashedNoiseFhePartialDecrypt for SquashedNoiseFheBool {
+    fn partial_decrypt(
+        &self,
+        key_share: &NoiseSquashingKeyShare,
+    ) -> crate::Result<SquashedNoisePartialDecryption> {
+        key_share.partial_decrypt_blocks(
+            std::slice::from_ref(&self.inner.ciphertext),
+            DataKind::Boolean,
+        )
+    }
+}
+
+/// The squashed blocks of an element of a [CompressedSquashedNoiseCiphertextList], whatever its
+/// kind.
+struct SquashedBlocks {
+    blocks: Vec<SquashedNoiseCiphertext>,
+    kind: DataKind,
+    tag: Tag,
+}
+
+impl SquashedNoiseExpandable for SquashedBlocks {
+    fn from_expanded_blocks(
+        blocks: Vec<SquashedNoiseCiphertext>,
+        kind: DataKind,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            blocks,
+            kind,
+            tag: Tag::default(),
+        })
+    }
+}
+
+impl Tagged for SquashedBlocks {
+    fn tag(&self) -> &Tag {
+        &self.tag
+    }
+
+    fn tag_mut(&mut self) -> &mut Tag {
+        &mut self.tag
+    }
+}
+
+mod sealed {
+    use crate::integer::ciphertext::DataKind;
+    use crate::shortint::{CarryModulus, MessageModulus};
+
+    /// The fields of a partial decryption which must be the same for all the parties.
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    pub struct Header {
+        pub num_parties: usize,
+        pub kind: DataKind,
+        pub message_modulus: MessageModulus,
+        pub carry_modulus: CarryModulus,
+        pub num_blocks: usize,
+    }
+
+    pub trait Sealed: Sized {
+        fn party(&self) -> usize;
+
+        fn header(&self) -> Header;
+
+        /// Sums the partial decryptions of all the parties, already checked to be consistent,
+        /// and decodes the blocks of the message.
+        fn decode_blocks(partials: &[Self]) -> Vec<u64>;
+    }
+}
+
+/// The partial decryptions [combined](combine_unsigned) into a clear value:
+/// [PartialDecryption] and [SquashedNoisePartialDecryption].
+pub trait CombinablePartialDecryption: sealed::Sealed {}
+
+impl CombinablePartialDecryption for PartialDecryption {}
+
+impl CombinablePartialDecryption for SquashedNoisePartialDecryption {}
+
+impl sealed::Sealed for PartialDecryption {
+    fn party(&self) -> usize {
+        self.party
+    }
+
+    fn header(&self) -> sealed::Header {
+        sealed::Header {
+            num_parties: self.num_parties,
+            kind: self.kind,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            num_blocks: self.blocks.len(),
+        }
+    }
+
+    /// Decodes each block into its message and carry.
+    fn decode_blocks(partials: &[Self]) -> Vec<u64> {
+        let first = &partials[0];
+        let delta_log2 = delta_log2(first.message_modulus, first.carry_modulus);
+        (0..first.blocks.len())
+            .map(|index| {
+                let decrypted = partials
+                    .iter()
+                    .fold(0u64, |sum, partial| sum.wrapping_add(partial.blocks[index]));
+                // Rounds to the closest multiple of delta
+                let rounding = 1u64 << (delta_log2 - 1);
+                (decrypted.wrapping_add(rounding) >> delta_log2)
+                    & ((first.message_modulus.0 * first.carry_modulus.0) as u64 - 1)
+            })
+            .collect()
+    }
+}
+
+impl sealed::Sealed for SquashedNoisePartialDecryption {
+    fn party(&self) -> usize {
+        self.party
+    }
+
+    fn header(&self) -> sealed::Header {
+        sealed::Header {
+            num_parties: self.num_parties,
+            kind: self.kind,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            num_blocks: self.blocks.len(),
+        }
+    }
+
+    /// Decodes each squashed block into the blocks it packs.
+    fn decode_blocks(partials: &[Self]) -> Vec<u64> {
+        let first = &partials[0];
+        let delta_log2 = squashed_delta_log2(first.message_modulus, first.carry_modulus);
+        let decoded = (0..first.blocks.len()).map(|index| {
+            let decrypted = partials.iter().fold(0u128, |sum, partial| {
+                sum.wrapping_add(partial.blocks[index])
+            });
+            // Rounds to the closest multiple of delta
+            let rounding = 1u128 << (delta_log2 - 1);
+            (decrypted.wrapping_add(rounding) >> delta_log2)
+                & ((first.message_modulus.0 * first.carry_modulus.0) as u128 - 1)
+        });
+
+        match first.kind {
+            DataKind::Unsigned(block_count) | DataKind::Signed(block_count) => {
+                let message_modulus = first.message_modulus.0 as u128;
+                let blocks_per_squashed_block =
+                    blocks_per_squashed_block(first.message_modulus, first.carry_modulus);
+
+                let mut blocks = Vec::with_capacity(first.blocks.len() * blocks_per_squashed_block);
+                for mut packed in decoded {
+                    for _ in 0..blocks_per_squashed_block {
+                        blocks.push((packed % message_modulus) as u64);
+                        packed /= message_modulus;
+                    }
+                }
+                blocks.truncate(block_count);
+                blocks
+            }
+            DataKind::Boolean => decoded.map(|block| block as u64).collect(),
+        }
+    }
+}
+
+/// Checks that there is exactly one partial decryption per party, all of the same ciphertext,
+/// then sums them and decodes the blocks.
+fn combine_blocks<P: CombinablePartialDecryption>(
+    partials: &[P],
+) -> crate::Result<(DataKind, MessageModulus, Vec<u64>)> {
+    let Some(first) = partials.first() else {
+        return Err(Error::new("No partial decryption to combine".to_string()));
+    };
+    let header = first.header();
+
+    let mut parties = vec![false; header.num_parties];
+    for partial in partials {
+        if partial.header() != header {
+            return Err(Error::new(format!(
+                "Partial decryption of party {} does not match the one of party {}",
+                partial.party(),
+                first.party()
+            )));
+        }
+        match parties.get_mut(partial.party()) {
+            Some(seen) if !*seen => *seen = true,
+            _ => {
+                return Err(Error::new(format!(
+                    "Duplicated or invalid partial decryption of party {}",
+                    partial.party()
+                )))
+            }
+        }
+    }
+    if let Some(missing) = parties.iter().position(|seen| !seen) {
+        return Err(Error::new(format!(
+            "Missing the partial decryption of party {missing}"
+        )));
+    }
+
+    Ok((
+        header.kind,
+        header.message_modulus,
+        P::decode_blocks(partials),
+    ))
+}
+
+fn recompose<T: RecomposableFrom<u64>>(blocks: &[u64], message_modulus: MessageModulus) -> T {
+    let mut recomposer = BlockRecomposer::<T>::new(message_modulus.0.ilog2());
+    for block in blocks {
+        if !recomposer.add_unmasked(*block) {
+            break;
+        }
+    }
+    recomposer.value()
+}
+
+/// Combines the partial decryptions of an [FheUint] from all the parties.
+pub fn combine_unsigned<T>(partials: &[impl CombinablePartialDecryption]) -> crate::Result<T>
+where
+    T: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    match combine_blocks(partials)? {
+        (DataKind::Unsigned(_), message_modulus, blocks) => Ok(recompose(&blocks, message_modulus)),
+        (kind, _, _) => Err(Error::new(format!(
+            "Expected the partial decryption of an unsigned integer, got {kind:?}"
+        ))),
+    }
+}
+
+/// Combines the partial decryptions of an [FheInt] from all the parties.
+pub fn combine_signed<T>(partials: &[impl CombinablePartialDecryption]) -> crate::Result<T>
+where
+    T: RecomposableSignedInteger,
+{
+    match combine_blocks(partials)? {
+        (DataKind::Signed(_), message_modulus, blocks) => {
+            let unpadded_value = recompose(&blocks, message_modulus);
+            let num_bits = message_modulus.0.ilog2() * blocks.len() as u32;
+            Ok(sign_extend_partial_number(unpadded_value, num_bits))
+        }
+        (kind, _, _) => Err(Error::new(format!(
+            "Expected the partial decryption of a signed integer, got {kind:?}"
+        ))),
+    }
+}
+
+/// Combines the partial decryptions of an [FheBool] from all the parties.
+pub fn combine_bool(partials: &[impl CombinablePartialDecryption]) -> crate::Result<bool> {
+    match combine_blocks(partials)? {
+        (DataKind::Boolean, message_modulus, blocks) => {
+            Ok(blocks[0] % message_modulus.0 as u64 != 0)
+        }
+        (kind, _, _) => Err(Error::new(format!(
+            "Expected the partial decryption of a boolean, got {kind:?}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::{
+        COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    };
+    use crate::{
+        generate_keys, set_server_key, CompressedCiphertextListBuilder,
+        CompressedSquashedNoiseCiphertextListBuilder, ConfigBuilder, FheInt16, FheUint32,
+    };
+
+    fn partial_decrypt_all<T: FhePartialDecrypt>(
+        ct: &T,
+        key_shares: &[KeyShare],
+    ) -> Vec<PartialDecryption> {
+        key_shares
+            .iter()
+            .map(|key_share| ct.partial_decrypt(key_share).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn threshold_decryption() {
+        for params in [
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+        ] {
+            let config = ConfigBuilder::with_custom_parameters(params).build();
+            let client_key = ClientKey::generate(config);
+            set_server_key(client_key.generate_server_key());
+            let key_shares = share_client_key(&client_key, 4).unwrap();
+
+            let a = FheUint32::encrypt(u32::MAX - 5, &client_key);
+            let partials = partial_decrypt_all(&a, &key_shares);
+            assert_eq!(combine_unsigned::<u32>(&partials).unwrap(), u32::MAX - 5);
+            assert_eq!(
+                combine_unsigned::<u64>(&partials).unwrap(),
+                u64::from(u32::MAX - 5)
+            );
+
+            // After PBS, the noise is the one of the bootstrapped blocks
+            let b = FheInt16::encrypt(-1234i16, &client_key) * 2i16;
+            let partials = partial_decrypt_all(&b, &key_shares);
+            assert_eq!(combine_signed::<i16>(&partials).unwrap(), -2468);
+            assert_eq!(combine_signed::<i64>(&partials).unwrap(), -2468);
+            assert!(combine_unsigned::<u16>(&partials).is_err());
+
+            let c = FheBool::encrypt(true, &client_key);
+            let partials = partial_decrypt_all(&c, &key_shares);
+            assert!(combine_bool(&partials).unwrap());
+
+            // All parties are needed
+            assert!(combine_bool(&partials[1..]).is_err());
+            let duplicated = [partials.clone(), vec![partials[0].clone()]].concat();
+            assert!(combine_bool(&duplicated).is_err());
+        }
+
+        assert!(share_client_key(&ClientKey::generate(ConfigBuilder::default()), 1).is_err());
+    }
+
+    #[test]
+    fn threshold_decryption_with_a_mismatched_key_share() {
+        let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS).build();
+        let client_key = ClientKey::generate(config);
+        let key_shares = share_client_key(&client_key, 2).unwrap();
+        let a = FheUint32::encrypt(17u32, &client_key);
+
+        let truncated_key_share = KeyShare {
+            large_key_share: LweSecretKey::from_container(
+                key_shares[0].large_key_share.as_ref()[1..].to_vec(),
+            ),
+            ..key_shares[0].clone()
+        };
+        assert!(a.partial_decrypt(&truncated_key_share).is_err());
+    }
+
+    #[test]
+    fn threshold_decryption_of_compressed_list() {
+        let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+            .build();
+        let client_key = ClientKey::generate(config);
+        set_server_key(client_key.generate_server_key());
+        let key_shares = share_client_key(&client_key, 3).unwrap();
+
+        let a = FheUint32::encrypt(17u32, &client_key);
+        let b = FheInt16::encrypt(-3i16, &client_key);
+        let c = FheBool::encrypt(false, &client_key);
+        let list = CompressedCiphertextListBuilder::new()
+            .push(&a + &a)
+            .push(b)
+            .push(c)
+            .build()
+            .unwrap();
+
+        let partials_per_party = key_shares
+            .iter()
+            .map(|key_share| key_share.partial_decrypt_list(&list).unwrap())
+            .collect::<Vec<_>>();
+        let partials_of = |index: usize| {
+            partials_per_party
+                .iter()
+                .map(|partials| partials[index].clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(combine_unsigned::<u32>(&partials_of(0)).unwrap(), 34);
+        assert_eq!(combine_signed::<i16>(&partials_of(1)).unwrap(), -3);
+        assert!(!combine_bool(&partials_of(2)).unwrap());
+    }
+
+    fn squashed_keys(
+        num_parties: usize,
+    ) -> (ClientKey, NoiseSquashingKey, Vec<NoiseSquashingKeyShare>) {
+        let config =
+            ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let (client_key, server_key) = generate_keys(config);
+        set_server_key(server_key);
+
+        let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+            &client_key,
+            NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+        let noise_squashing_key = NoiseSquashingKey::new(&client_key, &noise_squashing_private_key);
+        let key_shares = share_noise_squashing_private_key(
+            &noise_squashing_private_key,
+            &noise_squashing_key,
+            num_parties,
+        )
+        .unwrap();
+
+        (client_key, noise_squashing_key, key_shares)
+    }
+
+    fn squashed_partial_decrypt_all<T: SquashedNoiseFhePartialDecrypt>(
+        ct: &T,
+        key_shares: &[NoiseSquashingKeyShare],
+    ) -> Vec<SquashedNoisePartialDecryption> {
+        key_shares
+            .iter()
+            .map(|key_share| ct.partial_decrypt(key_share).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn threshold_decryption_of_squashed_ciphertexts() {
+        let (client_key, noise_squashing_key, key_shares) = squashed_keys(4);
+        // 16 times the standard deviation of about 2^60.1 given in the parameters
+        assert_eq!(key_shares[0].noise_bound_log2(), 65);
+
+        let a = FheUint32::encrypt(u32::MAX - 5, &client_key).squash_noise(&noise_squashing_key);
+        let partials = squashed_partial_decrypt_all(&a, &key_shares);
+        assert_eq!(combine_unsigned::<u32>(&partials).unwrap(), u32::MAX - 5);
+        assert_eq!(
+            combine_unsigned::<u64>(&partials).unwrap(),
+            u64::from(u32::MAX - 5)
+        );
+
+        let b =
+            (FheInt16::encrypt(-1234i16, &client_key) * 2i16).squash_noise(&noise_squashing_key);
+        let partials = squashed_partial_decrypt_all(&b, &key_shares);
+        assert_eq!(combine_signed::<i16>(&partials).unwrap(), -2468);
+        assert_eq!(combine_signed::<i64>(&partials).unwrap(), -2468);
+        assert!(combine_unsigned::<u16>(&partials).is_err());
+
+        let c = FheBool::encrypt(true, &client_key).squash_noise(&noise_squashing_key);
+        let partials = squashed_partial_decrypt_all(&c, &key_shares);
+        assert!(combine_bool(&partials).unwrap());
+
+        // All parties are needed
+        assert!(combine_bool(&partials[1..]).is_err());
+        let duplicated = [partials.clone(), vec![partials[0].clone()]].concat();
+        assert!(combine_bool(&duplicated).is_err());
+    }
+
+    #[test]
+    fn threshold_decryption_of_squashed_ciphertexts_without_room_for_smudging() {
+        let (client_key, noise_squashing_key, key_shares) = squashed_keys(2);
+        let a = FheUint32::encrypt(17u32, &client_key).squash_noise(&noise_squashing_key);
+
+        // 65 + 40 + 1 + 2 <= 123
+        assert!(a.partial_decrypt(&key_shares[0]).is_ok());
+
+        // A noise bound larger by 16 bits does not leave room for the smudging noise
+        let noisy_key_share = NoiseSquashingKeyShare {
+            noise_bound_log2: key_shares[0].noise_bound_log2 + 16,
+            ..key_shares[0].clone()
+        };
+        assert!(a.partial_decrypt(&noisy_key_share).is_err());
+
+        // Neither do 2^17 parties
+        let crowded_key_share = NoiseSquashingKeyShare {
+            num_parties: 1 << 17,
+            ..key_shares[0].clone()
+        };
+        assert!(a.partial_decrypt(&crowded_key_share).is_err());
+    }
+
+    #[test]
+    fn threshold_decryption_of_compressed_squashed_list() {
+        let (client_key, noise_squashing_key, key_shares) = squashed_keys(3);
+
+        let a = FheUint32::encrypt(17u32, &client_key);
+        let b = FheInt16::encrypt(-3i16, &client_key);
+        let c = FheBool::encrypt(false, &client_key);
+        let list = CompressedSquashedNoiseCiphertextListBuilder::new()
+            .push((&a + &a).squash_noise(&noise_squashing_key))
+            .push(b.squash_noise(&noise_squashing_key))
+            .push(c.squash_noise(&noise_squashing_key))
+            .build(&noise_squashing_key);
+
+        let partials_per_party = key_shares
+            .iter()
+            .map(|key_share| key_share.partial_decrypt_list(&list).unwrap())
+            .collect::<Vec<_>>();
+        let partials_of = |index: usize| {
+            partials_per_party
+                .iter()
+                .map(|partials| partials[index].clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(combine_unsigned::<u32>(&partials_of(0)).unwrap(), 34);
+        assert_eq!(combine_signed::<i16>(&partials_of(1)).unwrap(), -3);
+        assert!(!combine_bool(&partials_of(2)).unwrap());
+    }
+}
@@ -1,6 +1,32 @@
-use crate::zk::CompactPkeCrs;
+use crate::shortint::parameters::CompactPublicKeyEncryptionParameters;
+use crate::zk::{CompactPkeBn254Crs, CompactPkeCrs, DecryptionCrs, GlwePkeCrs};
 use crate::{Config, Error};
 
+/// Returns the compact encryption parameters of the config and how many messages a proof must
+/// cover for `max_bit_size` bits, assuming that packing is applied.
+fn packed_crs_parameters(
+    config: Config,
+    max_bit_size: usize,
+) -> crate::Result<(CompactPublicKeyEncryptionParameters, usize)> {
+    let compact_encryption_parameters = config.public_key_encryption_parameters()?;
+
+    if compact_encryption_parameters.carry_modulus.0
+        < compact_encryption_parameters.message_modulus.0
+    {
+        return Err(Error::new(
+            "In order to build a ZK-CRS for packed compact ciphertext list encryption, \
+            parameters must have CarryModulus >= MessageModulus"
+                .to_string(),
+        ));
+    }
+
+    let carry_and_message_bit_capacity = (compact_encryption_parameters.carry_modulus.0
+        * compact_encryption_parameters.message_modulus.0)
+        .ilog2() as usize;
+    let max_num_message = max_bit_size.div_ceil(carry_and_message_bit_capacity);
+    Ok((compact_encryption_parameters, max_num_message))
+}
+
 impl CompactPkeCrs {
     /// Create a new `CompactPkeCrs` from a `Config` object.
     /// max_bit_size is the maximum number of bits that can be proven, e.g. 64 for a single
@@ -8,23 +34,54 @@ impl CompactPkeCrs {
     ///
     /// This function assumes that packing will be applied during ZK proof.
     pub fn from_config(config: Config, max_bit_size: usize) -> crate::Result<Self> {
-        let compact_encryption_parameters = config.public_key_encryption_parameters()?;
+        let (compact_encryption_parameters, max_num_message) =
+            packed_crs_parameters(config, max_bit_size)?;
+        let crs = Self::from_shortint_params(compact_encryption_parameters, max_num_message)?;
+        Ok(crs)
+    }
+}
 
-        if compact_encryption_parameters.carry_modulus.0
-            < compact_encryption_parameters.message_modulus.0
+impl CompactPkeBn254Crs {
+    /// Create a new `CompactPkeBn254Crs` from a `Config` object.
+    ///
+    /// See [`CompactPkeCrs::from_config`].
+    pub fn from_config(config: Config, max_bit_size: usize) -> crate::Result<Self> {
+        let (compact_encryption_parameters, max_num_message) =
+            packed_crs_parameters(config, max_bit_size)?;
+        let crs = Self::from_shortint_params(compact_encryption_parameters, max_num_message)?;
+        Ok(crs)
+    }
+}
+
+impl GlwePkeCrs {
+    /// Create a new `GlwePkeCrs` from a `Config` object, used to prove and verify a
+    /// [ProvenGlweCiphertextList](crate::ProvenGlweCiphertextList).
+    ///
+    /// A single proof covers as many blocks as the polynomial size of the parameters.
+    pub fn from_config(config: Config) -> crate::Result<Self> {
+        Self::from_shortint_params(config.inner.block_parameters)
+    }
+}
+
+impl DecryptionCrs {
+    /// Create a new `DecryptionCrs` from a `Config` object, used to prove and verify decryptions
+    /// of [FheUint](crate::FheUint) values.
+    ///
+    /// Decryptions are proven against the [CompactPublicKey](crate::CompactPublicKey) of the
+    /// client key, so the config must not use dedicated compact public key parameters.
+    pub fn from_config(config: Config) -> crate::Result<Self> {
+        if config
+            .inner
+            .dedicated_compact_public_key_parameters
+            .is_some()
         {
             return Err(Error::new(
-                "In order to build a ZK-CRS for packed compact ciphertext list encryption, \
-                parameters must have CarryModulus >= MessageModulus"
+                "Decryption proofs require the compact public key to encrypt under the \
+                client key, dedicated compact public key parameters are not supported"
                     .to_string(),
             ));
         }
 
-        let carry_and_message_bit_capacity = (compact_encryption_parameters.carry_modulus.0
-            * compact_encryption_parameters.message_modulus.0)
-            .ilog2() as usize;
-        let max_num_message = max_bit_size.div_ceil(carry_and_message_bit_capacity);
-        let crs = Self::from_shortint_params(compact_encryption_parameters, max_num_message)?;
-        Ok(crs)
+        Self::from_shortint_params(config.inner.block_parameters)
     }
 }
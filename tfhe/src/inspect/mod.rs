@@ -0,0 +1,511 @@
+//! Inspection of serialized objects
+//!
+//! [inspect] reads an object serialized with [safe_serialize](crate::safe_serialize) or
+//! [safe_serialize_versioned](crate::safe_serialize_versioned) and reports what it is and its
+//! main parameters, without building the object in memory. This allows to inspect large keys
+//! with a small memory footprint.
+//!
+//! The parameters are found heuristically from the serialized data: every integer wrapped in a
+//! parameter newtype (`LweDimension`, `PolynomialSize`, ...) is reported with the path of fields
+//! leading to it, see [ObjectInfo::parameters]. An object can hold several values for the same
+//! parameter type, e.g. a server key holds the input and output dimensions of its key switching
+//! key, so the summary fields of [ObjectInfo] only give the first value found and should not be
+//! relied upon to identify a parameter set.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::inspect::inspect;
+//! use tfhe::prelude::*;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//! use tfhe::{safe_serialize, ClientKey, ConfigBuilder, FheUint8};
+//!
+//! let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//! let config = ConfigBuilder::with_custom_parameters(params).build();
+//! let mut client_key = ClientKey::generate(config);
+//! client_key.tag_mut().set_u64(42);
+//!
+//! let ct = FheUint8::encrypt(12u8, &client_key);
+//!
+//! let mut buffer = vec![];
+//! safe_serialize(&ct, &mut buffer, 1 << 20).unwrap();
+//!
+//! let info = inspect(buffer.as_slice(), 1 << 20).unwrap();
+//! assert_eq!(info.header.type_name, "high_level_api::FheUint");
+//! assert_eq!(info.polynomial_size, None);
+//! assert_eq!(info.message_modulus, Some(params.message_modulus));
+//! // 8 bits in blocks of 2 bits
+//! assert_eq!(info.list_len, Some(4));
+//! assert_eq!(info.tag.unwrap().as_u64(), 42);
+//! ```
+
+mod tracer;
+
+use crate::core_crypto::prelude::{GlweDimension, LweDimension, PolynomialSize};
+use crate::named::Named;
+use crate::safe_deserialization::{read_serialization_header, SerializedObjectHeader};
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::Tag;
+use bincode::Options;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use tfhe_versionable::VersionizeOwned;
+use tracer::{Trace, TraceDeserializer};
+
+/// A parameter value found in a serialized object
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterValue {
+    /// The name of the newtype wrapping the value, e.g. `LweDimension`
+    pub name: &'static str,
+    /// The names of the fields leading to the value, separated by dots
+    pub path: String,
+    pub value: u64,
+}
+
+/// Information about a serialized object, as returned by [inspect]
+///
+/// The parameters are only filled for the types known by this version of TFHE-rs (see
+/// [ObjectInfo::is_known_type]), and if they are relevant for the type.
+///
+/// The summary fields (`lwe_dimension`, `polynomial_size`, ...) hold the first value found in
+/// the serialized data for their type, which is a heuristic: an object holding several values
+/// for a type only reports one of them there. All the values are listed in
+/// [ObjectInfo::parameters].
+#[derive(Clone, Debug)]
+pub struct ObjectInfo {
+    /// The header written by the safe serialization functions
+    pub header: SerializedObjectHeader,
+    /// The size of the serialized object in bytes, excluding the header
+    pub serialized_size: u64,
+    /// Every distinct value of a parameter newtype found in the object, with the path of fields
+    /// leading to it, in the order they were found
+    pub parameters: Vec<ParameterValue>,
+    pub lwe_dimension: Option<LweDimension>,
+    pub glwe_dimension: Option<GlweDimension>,
+    pub polynomial_size: Option<PolynomialSize>,
+    pub message_modulus: Option<MessageModulus>,
+    pub carry_modulus: Option<CarryModulus>,
+    /// For lists, the number of values stored. For integers, the number of blocks.
+    pub list_len: Option<usize>,
+    pub tag: Option<Tag>,
+    known_type: bool,
+}
+
+impl ObjectInfo {
+    /// Returns `true` if the serialized type is known by this version of TFHE-rs, in which case
+    /// its parameters have been read.
+    pub fn is_known_type(&self) -> bool {
+        self.known_type
+    }
+
+    /// Returns the distinct values found for the parameter newtype with this name, in the order
+    /// they were found
+    pub fn values_of(&self, name: &str) -> Vec<u64> {
+        let mut values = Vec::new();
+        for parameter in self.parameters.iter().filter(|param| param.name == name) {
+            if !values.contains(&parameter.value) {
+                values.push(parameter.value);
+            }
+        }
+        values
+    }
+}
+
+impl Display for ObjectInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "type: {}", self.header.type_name)?;
+        writeln!(
+            f,
+            "format: {} {}",
+            if self.header.versioned {
+                "versioned"
+            } else {
+                "unversioned"
+            },
+            self.header.format_version
+        )?;
+        writeln!(f, "serialized size: {} bytes", self.serialized_size)?;
+
+        if !self.known_type {
+            return writeln!(f, "parameters: unknown type");
+        }
+
+        if let Some(lwe_dimension) = self.lwe_dimension {
+            writeln!(f, "lwe dimension: {}", lwe_dimension.0)?;
+        }
+        if let Some(glwe_dimension) = self.glwe_dimension {
+            writeln!(f, "glwe dimension: {}", glwe_dimension.0)?;
+        }
+        if let Some(polynomial_size) = self.polynomial_size {
+            writeln!(f, "polynomial size: {}", polynomial_size.0)?;
+        }
+        if let Some(message_modulus) = self.message_modulus {
+            writeln!(f, "message modulus: {}", message_modulus.0)?;
+        }
+        if let Some(carry_modulus) = self.carry_modulus {
+            writeln!(f, "carry modulus: {}", carry_modulus.0)?;
+        }
+        if let Some(list_len) = self.list_len {
+            writeln!(f, "length: {list_len}")?;
+        }
+        if let Some(tag) = self.tag.as_ref() {
+            write!(f, "tag: 0x")?;
+            for byte in tag.data() {
+                write!(f, "{byte:02x}")?;
+            }
+            writeln!(f)?;
+        }
+
+        if !self.parameters.is_empty() {
+            writeln!(f, "parameters by field:")?;
+            for parameter in &self.parameters {
+                let path = if parameter.path.is_empty() {
+                    "<root>"
+                } else {
+                    parameter.path.as_str()
+                };
+                writeln!(f, "  {path}: {}({})", parameter.name, parameter.value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads an object serialized with [safe_serialize](crate::safe_serialize) or
+/// [safe_serialize_versioned](crate::safe_serialize_versioned) and returns information about
+/// it.
+///
+/// The whole object is read from the `reader` but it is not kept in memory: the bulk of its
+/// content is discarded as it is read. The object is not validated beyond what is needed to read
+/// it.
+///
+/// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
+/// (excluding version and name serialization).
+pub fn inspect(reader: impl Read, serialized_size_limit: u64) -> Result<ObjectInfo, String> {
+    let mut reader = CountingReader {
+        inner: reader,
+        count: 0,
+    };
+
+    let header = read_serialization_header(&mut reader)?;
+    let header_size = reader.count;
+
+    let trace = trace_known_type(&header, &mut reader, serialized_size_limit)?;
+
+    if trace.is_none() {
+        std::io::copy(&mut reader, &mut std::io::sink()).map_err(|err| err.to_string())?;
+    }
+
+    let serialized_size = reader.count - header_size;
+
+    let Some((trace, list_fields)) = trace else {
+        return Ok(ObjectInfo {
+            header,
+            serialized_size,
+            parameters: Vec::new(),
+            lwe_dimension: None,
+            glwe_dimension: None,
+            polynomial_size: None,
+            message_modulus: None,
+            carry_modulus: None,
+            list_len: None,
+            tag: None,
+            known_type: false,
+        });
+    };
+
+    let value = |newtype: &str| trace.value(newtype).map(|value| value as usize);
+
+    let parameters = trace
+        .values()
+        .map(|(name, path, value)| ParameterValue {
+            name,
+            path: path.join("."),
+            value,
+        })
+        .collect();
+
+    Ok(ObjectInfo {
+        header,
+        serialized_size,
+        parameters,
+        lwe_dimension: value("LweDimension")
+            .or_else(|| value("LweSize").and_then(|size| size.checked_sub(1)))
+            // Non-seeded LWE ciphertexts only store their data, from which the dimension is
+            // deduced. Other entities store their data in a different layout but have a size field
+            .or_else(|| {
+                trace
+                    .struct_sequence_length("LweCiphertext", "data")
+                    .and_then(|size| size.checked_sub(1))
+            })
+            .map(LweDimension),
+        glwe_dimension: value("GlweDimension")
+            .or_else(|| value("GlweSize").and_then(|size| size.checked_sub(1)))
+            .map(GlweDimension),
+        polynomial_size: value("PolynomialSize").map(PolynomialSize),
+        message_modulus: value("MessageModulus").map(MessageModulus),
+        carry_modulus: value("CarryModulus").map(CarryModulus),
+        list_len: list_fields
+            .iter()
+            .find_map(|field| trace.sequence_length(field)),
+        tag: trace.tag().map(|data| {
+            let mut tag = Tag::default();
+            tag.set_data(data);
+            tag
+        }),
+        known_type: true,
+    })
+}
+
+/// Deserializes a `T` with a [TraceDeserializer], reading the format given by the header
+fn trace<T>(
+    header: &SerializedObjectHeader,
+    reader: impl Read,
+    serialized_size_limit: u64,
+) -> Result<Trace, String>
+where
+    T: DeserializeOwned + VersionizeOwned,
+    T::VersionedOwned: DeserializeOwned,
+{
+    let trace = RefCell::new(Trace::default());
+    let options = bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_limit(serialized_size_limit);
+    let mut deserializer = bincode::Deserializer::with_reader(reader, options);
+
+    let tracer = TraceDeserializer::new(&mut deserializer, &trace);
+    let result = if header.versioned {
+        T::VersionedOwned::deserialize(tracer).map(drop)
+    } else {
+        T::deserialize(tracer).map(drop)
+    };
+    result.map_err(|err| err.to_string())?;
+
+    Ok(trace.into_inner())
+}
+
+/// Traces the object if its type is known, returning the trace and the names of the fields
+/// holding the list of values for this type
+fn trace_known_type(
+    header: &SerializedObjectHeader,
+    reader: impl Read,
+    serialized_size_limit: u64,
+) -> Result<Option<(Trace, &'static [&'static str])>, String> {
+    macro_rules! trace_types {
+        ($($ty:ty => $list_fields:expr),* $(,)?) => {
+            $(
+                if header.type_name == <$ty as Named>::NAME {
+                    return trace::<$ty>(header, reader, serialized_size_limit)
+                        .map(|trace| Some((trace, $list_fields)));
+                }
+            )*
+        };
+    }
+
+    // Types that are generic over an Id share the same name and representation, so any Id can
+    // be used to read them
+    trace_types!(
+        crate::shortint::Ciphertext => &[],
+        crate::FheBool => &[],
+        crate::CompressedFheBool => &[],
+        crate::FheUint8 => &["blocks"],
+        crate::CompressedFheUint8 => &["blocks", "paired_blocks"],
+        crate::FheInt8 => &["blocks"],
+        crate::CompressedFheInt8 => &["blocks", "paired_blocks"],
+        crate::CompactCiphertextList => &["info"],
+        crate::CompressedCiphertextList => &["info"],
+        crate::ClientKey => &[],
+        crate::ServerKey => &[],
+        crate::CompressedServerKey => &[],
+        crate::PublicKey => &[],
+        crate::CompressedPublicKey => &[],
+        crate::CompactPublicKey => &[],
+        crate::CompressedCompactPublicKey => &[],
+        crate::KeySwitchingKey => &[],
+        crate::KeyBundle => &[],
+    );
+
+    #[cfg(feature = "zk-pok")]
+    trace_types!(crate::ProvenCompactCiphertextList => &["info"]);
+
+    Ok(None)
+}
+
+struct CountingReader<R> {
+    inner: R,
+    count: u64,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.count += read as u64;
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::inspect;
+    use crate::prelude::*;
+    use crate::safe_deserialization::{safe_serialize, safe_serialize_versioned};
+    use crate::shortint::parameters::{
+        COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    };
+    use crate::{
+        set_server_key, ClientKey, CompactCiphertextList, CompactPublicKey,
+        CompressedCiphertextListBuilder, CompressedFheBool, CompressedFheUint16,
+        CompressedServerKey, ConfigBuilder, FheUint16,
+    };
+
+    #[test]
+    fn test_inspect() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+        let config = ConfigBuilder::with_custom_parameters(params).build();
+        let mut client_key = ClientKey::generate(config);
+        client_key.tag_mut().set_u64(1234);
+
+        let mut buffer = vec![];
+        safe_serialize_versioned(&client_key, &mut buffer, 1 << 30).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 30).unwrap();
+        assert!(info.is_known_type());
+        assert!(info.header.versioned);
+        assert_eq!(info.header.type_name, "high_level_api::ClientKey");
+        assert_eq!(info.lwe_dimension, Some(params.lwe_dimension));
+        assert_eq!(info.glwe_dimension, Some(params.glwe_dimension));
+        assert_eq!(info.polynomial_size, Some(params.polynomial_size));
+        assert_eq!(info.message_modulus, Some(params.message_modulus));
+        assert_eq!(info.carry_modulus, Some(params.carry_modulus));
+        assert_eq!(info.tag.unwrap().as_u64(), 1234);
+        // Parameter types holding several values report all of them, with their field
+        assert!(info
+            .parameters
+            .iter()
+            .any(|param| param.name == "DecompositionLevelCount"
+                && param.path.ends_with("ks_level")
+                && param.value == params.ks_level.0 as u64));
+        assert!(info
+            .parameters
+            .iter()
+            .any(|param| param.name == "DecompositionLevelCount"
+                && param.path.ends_with("pbs_level")
+                && param.value == params.pbs_level.0 as u64));
+        let level_counts = info.values_of("DecompositionLevelCount");
+        assert!(level_counts.contains(&(params.ks_level.0 as u64)));
+        assert!(level_counts.contains(&(params.pbs_level.0 as u64)));
+
+        let server_key = CompressedServerKey::new(&client_key);
+        let mut buffer = vec![];
+        safe_serialize_versioned(&server_key, &mut buffer, 1 << 30).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 30).unwrap();
+        assert_eq!(info.header.type_name, "high_level_api::CompressedServerKey");
+        let header_len =
+            4 + (8 + info.header.format_version.len()) + (8 + info.header.type_name.len());
+        assert_eq!(info.serialized_size as usize, buffer.len() - header_len);
+        assert_eq!(info.lwe_dimension, Some(params.lwe_dimension));
+        assert_eq!(info.polynomial_size, Some(params.polynomial_size));
+        assert_eq!(info.message_modulus, Some(params.message_modulus));
+        assert_eq!(info.tag.unwrap().as_u64(), 1234);
+
+        let mut buffer = vec![];
+        safe_serialize(&server_key.decompress(), &mut buffer, 1 << 32).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 32).unwrap();
+        assert!(!info.header.versioned);
+        assert_eq!(info.header.type_name, "high_level_api::ServerKey");
+        assert_eq!(info.lwe_dimension, Some(params.lwe_dimension));
+        assert_eq!(info.polynomial_size, Some(params.polynomial_size));
+        assert_eq!(info.carry_modulus, Some(params.carry_modulus));
+
+        let public_key = CompactPublicKey::new(&client_key);
+        let list = CompactCiphertextList::builder(&public_key)
+            .push(1u32)
+            .push(false)
+            .push(-3i64)
+            .build();
+        let mut buffer = vec![];
+        safe_serialize_versioned(&list, &mut buffer, 1 << 30).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 30).unwrap();
+        assert_eq!(info.list_len, Some(3));
+        assert_eq!(info.message_modulus, Some(params.message_modulus));
+        assert_eq!(info.tag.unwrap().as_u64(), 1234);
+
+        let ct = FheUint16::encrypt(3u16, &client_key);
+        let mut buffer = vec![];
+        safe_serialize(&ct, &mut buffer, 1 << 30).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 30).unwrap();
+        assert!(!info.header.versioned);
+        assert_eq!(info.list_len, Some(8));
+        // Fresh ciphertexts are encrypted under the big key
+        assert_eq!(
+            info.lwe_dimension,
+            Some(
+                params
+                    .glwe_dimension
+                    .to_equivalent_lwe_dimension(params.polynomial_size)
+            )
+        );
+        assert_eq!(info.message_modulus, Some(params.message_modulus));
+    }
+
+    #[test]
+    fn test_inspect_compressed() {
+        let params = PARAM_MESSAGE_2_CARRY_2;
+        let comp_params = COMP_PARAM_MESSAGE_2_CARRY_2;
+        let config = ConfigBuilder::with_custom_parameters(params)
+            .enable_compression(comp_params)
+            .build();
+        let client_key = ClientKey::generate(config);
+        let big_lwe_dimension = params
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(params.polynomial_size);
+
+        // Seeded ciphertexts only store their body, their dimension is read from their size
+        let ct = CompressedFheUint16::encrypt(3u16, &client_key);
+        let mut buffer = vec![];
+        safe_serialize_versioned(&ct, &mut buffer, 1 << 30).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 30).unwrap();
+        assert_eq!(info.header.type_name, "high_level_api::CompressedFheUint");
+        assert_eq!(info.lwe_dimension, Some(big_lwe_dimension));
+        assert_eq!(info.message_modulus, Some(params.message_modulus));
+
+        let ct = CompressedFheBool::encrypt(true, &client_key);
+        let mut buffer = vec![];
+        safe_serialize(&ct, &mut buffer, 1 << 30).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 30).unwrap();
+        assert_eq!(info.lwe_dimension, Some(big_lwe_dimension));
+
+        // Modulus switched ciphertexts store their data packed
+        let ct = FheUint16::encrypt(3u16, &client_key).compress();
+        let mut buffer = vec![];
+        safe_serialize_versioned(&ct, &mut buffer, 1 << 30).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 30).unwrap();
+        assert_eq!(info.lwe_dimension, Some(big_lwe_dimension));
+        assert_eq!(info.list_len, Some(8));
+
+        // Compressed lists store packed glwe ciphertexts, which have no lwe dimension
+        set_server_key(CompressedServerKey::new(&client_key).decompress());
+        let list = CompressedCiphertextListBuilder::new()
+            .push(FheUint16::encrypt(3u16, &client_key))
+            .push(FheUint16::encrypt(4u16, &client_key))
+            .build()
+            .unwrap();
+        let mut buffer = vec![];
+        safe_serialize_versioned(&list, &mut buffer, 1 << 30).unwrap();
+        let info = inspect(buffer.as_slice(), 1 << 30).unwrap();
+        assert_eq!(info.list_len, Some(2));
+        assert_eq!(info.lwe_dimension, None);
+        assert_eq!(
+            info.glwe_dimension,
+            Some(comp_params.packing_ks_glwe_dimension)
+        );
+        assert_eq!(
+            info.polynomial_size,
+            Some(comp_params.packing_ks_polynomial_size)
+        );
+        assert_eq!(info.message_modulus, Some(params.message_modulus));
+    }
+}
@@ -0,0 +1,695 @@
+//! A [Deserializer] wrapper that records the metadata of an object while it is being
+//! deserialized.
+//!
+//! Values of newtype structs (`LweDimension`, `PolynomialSize`, ...), sequence lengths and the
+//! content of `tag` fields are recorded, keyed by the name of the newtype and the path of fields
+//! leading to it, or by the name of the struct and field. Long sequences of scalars (the bulk of keys and ciphertexts) are read and discarded after
+//! their first element so that the deserialized object stays small. Types that request
+//! [SKIPPABLE_BULK_DATA] are told to discard their content as well. The object produced this way is
+//! truncated and must only be dropped.
+
+use crate::safe_deserialization::SKIPPABLE_BULK_DATA;
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use std::cell::RefCell;
+use std::fmt;
+
+/// Sequences of scalars longer than this are truncated to their first element
+const TRUNCATION_THRESHOLD: usize = 64;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Scalar {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    F32,
+    F64,
+    Char,
+}
+
+/// How the content given to a visitor has been requested
+#[derive(Copy, Clone)]
+enum Shape {
+    /// A variable length sequence, that may be truncated
+    Seq,
+    /// A struct with the given name, whose elements are its fields
+    Struct(&'static str, &'static [&'static str]),
+    /// Anything else, passed through untouched
+    Other,
+}
+
+#[derive(Default)]
+pub(super) struct Trace {
+    /// Names of the fields being deserialized with the name of their struct, from the outermost to
+    /// the innermost
+    fields: Vec<(&'static str, &'static str)>,
+    /// Name of the newtype struct whose content is about to be deserialized
+    newtype: Option<&'static str>,
+    /// Distinct values seen for each newtype struct wrapping an integer, with the names of the
+    /// fields leading to them, in the order they were first seen
+    values: Vec<(&'static str, Vec<&'static str>, u64)>,
+    /// First sequence length seen for each struct and field name
+    sequence_lengths: Vec<(&'static str, &'static str, usize)>,
+    /// Content of the first `tag` field
+    tag: Option<Vec<u8>>,
+    /// The field depth and content of the `tag` being read
+    tag_in_progress: Option<(usize, Vec<u8>)>,
+    scalar_count: usize,
+    compound_count: usize,
+    last_scalar: Option<Scalar>,
+}
+
+impl Trace {
+    /// Returns the first value seen for this newtype
+    pub(super) fn value(&self, newtype: &str) -> Option<u64> {
+        self.values
+            .iter()
+            .find_map(|(name, _, value)| (*name == newtype).then_some(*value))
+    }
+
+    /// Returns the distinct newtype values with the field path leading to them
+    pub(super) fn values(&self) -> impl Iterator<Item = (&'static str, &[&'static str], u64)> {
+        self.values
+            .iter()
+            .map(|(name, path, value)| (*name, path.as_slice(), *value))
+    }
+
+    /// Returns the first length seen for a sequence in a field with this name, in any struct
+    pub(super) fn sequence_length(&self, field: &str) -> Option<usize> {
+        self.sequence_lengths
+            .iter()
+            .find_map(|(_, name, len)| (*name == field).then_some(*len))
+    }
+
+    /// Returns the first length seen for a sequence in this field of this struct
+    pub(super) fn struct_sequence_length(&self, struct_name: &str, field: &str) -> Option<usize> {
+        self.sequence_lengths.iter().find_map(|(owner, name, len)| {
+            (*owner == struct_name && *name == field).then_some(*len)
+        })
+    }
+
+    pub(super) fn tag(&self) -> Option<&[u8]> {
+        self.tag.as_deref()
+    }
+
+    /// Called at the start of each `deserialize_*` call, returns the newtype label of the value
+    fn enter(&mut self, scalar: Option<Scalar>) -> Option<&'static str> {
+        match scalar {
+            Some(_) => {
+                self.scalar_count += 1;
+                self.last_scalar = scalar;
+            }
+            None => self.compound_count += 1,
+        }
+        self.newtype.take()
+    }
+
+    fn record_value(&mut self, label: Option<&'static str>, value: u64) {
+        let Some(name) = label else {
+            return;
+        };
+
+        let is_known = self.values.iter().any(|(known_name, path, known_value)| {
+            *known_name == name
+                && *known_value == value
+                && path
+                    .iter()
+                    .copied()
+                    .eq(self.fields.iter().map(|(_, field)| *field))
+        });
+        if !is_known {
+            let path = self.fields.iter().map(|(_, field)| *field).collect();
+            self.values.push((name, path, value));
+        }
+    }
+
+    fn record_sequence_length(&mut self, len: usize) {
+        if let Some(&(owner, field)) = self.fields.last() {
+            if self.struct_sequence_length(owner, field).is_none() {
+                self.sequence_lengths.push((owner, field, len));
+            }
+        }
+    }
+
+    fn record_tag_bytes(&mut self, bytes: &[u8]) {
+        if let Some((_, tag)) = self.tag_in_progress.as_mut() {
+            tag.extend_from_slice(bytes);
+        }
+    }
+
+    fn push_field(&mut self, owner: &'static str, field: &'static str) {
+        self.fields.push((owner, field));
+        if field == "tag" && self.tag.is_none() && self.tag_in_progress.is_none() {
+            self.tag_in_progress = Some((self.fields.len(), Vec::new()));
+        }
+    }
+
+    fn pop_field(&mut self) {
+        if matches!(self.tag_in_progress, Some((depth, _)) if depth == self.fields.len()) {
+            self.tag = self.tag_in_progress.take().map(|(_, tag)| tag);
+        }
+        self.fields.pop();
+    }
+}
+
+pub(super) struct TraceDeserializer<'a, D> {
+    inner: D,
+    trace: &'a RefCell<Trace>,
+}
+
+impl<'a, D> TraceDeserializer<'a, D> {
+    pub(super) fn new(inner: D, trace: &'a RefCell<Trace>) -> Self {
+        Self { inner, trace }
+    }
+
+    fn visitor<V>(
+        &self,
+        inner: V,
+        label: Option<&'static str>,
+        shape: Shape,
+    ) -> TraceVisitor<'a, V> {
+        TraceVisitor {
+            inner,
+            trace: self.trace,
+            label,
+            shape,
+        }
+    }
+}
+
+/// Versioned types are serialized as their `...VersionOwned` equivalent, returns the name of the
+/// original type
+fn unversioned_name(name: &'static str) -> &'static str {
+    name.strip_suffix("VersionOwned").unwrap_or(name)
+}
+
+macro_rules! forward_scalar {
+    ($($method:ident => $scalar:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let label = self.trace.borrow_mut().enter(Some(Scalar::$scalar));
+                let visitor = self.visitor(visitor, label, Shape::Other);
+                self.inner.$method(visitor)
+            }
+        )*
+    };
+}
+
+macro_rules! forward_compound {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let label = self.trace.borrow_mut().enter(None);
+                let visitor = self.visitor(visitor, label, Shape::Other);
+                self.inner.$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for TraceDeserializer<'_, D> {
+    type Error = D::Error;
+
+    forward_scalar!(
+        deserialize_bool => Bool,
+        deserialize_i8 => I8,
+        deserialize_i16 => I16,
+        deserialize_i32 => I32,
+        deserialize_i64 => I64,
+        deserialize_i128 => I128,
+        deserialize_u8 => U8,
+        deserialize_u16 => U16,
+        deserialize_u32 => U32,
+        deserialize_u64 => U64,
+        deserialize_u128 => U128,
+        deserialize_f32 => F32,
+        deserialize_f64 => F64,
+        deserialize_char => Char,
+    );
+
+    forward_compound!(
+        deserialize_any,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_option,
+        deserialize_unit,
+        deserialize_map,
+        deserialize_identifier,
+        deserialize_ignored_any,
+    );
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let label = self.trace.borrow_mut().enter(None);
+        let visitor = self.visitor(visitor, label, Shape::Other);
+        self.inner.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let label = self.trace.borrow_mut().enter(None);
+        let visitor = self.visitor(visitor, label, Shape::Other);
+        if name == SKIPPABLE_BULK_DATA {
+            // Giving the content directly instead of a newtype tells the visitor that it can be
+            // discarded
+            return self.inner.deserialize_seq(visitor);
+        }
+        self.trace.borrow_mut().newtype = Some(unversioned_name(name));
+        self.inner.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let label = self.trace.borrow_mut().enter(None);
+        let visitor = self.visitor(visitor, label, Shape::Seq);
+        self.inner.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let label = self.trace.borrow_mut().enter(None);
+        let visitor = self.visitor(visitor, label, Shape::Other);
+        self.inner.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let label = self.trace.borrow_mut().enter(None);
+        let visitor = self.visitor(visitor, label, Shape::Other);
+        self.inner.deserialize_tuple_struct(name, len, visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let label = self.trace.borrow_mut().enter(None);
+        let visitor = self.visitor(
+            visitor,
+            label,
+            Shape::Struct(unversioned_name(name), fields),
+        );
+        self.inner.deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let label = self.trace.borrow_mut().enter(None);
+        let visitor = self.visitor(visitor, label, Shape::Other);
+        self.inner.deserialize_enum(name, variants, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+struct TraceVisitor<'a, V> {
+    inner: V,
+    trace: &'a RefCell<Trace>,
+    label: Option<&'static str>,
+    shape: Shape,
+}
+
+macro_rules! forward_visit_integer {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method<E: serde::de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.trace.borrow_mut().record_value(self.label, u64::from(v));
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+macro_rules! forward_visit {
+    ($($method:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method<E: serde::de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for TraceVisitor<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit_integer!(visit_u16: u16, visit_u32: u32, visit_u64: u64);
+
+    forward_visit!(
+        visit_bool: bool,
+        visit_i8: i8,
+        visit_i16: i16,
+        visit_i32: i32,
+        visit_i64: i64,
+        visit_u128: u128,
+        visit_i128: i128,
+        visit_f32: f32,
+        visit_f64: f64,
+        visit_char: char,
+        visit_str: &str,
+        visit_borrowed_str: &'de str,
+        visit_string: String,
+    );
+
+    fn visit_u8<E: serde::de::Error>(self, v: u8) -> Result<Self::Value, E> {
+        let mut trace = self.trace.borrow_mut();
+        trace.record_value(self.label, u64::from(v));
+        trace.record_tag_bytes(&[v]);
+        drop(trace);
+        self.inner.visit_u8(v)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        self.trace.borrow_mut().record_tag_bytes(v);
+        self.inner.visit_bytes(v)
+    }
+
+    fn visit_borrowed_bytes<E: serde::de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        self.trace.borrow_mut().record_tag_bytes(v);
+        self.inner.visit_borrowed_bytes(v)
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        self.trace.borrow_mut().record_tag_bytes(&v);
+        self.inner.visit_byte_buf(v)
+    }
+
+    fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner
+            .visit_some(TraceDeserializer::new(deserializer, self.trace))
+    }
+
+    fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        self.inner
+            .visit_newtype_struct(TraceDeserializer::new(deserializer, self.trace))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let len = seq.size_hint();
+        let (fields, truncate) = match self.shape {
+            Shape::Seq => {
+                if let Some(len) = len {
+                    self.trace.borrow_mut().record_sequence_length(len);
+                }
+                (None, true)
+            }
+            Shape::Struct(name, fields) => (Some((name, fields)), false),
+            Shape::Other => (None, false),
+        };
+
+        self.inner.visit_seq(TraceSeqAccess {
+            inner: seq,
+            trace: self.trace,
+            fields,
+            len,
+            truncate,
+            index: 0,
+            scalar: None,
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(TraceMapAccess {
+            inner: map,
+            trace: self.trace,
+        })
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_enum(TraceEnumAccess {
+            inner: data,
+            trace: self.trace,
+        })
+    }
+}
+
+struct TraceSeed<'a, S> {
+    inner: S,
+    trace: &'a RefCell<Trace>,
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for TraceSeed<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        self.inner
+            .deserialize(TraceDeserializer::new(deserializer, self.trace))
+    }
+}
+
+struct TraceSeqAccess<'a, A> {
+    inner: A,
+    trace: &'a RefCell<Trace>,
+    /// The name and fields of the struct whose fields are the elements of this sequence
+    fields: Option<(&'static str, &'static [&'static str])>,
+    len: Option<usize>,
+    truncate: bool,
+    index: usize,
+    /// Set if the elements of this sequence are scalars of this kind
+    scalar: Option<Scalar>,
+}
+
+impl<'de, A: SeqAccess<'de>> TraceSeqAccess<'_, A> {
+    /// Reads and discards the remaining elements of the sequence, which are scalars of the given
+    /// kind
+    fn skip_remaining(&mut self, scalar: Scalar, len: usize) -> Result<(), A::Error> {
+        for _ in self.index..len {
+            let element = match scalar {
+                Scalar::Bool => self.inner.next_element::<bool>()?.map(drop),
+                Scalar::I8 => self.inner.next_element::<i8>()?.map(drop),
+                Scalar::I16 => self.inner.next_element::<i16>()?.map(drop),
+                Scalar::I32 => self.inner.next_element::<i32>()?.map(drop),
+                Scalar::I64 => self.inner.next_element::<i64>()?.map(drop),
+                Scalar::I128 => self.inner.next_element::<i128>()?.map(drop),
+                Scalar::U8 => self.inner.next_element::<u8>()?.map(drop),
+                Scalar::U16 => self.inner.next_element::<u16>()?.map(drop),
+                Scalar::U32 => self.inner.next_element::<u32>()?.map(drop),
+                Scalar::U64 => self.inner.next_element::<u64>()?.map(drop),
+                Scalar::U128 => self.inner.next_element::<u128>()?.map(drop),
+                Scalar::F32 => self.inner.next_element::<f32>()?.map(drop),
+                Scalar::F64 => self.inner.next_element::<f64>()?.map(drop),
+                Scalar::Char => self.inner.next_element::<char>()?.map(drop),
+            };
+            if element.is_none() {
+                break;
+            }
+            self.index += 1;
+        }
+        Ok(())
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for TraceSeqAccess<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if let (Some(scalar), Some(len)) = (self.scalar, self.len) {
+            if len > TRUNCATION_THRESHOLD && self.trace.borrow().tag_in_progress.is_none() {
+                self.skip_remaining(scalar, len)?;
+                return Ok(None);
+            }
+        }
+
+        let field = self
+            .fields
+            .map(|(owner, fields)| (owner, fields.get(self.index).copied().unwrap_or("?")));
+        let (scalar_count, compound_count) = {
+            let mut trace = self.trace.borrow_mut();
+            if let Some((owner, field)) = field {
+                trace.push_field(owner, field);
+            }
+            (trace.scalar_count, trace.compound_count)
+        };
+
+        let element = self.inner.next_element_seed(TraceSeed {
+            inner: seed,
+            trace: self.trace,
+        });
+
+        let mut trace = self.trace.borrow_mut();
+        if field.is_some() {
+            trace.pop_field();
+        }
+        if self.truncate
+            && self.index == 0
+            && trace.scalar_count == scalar_count + 1
+            && trace.compound_count == compound_count
+        {
+            self.scalar = trace.last_scalar;
+        }
+        drop(trace);
+
+        self.index += 1;
+        element
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.len.map(|len| len.saturating_sub(self.index))
+    }
+}
+
+struct TraceMapAccess<'a, A> {
+    inner: A,
+    trace: &'a RefCell<Trace>,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for TraceMapAccess<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        self.inner.next_key_seed(TraceSeed {
+            inner: seed,
+            trace: self.trace,
+        })
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner.next_value_seed(TraceSeed {
+            inner: seed,
+            trace: self.trace,
+        })
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+struct TraceEnumAccess<'a, A> {
+    inner: A,
+    trace: &'a RefCell<Trace>,
+}
+
+impl<'de, 'a, A: EnumAccess<'de>> EnumAccess<'de> for TraceEnumAccess<'a, A> {
+    type Error = A::Error;
+    type Variant = TraceVariantAccess<'a, A::Variant>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let (value, variant) = self.inner.variant_seed(TraceSeed {
+            inner: seed,
+            trace: self.trace,
+        })?;
+
+        Ok((
+            value,
+            TraceVariantAccess {
+                inner: variant,
+                trace: self.trace,
+            },
+        ))
+    }
+}
+
+struct TraceVariantAccess<'a, A> {
+    inner: A,
+    trace: &'a RefCell<Trace>,
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for TraceVariantAccess<'_, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        self.inner.newtype_variant_seed(TraceSeed {
+            inner: seed,
+            trace: self.trace,
+        })
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner.tuple_variant(
+            len,
+            TraceVisitor {
+                inner: visitor,
+                trace: self.trace,
+                label: None,
+                shape: Shape::Other,
+            },
+        )
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.inner.struct_variant(
+            fields,
+            TraceVisitor {
+                inner: visitor,
+                trace: self.trace,
+                label: None,
+                // The name of the enum is not known here
+                shape: Shape::Struct("", fields),
+            },
+        )
+    }
+}
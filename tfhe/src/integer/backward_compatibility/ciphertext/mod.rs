@@ -6,8 +6,12 @@ use crate::integer::ciphertext::{
     BaseCrtCiphertext, BaseRadixCiphertext, BaseSignedRadixCiphertext, CompactCiphertextList,
     CompressedCiphertextList, CompressedModulusSwitchedRadixCiphertext,
     CompressedModulusSwitchedRadixCiphertextGeneric,
-    CompressedModulusSwitchedSignedRadixCiphertext, DataKind,
+    CompressedModulusSwitchedSignedRadixCiphertext, CompressedSquashedNoiseCiphertextList,
+    DataKind, SquashedNoiseBooleanBlock, SquashedNoiseRadixCiphertext,
+    SquashedNoiseSignedRadixCiphertext,
 };
+#[cfg(feature = "zk-pok")]
+use crate::integer::ciphertext::{ProvenCompactCiphertextList, ValueRangeProof};
 use crate::integer::BooleanBlock;
 use crate::shortint::ciphertext::CompressedModulusSwitchedCiphertext;
 
@@ -58,6 +62,18 @@ pub enum DataKindVersions {
     V0(DataKind),
 }
 
+#[cfg(feature = "zk-pok")]
+#[derive(VersionsDispatch)]
+pub enum ValueRangeProofVersions {
+    V0(ValueRangeProof),
+}
+
+#[cfg(feature = "zk-pok")]
+#[derive(VersionsDispatch)]
+pub enum ProvenCompactCiphertextListVersions {
+    V0(ProvenCompactCiphertextList),
+}
+
 #[derive(VersionsDispatch)]
 pub enum CompressedModulusSwitchedSignedRadixCiphertextVersions {
     V0(CompressedModulusSwitchedSignedRadixCiphertext),
@@ -91,3 +107,23 @@ pub type CompressedModulusSwitchedRadixCiphertextTFHE06 =
 pub enum CompressedCiphertextListVersions {
     V0(CompressedCiphertextList),
 }
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseRadixCiphertextVersions {
+    V0(SquashedNoiseRadixCiphertext),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseSignedRadixCiphertextVersions {
+    V0(SquashedNoiseSignedRadixCiphertext),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseBooleanBlockVersions {
+    V0(SquashedNoiseBooleanBlock),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedSquashedNoiseCiphertextListVersions {
+    V0(CompressedSquashedNoiseCiphertextList),
+}
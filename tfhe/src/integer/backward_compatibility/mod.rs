@@ -4,6 +4,9 @@ pub mod ciphertext;
 pub mod client_key;
 pub mod key_switching_key;
 pub mod list_compression;
+pub mod noise_squashing;
+pub mod proxy_reencryption;
 pub mod public_key;
+pub mod sanitization;
 pub mod server_key;
 pub mod wopbs;
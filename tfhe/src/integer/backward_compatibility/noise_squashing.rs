@@ -0,0 +1,12 @@
+use crate::integer::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingKeyVersions {
+    V0(NoiseSquashingKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingPrivateKeyVersions {
+    V0(NoiseSquashingPrivateKey),
+}
@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::integer::proxy_reencryption::ProxyReencryptionKey;
+
+#[derive(VersionsDispatch)]
+pub enum ProxyReencryptionKeyVersions {
+    V0(ProxyReencryptionKey),
+}
@@ -0,0 +1,7 @@
+use crate::integer::sanitization::SanitizationKey;
+use tfhe_versionable::VersionsDispatch;
+
+#[derive(VersionsDispatch)]
+pub enum SanitizationKeyVersions {
+    V0(SanitizationKey),
+}
@@ -1,5 +1,5 @@
 use super::super::parameters::RadixCiphertextConformanceParams;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_elements, check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::prelude::UnsignedNumeric;
 use crate::integer::backward_compatibility::ciphertext::{
     BaseCrtCiphertextVersions, BaseRadixCiphertextVersions, BaseSignedRadixCiphertextVersions,
@@ -35,13 +35,22 @@ impl<T: ParameterSetConformant<ParameterSet = CiphertextConformanceParams>> Para
 {
     type ParameterSet = RadixCiphertextConformanceParams;
 
-    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &RadixCiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self { blocks } = self;
 
-        blocks.len() == params.num_blocks_per_integer
-            && blocks
-                .iter()
-                .all(|block| block.is_conformant(&params.shortint_params))
+        check_field(
+            "number of blocks",
+            &params.num_blocks_per_integer,
+            &blocks.len(),
+        )?;
+        check_elements("blocks", blocks, &params.shortint_params)
     }
 }
 
@@ -137,13 +146,22 @@ impl<T: ParameterSetConformant<ParameterSet = CiphertextConformanceParams>> Para
 {
     type ParameterSet = RadixCiphertextConformanceParams;
 
-    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &RadixCiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self { blocks } = self;
 
-        blocks.len() == params.num_blocks_per_integer
-            && blocks
-                .iter()
-                .all(|block| block.is_conformant(&params.shortint_params))
+        check_field(
+            "number of blocks",
+            &params.num_blocks_per_integer,
+            &blocks.len(),
+        )?;
+        check_elements("blocks", blocks, &params.shortint_params)
     }
 }
 
@@ -1,24 +1,41 @@
 use super::{DataKind, Expandable};
-use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
-use crate::core_crypto::prelude::Numeric;
+use crate::conformance::{
+    check_elements, ConformanceError, ListSizeConstraint, ParameterSetConformant,
+};
+use crate::core_crypto::prelude::{Container, LweSecretKey, Numeric};
 use crate::integer::backward_compatibility::ciphertext::CompactCiphertextListVersions;
+#[cfg(feature = "zk-pok")]
+use crate::integer::backward_compatibility::ciphertext::{
+    ProvenCompactCiphertextListVersions, ValueRangeProofVersions,
+};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::encryption::{create_clear_radix_block_iterator, KnowsMessageModulus};
 use crate::integer::parameters::CompactCiphertextListConformanceParams;
 pub use crate::integer::parameters::{
     IntegerCompactCiphertextListCastingMode, IntegerCompactCiphertextListUnpackingMode,
 };
-use crate::integer::{CompactPublicKey, ServerKey};
+use crate::integer::{CompactPrivateKey, CompactPublicKey, ServerKey};
 use crate::shortint::parameters::CiphertextConformanceParams;
+use crate::shortint::parameters::CompactPublicKeyEncryptionParameters;
 use crate::shortint::{Ciphertext, MessageModulus};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
 #[cfg(feature = "zk-pok")]
-use crate::zk::{CompactPkePublicParams, ZkComputeLoad, ZkVerificationOutCome};
+use crate::shortint::engine::ShortintEngine;
+#[cfg(feature = "zk-pok")]
+use crate::zk::{
+    CompactPkeProofOpening, CompactPkePublicParams, RangeCommit, RangeConstraint, RangeLinkProof,
+    RangeProof, RangePublicParams, ZkComputeLoad, ZkVerificationOutCome,
+};
+#[cfg(feature = "zk-pok")]
+use tfhe_zk_pok::proofs::{pke, range};
 
-fn extract_message_and_carries(packed_blocks: Vec<Ciphertext>, sks: &ServerKey) -> Vec<Ciphertext> {
+pub(crate) fn extract_message_and_carries(
+    packed_blocks: Vec<Ciphertext>,
+    sks: &ServerKey,
+) -> Vec<Ciphertext> {
     packed_blocks
         .into_par_iter()
         .flat_map(|block| {
@@ -85,10 +102,252 @@ where
     }
 }
 
+/// Key used by a [`CompactCiphertextListBuilder`] to encrypt its messages.
+#[derive(Clone)]
+pub(crate) enum CompactEncryptionKey {
+    Public(CompactPublicKey),
+    /// The public key is the one of the private key, proofs are verified against it
+    Secret(CompactPrivateKey<Vec<u64>>, CompactPublicKey),
+}
+
+impl CompactEncryptionKey {
+    pub(crate) fn parameters(&self) -> CompactPublicKeyEncryptionParameters {
+        match self {
+            Self::Public(pk) => pk.key.parameters,
+            Self::Secret(sk, _) => sk.key.parameters(),
+        }
+    }
+
+    pub(crate) fn message_modulus(&self) -> MessageModulus {
+        self.parameters().message_modulus
+    }
+
+    fn encrypt_iter_with_modulus(
+        &self,
+        messages: impl Iterator<Item = u64>,
+        encryption_modulus: u64,
+    ) -> crate::shortint::ciphertext::CompactCiphertextList {
+        match self {
+            Self::Public(pk) => pk
+                .key
+                .encrypt_iter_with_modulus(messages, encryption_modulus),
+            Self::Secret(sk, _) => sk
+                .key
+                .encrypt_iter_with_modulus(messages, encryption_modulus),
+        }
+    }
+
+    #[cfg(feature = "zk-pok")]
+    fn encrypt_and_prove_slice_with_openings(
+        &self,
+        messages: &[u64],
+        public_params: &CompactPkePublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        encryption_modulus: u64,
+    ) -> crate::Result<(
+        crate::shortint::ciphertext::ProvenCompactCiphertextList,
+        Vec<CompactPkeProofOpening>,
+    )> {
+        match self {
+            Self::Public(pk) => pk.key.encrypt_and_prove_slice_with_openings(
+                messages,
+                public_params,
+                metadata,
+                load,
+                encryption_modulus,
+            ),
+            Self::Secret(sk, pk) => sk.key.encrypt_and_prove_slice_with_openings(
+                &pk.key,
+                messages,
+                public_params,
+                metadata,
+                load,
+                encryption_modulus,
+            ),
+        }
+    }
+}
+
+/// Proof that the plaintext of a value pushed in a [`CompactCiphertextListBuilder`] satisfies a
+/// [`RangeConstraint`].
+///
+/// The range proof is made on a commitment to the clear value, and a link proof shows that this
+/// commitment opens to the value made by the blocks encrypted in the list, as committed in the
+/// encryption proofs of the list. The carries of the blocks, if any, are part of the value.
+#[cfg(feature = "zk-pok")]
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(ValueRangeProofVersions)]
+pub struct ValueRangeProof {
+    pub(crate) index: usize,
+    pub(crate) constraint: RangeConstraint,
+    pub(crate) commit: RangeCommit,
+    pub(crate) proof: RangeProof,
+    pub(crate) link: RangeLinkProof,
+}
+
+#[cfg(feature = "zk-pok")]
+impl ValueRangeProof {
+    /// Index of the value in the list
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn constraint(&self) -> RangeConstraint {
+        self.constraint
+    }
+
+    fn verify(
+        &self,
+        list: &crate::shortint::ciphertext::ProvenCompactCiphertextList,
+        info: &[DataKind],
+        public_params: &CompactPkePublicParams,
+        range_public_params: &RangePublicParams,
+    ) -> ZkVerificationOutCome {
+        let Some(first_list) = list.proved_lists.first() else {
+            return ZkVerificationOutCome::Invalid;
+        };
+
+        let Some(chunk_message_bits) = value_message_bits(
+            info,
+            self.index,
+            list.proved_lists
+                .iter()
+                .map(|(ct_list, _)| ct_list.ct_list.lwe_ciphertext_count().0),
+            is_packed_list(&first_list.0),
+            first_list.0.message_modulus,
+            public_params.t,
+        ) else {
+            return ZkVerificationOutCome::Invalid;
+        };
+
+        let linked = list
+            .proved_lists
+            .iter()
+            .zip(chunk_message_bits.iter())
+            .filter(|(_, message_bits)| !message_bits.is_empty())
+            .map(|((ct_list, proof), message_bits)| {
+                (
+                    proof,
+                    ct_list.ct_list.lwe_ciphertext_count().0,
+                    message_bits.as_slice(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let range_public = (range_public_params, &self.commit);
+        let is_valid =
+            range::verify_upper_bound(&self.proof, range_public, self.constraint.upper_bound())
+                .is_ok()
+                && pke::verify_range_link(public_params, &linked, range_public, &self.link).is_ok();
+
+        if is_valid {
+            ZkVerificationOutCome::Valid
+        } else {
+            ZkVerificationOutCome::Invalid
+        }
+    }
+}
+
+/// A value pushed with [`CompactCiphertextListBuilder::push_with_range_proof`], its range proof is
+/// made when the list is built, as it must be linked to the encryption proofs.
+#[cfg(feature = "zk-pok")]
+struct PendingRangeProof {
+    index: usize,
+    value: u64,
+    constraint: RangeConstraint,
+    range_public_params: RangePublicParams,
+}
+
+#[cfg(feature = "zk-pok")]
+fn is_packed_list(ct_list: &crate::shortint::ciphertext::CompactCiphertextList) -> bool {
+    ct_list.degree.get() > ct_list.message_modulus.corresponding_max_degree().get()
+}
+
+/// Returns, for each proof of a list, the bits of its messages that make the value at `index`.
+///
+/// `chunk_sizes` are the numbers of messages proven by each proof and `plaintext_modulus` the one
+/// of the proofs. Each block of the value is weighted by its position in the radix decomposition,
+/// all the bits of the plaintext of a block being part of it, so that carries are accounted for.
+/// When messages are packed, a message holds two consecutive blocks.
+///
+/// Returns `None` if the value is not in the list.
+#[cfg(feature = "zk-pok")]
+fn value_message_bits(
+    info: &[DataKind],
+    index: usize,
+    chunk_sizes: impl Iterator<Item = usize>,
+    is_packed: bool,
+    message_modulus: MessageModulus,
+    plaintext_modulus: u64,
+) -> Option<Vec<Vec<range::MessageBits>>> {
+    let start_block = info
+        .get(..index)?
+        .iter()
+        .map(|kind| kind.num_blocks())
+        .sum::<usize>();
+    let end_block = start_block + info.get(index)?.num_blocks();
+
+    let message_bits = message_modulus.0.ilog2();
+    let plaintext_bits = plaintext_modulus.ilog2();
+    let block_weight = |block: usize| {
+        let exponent = u32::try_from(block - start_block).ok()?;
+        (message_modulus.0 as u128).checked_pow(exponent)
+    };
+
+    // (message, bits, weight) of the messages holding blocks of the value
+    let mut value_messages = Vec::new();
+    if is_packed {
+        for message in start_block / 2..end_block.div_ceil(2) {
+            let (low, high) = (2 * message, 2 * message + 1);
+            let has_low = low >= start_block;
+            let has_high = high < end_block;
+            let (bits, first_block) = match (has_low, has_high) {
+                (true, true) => (0..plaintext_bits, low),
+                (true, false) => (0..message_bits, low),
+                (false, true) => (message_bits..plaintext_bits, high),
+                (false, false) => unreachable!(),
+            };
+            value_messages.push((message, bits, block_weight(first_block)?));
+        }
+    } else {
+        for block in start_block..end_block {
+            value_messages.push((block, 0..plaintext_bits, block_weight(block)?));
+        }
+    }
+
+    let mut chunk_message_bits = Vec::new();
+    let mut chunk_start = 0;
+    let mut value_messages = value_messages.into_iter().peekable();
+    for chunk_size in chunk_sizes {
+        let mut message_bits = Vec::new();
+        while let Some((message, bits, weight)) =
+            value_messages.next_if(|(message, _, _)| *message < chunk_start + chunk_size)
+        {
+            message_bits.push(range::MessageBits {
+                message_index: message - chunk_start,
+                bits,
+                weight,
+            });
+        }
+        chunk_message_bits.push(message_bits);
+        chunk_start += chunk_size;
+    }
+
+    // Some blocks are past the end of the list
+    if value_messages.next().is_some() {
+        return None;
+    }
+
+    Some(chunk_message_bits)
+}
+
 pub struct CompactCiphertextListBuilder {
     messages: Vec<u64>,
     info: Vec<DataKind>,
-    pub(crate) pk: CompactPublicKey,
+    pub(crate) key: CompactEncryptionKey,
+    #[cfg(feature = "zk-pok")]
+    pending_range_proofs: Vec<PendingRangeProof>,
 }
 
 impl CompactCiphertextListBuilder {
@@ -96,7 +355,34 @@ impl CompactCiphertextListBuilder {
         Self {
             messages: vec![],
             info: vec![],
-            pk: pk.clone(),
+            key: CompactEncryptionKey::Public(pk.clone()),
+            #[cfg(feature = "zk-pok")]
+            pending_range_proofs: vec![],
+        }
+    }
+
+    /// Creates a builder encrypting with a secret key instead of a public key.
+    ///
+    /// `pk` must be the public key of `sk`, lists proven with this builder are verified against it
+    /// with [`ProvenCompactCiphertextList::verify`].
+    pub fn new_with_secret_key<C: Container<Element = u64>>(
+        sk: &CompactPrivateKey<C>,
+        pk: &CompactPublicKey,
+    ) -> Self {
+        let (key, parameters) = sk.key.as_view().into_raw_parts();
+        let key = LweSecretKey::from_container(key.as_ref().to_vec());
+        Self {
+            messages: vec![],
+            info: vec![],
+            key: CompactEncryptionKey::Secret(
+                CompactPrivateKey::from_raw_parts(
+                    crate::shortint::CompactPrivateKey::from_raw_parts(key, parameters)
+                        .expect("Invalid CompactPrivateKey"),
+                ),
+                pk.clone(),
+            ),
+            #[cfg(feature = "zk-pok")]
+            pending_range_proofs: vec![],
         }
     }
 
@@ -105,7 +391,7 @@ impl CompactCiphertextListBuilder {
         T: Compactable,
     {
         let n = self.messages.len();
-        let kind = data.compact_into(&mut self.messages, self.pk.key.message_modulus(), None);
+        let kind = data.compact_into(&mut self.messages, self.key.message_modulus(), None);
         assert_eq!(n + kind.num_blocks(), self.messages.len());
 
         if kind.num_blocks() != 0 {
@@ -127,7 +413,7 @@ impl CompactCiphertextListBuilder {
         let n = self.messages.len();
         let kind = data.compact_into(
             &mut self.messages,
-            self.pk.key.message_modulus(),
+            self.key.message_modulus(),
             Some(num_blocks),
         );
         assert_eq!(n + kind.num_blocks(), self.messages.len());
@@ -135,6 +421,138 @@ impl CompactCiphertextListBuilder {
         self
     }
 
+    /// Pushes a value along with a proof that it satisfies the given constraint.
+    ///
+    /// The range proof is made and attached to the list by [`Self::build_with_proof`] and
+    /// [`Self::build_with_proof_packed`], where it is linked to the encryption proofs of the list.
+    /// It is checked by [`ProvenCompactCiphertextList::verify_range_proofs`].
+    ///
+    /// Returns an error if the value is negative, does not satisfy the constraint, or if the
+    /// constraint has more bits than the public parameters support.
+    #[cfg(feature = "zk-pok")]
+    pub fn push_with_range_proof<T>(
+        &mut self,
+        data: T,
+        constraint: RangeConstraint,
+        range_public_params: &RangePublicParams,
+    ) -> crate::Result<&mut Self>
+    where
+        T: Compactable + Numeric + TryInto<u64>,
+    {
+        let value: u64 = data.try_into().map_err(|_| {
+            crate::Error::new("Range proofs can only be made on non negative values".to_string())
+        })?;
+
+        if !constraint.is_satisfied_by(value) {
+            return Err(crate::Error::new(format!(
+                "The value does not satisfy the range constraint {constraint:?}"
+            )));
+        }
+
+        let required_bits = range::upper_bound_num_bits(constraint.upper_bound());
+        if required_bits > range_public_params.max_num_bits() {
+            return Err(crate::Error::new(format!(
+                "The range constraint {constraint:?} requires {required_bits} bits, \
+                but the public parameters only support {} bits",
+                range_public_params.max_num_bits()
+            )));
+        }
+
+        let index = self.info.len();
+        self.push(data);
+        if self.info.len() == index {
+            // The value was not pushed, e.g. the type has no blocks
+            return Ok(self);
+        }
+
+        self.pending_range_proofs.push(PendingRangeProof {
+            index,
+            value,
+            constraint,
+            range_public_params: range_public_params.clone(),
+        });
+
+        Ok(self)
+    }
+
+    /// Proves the values pushed with [`Self::push_with_range_proof`], linking them to the
+    /// messages encrypted in `ct_list`.
+    #[cfg(feature = "zk-pok")]
+    fn prove_ranges(
+        &self,
+        ct_list: &crate::shortint::ciphertext::ProvenCompactCiphertextList,
+        openings: &[CompactPkeProofOpening],
+        public_params: &CompactPkePublicParams,
+        is_packed: bool,
+    ) -> Vec<ValueRangeProof> {
+        self.pending_range_proofs
+            .iter()
+            .map(|pending| {
+                let chunk_message_bits = value_message_bits(
+                    &self.info,
+                    pending.index,
+                    ct_list
+                        .proved_lists
+                        .iter()
+                        .map(|(ct_list, _)| ct_list.ct_list.lwe_ciphertext_count().0),
+                    is_packed,
+                    self.key.message_modulus(),
+                    public_params.t,
+                )
+                .expect("The pushed value is part of the list");
+
+                let linked = ct_list
+                    .proved_lists
+                    .iter()
+                    .zip(openings.iter())
+                    .zip(chunk_message_bits.iter())
+                    .filter(|(_, message_bits)| !message_bits.is_empty())
+                    .map(|(((ct_list, proof), opening), message_bits)| {
+                        (
+                            proof,
+                            opening,
+                            ct_list.ct_list.lwe_ciphertext_count().0,
+                            message_bits.as_slice(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                let upper_bound = pending.constraint.upper_bound();
+                let range_public_params = &pending.range_public_params;
+                let (commit, proof, link) = ShortintEngine::with_thread_local_mut(|engine| {
+                    let (commit, private_commit) = range::commit_upper_bound(
+                        pending.value,
+                        upper_bound,
+                        range_public_params,
+                        &mut engine.random_generator,
+                    );
+                    let proof = range::prove_upper_bound(
+                        (range_public_params, &commit),
+                        &private_commit,
+                        upper_bound,
+                        &mut engine.random_generator,
+                    );
+                    let link = pke::prove_range_link(
+                        public_params,
+                        &linked,
+                        (range_public_params, &commit),
+                        &private_commit,
+                        &mut engine.random_generator,
+                    );
+                    (commit, proof, link)
+                });
+
+                ValueRangeProof {
+                    index: pending.index,
+                    constraint: pending.constraint,
+                    commit,
+                    proof,
+                    link,
+                }
+            })
+            .collect()
+    }
+
     pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
     where
         T: Compactable,
@@ -160,7 +578,10 @@ impl CompactCiphertextListBuilder {
     }
 
     pub fn build(&self) -> CompactCiphertextList {
-        let ct_list = self.pk.key.encrypt_slice(self.messages.as_slice());
+        let ct_list = self.key.encrypt_iter_with_modulus(
+            self.messages.iter().copied(),
+            self.key.message_modulus().0 as u64,
+        );
         CompactCiphertextList {
             ct_list,
             info: self.info.clone(),
@@ -168,18 +589,18 @@ impl CompactCiphertextListBuilder {
     }
 
     pub fn build_packed(&self) -> crate::Result<CompactCiphertextList> {
-        if self.pk.key.parameters.carry_modulus.0 < self.pk.key.parameters.message_modulus.0 {
+        let parameters = self.key.parameters();
+        if parameters.carry_modulus.0 < parameters.message_modulus.0 {
             return Err(crate::Error::new("In order to build a packed compact ciphertext list, parameters must have CarryModulus >= MessageModulus".to_string()));
         }
 
         // Here self.messages are decomposed blocks in range [0..message_modulus[
-        let msg_mod = self.pk.key.message_modulus().0 as u64;
+        let msg_mod = parameters.message_modulus.0 as u64;
         let packed_messaged_iter = self
             .messages
             .chunks(2)
             .map(|two_values| (two_values.get(1).copied().unwrap_or(0) * msg_mod) + two_values[0]);
         let ct_list = self
-            .pk
             .key
             .encrypt_iter_with_modulus(packed_messaged_iter, msg_mod * msg_mod);
 
@@ -196,16 +617,18 @@ impl CompactCiphertextListBuilder {
         metadata: &[u8],
         load: ZkComputeLoad,
     ) -> crate::Result<ProvenCompactCiphertextList> {
-        let ct_list = self.pk.key.encrypt_and_prove_slice(
+        let (ct_list, openings) = self.key.encrypt_and_prove_slice_with_openings(
             self.messages.as_slice(),
             public_params,
             metadata,
             load,
-            self.pk.key.parameters.message_modulus.0 as u64,
+            self.key.message_modulus().0 as u64,
         )?;
+        let range_proofs = self.prove_ranges(&ct_list, &openings, public_params, false);
         Ok(ProvenCompactCiphertextList {
             ct_list,
             info: self.info.clone(),
+            range_proofs,
         })
     }
 
@@ -216,7 +639,8 @@ impl CompactCiphertextListBuilder {
         metadata: &[u8],
         load: ZkComputeLoad,
     ) -> crate::Result<ProvenCompactCiphertextList> {
-        if self.pk.key.parameters.carry_modulus.0 < self.pk.key.parameters.message_modulus.0 {
+        let parameters = self.key.parameters();
+        if parameters.carry_modulus.0 < parameters.message_modulus.0 {
             return Err(crate::Error::new(
                 "In order to build a packed ProvenCompactCiphertextList, \
                 parameters must have CarryModulus >= MessageModulus"
@@ -224,22 +648,24 @@ impl CompactCiphertextListBuilder {
             ));
         }
 
-        let msg_mod = self.pk.key.parameters.message_modulus.0 as u64;
+        let msg_mod = parameters.message_modulus.0 as u64;
         let packed_messages = self
             .messages
             .chunks(2)
             .map(|two_values| (two_values.get(1).copied().unwrap_or(0) * msg_mod) + two_values[0])
             .collect::<Vec<_>>();
-        let ct_list = self.pk.key.encrypt_and_prove_slice(
+        let (ct_list, openings) = self.key.encrypt_and_prove_slice_with_openings(
             packed_messages.as_slice(),
             public_params,
             metadata,
             load,
             msg_mod * msg_mod,
         )?;
+        let range_proofs = self.prove_ranges(&ct_list, &openings, public_params, true);
         Ok(ProvenCompactCiphertextList {
             ct_list,
             info: self.info.clone(),
+            range_proofs,
         })
     }
 }
@@ -250,7 +676,7 @@ pub struct CompactCiphertextListExpander {
 }
 
 impl CompactCiphertextListExpander {
-    fn new(expanded_blocks: Vec<Ciphertext>, info: Vec<DataKind>) -> Self {
+    pub(crate) fn new(expanded_blocks: Vec<Ciphertext>, info: Vec<DataKind>) -> Self {
         Self {
             expanded_blocks,
             info,
@@ -310,14 +736,21 @@ pub struct CompactCiphertextList {
 impl ParameterSetConformant for CompactCiphertextList {
     type ParameterSet = CompactCiphertextListConformanceParams;
 
-    fn is_conformant(&self, params: &CompactCiphertextListConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &CompactCiphertextListConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self { ct_list: _, info } = self;
 
-        if !params.num_elements_constraint.is_valid(info.len()) {
-            return false;
-        }
+        params
+            .num_elements_constraint
+            .check("number of elements", info.len())?;
 
-        self.is_conformant_with_shortint_params(params.shortint_params)
+        self.check_conformance_with_shortint_params(params.shortint_params)
     }
 }
 
@@ -503,14 +936,13 @@ impl CompactCiphertextList {
                     let mut conformance_params = sks.key.conformance_params();
                     conformance_params.degree = degree;
 
-                    for ct in expanded_blocks.iter() {
-                        if !ct.is_conformant(&conformance_params) {
-                            return Err(crate::Error::new(
-                                "This compact list is not conformant with the given server key"
-                                    .to_string(),
-                            ));
-                        }
-                    }
+                    check_elements("expanded blocks", &expanded_blocks, &conformance_params)
+                        .map_err(|err| {
+                            crate::Error::new(format!(
+                                "This compact list is not conformant with the given server key: \
+                                {err}"
+                            ))
+                        })?;
 
                     extract_message_and_carries(expanded_blocks, sks)
                 }
@@ -534,10 +966,10 @@ impl CompactCiphertextList {
         self.ct_list.size_bytes()
     }
 
-    fn is_conformant_with_shortint_params(
+    fn check_conformance_with_shortint_params(
         &self,
         shortint_params: CiphertextConformanceParams,
-    ) -> bool {
+    ) -> Result<(), ConformanceError> {
         let Self { ct_list, info } = self;
 
         let mut num_blocks: usize = info.iter().copied().map(DataKind::num_blocks).sum();
@@ -549,17 +981,21 @@ impl CompactCiphertextList {
         }
         let shortint_list_params = shortint_params
             .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(num_blocks));
-        ct_list.is_conformant(&shortint_list_params)
+        ct_list
+            .check_conformance(&shortint_list_params)
+            .map_err(|err| err.in_field("ct_list"))
     }
 }
 
 #[cfg(feature = "zk-pok")]
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(ProvenCompactCiphertextListVersions)]
 pub struct ProvenCompactCiphertextList {
     pub(crate) ct_list: crate::shortint::ciphertext::ProvenCompactCiphertextList,
     // Integers stored can have a heterogeneous number of blocks and signedness
     // We store this info to safeguard the expansion
     pub(crate) info: Vec<DataKind>,
+    pub(crate) range_proofs: Vec<ValueRangeProof>,
 }
 
 #[cfg(feature = "zk-pok")]
@@ -578,6 +1014,25 @@ impl ProvenCompactCiphertextList {
             .verify(public_params, &public_key.key, metadata)
     }
 
+    /// Verifies many lists at once, sharing the cost of the pairings between all their proofs.
+    ///
+    /// Each entry holds a list with the public key used to encrypt it and the metadata given when
+    /// proving. Returns the indices of the lists with at least one invalid proof.
+    pub fn verify_batch(
+        lists: &[(&Self, &CompactPublicKey, &[u8])],
+        public_params: &CompactPkePublicParams,
+    ) -> Vec<usize> {
+        let shortint_lists = lists
+            .iter()
+            .map(|&(list, public_key, metadata)| (&list.ct_list, &public_key.key, metadata))
+            .collect::<Vec<_>>();
+
+        crate::shortint::ciphertext::ProvenCompactCiphertextList::verify_batch(
+            &shortint_lists,
+            public_params,
+        )
+    }
+
     pub fn verify_and_expand(
         &self,
         public_params: &CompactPkePublicParams,
@@ -613,14 +1068,13 @@ impl ProvenCompactCiphertextList {
                     let mut conformance_params = sks.key.conformance_params();
                     conformance_params.degree = degree;
 
-                    for ct in expanded_blocks.iter() {
-                        if !ct.is_conformant(&conformance_params) {
-                            return Err(crate::Error::new(
-                                "This compact list is not conformant with the given server key"
-                                    .to_string(),
-                            ));
-                        }
-                    }
+                    check_elements("expanded blocks", &expanded_blocks, &conformance_params)
+                        .map_err(|err| {
+                            crate::Error::new(format!(
+                                "This compact list is not conformant with the given server key: \
+                                {err}"
+                            ))
+                        })?;
 
                     extract_message_and_carries(expanded_blocks, sks)
                 }
@@ -636,6 +1090,85 @@ impl ProvenCompactCiphertextList {
         ))
     }
 
+    /// Verifies the range proofs attached to the list.
+    ///
+    /// Each `(index, constraint)` of `expected_constraints` must be covered by a range proof on
+    /// the value at `index` with a bound at most as large as the one of `constraint`, and every
+    /// range proof attached to the list must be valid and linked to the messages committed in the
+    /// encryption proofs.
+    ///
+    /// This does not verify the encryption proofs themselves, see
+    /// [`Self::verify_and_expand_with_range_proofs`].
+    pub fn verify_range_proofs(
+        &self,
+        public_params: &CompactPkePublicParams,
+        range_public_params: &RangePublicParams,
+        expected_constraints: &[(usize, RangeConstraint)],
+    ) -> ZkVerificationOutCome {
+        let all_expected_are_proven =
+            expected_constraints
+                .iter()
+                .all(|(expected_index, expected_constraint)| {
+                    self.range_proofs.iter().any(|range_proof| {
+                        range_proof.index == *expected_index
+                            && range_proof.constraint.upper_bound()
+                                <= expected_constraint.upper_bound()
+                    })
+                });
+
+        if !all_expected_are_proven {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        let all_valid = self.range_proofs.par_iter().all(|range_proof| {
+            range_proof
+                .verify(
+                    &self.ct_list,
+                    &self.info,
+                    public_params,
+                    range_public_params,
+                )
+                .is_valid()
+        });
+
+        if all_valid {
+            ZkVerificationOutCome::Valid
+        } else {
+            ZkVerificationOutCome::Invalid
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_and_expand_with_range_proofs(
+        &self,
+        public_params: &CompactPkePublicParams,
+        range_public_params: &RangePublicParams,
+        public_key: &CompactPublicKey,
+        metadata: &[u8],
+        expected_constraints: &[(usize, RangeConstraint)],
+        unpacking_mode: IntegerCompactCiphertextListUnpackingMode<'_>,
+        casting_mode: IntegerCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        if self
+            .verify_range_proofs(public_params, range_public_params, expected_constraints)
+            .is_invalid()
+        {
+            return Err(crate::ErrorKind::InvalidZkProof.into());
+        }
+
+        self.verify_and_expand(
+            public_params,
+            public_key,
+            metadata,
+            unpacking_mode,
+            casting_mode,
+        )
+    }
+
+    pub fn range_proofs(&self) -> &[ValueRangeProof] {
+        &self.range_proofs
+    }
+
     #[doc(hidden)]
     /// This function allows to expand a ciphertext without verifying the associated proof.
     ///
@@ -669,14 +1202,13 @@ impl ProvenCompactCiphertextList {
                     let mut conformance_params = sks.key.conformance_params();
                     conformance_params.degree = degree;
 
-                    for ct in expanded_blocks.iter() {
-                        if !ct.is_conformant(&conformance_params) {
-                            return Err(crate::Error::new(
-                                "This compact list is not conformant with the given server key"
-                                    .to_string(),
-                            ));
-                        }
-                    }
+                    check_elements("expanded blocks", &expanded_blocks, &conformance_params)
+                        .map_err(|err| {
+                            crate::Error::new(format!(
+                                "This compact list is not conformant with the given server key: \
+                                {err}"
+                            ))
+                        })?;
 
                     extract_message_and_carries(expanded_blocks, sks)
                 }
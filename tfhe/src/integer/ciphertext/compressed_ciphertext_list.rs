@@ -1,6 +1,7 @@
 use super::{DataKind, Expandable, RadixCiphertext, SignedRadixCiphertext};
 use crate::integer::backward_compatibility::ciphertext::CompressedCiphertextListVersions;
 use crate::integer::compression_keys::{CompressionKey, DecompressionKey};
+use crate::integer::key_switching_key::KeySwitchingKey;
 use crate::integer::BooleanBlock;
 use crate::shortint::ciphertext::CompressedCiphertextList as ShortintCompressedCiphertextList;
 use crate::shortint::Ciphertext;
@@ -148,6 +149,60 @@ impl CompressedCiphertextList {
             .map(|(blocks, kind)| T::from_expanded_blocks(blocks, kind))
             .transpose()
     }
+
+    /// Converts the list to another key set, typically when rotating keys.
+    ///
+    /// The blocks are decompressed with `decomp_key`, cast to the destination key set of
+    /// `key_switching_key` and compressed again with `comp_key`, which must belong to the
+    /// destination key set.
+    ///
+    /// Both key sets must use the same message modulus, so that the integers keep their number of
+    /// blocks.
+    pub fn rotate(
+        &self,
+        decomp_key: &DecompressionKey,
+        key_switching_key: &KeySwitchingKey,
+        comp_key: &CompressionKey,
+    ) -> crate::Result<Self> {
+        let dest_server_key = &key_switching_key.key.dest_server_key;
+
+        if dest_server_key.message_modulus != self.packed_list.message_modulus {
+            return Err(crate::Error::new(format!(
+                "Cannot rotate a list with message modulus {:?} to a key set with message modulus \
+                {:?}",
+                self.packed_list.message_modulus, dest_server_key.message_modulus,
+            )));
+        }
+
+        let input_lwe_dimension = key_switching_key
+            .key
+            .key_switching_key_material
+            .key_switching_key
+            .input_key_lwe_dimension();
+        if decomp_key.key.blind_rotate_key.output_lwe_dimension() != input_lwe_dimension {
+            return Err(crate::Error::new(
+                "The decompression key does not match the input key of the KeySwitchingKey"
+                    .to_string(),
+            ));
+        }
+
+        if self.packed_list.count.0 == 0 {
+            return Ok(self.clone());
+        }
+
+        let blocks: Vec<Ciphertext> = (0..self.packed_list.count.0)
+            .into_par_iter()
+            .map(|i| {
+                let block = decomp_key.key.unpack(&self.packed_list, i).unwrap();
+                key_switching_key.key.cast(&block)
+            })
+            .collect();
+
+        Ok(Self {
+            packed_list: comp_key.key.compress_ciphertexts_into_list(&blocks),
+            info: self.info.clone(),
+        })
+    }
 }
 
 #[cfg(test)]
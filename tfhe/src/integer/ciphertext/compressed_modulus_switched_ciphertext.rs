@@ -1,6 +1,6 @@
 use tfhe_versionable::Versionize;
 
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_elements, check_field, ConformanceError, ParameterSetConformant};
 use crate::integer::backward_compatibility::ciphertext::{
     CompressedModulusSwitchedRadixCiphertextGenericVersions,
     CompressedModulusSwitchedRadixCiphertextVersions,
@@ -45,10 +45,17 @@ pub struct CompressedModulusSwitchedRadixCiphertext(
 impl ParameterSetConformant for CompressedModulusSwitchedRadixCiphertext {
     type ParameterSet = RadixCiphertextConformanceParams;
 
-    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &RadixCiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self(ct) = self;
 
-        ct.is_conformant(params)
+        ct.check_conformance(params)
     }
 }
 
@@ -87,10 +94,17 @@ pub struct CompressedModulusSwitchedSignedRadixCiphertext(
 impl ParameterSetConformant for CompressedModulusSwitchedSignedRadixCiphertext {
     type ParameterSet = RadixCiphertextConformanceParams;
 
-    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &RadixCiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self(ct) = self;
 
-        ct.is_conformant(params)
+        ct.check_conformance(params)
     }
 }
 
@@ -104,7 +118,14 @@ pub(crate) struct CompressedModulusSwitchedRadixCiphertextGeneric {
 impl ParameterSetConformant for CompressedModulusSwitchedRadixCiphertextGeneric {
     type ParameterSet = RadixCiphertextConformanceParams;
 
-    fn is_conformant(&self, params: &RadixCiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &RadixCiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self {
             paired_blocks,
             last_block,
@@ -120,20 +141,27 @@ impl ParameterSetConformant for CompressedModulusSwitchedRadixCiphertextGeneric
             .get(),
         );
 
-        let paired_blocks_len_ok = paired_blocks.len() == params.num_blocks_per_integer / 2;
-
-        let paired_blocks_ok = paired_blocks
-            .iter()
-            .all(|block| block.is_conformant(&shortint_params));
-
-        let last_item_ok = if params.num_blocks_per_integer % 2 == 1 {
-            last_block.as_ref().map_or(false, |last_block| {
-                last_block.is_conformant(&params.shortint_params)
-            })
-        } else {
-            true
-        };
-
-        paired_blocks_len_ok && paired_blocks_ok && last_item_ok
+        check_field(
+            "number of paired blocks",
+            &(params.num_blocks_per_integer / 2),
+            &paired_blocks.len(),
+        )?;
+
+        check_elements("paired_blocks", paired_blocks, &shortint_params)?;
+
+        if params.num_blocks_per_integer % 2 == 1 {
+            last_block
+                .as_ref()
+                .ok_or_else(|| {
+                    ConformanceError::invalid(
+                        "last_block",
+                        "missing last block for an odd number of blocks",
+                    )
+                })?
+                .check_conformance(&params.shortint_params)
+                .map_err(|err| err.in_field("last_block"))?;
+        }
+
+        Ok(())
     }
 }
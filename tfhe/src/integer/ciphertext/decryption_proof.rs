@@ -0,0 +1,66 @@
+use super::RadixCiphertext;
+use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::CompactPublicKey;
+use crate::zk::{DecryptionProof, DecryptionPublicParams, ZkVerificationOutCome};
+use serde::{Deserialize, Serialize};
+
+/// Proofs that a clear value is the decryption of a [`RadixCiphertext`], one proof per block.
+///
+/// See [`ClientKey::decrypt_radix_with_proof`](crate::integer::ClientKey::decrypt_radix_with_proof).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RadixDecryptionProof {
+    pub(crate) block_proofs: Vec<DecryptionProof>,
+}
+
+impl RadixDecryptionProof {
+    pub fn proof_size(&self) -> usize {
+        self.block_proofs.len() * core::mem::size_of::<DecryptionProof>()
+    }
+}
+
+impl RadixCiphertext {
+    /// Verifies that `clear` is the decryption of this ciphertext with the proof returned by
+    /// [`ClientKey::decrypt_radix_with_proof`](crate::integer::ClientKey::decrypt_radix_with_proof)
+    pub fn verify_decryption<T>(
+        &self,
+        clear: T,
+        proof: &RadixDecryptionProof,
+        public_params: &DecryptionPublicParams,
+        public_key: &CompactPublicKey,
+        metadata: &[u8],
+    ) -> ZkVerificationOutCome
+    where
+        T: DecomposableInto<u64>,
+    {
+        if self.blocks.is_empty() || proof.block_proofs.len() != self.blocks.len() {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        let bits_in_block = self.blocks[0].message_modulus.0.ilog2();
+        let mut digits = BlockDecomposer::with_early_stop_at_zero(clear, bits_in_block)
+            .iter_as::<u64>()
+            .collect::<Vec<_>>();
+        if digits.len() > self.blocks.len() {
+            // The clear value does not fit in the ciphertext
+            return ZkVerificationOutCome::Invalid;
+        }
+        digits.resize(self.blocks.len(), 0);
+
+        let all_valid = self
+            .blocks
+            .iter()
+            .zip(proof.block_proofs.iter())
+            .zip(digits)
+            .all(|((block, block_proof), digit)| {
+                block
+                    .verify_decryption(digit, block_proof, public_params, &public_key.key, metadata)
+                    .is_valid()
+            });
+
+        if all_valid {
+            ZkVerificationOutCome::Valid
+        } else {
+            ZkVerificationOutCome::Invalid
+        }
+    }
+}
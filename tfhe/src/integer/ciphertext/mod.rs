@@ -4,14 +4,32 @@ mod compact_list;
 mod compressed;
 mod compressed_ciphertext_list;
 mod compressed_modulus_switched_ciphertext;
+#[cfg(feature = "zk-pok")]
+mod decryption_proof;
 mod integer_ciphertext;
+#[cfg(feature = "zk-pok")]
+mod proven_glwe_list;
+mod rerandomization;
+mod squashed_noise;
 mod utils;
 
 pub use base::*;
 pub use boolean_value::*;
+pub(crate) use compact_list::extract_message_and_carries;
 pub use compact_list::*;
 pub use compressed::*;
 pub use compressed_ciphertext_list::*;
 pub use compressed_modulus_switched_ciphertext::*;
+#[cfg(feature = "zk-pok")]
+pub use decryption_proof::*;
 pub use integer_ciphertext::*;
+#[cfg(feature = "zk-pok")]
+pub use proven_glwe_list::*;
+pub(crate) use rerandomization::rerandomize_blocks;
+pub(crate) use squashed_noise::blocks_per_squashed_block;
+pub use squashed_noise::{
+    CompressedSquashedNoiseCiphertextList, CompressedSquashedNoiseCiphertextListBuilder,
+    SquashedNoiseBooleanBlock, SquashedNoiseCompressible, SquashedNoiseExpandable,
+    SquashedNoiseRadixCiphertext, SquashedNoiseSignedRadixCiphertext,
+};
 pub use utils::*;
@@ -0,0 +1,157 @@
+use super::{CompactCiphertextListExpander, Compactable, DataKind};
+use crate::core_crypto::prelude::Numeric;
+use crate::integer::GlwePublicKey;
+use crate::shortint::MessageModulus;
+use crate::zk::{GlwePkePublicParams, ZkComputeLoad, ZkVerificationOutCome};
+use serde::{Deserialize, Serialize};
+
+/// Builder of a [`ProvenGlweCiphertextList`], encrypting its values with a [`GlwePublicKey`].
+pub struct ProvenGlweCiphertextListBuilder {
+    messages: Vec<u64>,
+    info: Vec<DataKind>,
+    key: crate::shortint::GlwePublicKey,
+}
+
+impl ProvenGlweCiphertextListBuilder {
+    pub fn new(pk: &GlwePublicKey) -> Self {
+        Self {
+            messages: vec![],
+            info: vec![],
+            key: pk.key.clone(),
+        }
+    }
+
+    pub(crate) fn message_modulus(&self) -> MessageModulus {
+        self.key.parameters.message_modulus()
+    }
+
+    pub fn push<T>(&mut self, data: T) -> &mut Self
+    where
+        T: Compactable,
+    {
+        let n = self.messages.len();
+        let message_modulus = self.message_modulus();
+        let kind = data.compact_into(&mut self.messages, message_modulus, None);
+        assert_eq!(n + kind.num_blocks(), self.messages.len());
+
+        if kind.num_blocks() != 0 {
+            self.info.push(kind);
+        }
+
+        self
+    }
+
+    pub fn push_with_num_blocks<T>(&mut self, data: T, num_blocks: usize) -> &mut Self
+    where
+        // The extra `Numeric` bound is to prevent T from being `bool`
+        T: Compactable + Numeric,
+    {
+        if num_blocks == 0 {
+            return self;
+        }
+
+        let n = self.messages.len();
+        let message_modulus = self.message_modulus();
+        let kind = data.compact_into(&mut self.messages, message_modulus, Some(num_blocks));
+        assert_eq!(n + kind.num_blocks(), self.messages.len());
+        self.info.push(kind);
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compactable,
+    {
+        for value in values {
+            self.push(value);
+        }
+        self
+    }
+
+    pub fn build_with_proof(
+        &self,
+        public_params: &GlwePkePublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+    ) -> crate::Result<ProvenGlweCiphertextList> {
+        let glwe_list = self.key.encrypt_and_prove_slice(
+            self.messages.as_slice(),
+            public_params,
+            metadata,
+            load,
+            self.message_modulus().0 as u64,
+        )?;
+        Ok(ProvenGlweCiphertextList {
+            glwe_list,
+            info: self.info.clone(),
+        })
+    }
+}
+
+/// A list of values encrypted in GLWE ciphertexts with their zero-knowledge proofs.
+///
+/// See [`crate::shortint::ciphertext::ProvenGlweCiphertextList`] for what the proofs show.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProvenGlweCiphertextList {
+    pub(crate) glwe_list: crate::shortint::ciphertext::ProvenGlweCiphertextList,
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl ProvenGlweCiphertextList {
+    pub fn builder(pk: &GlwePublicKey) -> ProvenGlweCiphertextListBuilder {
+        ProvenGlweCiphertextListBuilder::new(pk)
+    }
+
+    pub fn verify(
+        &self,
+        public_params: &GlwePkePublicParams,
+        public_key: &GlwePublicKey,
+        metadata: &[u8],
+    ) -> ZkVerificationOutCome {
+        self.glwe_list
+            .verify(public_params, &public_key.key, metadata)
+    }
+
+    pub fn verify_and_expand(
+        &self,
+        public_params: &GlwePkePublicParams,
+        public_key: &GlwePublicKey,
+        metadata: &[u8],
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        let expanded_blocks =
+            self.glwe_list
+                .verify_and_expand(public_params, &public_key.key, metadata)?;
+
+        Ok(CompactCiphertextListExpander::new(
+            expanded_blocks,
+            self.info.clone(),
+        ))
+    }
+
+    #[doc(hidden)]
+    /// This function allows to expand a ciphertext without verifying the associated proof.
+    ///
+    /// If you are here you were probably looking for it: use at your own risks.
+    pub fn expand_without_verification(&self) -> CompactCiphertextListExpander {
+        CompactCiphertextListExpander::new(
+            self.glwe_list.expand_without_verification(),
+            self.info.clone(),
+        )
+    }
+
+    pub fn proof_size(&self) -> usize {
+        self.glwe_list.proof_size()
+    }
+
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_kind_of(&self, index: usize) -> Option<DataKind> {
+        self.info.get(index).copied()
+    }
+}
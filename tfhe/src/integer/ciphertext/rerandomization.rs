@@ -0,0 +1,283 @@
+use super::{IntegerCompactCiphertextListCastingMode, RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::public_key::CompactPublicKey;
+use crate::integer::ServerKey;
+use crate::shortint::parameters::compact_public_key_only::CompactCiphertextListCastingMode;
+use crate::shortint::parameters::CompactCiphertextListExpansionKind;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+/// Checks that the encryptions of zero of the compact public key can be added to the ciphertexts
+/// of the server key.
+///
+/// Only the parameters can be checked: a public key of the same parameters generated from
+/// another secret key is not detected.
+fn check_public_key_parameters(
+    public_key: &CompactPublicKey,
+    server_key: &ServerKey,
+    casting_mode: IntegerCompactCiphertextListCastingMode<'_>,
+) -> crate::Result<()> {
+    let params = public_key.key.parameters;
+    let sks = &server_key.key;
+
+    if params.message_modulus != sks.message_modulus
+        || params.carry_modulus != sks.carry_modulus
+        || params.ciphertext_modulus != sks.ciphertext_modulus
+    {
+        return Err(crate::Error::new(
+            "The compact public key parameters do not match the server key".to_string(),
+        ));
+    }
+
+    match (params.expansion_kind, casting_mode) {
+        (CompactCiphertextListExpansionKind::NoCasting(pbs_order), _) => {
+            if pbs_order != sks.pbs_order
+                || params.encryption_lwe_dimension != sks.ciphertext_lwe_dimension()
+            {
+                return Err(crate::Error::new(
+                    "The compact public key does not encrypt under the key of the server key"
+                        .to_string(),
+                ));
+            }
+        }
+        (
+            CompactCiphertextListExpansionKind::RequiresCasting,
+            CompactCiphertextListCastingMode::CastIfNecessary(casting_key),
+        ) => {
+            let casting_ksk = casting_key.key.key_switching_key_material.key_switching_key;
+            if casting_ksk.input_key_lwe_dimension() != params.encryption_lwe_dimension {
+                return Err(crate::Error::new(
+                    "The casting key does not keyswitch from the key of the compact public key"
+                        .to_string(),
+                ));
+            }
+        }
+        // Rejected when expanding the encryptions of zero
+        (
+            CompactCiphertextListExpansionKind::RequiresCasting,
+            CompactCiphertextListCastingMode::NoCasting,
+        ) => {}
+    }
+
+    Ok(())
+}
+
+/// Adds a fresh encryption of zero to each block, and refreshes it with an identity PBS.
+///
+/// The encryptions of zero are produced with the compact public key, and keyswitched to the
+/// computation parameters by `casting_mode` when the public key uses dedicated parameters.
+///
+/// The parameters of the public key are checked against the server key, the caller is
+/// responsible for checking that it was generated from the secret key of the ciphertexts.
+pub(crate) fn rerandomize_blocks(
+    blocks: &mut [Ciphertext],
+    public_key: &CompactPublicKey,
+    server_key: &ServerKey,
+    casting_mode: IntegerCompactCiphertextListCastingMode<'_>,
+) -> crate::Result<()> {
+    if blocks.is_empty() {
+        return Ok(());
+    }
+
+    check_public_key_parameters(public_key, server_key, casting_mode)?;
+
+    let sks = &server_key.key;
+
+    let zeros = public_key
+        .key
+        .encrypt_slice(&vec![0; blocks.len()])
+        .expand(casting_mode.into())?;
+
+    for (block, zero) in blocks.iter().zip(zeros.iter()) {
+        if block.message_modulus != sks.message_modulus || block.carry_modulus != sks.carry_modulus
+        {
+            return Err(crate::Error::new(
+                "The ciphertext does not use the parameters of the server key".to_string(),
+            ));
+        }
+        if zero.ct.lwe_size() != block.ct.lwe_size() || zero.pbs_order != block.pbs_order {
+            return Err(crate::Error::new(
+                "The compact public key does not encrypt under the key of the ciphertext, \
+                a casting key is required"
+                    .to_string(),
+            ));
+        }
+    }
+
+    let identity = sks.generate_lookup_table(|x| x);
+
+    blocks
+        .par_iter_mut()
+        .zip(zeros.par_iter())
+        .for_each(|(block, zero)| {
+            let degree = block.degree;
+
+            // Make room for the noise of the encryption of zero
+            if sks
+                .max_noise_level
+                .validate(block.noise_level() + zero.noise_level())
+                .is_err()
+            {
+                sks.apply_lookup_table_assign(block, &identity);
+            }
+
+            sks.unchecked_add_assign(block, zero);
+            sks.apply_lookup_table_assign(block, &identity);
+
+            // The encrypted value is unchanged, and so is its degree
+            block.degree = degree;
+        });
+
+    Ok(())
+}
+
+impl RadixCiphertext {
+    /// Re-randomizes the ciphertext, making it unlinkable to the ciphertexts it was computed from.
+    ///
+    /// A fresh encryption of zero, produced with the `public_key`, is added to each block, which
+    /// is then refreshed with a PBS. `casting_mode` must provide the keyswitching key to the
+    /// computation parameters when the public key uses dedicated parameters.
+    ///
+    /// An error is returned if the parameters of the public key do not match the server key.
+    /// A public key with the right parameters but generated from another secret key cannot be
+    /// detected, and corrupts the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ciphertext::IntegerCompactCiphertextListCastingMode;
+    /// use tfhe::integer::{ClientKey, CompactPublicKey, ServerKey};
+    /// use tfhe::shortint::parameters::classic::compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let sks = ServerKey::new_radix_server_key(&cks);
+    /// let public_key = CompactPublicKey::new(&cks);
+    ///
+    /// let a = cks.encrypt_radix(12u8, num_blocks);
+    /// let b = cks.encrypt_radix(30u8, num_blocks);
+    /// let mut ct = sks.add_parallelized(&a, &b);
+    /// let original = ct.clone();
+    ///
+    /// ct.rerandomize(
+    ///     &public_key,
+    ///     &sks,
+    ///     IntegerCompactCiphertextListCastingMode::NoCasting,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_ne!(ct, original);
+    /// let dec: u8 = cks.decrypt_radix(&ct);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn rerandomize(
+        &mut self,
+        public_key: &CompactPublicKey,
+        server_key: &ServerKey,
+        casting_mode: IntegerCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<()> {
+        rerandomize_blocks(&mut self.blocks, public_key, server_key, casting_mode)
+    }
+}
+
+impl SignedRadixCiphertext {
+    /// Re-randomizes the ciphertext, see [RadixCiphertext::rerandomize].
+    pub fn rerandomize(
+        &mut self,
+        public_key: &CompactPublicKey,
+        server_key: &ServerKey,
+        casting_mode: IntegerCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<()> {
+        rerandomize_blocks(&mut self.blocks, public_key, server_key, casting_mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::integer::ciphertext::IntegerCompactCiphertextListCastingMode;
+    use crate::integer::key_switching_key::KeySwitchingKey;
+    use crate::integer::{gen_keys_radix, ClientKey, CompactPrivateKey, CompactPublicKey};
+    use crate::shortint::parameters::compact_public_key_only::p_fail_2_minus_64::ks_pbs::PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::key_switching::p_fail_2_minus_64::ks_pbs::PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    };
+
+    #[test]
+    fn rerandomize_with_dedicated_public_key_parameters() {
+        let num_blocks = 4;
+        let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+        let private_key = CompactPrivateKey::new(PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let public_key = CompactPublicKey::new(&private_key);
+        let ksk = KeySwitchingKey::new(
+            (&private_key, None),
+            (&cks, &sks),
+            PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+
+        let a = cks.encrypt(200u8);
+        let mut ct = sks.unchecked_add(&a, &a);
+        let original = ct.clone();
+
+        // Without the casting key the zeros are not under the key of the ciphertext
+        assert!(ct
+            .rerandomize(
+                &public_key,
+                &sks,
+                IntegerCompactCiphertextListCastingMode::NoCasting
+            )
+            .is_err());
+
+        ct.rerandomize(
+            &public_key,
+            &sks,
+            IntegerCompactCiphertextListCastingMode::CastIfNecessary(ksk.as_view()),
+        )
+        .unwrap();
+        assert_ne!(ct, original);
+        for (block, original_block) in ct.blocks.iter().zip(original.blocks.iter()) {
+            assert_eq!(block.degree, original_block.degree);
+        }
+
+        let sum = sks.add_parallelized(&ct, &a);
+        let dec: u8 = cks.decrypt(&sum);
+        assert_eq!(dec, 200u8.wrapping_mul(3));
+    }
+
+    #[test]
+    fn rerandomize_with_mismatched_public_key() {
+        let num_blocks = 4;
+        let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+        let other_cks = ClientKey::new(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+
+        let mut ct = cks.encrypt(200u8);
+        let original = ct.clone();
+
+        // Public key of other parameters
+        let other_public_key = CompactPublicKey::new(&other_cks);
+        assert!(ct
+            .rerandomize(
+                &other_public_key,
+                &sks,
+                IntegerCompactCiphertextListCastingMode::NoCasting
+            )
+            .is_err());
+        assert_eq!(ct, original);
+
+        // Casting key that does not start from the key of the public key
+        let private_key = CompactPrivateKey::new(PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let public_key = CompactPublicKey::new(&private_key);
+        let other_ksk = KeySwitchingKey::new(
+            (&cks, Some(&sks)),
+            (&cks, &sks),
+            PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+        assert!(ct
+            .rerandomize(
+                &public_key,
+                &sks,
+                IntegerCompactCiphertextListCastingMode::CastIfNecessary(other_ksk.as_view()),
+            )
+            .is_err());
+        assert_eq!(ct, original);
+    }
+}
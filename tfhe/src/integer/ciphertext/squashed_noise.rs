@@ -0,0 +1,267 @@
+use super::DataKind;
+use crate::integer::backward_compatibility::ciphertext::{
+    CompressedSquashedNoiseCiphertextListVersions, SquashedNoiseBooleanBlockVersions,
+    SquashedNoiseRadixCiphertextVersions, SquashedNoiseSignedRadixCiphertextVersions,
+};
+use crate::integer::noise_squashing::NoiseSquashingKey;
+use crate::shortint::ciphertext::{
+    CompressedSquashedNoiseCiphertextList as ShortintCompressedSquashedNoiseCiphertextList,
+    SquashedNoiseCiphertext,
+};
+use crate::shortint::{CarryModulus, MessageModulus};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// Returns how many blocks of `message_modulus` are packed in one squashed block.
+///
+/// Two blocks are packed in the message and carries of one block when the carries are large
+/// enough, halving the number of 128 bits PBS.
+pub(crate) fn blocks_per_squashed_block(
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+) -> usize {
+    if carry_modulus.0 >= message_modulus.0 {
+        2
+    } else {
+        1
+    }
+}
+
+/// A radix ciphertext whose noise was squashed, see
+/// [`NoiseSquashingKey::squash_radix_ciphertext_noise`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseRadixCiphertextVersions)]
+pub struct SquashedNoiseRadixCiphertext {
+    pub(crate) packed_blocks: Vec<SquashedNoiseCiphertext>,
+    pub(crate) original_block_count: usize,
+}
+
+impl SquashedNoiseRadixCiphertext {
+    pub fn original_block_count(&self) -> usize {
+        self.original_block_count
+    }
+}
+
+/// A signed radix ciphertext whose noise was squashed, see
+/// [`NoiseSquashingKey::squash_signed_radix_ciphertext_noise`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseSignedRadixCiphertextVersions)]
+pub struct SquashedNoiseSignedRadixCiphertext {
+    pub(crate) packed_blocks: Vec<SquashedNoiseCiphertext>,
+    pub(crate) original_block_count: usize,
+}
+
+impl SquashedNoiseSignedRadixCiphertext {
+    pub fn original_block_count(&self) -> usize {
+        self.original_block_count
+    }
+}
+
+/// A boolean block whose noise was squashed, see
+/// [`NoiseSquashingKey::squash_boolean_block_noise`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(SquashedNoiseBooleanBlockVersions)]
+pub struct SquashedNoiseBooleanBlock {
+    pub(crate) ciphertext: SquashedNoiseCiphertext,
+}
+
+pub trait SquashedNoiseCompressible {
+    fn compress_into(self, messages: &mut Vec<SquashedNoiseCiphertext>) -> DataKind;
+}
+
+impl SquashedNoiseCompressible for SquashedNoiseBooleanBlock {
+    fn compress_into(self, messages: &mut Vec<SquashedNoiseCiphertext>) -> DataKind {
+        messages.push(self.ciphertext);
+        DataKind::Boolean
+    }
+}
+
+impl SquashedNoiseCompressible for SquashedNoiseRadixCiphertext {
+    fn compress_into(self, messages: &mut Vec<SquashedNoiseCiphertext>) -> DataKind {
+        messages.extend(self.packed_blocks);
+        DataKind::Unsigned(self.original_block_count)
+    }
+}
+
+impl SquashedNoiseCompressible for SquashedNoiseSignedRadixCiphertext {
+    fn compress_into(self, messages: &mut Vec<SquashedNoiseCiphertext>) -> DataKind {
+        messages.extend(self.packed_blocks);
+        DataKind::Signed(self.original_block_count)
+    }
+}
+
+pub trait SquashedNoiseExpandable: Sized {
+    fn from_expanded_blocks(
+        blocks: Vec<SquashedNoiseCiphertext>,
+        kind: DataKind,
+    ) -> crate::Result<Self>;
+}
+
+impl SquashedNoiseExpandable for SquashedNoiseRadixCiphertext {
+    fn from_expanded_blocks(
+        blocks: Vec<SquashedNoiseCiphertext>,
+        kind: DataKind,
+    ) -> crate::Result<Self> {
+        match kind {
+            DataKind::Unsigned(original_block_count) => Ok(Self {
+                packed_blocks: blocks,
+                original_block_count,
+            }),
+            DataKind::Signed(_) => Err(crate::Error::new(
+                "Tried to expand an unsigned radix while a signed radix is stored".to_string(),
+            )),
+            DataKind::Boolean => Err(crate::Error::new(
+                "Tried to expand an unsigned radix while boolean is stored".to_string(),
+            )),
+        }
+    }
+}
+
+impl SquashedNoiseExpandable for SquashedNoiseSignedRadixCiphertext {
+    fn from_expanded_blocks(
+        blocks: Vec<SquashedNoiseCiphertext>,
+        kind: DataKind,
+    ) -> crate::Result<Self> {
+        match kind {
+            DataKind::Signed(original_block_count) => Ok(Self {
+                packed_blocks: blocks,
+                original_block_count,
+            }),
+            DataKind::Unsigned(_) => Err(crate::Error::new(
+                "Tried to expand a signed radix while an unsigned radix is stored".to_string(),
+            )),
+            DataKind::Boolean => Err(crate::Error::new(
+                "Tried to expand a signed radix while boolean is stored".to_string(),
+            )),
+        }
+    }
+}
+
+impl SquashedNoiseExpandable for SquashedNoiseBooleanBlock {
+    fn from_expanded_blocks(
+        mut blocks: Vec<SquashedNoiseCiphertext>,
+        kind: DataKind,
+    ) -> crate::Result<Self> {
+        match kind {
+            DataKind::Unsigned(_) => Err(crate::Error::new(
+                "Tried to expand a boolean block while an unsigned radix was stored".to_string(),
+            )),
+            DataKind::Signed(_) => Err(crate::Error::new(
+                "Tried to expand a boolean block while a signed radix was stored".to_string(),
+            )),
+            DataKind::Boolean => Ok(Self {
+                ciphertext: blocks.swap_remove(0),
+            }),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CompressedSquashedNoiseCiphertextListBuilder {
+    pub(crate) ciphertexts: Vec<SquashedNoiseCiphertext>,
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl CompressedSquashedNoiseCiphertextListBuilder {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            ciphertexts: vec![],
+            info: vec![],
+        }
+    }
+
+    pub fn push<T>(&mut self, data: T) -> &mut Self
+    where
+        T: SquashedNoiseCompressible,
+    {
+        let n = self.ciphertexts.len();
+        let kind = data.compress_into(&mut self.ciphertexts);
+
+        if self.ciphertexts.len() != n {
+            self.info.push(kind);
+        }
+
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: SquashedNoiseCompressible,
+    {
+        for value in values {
+            self.push(value);
+        }
+        self
+    }
+
+    pub fn build(
+        &self,
+        noise_squashing_key: &NoiseSquashingKey,
+    ) -> CompressedSquashedNoiseCiphertextList {
+        let list = noise_squashing_key
+            .key
+            .compress_squashed_noise_ciphertexts_into_list(&self.ciphertexts);
+
+        CompressedSquashedNoiseCiphertextList {
+            list,
+            info: self.info.clone(),
+        }
+    }
+}
+
+/// A list of squashed ciphertexts, stored with a reduced modulus.
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(CompressedSquashedNoiseCiphertextListVersions)]
+pub struct CompressedSquashedNoiseCiphertextList {
+    pub(crate) list: ShortintCompressedSquashedNoiseCiphertextList,
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl CompressedSquashedNoiseCiphertextList {
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn packed_block_count(&self, kind: DataKind) -> usize {
+        match kind {
+            DataKind::Unsigned(n) | DataKind::Signed(n) => n.div_ceil(blocks_per_squashed_block(
+                self.list.message_modulus,
+                self.list.carry_modulus,
+            )),
+            DataKind::Boolean => 1,
+        }
+    }
+
+    pub fn get_kind_of(&self, index: usize) -> Option<DataKind> {
+        self.info.get(index).copied()
+    }
+
+    pub fn get<T>(&self, index: usize) -> crate::Result<Option<T>>
+    where
+        T: SquashedNoiseExpandable,
+    {
+        let Some(preceding_infos) = self.info.get(..index) else {
+            return Ok(None);
+        };
+        let Some(current_info) = self.info.get(index).copied() else {
+            return Ok(None);
+        };
+
+        let start_block_index: usize = preceding_infos
+            .iter()
+            .map(|kind| self.packed_block_count(*kind))
+            .sum();
+        let end_block_index = start_block_index + self.packed_block_count(current_info);
+
+        let blocks = (start_block_index..end_block_index)
+            .map(|i| self.list.unpack(i).unwrap())
+            .collect();
+
+        T::from_expanded_blocks(blocks, current_info).map(Some)
+    }
+}
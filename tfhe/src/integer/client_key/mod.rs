@@ -18,6 +18,8 @@ use crate::core_crypto::prelude::{CastFrom, SignedNumeric, UnsignedNumeric};
 use crate::integer::bigint::static_signed::StaticSignedBigInt;
 use crate::integer::block_decomposition::BlockRecomposer;
 use crate::integer::ciphertext::boolean_value::BooleanBlock;
+#[cfg(feature = "zk-pok")]
+use crate::integer::ciphertext::RadixDecryptionProof;
 use crate::integer::ciphertext::{CompressedCrtCiphertext, CrtCiphertext};
 use crate::integer::client_key::utils::i_crt;
 use crate::integer::compression_keys::{CompressionKey, CompressionPrivateKeys, DecompressionKey};
@@ -27,6 +29,8 @@ use crate::shortint::parameters::{CompressionParameters, MessageModulus};
 use crate::shortint::{
     Ciphertext, ClientKey as ShortintClientKey, ShortintParameterSet as ShortintParameters,
 };
+#[cfg(feature = "zk-pok")]
+use crate::zk::{DecryptionPublicParams, ZkComputeLoad};
 pub use crt::CrtClientKey;
 pub use radix::RadixClientKey;
 use secret_encryption_key::SecretEncryptionKeyView;
@@ -63,7 +67,7 @@ impl<const N: usize> RecomposableSignedInteger for StaticSignedBigInt<N> {}
 /// that has more bits than the original ciphertext.
 ///
 /// This is like doing i8 as i16, i16 as i64, i6 as i8, etc
-pub(in crate::integer) fn sign_extend_partial_number<T>(unpadded_value: T, num_bits_set: u32) -> T
+pub(crate) fn sign_extend_partial_number<T>(unpadded_value: T, num_bits_set: u32) -> T
 where
     T: RecomposableSignedInteger,
 {
@@ -350,6 +354,80 @@ impl ClientKey {
         )
     }
 
+    /// Decrypts a ciphertext encrypting a radix integer and proves that the returned value is its
+    /// decryption.
+    ///
+    /// The carries of the ciphertext must be empty, and `T` must be large enough to hold all its
+    /// blocks. The proof is verified with [`RadixCiphertext::verify_decryption`] by anyone knowing
+    /// the [`CompactPublicKey`](crate::integer::CompactPublicKey) of this key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, CompactPublicKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::zk::{DecryptionCrs, ZkComputeLoad};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    /// let crs = DecryptionCrs::from_shortint_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS).unwrap();
+    /// let metadata = [b'T', b'F', b'H', b'E', b'-', b'r', b's'];
+    ///
+    /// let msg = 191_u64;
+    /// let ct = cks.encrypt_radix(msg, 4);
+    ///
+    /// let (dec, proof) = cks
+    ///     .decrypt_radix_with_proof::<u64>(
+    ///         &ct,
+    ///         &pk,
+    ///         crs.public_params(),
+    ///         &metadata,
+    ///         ZkComputeLoad::Proof,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(msg, dec);
+    ///
+    /// assert!(ct
+    ///     .verify_decryption(dec, &proof, crs.public_params(), &pk, &metadata)
+    ///     .is_valid());
+    /// ```
+    #[cfg(feature = "zk-pok")]
+    pub fn decrypt_radix_with_proof<T>(
+        &self,
+        ctxt: &RadixCiphertext,
+        public_key: &crate::integer::CompactPublicKey,
+        public_params: &DecryptionPublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+    ) -> crate::Result<(T, RadixDecryptionProof)>
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        let message_modulus = self.key.parameters.message_modulus().0 as u64;
+        let bits_in_block = message_modulus.ilog2();
+        let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+        let mut block_proofs = Vec::with_capacity(ctxt.blocks.len());
+
+        for encrypted_block in &ctxt.blocks {
+            let (decrypted_block, proof) = self.key.decrypt_with_proof(
+                encrypted_block,
+                &public_key.key,
+                public_params,
+                metadata,
+                load,
+            )?;
+            if decrypted_block >= message_modulus {
+                return Err("Decryption proofs require ciphertexts with empty carries".into());
+            }
+            if !recomposer.add_unmasked(decrypted_block) && decrypted_block != 0 {
+                return Err("The decrypted value does not fit in the output type".into());
+            }
+            block_proofs.push(proof);
+        }
+
+        Ok((recomposer.value(), RadixDecryptionProof { block_proofs }))
+    }
+
     /// Decrypts a ciphertext in radix decomposition into 64bits
     ///
     /// The words are assumed to be in little endian order.
@@ -5,6 +5,7 @@ use super::backward_compatibility::key_switching_key::{
 use super::{ClientKey, CompressedServerKey, ServerKey};
 use crate::integer::client_key::secret_encryption_key::SecretEncryptionKeyView;
 use crate::integer::IntegerCiphertext;
+use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -39,6 +40,31 @@ impl<'keys> KeySwitchingKeyBuildHelper<'keys> {
             ),
         }
     }
+
+    /// Same as [Self::new], the key switching key is generated by the given engine instead of
+    /// the thread local one
+    pub(crate) fn new_with_engine<'input_key, InputEncryptionKey>(
+        input_key_pair: (InputEncryptionKey, Option<&'keys ServerKey>),
+        output_key_pair: (&'keys ClientKey, &'keys ServerKey),
+        params: ShortintKeySwitchingParameters,
+        engine: &mut ShortintEngine,
+    ) -> Self
+    where
+        InputEncryptionKey: Into<SecretEncryptionKeyView<'input_key>>,
+    {
+        let (secret_key, src_sks) = input_key_pair;
+        let secret_key: SecretEncryptionKeyView<'_> = secret_key.into();
+
+        Self {
+            build_helper:
+                crate::shortint::key_switching_key::KeySwitchingKeyBuildHelper::new_with_engine(
+                    (&secret_key.key, src_sks.map(AsRef::as_ref)),
+                    (output_key_pair.0.as_ref(), output_key_pair.1.as_ref()),
+                    params,
+                    engine,
+                ),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
@@ -207,6 +233,31 @@ impl<'keys> CompressedKeySwitchingKeyBuildHelper<'keys> {
                 ),
         }
     }
+
+    /// Same as [Self::new], the key switching key is generated by the given engine instead of
+    /// the thread local one
+    pub(crate) fn new_with_engine<'input_key, InputEncryptionKey>(
+        input_key_pair: (InputEncryptionKey, Option<&'keys CompressedServerKey>),
+        output_key_pair: (&'keys ClientKey, &'keys CompressedServerKey),
+        params: ShortintKeySwitchingParameters,
+        engine: &mut ShortintEngine,
+    ) -> Self
+    where
+        InputEncryptionKey: Into<SecretEncryptionKeyView<'input_key>>,
+    {
+        let (secret_key, src_sks) = input_key_pair;
+        let secret_key: SecretEncryptionKeyView<'_> = secret_key.into();
+
+        Self {
+            build_helper:
+                crate::shortint::key_switching_key::CompressedKeySwitchingKeyBuildHelper::new_with_engine(
+                    (&secret_key.key, src_sks.map(|k| &k.key)),
+                    (output_key_pair.0.as_ref(), &output_key_pair.1.key),
+                    params,
+                    engine,
+                ),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
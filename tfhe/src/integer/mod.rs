@@ -58,10 +58,13 @@ pub mod compression_keys;
 pub mod key_switching_key;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod noise_squashing;
 pub mod oprf;
 pub mod parameters;
 pub mod prelude;
+pub mod proxy_reencryption;
 pub mod public_key;
+pub mod sanitization;
 pub mod server_key;
 pub mod wopbs;
 
@@ -69,7 +72,7 @@ pub mod wopbs;
 pub mod gpu;
 
 #[cfg(feature = "zk-pok")]
-pub use ciphertext::ProvenCompactCiphertextList;
+pub use ciphertext::{ProvenCompactCiphertextList, RadixDecryptionProof};
 
 pub use bigint::i256::I256;
 pub use bigint::i512::I512;
@@ -82,7 +85,8 @@ pub use ciphertext::{
 };
 pub use client_key::{ClientKey, CrtClientKey, RadixClientKey};
 pub use public_key::{
-    CompactPrivateKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey,
+    CompactPrivateKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
+    GlwePublicKey, PublicKey,
 };
 pub use server_key::{CheckError, CompressedServerKey, ServerKey};
 
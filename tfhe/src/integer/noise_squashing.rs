@@ -0,0 +1,314 @@
+use super::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use super::ciphertext::{
+    blocks_per_squashed_block, SquashedNoiseBooleanBlock, SquashedNoiseRadixCiphertext,
+    SquashedNoiseSignedRadixCiphertext,
+};
+use super::client_key::{sign_extend_partial_number, RecomposableSignedInteger};
+use super::{BooleanBlock, ClientKey, RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::integer::backward_compatibility::noise_squashing::{
+    NoiseSquashingKeyVersions, NoiseSquashingPrivateKeyVersions,
+};
+use crate::shortint::ciphertext::{NoiseLevel, SquashedNoiseCiphertext};
+use crate::shortint::parameters::NoiseSquashingParameters;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// The key decrypting squashed integers, see
+/// [`crate::shortint::noise_squashing::NoiseSquashingPrivateKey`].
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingPrivateKeyVersions)]
+pub struct NoiseSquashingPrivateKey {
+    pub(crate) key: crate::shortint::noise_squashing::NoiseSquashingPrivateKey,
+}
+
+impl NoiseSquashingPrivateKey {
+    pub fn new(params: NoiseSquashingParameters) -> Self {
+        Self {
+            key: crate::shortint::noise_squashing::NoiseSquashingPrivateKey::new(params),
+        }
+    }
+
+    pub fn into_raw_parts(self) -> crate::shortint::noise_squashing::NoiseSquashingPrivateKey {
+        let Self { key } = self;
+        key
+    }
+
+    pub fn from_raw_parts(key: crate::shortint::noise_squashing::NoiseSquashingPrivateKey) -> Self {
+        Self { key }
+    }
+
+    /// Decrypts the packed blocks, returning the blocks of the original ciphertext.
+    fn decrypt_blocks(
+        &self,
+        packed_blocks: &[SquashedNoiseCiphertext],
+        original_block_count: usize,
+    ) -> Vec<u64> {
+        let Some(first_block) = packed_blocks.first() else {
+            return vec![];
+        };
+
+        let message_modulus = first_block.message_modulus.0 as u128;
+        let blocks_per_squashed_block =
+            blocks_per_squashed_block(first_block.message_modulus, first_block.carry_modulus);
+
+        let mut blocks = Vec::with_capacity(packed_blocks.len() * blocks_per_squashed_block);
+        for packed_block in packed_blocks {
+            let mut decrypted = self.key.decrypt_squashed_noise_ciphertext(packed_block);
+            for _ in 0..blocks_per_squashed_block {
+                blocks.push((decrypted % message_modulus) as u64);
+                decrypted /= message_modulus;
+            }
+        }
+
+        blocks.truncate(original_block_count);
+        blocks
+    }
+
+    fn decrypt_unsigned<T>(
+        &self,
+        packed_blocks: &[SquashedNoiseCiphertext],
+        original_block_count: usize,
+    ) -> T
+    where
+        T: RecomposableFrom<u64>,
+    {
+        let Some(first_block) = packed_blocks.first() else {
+            return T::ZERO;
+        };
+
+        let bits_in_block = first_block.message_modulus.0.ilog2();
+        let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+        for block in self.decrypt_blocks(packed_blocks, original_block_count) {
+            if !recomposer.add_unmasked(block) {
+                // End of T::BITS reached no need to try more
+                // recomposition
+                break;
+            };
+        }
+
+        recomposer.value()
+    }
+
+    /// Decrypts a squashed radix ciphertext.
+    pub fn decrypt_radix<T>(&self, ct: &SquashedNoiseRadixCiphertext) -> T
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        self.decrypt_unsigned(&ct.packed_blocks, ct.original_block_count)
+    }
+
+    /// Decrypts a squashed signed radix ciphertext.
+    pub fn decrypt_signed_radix<T>(&self, ct: &SquashedNoiseSignedRadixCiphertext) -> T
+    where
+        T: RecomposableSignedInteger,
+    {
+        let Some(first_block) = ct.packed_blocks.first() else {
+            return T::ZERO;
+        };
+
+        let unpadded_value = self.decrypt_unsigned(&ct.packed_blocks, ct.original_block_count);
+
+        let num_bits_in_message = first_block.message_modulus.0.ilog2();
+        let num_bits_in_ctxt = num_bits_in_message * ct.original_block_count as u32;
+        sign_extend_partial_number(unpadded_value, num_bits_in_ctxt)
+    }
+
+    /// Decrypts a squashed boolean block.
+    pub fn decrypt_bool(&self, ct: &SquashedNoiseBooleanBlock) -> bool {
+        self.key.decrypt_squashed_noise_ciphertext(&ct.ciphertext) != 0
+    }
+}
+
+/// The key squashing the noise of integer ciphertexts, see
+/// [`crate::shortint::noise_squashing::NoiseSquashingKey`].
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingKeyVersions)]
+pub struct NoiseSquashingKey {
+    pub(crate) key: crate::shortint::noise_squashing::NoiseSquashingKey,
+}
+
+impl NoiseSquashingKey {
+    pub fn new(
+        client_key: &ClientKey,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> Self {
+        Self {
+            key: crate::shortint::noise_squashing::NoiseSquashingKey::new(
+                &client_key.key,
+                &noise_squashing_private_key.key,
+            ),
+        }
+    }
+
+    pub fn into_raw_parts(self) -> crate::shortint::noise_squashing::NoiseSquashingKey {
+        let Self { key } = self;
+        key
+    }
+
+    pub fn from_raw_parts(key: crate::shortint::noise_squashing::NoiseSquashingKey) -> Self {
+        Self { key }
+    }
+
+    /// Squashes the noise of the blocks, packing pairs of blocks in one squashed block when the
+    /// carries are large enough.
+    fn squash_blocks(
+        &self,
+        blocks: &[Ciphertext],
+        src_server_key: &ServerKey,
+    ) -> Vec<SquashedNoiseCiphertext> {
+        let sks = &src_server_key.key;
+        let blocks_per_squashed_block =
+            blocks_per_squashed_block(sks.message_modulus, sks.carry_modulus);
+
+        blocks
+            .par_chunks(blocks_per_squashed_block)
+            .map(|chunk| {
+                let clean = |block: &Ciphertext| {
+                    let mut block = block.clone();
+                    if !block.carry_is_empty() || block.noise_level() > NoiseLevel::NOMINAL {
+                        sks.message_extract_assign(&mut block);
+                    }
+                    block
+                };
+
+                let packed = match chunk {
+                    [low, high] => sks.unchecked_add(
+                        &clean(low),
+                        &sks.unchecked_scalar_mul(&clean(high), sks.message_modulus.0 as u8),
+                    ),
+                    [single] => clean(single),
+                    _ => unreachable!(),
+                };
+
+                self.key.squash_ciphertext_noise(&packed, sks)
+            })
+            .collect()
+    }
+
+    /// Squashes the noise of a radix ciphertext, the result can only be decrypted with the
+    /// [`NoiseSquashingPrivateKey`] of the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+    /// let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    /// let noise_squashing_key = NoiseSquashingKey::new(cks.as_ref(), &noise_squashing_private_key);
+    ///
+    /// let ct = sks.mul_parallelized(&cks.encrypt(7u8), &cks.encrypt(6u8));
+    /// let squashed = noise_squashing_key.squash_radix_ciphertext_noise(&ct, &sks);
+    ///
+    /// let dec: u8 = noise_squashing_private_key.decrypt_radix(&squashed);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn squash_radix_ciphertext_noise(
+        &self,
+        ct: &RadixCiphertext,
+        src_server_key: &ServerKey,
+    ) -> SquashedNoiseRadixCiphertext {
+        SquashedNoiseRadixCiphertext {
+            packed_blocks: self.squash_blocks(&ct.blocks, src_server_key),
+            original_block_count: ct.blocks.len(),
+        }
+    }
+
+    /// Squashes the noise of a signed radix ciphertext, see
+    /// [`Self::squash_radix_ciphertext_noise`].
+    pub fn squash_signed_radix_ciphertext_noise(
+        &self,
+        ct: &SignedRadixCiphertext,
+        src_server_key: &ServerKey,
+    ) -> SquashedNoiseSignedRadixCiphertext {
+        SquashedNoiseSignedRadixCiphertext {
+            packed_blocks: self.squash_blocks(&ct.blocks, src_server_key),
+            original_block_count: ct.blocks.len(),
+        }
+    }
+
+    /// Squashes the noise of a boolean block, see [`Self::squash_radix_ciphertext_noise`].
+    pub fn squash_boolean_block_noise(
+        &self,
+        ct: &BooleanBlock,
+        src_server_key: &ServerKey,
+    ) -> SquashedNoiseBooleanBlock {
+        SquashedNoiseBooleanBlock {
+            ciphertext: self.key.squash_ciphertext_noise(&ct.0, &src_server_key.key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+    use crate::integer::ciphertext::{
+        CompressedSquashedNoiseCiphertextListBuilder, SquashedNoiseBooleanBlock,
+        SquashedNoiseRadixCiphertext, SquashedNoiseSignedRadixCiphertext,
+    };
+    use crate::integer::gen_keys_radix;
+    use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+    #[test]
+    fn squash_and_compress_radix_ciphertexts() {
+        // An odd number of blocks, the last one is not packed with another block
+        let num_blocks = 5;
+        let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+        let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+            NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+        let noise_squashing_key =
+            NoiseSquashingKey::new(cks.as_ref(), &noise_squashing_private_key);
+
+        let clear_a = 517u16;
+        let clear_b = -300i16;
+
+        // The sum leaves carries in the blocks, they are cleaned before the packing
+        let a = sks.unchecked_add(&cks.encrypt(clear_a), &cks.encrypt(2u16));
+        let b = cks.encrypt_signed(clear_b);
+        let c = sks.scalar_gt_parallelized(&a, 100u16);
+
+        let squashed_a = noise_squashing_key.squash_radix_ciphertext_noise(&a, &sks);
+        let squashed_b = noise_squashing_key.squash_signed_radix_ciphertext_noise(&b, &sks);
+        let squashed_c = noise_squashing_key.squash_boolean_block_noise(&c, &sks);
+
+        assert_eq!(squashed_a.packed_blocks.len(), 3);
+        assert_eq!(squashed_a.original_block_count(), num_blocks);
+
+        let dec: u16 = noise_squashing_private_key.decrypt_radix(&squashed_a);
+        assert_eq!(dec, clear_a + 2);
+        let dec: i16 = noise_squashing_private_key.decrypt_signed_radix(&squashed_b);
+        assert_eq!(dec, clear_b);
+        assert!(noise_squashing_private_key.decrypt_bool(&squashed_c));
+
+        let list = CompressedSquashedNoiseCiphertextListBuilder::new()
+            .push(squashed_a)
+            .push(squashed_b)
+            .push(squashed_c)
+            .build(&noise_squashing_key);
+        assert_eq!(list.len(), 3);
+
+        let a: SquashedNoiseRadixCiphertext = list.get(0).unwrap().unwrap();
+        let b: SquashedNoiseSignedRadixCiphertext = list.get(1).unwrap().unwrap();
+        let c: SquashedNoiseBooleanBlock = list.get(2).unwrap().unwrap();
+        assert!(list.get::<SquashedNoiseBooleanBlock>(0).is_err());
+        assert!(list.get::<SquashedNoiseBooleanBlock>(3).unwrap().is_none());
+
+        let dec: u16 = noise_squashing_private_key.decrypt_radix(&a);
+        assert_eq!(dec, clear_a + 2);
+        let dec: i16 = noise_squashing_private_key.decrypt_signed_radix(&b);
+        assert_eq!(dec, clear_b);
+        assert!(noise_squashing_private_key.decrypt_bool(&c));
+    }
+}
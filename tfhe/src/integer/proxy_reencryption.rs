@@ -0,0 +1,261 @@
+use super::ciphertext::{
+    extract_message_and_carries, CompactCiphertextList, CompactCiphertextListExpander,
+    CompressedCiphertextList,
+};
+use super::compression_keys::CompressionPrivateKeys;
+use super::parameters::IntegerCompactCiphertextListCastingMode;
+use super::{ClientKey, CompactPrivateKey, CompactPublicKey, IntegerCiphertext, ServerKey};
+use crate::core_crypto::prelude::Container;
+use crate::integer::backward_compatibility::proxy_reencryption::ProxyReencryptionKeyVersions;
+use crate::shortint::parameters::ProxyReencryptionParameters;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// Key re-encrypting ciphertexts to the key of a [`CompactPublicKey`], see
+/// [`crate::shortint::proxy_reencryption`].
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(ProxyReencryptionKeyVersions)]
+pub struct ProxyReencryptionKey {
+    pub(crate) key: crate::shortint::proxy_reencryption::ProxyReencryptionKey,
+}
+
+impl ProxyReencryptionKey {
+    pub fn into_raw_parts(self) -> crate::shortint::proxy_reencryption::ProxyReencryptionKey {
+        let Self { key } = self;
+        key
+    }
+
+    pub fn from_raw_parts(key: crate::shortint::proxy_reencryption::ProxyReencryptionKey) -> Self {
+        Self { key }
+    }
+}
+
+impl ClientKey {
+    pub fn new_proxy_reencryption_key(
+        &self,
+        public_key: &CompactPublicKey,
+        params: ProxyReencryptionParameters,
+    ) -> ProxyReencryptionKey {
+        ProxyReencryptionKey {
+            key: self.key.new_proxy_reencryption_key(&public_key.key, params),
+        }
+    }
+}
+
+impl<C: Container<Element = u64>> CompactPrivateKey<C> {
+    pub fn new_proxy_reencryption_key(
+        &self,
+        public_key: &CompactPublicKey,
+        params: ProxyReencryptionParameters,
+    ) -> ProxyReencryptionKey {
+        ProxyReencryptionKey {
+            key: self.key.new_proxy_reencryption_key(&public_key.key, params),
+        }
+    }
+}
+
+impl CompressionPrivateKeys {
+    pub fn new_proxy_reencryption_key(
+        &self,
+        public_key: &CompactPublicKey,
+        params: ProxyReencryptionParameters,
+    ) -> ProxyReencryptionKey {
+        ProxyReencryptionKey {
+            key: self.key.new_proxy_reencryption_key(&public_key.key, params),
+        }
+    }
+}
+
+impl ServerKey {
+    /// Re-encrypts each block of the ciphertext to the key of the compact public key of
+    /// `reencryption_key`, `self` being the server key of the recipient.
+    ///
+    /// See [`crate::shortint::proxy_reencryption::ProxyReencryptionKey::reencrypt`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::parameters::IntegerCompactCiphertextListCastingMode;
+    /// use tfhe::integer::{gen_keys_radix, CompactPublicKey};
+    /// use tfhe::shortint::parameters::proxy_reencryption::PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (alice_cks, alice_sks) =
+    ///     gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+    /// let (bob_cks, bob_sks) =
+    ///     gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+    /// let bob_public_key = CompactPublicKey::new(bob_cks.as_ref());
+    ///
+    /// let reencryption_key = alice_cks.as_ref().new_proxy_reencryption_key(
+    ///     &bob_public_key,
+    ///     PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    ///
+    /// let ct = alice_sks.mul_parallelized(&alice_cks.encrypt(7u8), &alice_cks.encrypt(6u8));
+    /// let reencrypted = bob_sks
+    ///     .reencrypt_parallelized(
+    ///         &ct,
+    ///         &reencryption_key,
+    ///         IntegerCompactCiphertextListCastingMode::NoCasting,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let dec: u8 = bob_cks.decrypt(&reencrypted);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn reencrypt_parallelized<T>(
+        &self,
+        ct: &T,
+        reencryption_key: &ProxyReencryptionKey,
+        casting_mode: IntegerCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<T>
+    where
+        T: IntegerCiphertext,
+    {
+        let casting_mode = casting_mode.into();
+        let blocks = ct
+            .blocks()
+            .par_iter()
+            .map(|block| {
+                reencryption_key
+                    .key
+                    .reencrypt(block, &self.key, casting_mode)
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        Ok(T::from_blocks(blocks))
+    }
+}
+
+impl CompactCiphertextList {
+    /// Re-encrypts the list to the key of the compact public key of `reencryption_key`, the
+    /// returned expander holds ciphertexts under the `server_key` of the recipient.
+    ///
+    /// Packed lists are unpacked by the PBS cleaning the noise of the re-encrypted ciphertexts.
+    pub fn reencrypt(
+        &self,
+        reencryption_key: &ProxyReencryptionKey,
+        server_key: &ServerKey,
+        casting_mode: IntegerCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        let casting_mode = casting_mode.into();
+        let blocks = if self.is_packed() {
+            let packed_blocks = reencryption_key.key.keyswitch_compact_list(
+                &self.ct_list,
+                &server_key.key,
+                casting_mode,
+            )?;
+            extract_message_and_carries(packed_blocks, server_key)
+        } else {
+            reencryption_key.key.reencrypt_compact_list(
+                &self.ct_list,
+                &server_key.key,
+                casting_mode,
+            )?
+        };
+
+        Ok(CompactCiphertextListExpander::new(
+            blocks,
+            self.info.clone(),
+        ))
+    }
+}
+
+impl CompressedCiphertextList {
+    /// Re-encrypts the list to the key of the compact public key of `reencryption_key`, the
+    /// returned expander holds ciphertexts under the `server_key` of the recipient.
+    ///
+    /// The list is not decompressed first, `reencryption_key` must have been generated from the
+    /// [`CompressionPrivateKeys`] of the list.
+    pub fn reencrypt(
+        &self,
+        reencryption_key: &ProxyReencryptionKey,
+        server_key: &ServerKey,
+        casting_mode: IntegerCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        let blocks = reencryption_key.key.reencrypt_compressed_list(
+            &self.packed_list,
+            &server_key.key,
+            casting_mode.into(),
+        )?;
+
+        Ok(CompactCiphertextListExpander::new(
+            blocks,
+            self.info.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::integer::ciphertext::CompactCiphertextList;
+    use crate::integer::key_switching_key::KeySwitchingKey;
+    use crate::integer::parameters::IntegerCompactCiphertextListCastingMode;
+    use crate::integer::{
+        gen_keys_radix, CompactPrivateKey, CompactPublicKey, RadixCiphertext, SignedRadixCiphertext,
+    };
+    use crate::shortint::parameters::compact_public_key_only::p_fail_2_minus_64::ks_pbs::PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::key_switching::p_fail_2_minus_64::ks_pbs::PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::proxy_reencryption::PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+    #[test]
+    fn reencrypt_packed_compact_list() {
+        let num_blocks = 4;
+        let (alice_cks, _) =
+            gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+        let alice_public_key = CompactPublicKey::new(alice_cks.as_ref());
+
+        let (bob_cks, bob_sks) =
+            gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+        let bob_private_key =
+            CompactPrivateKey::new(PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let bob_public_key = CompactPublicKey::new(&bob_private_key);
+        let bob_casting_key = KeySwitchingKey::new(
+            (&bob_private_key, None),
+            (&bob_cks, &bob_sks),
+            PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+
+        let reencryption_key = alice_cks.as_ref().new_proxy_reencryption_key(
+            &bob_public_key,
+            PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+
+        let list = CompactCiphertextList::builder(&alice_public_key)
+            .push_with_num_blocks(200u8, num_blocks)
+            .push_with_num_blocks(-3i8, num_blocks)
+            .push(true)
+            .build_packed()
+            .unwrap();
+
+        let expander = list
+            .reencrypt(
+                &reencryption_key,
+                &bob_sks,
+                IntegerCompactCiphertextListCastingMode::CastIfNecessary(bob_casting_key.as_view()),
+            )
+            .unwrap();
+
+        let a: RadixCiphertext = expander.get(0).unwrap().unwrap();
+        let b: SignedRadixCiphertext = expander.get(1).unwrap().unwrap();
+        let c = expander.get(2).unwrap().unwrap();
+
+        let a: u8 = bob_cks.decrypt(&a);
+        let b: i8 = bob_cks.decrypt_signed(&b);
+        assert_eq!(a, 200);
+        assert_eq!(b, -3);
+        assert!(bob_cks.decrypt_bool(&c));
+
+        // The list of Alice is not under the key of Bob's server key
+        assert!(list
+            .reencrypt(
+                &reencryption_key,
+                &bob_sks,
+                IntegerCompactCiphertextListCastingMode::NoCasting,
+            )
+            .is_err());
+    }
+}
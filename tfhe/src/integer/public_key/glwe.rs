@@ -0,0 +1,34 @@
+use crate::integer::ClientKey;
+use crate::shortint::GlwePublicKey as ShortintGlwePublicKey;
+use serde::{Deserialize, Serialize};
+
+/// A public key encrypting values in GLWE ciphertexts, see
+/// [`ProvenGlweCiphertextList`](crate::integer::ciphertext::ProvenGlweCiphertextList).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GlwePublicKey {
+    pub(crate) key: ShortintGlwePublicKey,
+}
+
+impl GlwePublicKey {
+    pub fn new(client_key: &ClientKey) -> Self {
+        Self {
+            key: ShortintGlwePublicKey::new(&client_key.key),
+        }
+    }
+
+    pub fn try_new(client_key: &ClientKey) -> crate::Result<Self> {
+        Ok(Self {
+            key: ShortintGlwePublicKey::try_new(&client_key.key)?,
+        })
+    }
+
+    /// Deconstruct a [`GlwePublicKey`] into its constituents.
+    pub fn into_raw_parts(self) -> ShortintGlwePublicKey {
+        self.key
+    }
+
+    /// Construct a [`GlwePublicKey`] from its constituents.
+    pub fn from_raw_parts(key: ShortintGlwePublicKey) -> Self {
+        Self { key }
+    }
+}
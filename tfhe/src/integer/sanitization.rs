@@ -0,0 +1,67 @@
+use super::{ClientKey, IntegerCiphertext, ServerKey};
+use crate::integer::backward_compatibility::sanitization::SanitizationKeyVersions;
+use crate::shortint::parameters::SanitizationParameters;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(SanitizationKeyVersions)]
+pub struct SanitizationKey {
+    pub(crate) key: crate::shortint::sanitization::SanitizationKey,
+}
+
+impl SanitizationKey {
+    pub fn into_raw_parts(self) -> crate::shortint::sanitization::SanitizationKey {
+        let Self { key } = self;
+        key
+    }
+
+    pub fn from_raw_parts(key: crate::shortint::sanitization::SanitizationKey) -> Self {
+        Self { key }
+    }
+}
+
+impl ClientKey {
+    pub fn new_sanitization_key(&self, params: SanitizationParameters) -> SanitizationKey {
+        SanitizationKey {
+            key: self.key.new_sanitization_key(params),
+        }
+    }
+}
+
+impl ServerKey {
+    /// Sanitizes each block of the ciphertext, see
+    /// [`crate::shortint::sanitization::SanitizationKey::sanitize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+    /// let sanitization_key = cks
+    ///     .as_ref()
+    ///     .new_sanitization_key(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    ///
+    /// let ct = sks.mul_parallelized(&cks.encrypt(7u8), &cks.encrypt(6u8));
+    /// let sanitized = sks.sanitize_parallelized(&ct, &sanitization_key);
+    ///
+    /// let dec: u8 = cks.decrypt(&sanitized);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn sanitize_parallelized<T>(&self, ct: &T, sanitization_key: &SanitizationKey) -> T
+    where
+        T: IntegerCiphertext,
+    {
+        T::from_blocks(
+            ct.blocks()
+                .par_iter()
+                .map(|block| sanitization_key.key.sanitize(&self.key, block))
+                .collect(),
+        )
+    }
+}
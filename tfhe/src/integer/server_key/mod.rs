@@ -10,6 +10,7 @@ pub(crate) mod radix_parallel;
 
 use crate::integer::client_key::ClientKey;
 use crate::shortint::ciphertext::{Degree, MaxDegree};
+use crate::shortint::engine::ShortintEngine;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
 
@@ -94,17 +95,24 @@ impl ServerKey {
     where
         C: AsRef<ClientKey>,
     {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new_radix_server_key_with_engine(cks.as_ref(), engine)
+        })
+    }
+
+    /// Same as [Self::new_radix_server_key], the key is generated by the given engine instead of
+    /// the thread local one
+    pub(crate) fn new_radix_server_key_with_engine(
+        client_key: &ClientKey,
+        engine: &mut ShortintEngine,
+    ) -> Self {
         // It should remain just enough space to add a carry
-        let client_key = cks.as_ref();
         let max_degree = MaxDegree::integer_radix_server_key(
             client_key.key.parameters.message_modulus(),
             client_key.key.parameters.carry_modulus(),
         );
 
-        let sks = crate::shortint::server_key::ServerKey::new_with_max_degree(
-            &client_key.key,
-            max_degree,
-        );
+        let sks = engine.new_server_key_with_max_degree(&client_key.key, max_degree);
 
         Self { key: sks }
     }
@@ -265,13 +273,23 @@ pub struct CompressedServerKey {
 
 impl CompressedServerKey {
     pub fn new_radix_compressed_server_key(client_key: &ClientKey) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new_radix_compressed_server_key_with_engine(client_key, engine)
+        })
+    }
+
+    /// Same as [Self::new_radix_compressed_server_key], the key is generated by the given engine
+    /// instead of the thread local one
+    pub(crate) fn new_radix_compressed_server_key_with_engine(
+        client_key: &ClientKey,
+        engine: &mut ShortintEngine,
+    ) -> Self {
         let max_degree = MaxDegree::integer_radix_server_key(
             client_key.key.parameters.message_modulus(),
             client_key.key.parameters.carry_modulus(),
         );
 
-        let key =
-            crate::shortint::CompressedServerKey::new_with_max_degree(&client_key.key, max_degree);
+        let key = engine.new_compressed_server_key_with_max_degree(&client_key.key, max_degree);
         Self { key }
     }
 
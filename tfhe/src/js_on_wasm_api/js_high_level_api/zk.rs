@@ -106,4 +106,41 @@ impl CompactPkeCrs {
     pub fn public_params(&self) -> CompactPkePublicParams {
         CompactPkePublicParams(self.0.public_params().clone())
     }
+
+    #[wasm_bindgen]
+    pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
+        catch_panic_result(|| bincode::serialize(&self.0).map_err(into_js_error))
+    }
+
+    #[wasm_bindgen]
+    pub fn deserialize(buffer: &[u8]) -> Result<CompactPkeCrs, JsError> {
+        catch_panic_result(|| {
+            bincode::deserialize(buffer)
+                .map(CompactPkeCrs)
+                .map_err(into_js_error)
+        })
+    }
+
+    #[wasm_bindgen]
+    pub fn safe_serialize(&self, serialized_size_limit: u64) -> Result<Vec<u8>, JsError> {
+        let mut buffer = vec![];
+        catch_panic_result(|| {
+            crate::safe_deserialization::safe_serialize(&self.0, &mut buffer, serialized_size_limit)
+                .map_err(into_js_error)
+        })?;
+
+        Ok(buffer)
+    }
+
+    #[wasm_bindgen]
+    pub fn safe_deserialize(
+        buffer: &[u8],
+        serialized_size_limit: u64,
+    ) -> Result<CompactPkeCrs, JsError> {
+        catch_panic_result(|| {
+            crate::safe_deserialization::safe_deserialize(buffer, serialized_size_limit)
+                .map(CompactPkeCrs)
+                .map_err(into_js_error)
+        })
+    }
 }
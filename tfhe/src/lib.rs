@@ -131,6 +131,9 @@ pub mod keycache;
 
 pub mod safe_deserialization;
 
+#[cfg(feature = "integer")]
+pub mod inspect;
+
 pub mod conformance;
 
 pub mod named;
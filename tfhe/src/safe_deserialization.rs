@@ -198,12 +198,12 @@ pub fn safe_deserialize_conformant<T: DeserializeOwned + Named + ParameterSetCon
 ) -> Result<T, String> {
     let deser: T = safe_deserialize(reader, serialized_size_limit)?;
 
-    if !deser.is_conformant(parameter_set) {
-        return Err(format!(
-            "Deserialized object of type {} not conformant with given parameter set",
+    deser.check_conformance(parameter_set).map_err(|err| {
+        format!(
+            "Deserialized object of type {} not conformant with given parameter set: {err}",
             T::NAME
-        ));
-    }
+        )
+    })?;
 
     Ok(deser)
 }
@@ -244,21 +244,112 @@ pub fn safe_deserialize_conformant_versioned<T: Unversionize + Named + Parameter
 ) -> Result<T, String> {
     let deser: T = safe_deserialize_versioned(reader, serialized_size_limit)?;
 
-    if !deser.is_conformant(parameter_set) {
-        return Err(format!(
-            "Deserialized object of type {} not conformant with given parameter set",
+    deser.check_conformance(parameter_set).map_err(|err| {
+        format!(
+            "Deserialized object of type {} not conformant with given parameter set: {err}",
             T::NAME
-        ));
-    }
+        )
+    })?;
 
     Ok(deser)
 }
 
+/// Metadata written in front of an object by [safe_serialize] or [safe_serialize_versioned]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializedObjectHeader {
+    /// `true` if the object was serialized with [safe_serialize_versioned]
+    pub versioned: bool,
+    /// The serialization version for [safe_serialize], or the versioning scheme version for
+    /// [safe_serialize_versioned]
+    pub format_version: String,
+    /// The [Named::NAME] of the serialized type
+    pub type_name: String,
+}
+
+/// Reads the header of an object serialized with [safe_serialize] or [safe_serialize_versioned].
+///
+/// Only the header is consumed from the reader, the serialized object itself is left untouched.
+/// The version and type name are not checked, this can be used to find out what a serialized
+/// object is before choosing how to deserialize it.
+pub fn read_serialization_header(
+    mut reader: impl std::io::Read,
+) -> Result<SerializedObjectHeader, String> {
+    let options = bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .with_limit(0);
+
+    // A versioned header starts with the `SerializationMode` discriminant, whereas
+    // `safe_serialize` directly writes the version as a String, starting with its u64 length.
+    // Since the version is never shorter than 2 characters, the first 4 bytes are enough to tell
+    // them apart.
+    let first_word: u32 = options
+        .with_limit(4)
+        .deserialize_from(&mut reader)
+        .map_err(|err| err.to_string())?;
+
+    let (versioned, format_version) = match first_word {
+        0 | 1 => {
+            let version: String = options
+                .with_limit(VERSION_LENGTH_LIMIT)
+                .deserialize_from(&mut reader)
+                .map_err(|err| err.to_string())?;
+            (first_word == 0, version)
+        }
+        length_low => {
+            let length_high: u32 = options
+                .with_limit(4)
+                .deserialize_from(&mut reader)
+                .map_err(|err| err.to_string())?;
+            let length = (u64::from(length_high) << 32) | u64::from(length_low);
+
+            if length > VERSION_LENGTH_LIMIT {
+                return Err(format!(
+                    "Serialized version of length {length} exceeds the limit of \
+                    {VERSION_LENGTH_LIMIT} bytes"
+                ));
+            }
+
+            let mut version = vec![0u8; length as usize];
+            reader
+                .read_exact(&mut version)
+                .map_err(|err| err.to_string())?;
+            let version = String::from_utf8(version).map_err(|err| err.to_string())?;
+            (false, version)
+        }
+    };
+
+    let type_name: String = options
+        .with_limit(TYPE_NAME_LENGTH_LIMIT)
+        .deserialize_from(&mut reader)
+        .map_err(|err| err.to_string())?;
+
+    Ok(SerializedObjectHeader {
+        versioned,
+        format_version,
+        type_name,
+    })
+}
+
+/// Name of the newtype struct through which objects holding large buffers that can be skipped
+/// are deserialized.
+///
+/// A [Deserialize](serde::Deserialize) implementation requests it with
+/// [deserialize_newtype_struct](serde::Deserializer::deserialize_newtype_struct), which is
+/// transparent for the formats used by TFHE-rs: the visitor's
+/// [visit_newtype_struct](serde::de::Visitor::visit_newtype_struct) is called and the content
+/// must be fully deserialized. The inspection deserializer instead calls
+/// [visit_seq](serde::de::Visitor::visit_seq) directly, in which case the content only has to be
+/// read and may be discarded instead of being stored.
+pub(crate) const SKIPPABLE_BULK_DATA: &str = "$tfhe::SkippableBulkData";
+
 #[cfg(all(test, feature = "shortint"))]
 mod test_shortint {
+    use crate::conformance::ParameterSetConformant;
+    use crate::named::Named;
     use crate::safe_deserialization::{
-        safe_deserialize_conformant, safe_deserialize_conformant_versioned, safe_serialize,
-        safe_serialize_versioned,
+        read_serialization_header, safe_deserialize_conformant,
+        safe_deserialize_conformant_versioned, safe_serialize, safe_serialize_versioned,
+        SERIALIZATION_VERSION, VERSIONING_VERSION,
     };
     use crate::shortint::parameters::{
         PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_3_CARRY_3_KS_PBS,
@@ -277,6 +368,11 @@ mod test_shortint {
 
         safe_serialize(&ct, &mut buffer, 1 << 40).unwrap();
 
+        let err = ct
+            .check_conformance(&PARAM_MESSAGE_3_CARRY_3_KS_PBS.to_shortint_conformance_param())
+            .unwrap_err();
+        assert_eq!(err.field(), "lwe_size");
+
         assert!(safe_deserialize_conformant::<Ciphertext>(
             buffer.as_slice(),
             1 << 20,
@@ -295,6 +391,27 @@ mod test_shortint {
         assert_eq!(msg, dec);
     }
 
+    #[test]
+    fn read_header() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let ct = ck.encrypt(2);
+
+        let mut buffer = vec![];
+        safe_serialize(&ct, &mut buffer, 1 << 40).unwrap();
+        let header = read_serialization_header(buffer.as_slice()).unwrap();
+        assert!(!header.versioned);
+        assert_eq!(header.format_version, SERIALIZATION_VERSION);
+        assert_eq!(header.type_name, Ciphertext::NAME);
+
+        let mut buffer = vec![];
+        safe_serialize_versioned(&ct, &mut buffer, 1 << 40).unwrap();
+        let header = read_serialization_header(buffer.as_slice()).unwrap();
+        assert!(header.versioned);
+        assert_eq!(header.format_version, VERSIONING_VERSION);
+        assert_eq!(header.type_name, Ciphertext::NAME);
+    }
+
     #[test]
     fn safe_deserialization_ct_versioned() {
         let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
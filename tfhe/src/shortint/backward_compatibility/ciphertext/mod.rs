@@ -112,3 +112,25 @@ pub(crate) enum InternalCompressedModulusSwitchedCiphertextVersions {
 pub enum CompressedCiphertextListVersions {
     V0(CompressedCiphertextList),
 }
+
+#[cfg(feature = "zk-pok")]
+#[derive(VersionsDispatch)]
+pub enum ProvenCompactCiphertextListVersions {
+    V0(ProvenCompactCiphertextList),
+}
+
+#[cfg(feature = "zk-pok")]
+#[derive(VersionsDispatch)]
+pub enum CompactListEncryptionKindVersions {
+    V0(CompactListEncryptionKind),
+}
+
+#[derive(VersionsDispatch)]
+pub enum SquashedNoiseCiphertextVersions {
+    V0(SquashedNoiseCiphertext),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompressedSquashedNoiseCiphertextListVersions {
+    V0(CompressedSquashedNoiseCiphertextList),
+}
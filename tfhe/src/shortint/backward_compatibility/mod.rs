@@ -4,7 +4,11 @@ pub mod ciphertext;
 pub mod client_key;
 pub mod key_switching_key;
 pub mod list_compression;
+pub mod multi_key;
+pub mod noise_squashing;
 pub mod parameters;
+pub mod proxy_reencryption;
 pub mod public_key;
+pub mod sanitization;
 pub mod server_key;
 pub mod wopbs;
@@ -0,0 +1,25 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::multi_key::{
+    MultiKeyPartialDecryption, MultiKeyPublicKey, MultiKeyServerKey, MultiKeyServerKeyContribution,
+};
+
+#[derive(VersionsDispatch)]
+pub enum MultiKeyPublicKeyVersions {
+    V0(MultiKeyPublicKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum MultiKeyServerKeyContributionVersions {
+    V0(MultiKeyServerKeyContribution),
+}
+
+#[derive(VersionsDispatch)]
+pub enum MultiKeyServerKeyVersions {
+    V0(MultiKeyServerKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum MultiKeyPartialDecryptionVersions {
+    V0(MultiKeyPartialDecryption),
+}
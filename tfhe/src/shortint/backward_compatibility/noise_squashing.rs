@@ -0,0 +1,13 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingKeyVersions {
+    V0(NoiseSquashingKey),
+}
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingPrivateKeyVersions {
+    V0(NoiseSquashingPrivateKey),
+}
@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::parameters::distributed_keygen::DistributedKeygenParameters;
+
+#[derive(VersionsDispatch)]
+pub enum DistributedKeygenParametersVersions {
+    V0(DistributedKeygenParameters),
+}
@@ -1,6 +1,11 @@
 pub mod compact_public_key_only;
+pub mod distributed_keygen;
 pub mod key_switching;
 pub mod list_compression;
+pub mod multi_key;
+pub mod noise_squashing;
+pub mod proxy_reencryption;
+pub mod sanitization;
 
 use tfhe_versionable::VersionsDispatch;
 
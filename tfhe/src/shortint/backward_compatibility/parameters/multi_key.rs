@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::parameters::multi_key::MultiKeyParameters;
+
+#[derive(VersionsDispatch)]
+pub enum MultiKeyParametersVersions {
+    V0(MultiKeyParameters),
+}
@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::parameters::noise_squashing::NoiseSquashingParameters;
+
+#[derive(VersionsDispatch)]
+pub enum NoiseSquashingParametersVersions {
+    V0(NoiseSquashingParameters),
+}
@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::parameters::proxy_reencryption::ProxyReencryptionParameters;
+
+#[derive(VersionsDispatch)]
+pub enum ProxyReencryptionParametersVersions {
+    V0(ProxyReencryptionParameters),
+}
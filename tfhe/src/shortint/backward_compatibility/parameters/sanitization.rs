@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::parameters::sanitization::SanitizationParameters;
+
+#[derive(VersionsDispatch)]
+pub enum SanitizationParametersVersions {
+    V0(SanitizationParameters),
+}
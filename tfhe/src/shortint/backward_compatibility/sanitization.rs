@@ -0,0 +1,8 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::shortint::sanitization::SanitizationKey;
+
+#[derive(VersionsDispatch)]
+pub enum SanitizationKeyVersions {
+    V0(SanitizationKey),
+}
@@ -2,7 +2,7 @@
 use super::super::parameters::CiphertextListConformanceParams;
 use super::common::*;
 use super::standard::Ciphertext;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::commons::traits::ContiguousEntityContainer;
 use crate::core_crypto::entities::*;
 use crate::shortint::backward_compatibility::ciphertext::CompactCiphertextListVersions;
@@ -30,7 +30,14 @@ pub struct CompactCiphertextList {
 impl ParameterSetConformant for CompactCiphertextList {
     type ParameterSet = CiphertextListConformanceParams;
 
-    fn is_conformant(&self, param: &CiphertextListConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        param: &CiphertextListConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self {
             ct_list,
             degree,
@@ -49,12 +56,14 @@ impl ParameterSetConformant for CompactCiphertextList {
             expansion_kind: param_expansion_kind,
         } = param;
 
-        ct_list.is_conformant(ct_list_params)
-            && *message_modulus == *param_message_modulus
-            && *carry_modulus == *param_carry_modulus
-            && *expansion_kind == *param_expansion_kind
-            && *degree == *param_degree
-            && *noise_level == *param_noise_level
+        ct_list
+            .check_conformance(ct_list_params)
+            .map_err(|err| err.in_field("ct_list"))?;
+        check_field("message_modulus", param_message_modulus, message_modulus)?;
+        check_field("carry_modulus", param_carry_modulus, carry_modulus)?;
+        check_field("expansion_kind", param_expansion_kind, expansion_kind)?;
+        check_field("degree", param_degree, degree)?;
+        check_field("noise_level", param_noise_level, noise_level)
     }
 }
 
@@ -1,7 +1,7 @@
 use tfhe_versionable::Versionize;
 
 use self::compressed_modulus_switched_glwe_ciphertext::CompressedModulusSwitchedGlweCiphertext;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_elements, check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::prelude::*;
 use crate::shortint::backward_compatibility::ciphertext::CompressedCiphertextListVersions;
 use crate::shortint::parameters::CompressedCiphertextConformanceParams;
@@ -22,7 +22,14 @@ pub struct CompressedCiphertextList {
 impl ParameterSetConformant for CompressedCiphertextList {
     type ParameterSet = CompressedCiphertextConformanceParams;
 
-    fn is_conformant(&self, params: &CompressedCiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        params: &CompressedCiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self {
             modulus_switched_glwe_ciphertext_list,
             ciphertext_modulus,
@@ -36,9 +43,25 @@ impl ParameterSetConformant for CompressedCiphertextList {
         let len = modulus_switched_glwe_ciphertext_list.len();
 
         if len == 0 {
-            return true;
+            return Ok(());
         }
 
+        check_field("lwe_per_glwe", &params.lwe_per_glwe, lwe_per_glwe)?;
+        if lwe_per_glwe.0 > params.ct_params.polynomial_size.0 {
+            return Err(ConformanceError::invalid(
+                "lwe_per_glwe",
+                "more LWEs per GLWE than the polynomial size",
+            ));
+        }
+        check_field(
+            "ciphertext_modulus",
+            &params.ct_params.ct_modulus,
+            ciphertext_modulus,
+        )?;
+        check_field("message_modulus", &params.message_modulus, message_modulus)?;
+        check_field("carry_modulus", &params.carry_modulus, carry_modulus)?;
+        check_field("pbs_order", &params.pbs_order, pbs_order)?;
+
         let last_body_count = modulus_switched_glwe_ciphertext_list
             .last()
             .unwrap()
@@ -52,15 +75,17 @@ impl ParameterSetConformant for CompressedCiphertextList {
             && last_body_count <= params.lwe_per_glwe.0
             && (len - 1) * params.lwe_per_glwe.0 + last_body_count == count.0;
 
-        count_is_ok
-            && modulus_switched_glwe_ciphertext_list
-                .iter()
-                .all(|glwe| glwe.is_conformant(&params.ct_params))
-            && lwe_per_glwe.0 <= params.ct_params.polynomial_size.0
-            && *lwe_per_glwe == params.lwe_per_glwe
-            && *ciphertext_modulus == params.ct_params.ct_modulus
-            && *message_modulus == params.message_modulus
-            && *carry_modulus == params.carry_modulus
-            && *pbs_order == params.pbs_order
+        if !count_is_ok {
+            return Err(ConformanceError::invalid(
+                "count",
+                "the number of ciphertexts does not match the stored GLWE bodies",
+            ));
+        }
+
+        check_elements(
+            "modulus_switched_glwe_ciphertext_list",
+            modulus_switched_glwe_ciphertext_list,
+            &params.ct_params,
+        )
     }
 }
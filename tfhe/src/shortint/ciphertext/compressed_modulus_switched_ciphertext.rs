@@ -1,7 +1,7 @@
 use tfhe_versionable::Versionize;
 
 use super::common::*;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::prelude::compressed_modulus_switched_lwe_ciphertext::CompressedModulusSwitchedLweCiphertext;
 use crate::core_crypto::prelude::compressed_modulus_switched_multi_bit_lwe_ciphertext::CompressedModulusSwitchedMultiBitLweCiphertext;
 use crate::core_crypto::prelude::LweCiphertextParameters;
@@ -51,7 +51,14 @@ pub struct CompressedModulusSwitchedCiphertext {
 impl ParameterSetConformant for CompressedModulusSwitchedCiphertext {
     type ParameterSet = CiphertextConformanceParams;
 
-    fn is_conformant(&self, param: &CiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        param: &CiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self {
             compressed_modulus_switched_lwe_ciphertext,
             degree,
@@ -60,11 +67,13 @@ impl ParameterSetConformant for CompressedModulusSwitchedCiphertext {
             pbs_order,
         } = self;
 
-        compressed_modulus_switched_lwe_ciphertext.is_conformant(&param.ct_params)
-            && *message_modulus == param.message_modulus
-            && *carry_modulus == param.carry_modulus
-            && *pbs_order == param.pbs_order
-            && *degree == param.degree
+        compressed_modulus_switched_lwe_ciphertext
+            .check_conformance(&param.ct_params)
+            .map_err(|err| err.in_field("compressed_modulus_switched_lwe_ciphertext"))?;
+        check_field("message_modulus", &param.message_modulus, message_modulus)?;
+        check_field("carry_modulus", &param.carry_modulus, carry_modulus)?;
+        check_field("pbs_order", &param.pbs_order, pbs_order)?;
+        check_field("degree", &param.degree, degree)
     }
 }
 
@@ -78,10 +87,17 @@ pub(crate) enum InternalCompressedModulusSwitchedCiphertext {
 impl ParameterSetConformant for InternalCompressedModulusSwitchedCiphertext {
     type ParameterSet = LweCiphertextParameters<u64>;
 
-    fn is_conformant(&self, param: &LweCiphertextParameters<u64>) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        param: &LweCiphertextParameters<u64>,
+    ) -> Result<(), ConformanceError> {
         match self {
-            Self::Classic(a) => a.is_conformant(param),
-            Self::MultiBit(a) => a.is_conformant(param),
+            Self::Classic(a) => a.check_conformance(param),
+            Self::MultiBit(a) => a.check_conformance(param),
         }
     }
 }
@@ -3,6 +3,7 @@ mod compact_list;
 mod compressed;
 mod compressed_ciphertext_list;
 mod compressed_modulus_switched_ciphertext;
+mod squashed_noise;
 mod standard;
 
 pub use common::*;
@@ -10,6 +11,7 @@ pub use compact_list::*;
 pub use compressed::*;
 pub use compressed_ciphertext_list::*;
 pub use compressed_modulus_switched_ciphertext::*;
+pub use squashed_noise::*;
 pub use standard::*;
 #[cfg(feature = "zk-pok")]
 pub use zk::*;
@@ -0,0 +1,72 @@
+use tfhe_versionable::Versionize;
+
+use super::common::Degree;
+use crate::core_crypto::prelude::{CompressedModulusSwitchedLweCiphertext, LweCiphertextOwned};
+use crate::shortint::backward_compatibility::ciphertext::{
+    CompressedSquashedNoiseCiphertextListVersions, SquashedNoiseCiphertextVersions,
+};
+use crate::shortint::{CarryModulus, MessageModulus};
+
+/// A ciphertext on 128 bits whose noise was squashed by a
+/// [`NoiseSquashingKey`](crate::shortint::noise_squashing::NoiseSquashingKey).
+///
+/// It encrypts the message and carries of the original ciphertext, and can only be decrypted.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(SquashedNoiseCiphertextVersions)]
+pub struct SquashedNoiseCiphertext {
+    pub(crate) ct: LweCiphertextOwned<u128>,
+    pub(crate) degree: Degree,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+}
+
+impl SquashedNoiseCiphertext {
+    pub fn lwe_ciphertext(&self) -> &LweCiphertextOwned<u128> {
+        &self.ct
+    }
+
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+}
+
+/// A list of [`SquashedNoiseCiphertext`] stored with a reduced modulus.
+#[derive(Clone, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(CompressedSquashedNoiseCiphertextListVersions)]
+pub struct CompressedSquashedNoiseCiphertextList {
+    pub(crate) modulus_switched_lwe_ciphertext_list:
+        Vec<CompressedModulusSwitchedLweCiphertext<u128>>,
+    pub(crate) degrees: Vec<Degree>,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+}
+
+impl CompressedSquashedNoiseCiphertextList {
+    pub fn len(&self) -> usize {
+        self.modulus_switched_lwe_ciphertext_list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modulus_switched_lwe_ciphertext_list.is_empty()
+    }
+
+    /// Returns the ciphertext at `index`, switched back to the 128 bits modulus.
+    pub fn unpack(&self, index: usize) -> Option<SquashedNoiseCiphertext> {
+        let compressed = self.modulus_switched_lwe_ciphertext_list.get(index)?;
+
+        Some(SquashedNoiseCiphertext {
+            ct: compressed.extract(),
+            degree: self.degrees[index],
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+        })
+    }
+}
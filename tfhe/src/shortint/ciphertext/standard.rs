@@ -1,7 +1,7 @@
 //! Module with the definition of the Ciphertext.
 use super::super::parameters::CiphertextConformanceParams;
 use super::common::*;
-use crate::conformance::ParameterSetConformant;
+use crate::conformance::{check_field, ConformanceError, ParameterSetConformant};
 use crate::core_crypto::entities::*;
 use crate::shortint::backward_compatibility::ciphertext::CiphertextVersions;
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
@@ -28,7 +28,14 @@ impl crate::named::Named for Ciphertext {
 impl ParameterSetConformant for Ciphertext {
     type ParameterSet = CiphertextConformanceParams;
 
-    fn is_conformant(&self, param: &CiphertextConformanceParams) -> bool {
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        self.check_conformance(parameter_set).is_ok()
+    }
+
+    fn check_conformance(
+        &self,
+        param: &CiphertextConformanceParams,
+    ) -> Result<(), ConformanceError> {
         let Self {
             ct,
             degree,
@@ -38,12 +45,13 @@ impl ParameterSetConformant for Ciphertext {
             pbs_order,
         } = self;
 
-        ct.is_conformant(&param.ct_params)
-            && *message_modulus == param.message_modulus
-            && *carry_modulus == param.carry_modulus
-            && *pbs_order == param.pbs_order
-            && *degree == param.degree
-            && *noise_level == param.noise_level
+        ct.check_conformance(&param.ct_params)
+            .map_err(|err| err.in_field("ct"))?;
+        check_field("message_modulus", &param.message_modulus, message_modulus)?;
+        check_field("carry_modulus", &param.carry_modulus, carry_modulus)?;
+        check_field("pbs_order", &param.pbs_order, pbs_order)?;
+        check_field("degree", &param.degree, degree)?;
+        check_field("noise_level", &param.noise_level, noise_level)
     }
 }
 
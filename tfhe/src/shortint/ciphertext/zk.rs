@@ -1,12 +1,45 @@
-use crate::core_crypto::algorithms::verify_lwe_compact_ciphertext_list;
-use crate::shortint::ciphertext::CompactCiphertextList;
+use crate::core_crypto::algorithms::{
+    extract_lwe_sample_from_glwe_ciphertext, verify_glwe_ciphertext,
+    verify_lwe_ciphertext_decryption, verify_lwe_compact_ciphertext_list,
+    verify_lwe_compact_ciphertext_list_batch,
+    verify_lwe_compact_ciphertext_list_secret_key_encryption,
+};
+use crate::core_crypto::commons::math::random::DynamicDistribution;
+use crate::core_crypto::entities::{GlweCiphertextOwned, LweCiphertextOwned};
+use crate::core_crypto::prelude::{Cleartext, LweDimension, MonomialDegree};
+use crate::shortint::backward_compatibility::ciphertext::{
+    CompactListEncryptionKindVersions, ProvenCompactCiphertextListVersions,
+};
+use crate::shortint::ciphertext::{CompactCiphertextList, Degree, NoiseLevel};
 use crate::shortint::parameters::{
-    CompactPublicKeyEncryptionParameters, MessageModulus, ShortintCompactCiphertextListCastingMode,
+    CarryModulus, CompactPublicKeyEncryptionParameters, MessageModulus,
+    ShortintCompactCiphertextListCastingMode, ShortintParameterSet,
+};
+use crate::shortint::{Ciphertext, CompactPublicKey, GlwePublicKey, PBSOrder};
+use crate::zk::{
+    CompactPkeBn254Crs, CompactPkeCrs, CompactPkeProof, CompactPkePublicParams, DecryptionCrs,
+    DecryptionProof, DecryptionPublicParams, GlwePkeCrs, GlwePkeProof, GlwePkePublicParams,
+    ZkVerificationOutCome,
 };
-use crate::shortint::{Ciphertext, CompactPublicKey};
-use crate::zk::{CompactPkeCrs, CompactPkeProof, CompactPkePublicParams, ZkVerificationOutCome};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// Returns the dimension, noise distribution and plaintext modulus to build a CRS for the given
+/// compact encryption parameters.
+fn compact_pke_crs_inputs(
+    params: &CompactPublicKeyEncryptionParameters,
+) -> (LweDimension, DynamicDistribution<u64>, u64) {
+    let mut plaintext_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64;
+    // Our plaintext modulus does not take into account the bit of padding
+    plaintext_modulus *= 2;
+
+    (
+        params.encryption_lwe_dimension,
+        params.encryption_noise_distribution,
+        plaintext_modulus,
+    )
+}
 
 impl CompactPkeCrs {
     /// Construct the CRS that corresponds to the given parameters
@@ -18,14 +51,32 @@ impl CompactPkeCrs {
         crate::Error: From<E>,
     {
         let params: CompactPublicKeyEncryptionParameters = params.try_into()?;
-        let (size, noise_distribution) = (
-            params.encryption_lwe_dimension,
-            params.encryption_noise_distribution,
-        );
+        let (size, noise_distribution, plaintext_modulus) = compact_pke_crs_inputs(&params);
 
-        let mut plaintext_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64;
-        // Our plaintext modulus does not take into account the bit of padding
-        plaintext_modulus *= 2;
+        crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new(
+                size,
+                max_num_message,
+                noise_distribution,
+                params.ciphertext_modulus,
+                plaintext_modulus,
+                &mut engine.random_generator,
+            )
+        })
+    }
+}
+
+impl CompactPkeBn254Crs {
+    /// Construct the BN254 CRS that corresponds to the given parameters
+    ///
+    /// max_num_message is how many message a single proof can prove
+    pub fn from_shortint_params<P, E>(params: P, max_num_message: usize) -> crate::Result<Self>
+    where
+        P: TryInto<CompactPublicKeyEncryptionParameters, Error = E>,
+        crate::Error: From<E>,
+    {
+        let params: CompactPublicKeyEncryptionParameters = params.try_into()?;
+        let (size, noise_distribution, plaintext_modulus) = compact_pke_crs_inputs(&params);
 
         crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
             Self::new(
@@ -40,12 +91,111 @@ impl CompactPkeCrs {
     }
 }
 
+impl GlwePkeCrs {
+    /// Construct the CRS that corresponds to the GLWE parameters of the given parameters
+    pub fn from_shortint_params<P, E>(params: P) -> crate::Result<Self>
+    where
+        P: TryInto<ShortintParameterSet, Error = E>,
+        crate::Error: From<E>,
+    {
+        let params: ShortintParameterSet = params.try_into()?;
+
+        let mut plaintext_modulus = (params.message_modulus().0 * params.carry_modulus().0) as u64;
+        // Our plaintext modulus does not take into account the bit of padding
+        plaintext_modulus *= 2;
+
+        crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new(
+                params.glwe_dimension(),
+                params.polynomial_size(),
+                params.glwe_noise_distribution(),
+                params.ciphertext_modulus(),
+                plaintext_modulus,
+                &mut engine.random_generator,
+            )
+        })
+    }
+}
+
+impl DecryptionCrs {
+    /// Construct the CRS to prove decryptions of ciphertexts encrypted under the
+    /// [CompactPublicKey] of the given parameters
+    pub fn from_shortint_params<P, E>(params: P) -> crate::Result<Self>
+    where
+        P: TryInto<CompactPublicKeyEncryptionParameters, Error = E>,
+        crate::Error: From<E>,
+    {
+        let params: CompactPublicKeyEncryptionParameters = params.try_into()?;
+
+        // Messages are encoded with a bit of padding
+        let delta = (1u64 << 63) / (params.message_modulus.0 * params.carry_modulus.0) as u64;
+
+        crate::shortint::engine::ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new(
+                params.encryption_lwe_dimension,
+                params.ciphertext_modulus,
+                delta,
+                &mut engine.random_generator,
+            )
+        })
+    }
+}
+
+impl Ciphertext {
+    /// Verifies that `message_and_carry` is the decryption of this ciphertext with the proof
+    /// returned by [ClientKey::decrypt_with_proof](crate::shortint::ClientKey::decrypt_with_proof)
+    ///
+    /// `message_and_carry` must be in `[0, message_modulus * carry_modulus)`.
+    pub fn verify_decryption(
+        &self,
+        message_and_carry: u64,
+        proof: &DecryptionProof,
+        public_params: &DecryptionPublicParams,
+        public_key: &CompactPublicKey,
+        metadata: &[u8],
+    ) -> ZkVerificationOutCome {
+        let full_modulus = (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        if message_and_carry >= full_modulus {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        let delta = (1u64 << 63) / full_modulus;
+
+        verify_lwe_ciphertext_decryption(
+            &self.ct,
+            &public_key.key,
+            Cleartext(message_and_carry),
+            delta,
+            proof,
+            public_params,
+            metadata,
+        )
+    }
+}
+
+/// The key used to encrypt the lists of a [ProvenCompactCiphertextList], which selects the
+/// relation checked by its proofs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(CompactListEncryptionKindVersions)]
+pub enum CompactListEncryptionKind {
+    /// Encrypted with a [CompactPublicKey]
+    PublicKey,
+    /// Encrypted with the [CompactPrivateKey](crate::shortint::CompactPrivateKey) of a
+    /// [CompactPublicKey], the proofs also show that the public key was generated from the
+    /// secret key used to encrypt
+    SecretKey,
+}
+
 /// A List of CompactCiphertext with their zero-knowledge proofs
 ///
-/// The proofs can only be generated during the encryption with a [CompactPublicKey]
-#[derive(Clone, Serialize, Deserialize)]
+/// The proofs can only be generated during the encryption with a [CompactPublicKey] or with its
+/// [CompactPrivateKey](crate::shortint::CompactPrivateKey), in both cases they are verified
+/// against the [CompactPublicKey].
+#[derive(Clone, Serialize, Deserialize, Versionize)]
+#[versionize(ProvenCompactCiphertextListVersions)]
 pub struct ProvenCompactCiphertextList {
     pub(crate) proved_lists: Vec<(CompactCiphertextList, CompactPkeProof)>,
+    pub(crate) encryption_kind: CompactListEncryptionKind,
 }
 
 impl ProvenCompactCiphertextList {
@@ -63,18 +213,10 @@ impl ProvenCompactCiphertextList {
         metadata: &[u8],
         casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
     ) -> crate::Result<Vec<Ciphertext>> {
-        let not_all_valid = self.proved_lists.par_iter().any(|(ct_list, proof)| {
-            verify_lwe_compact_ciphertext_list(
-                &ct_list.ct_list,
-                &public_key.key,
-                proof,
-                public_params,
-                metadata,
-            )
+        if self
+            .verify(public_params, public_key, metadata)
             .is_invalid()
-        });
-
-        if not_all_valid {
+        {
             return Err(crate::ErrorKind::InvalidZkProof.into());
         }
 
@@ -109,13 +251,24 @@ impl ProvenCompactCiphertextList {
         metadata: &[u8],
     ) -> ZkVerificationOutCome {
         let all_valid = self.proved_lists.par_iter().all(|(ct_list, proof)| {
-            verify_lwe_compact_ciphertext_list(
-                &ct_list.ct_list,
-                &public_key.key,
-                proof,
-                public_params,
-                metadata,
-            )
+            match self.encryption_kind {
+                CompactListEncryptionKind::PublicKey => verify_lwe_compact_ciphertext_list(
+                    &ct_list.ct_list,
+                    &public_key.key,
+                    proof,
+                    public_params,
+                    metadata,
+                ),
+                CompactListEncryptionKind::SecretKey => {
+                    verify_lwe_compact_ciphertext_list_secret_key_encryption(
+                        &ct_list.ct_list,
+                        &public_key.key,
+                        proof,
+                        public_params,
+                        metadata,
+                    )
+                }
+            }
             .is_valid()
         });
 
@@ -126,6 +279,52 @@ impl ProvenCompactCiphertextList {
         }
     }
 
+    /// Verifies many lists at once, sharing the cost of the pairings between all their proofs.
+    ///
+    /// Each entry holds a list with the public key used to encrypt it and the metadata given when
+    /// proving. Returns the indices of the lists with at least one invalid proof.
+    pub fn verify_batch(
+        lists: &[(&Self, &CompactPublicKey, &[u8])],
+        public_params: &CompactPkePublicParams,
+    ) -> Vec<usize> {
+        // Lists encrypted with a secret key prove another relation, they are verified on their own
+        let (list_indices, entries): (Vec<_>, Vec<_>) = lists
+            .iter()
+            .enumerate()
+            .filter(|(_, (list, _, _))| {
+                list.encryption_kind == CompactListEncryptionKind::PublicKey
+            })
+            .flat_map(|(list_index, &(list, public_key, metadata))| {
+                list.proved_lists.iter().map(move |(ct_list, proof)| {
+                    (
+                        list_index,
+                        (&ct_list.ct_list, &public_key.key, proof, metadata),
+                    )
+                })
+            })
+            .unzip();
+
+        let mut invalid_lists = verify_lwe_compact_ciphertext_list_batch(&entries, public_params)
+            .into_iter()
+            .map(|entry_index| list_indices[entry_index])
+            .chain(
+                lists
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (list, public_key, metadata))| {
+                        list.encryption_kind == CompactListEncryptionKind::SecretKey
+                            && list
+                                .verify(public_params, public_key, metadata)
+                                .is_invalid()
+                    })
+                    .map(|(list_index, _)| list_index),
+            )
+            .collect::<Vec<_>>();
+        invalid_lists.sort_unstable();
+        invalid_lists.dedup();
+        invalid_lists
+    }
+
     pub fn proof_size(&self) -> usize {
         self.proved_lists.len() * core::mem::size_of::<CompactPkeProof>()
     }
@@ -135,14 +334,136 @@ impl ProvenCompactCiphertextList {
     }
 }
 
+/// A list of GLWE ciphertexts with their zero-knowledge proofs, each ciphertext holding up to
+/// `polynomial_size` messages in the coefficients of its body.
+///
+/// The list is encrypted with
+/// [GlwePublicKey::encrypt_and_prove_slice](crate::shortint::GlwePublicKey::encrypt_and_prove_slice)
+/// and expanded by extracting each message as a [Ciphertext] under the large LWE key.
+///
+/// The proofs show that the ciphertexts are encryptions under the [GlwePublicKey] given to
+/// [ProvenGlweCiphertextList::verify], with a binary randomness, and a noise and messages bounded
+/// by the bound of the CRS, which is the largest of the noise bound and the plaintext modulus.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProvenGlweCiphertextList {
+    pub(crate) proved_glwes: Vec<(GlweCiphertextOwned<u64>, GlwePkeProof)>,
+    pub(crate) message_count: usize,
+    pub(crate) degree: Degree,
+    pub(crate) message_modulus: MessageModulus,
+    pub(crate) carry_modulus: CarryModulus,
+}
+
+impl ProvenGlweCiphertextList {
+    pub fn ciphertext_count(&self) -> usize {
+        self.message_count
+    }
+
+    fn delta(&self) -> u64 {
+        (1u64 << 63) / (self.message_modulus.0 * self.carry_modulus.0) as u64
+    }
+
+    pub fn verify(
+        &self,
+        public_params: &GlwePkePublicParams,
+        public_key: &GlwePublicKey,
+        metadata: &[u8],
+    ) -> ZkVerificationOutCome {
+        if self.message_modulus != public_key.parameters.message_modulus()
+            || self.carry_modulus != public_key.parameters.carry_modulus()
+        {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        let delta = self.delta();
+        let all_valid = self.proved_glwes.par_iter().all(|(glwe, proof)| {
+            verify_glwe_ciphertext(glwe, &public_key.key, delta, proof, public_params, metadata)
+                .is_valid()
+        });
+
+        if all_valid {
+            ZkVerificationOutCome::Valid
+        } else {
+            ZkVerificationOutCome::Invalid
+        }
+    }
+
+    pub fn verify_and_expand(
+        &self,
+        public_params: &GlwePkePublicParams,
+        public_key: &GlwePublicKey,
+        metadata: &[u8],
+    ) -> crate::Result<Vec<Ciphertext>> {
+        if self
+            .verify(public_params, public_key, metadata)
+            .is_invalid()
+        {
+            return Err(crate::ErrorKind::InvalidZkProof.into());
+        }
+
+        // We can call the function as we have verified the proofs
+        Ok(self.expand_without_verification())
+    }
+
+    #[doc(hidden)]
+    /// This function allows to expand a ciphertext without verifying the associated proof.
+    ///
+    /// If you are here you were probably looking for it: use at your own risks.
+    pub fn expand_without_verification(&self) -> Vec<Ciphertext> {
+        let Some((first_glwe, _)) = self.proved_glwes.first() else {
+            return Vec::new();
+        };
+        let polynomial_size = first_glwe.polynomial_size();
+        let lwe_size = first_glwe
+            .glwe_size()
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(polynomial_size)
+            .to_lwe_size();
+
+        (0..self.message_count)
+            .into_par_iter()
+            .map(|index| {
+                let (glwe, _) = &self.proved_glwes[index / polynomial_size.0];
+                let mut lwe = LweCiphertextOwned::new(0u64, lwe_size, glwe.ciphertext_modulus());
+                extract_lwe_sample_from_glwe_ciphertext(
+                    glwe,
+                    &mut lwe,
+                    MonomialDegree(index % polynomial_size.0),
+                );
+
+                Ciphertext::new(
+                    lwe,
+                    self.degree,
+                    NoiseLevel::NOMINAL,
+                    self.message_modulus,
+                    self.carry_modulus,
+                    PBSOrder::KeyswitchBootstrap,
+                )
+            })
+            .collect()
+    }
+
+    pub fn proof_size(&self) -> usize {
+        self.proved_glwes.len() * core::mem::size_of::<GlwePkeProof>()
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::ProvenCompactCiphertextList;
     use crate::shortint::parameters::{
         ShortintCompactCiphertextListCastingMode, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
     };
-    use crate::shortint::{ClientKey, CompactPublicKey};
-    use crate::zk::{CompactPkeCrs, ZkComputeLoad};
+    use crate::shortint::{ClientKey, CompactPrivateKey, CompactPublicKey};
+    use crate::zk::{
+        CanonicalSerialize, CompactPkeBn254Crs, CompactPkeCrs, Compress, DecryptionCrs,
+        ZkComputeLoad,
+    };
     use rand::random;
+    use tfhe_versionable::{Unversionize, Versionize};
 
     #[test]
     fn test_zk_ciphertext_encryption_ci_run_filter() {
@@ -191,6 +512,182 @@ mod tests {
         assert_eq!(msg, decrypted);
     }
 
+    #[test]
+    fn test_zk_decryption_proof_ci_run_filter() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+        let crs = DecryptionCrs::from_shortint_params(params).unwrap();
+        let cks = ClientKey::new(params);
+        let pk = CompactPublicKey::new(&cks);
+
+        let metadata = [b's', b'h', b'o', b'r', b't', b'i', b'n', b't'];
+        let fake_metadata = [b'i', b'n', b't', b'e', b'g', b'e', b'r'];
+
+        let msg = random::<u64>() % params.message_modulus.0 as u64;
+        let ct = cks.encrypt(msg);
+
+        let (decrypted, proof) = cks
+            .decrypt_with_proof(
+                &ct,
+                &pk,
+                crs.public_params(),
+                &metadata,
+                ZkComputeLoad::Proof,
+            )
+            .unwrap();
+        assert_eq!(msg, decrypted);
+
+        assert!(ct
+            .verify_decryption(decrypted, &proof, crs.public_params(), &pk, &metadata)
+            .is_valid());
+        assert!(ct
+            .verify_decryption(decrypted, &proof, crs.public_params(), &pk, &fake_metadata)
+            .is_invalid());
+
+        let wrong_decryption = (decrypted + 1) % params.message_modulus.0 as u64;
+        assert!(ct
+            .verify_decryption(
+                wrong_decryption,
+                &proof,
+                crs.public_params(),
+                &pk,
+                &metadata
+            )
+            .is_invalid());
+
+        // Shifting by the padding bit or by the whole torus gives the same encoding
+        let full_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64;
+        for congruent_decryption in [decrypted + full_modulus, decrypted + 2 * full_modulus] {
+            assert!(ct
+                .verify_decryption(
+                    congruent_decryption,
+                    &proof,
+                    crs.public_params(),
+                    &pk,
+                    &metadata
+                )
+                .is_invalid());
+        }
+    }
+
+    #[test]
+    fn test_zk_crs_ceremony_ci_run_filter() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+        let initial_crs = CompactPkeCrs::from_shortint_params(params, 4).unwrap();
+        let cks = ClientKey::new(params);
+        let pk = CompactPublicKey::new(&cks);
+
+        let mut rng = rand::thread_rng();
+        let mut transcript: Vec<(CompactPkeCrs, _)> = vec![];
+        for _ in 0..3 {
+            let previous_crs = transcript.last().map_or(&initial_crs, |(crs, _)| crs);
+            let (crs, proof) = previous_crs.update(&mut rng);
+            assert!(previous_crs.verify_update(&crs, &proof).is_valid());
+            transcript.push((crs, proof));
+        }
+
+        assert!(CompactPkeCrs::verify_ceremony_transcript(&initial_crs, &transcript).is_valid());
+        // A participant cannot be removed from the transcript
+        assert!(
+            CompactPkeCrs::verify_ceremony_transcript(&initial_crs, &transcript[1..]).is_invalid()
+        );
+
+        let crs = &transcript.last().unwrap().0;
+        let metadata = [b's', b'h', b'o', b'r', b't', b'i', b'n', b't'];
+        let msg = random::<u64>() % params.message_modulus.0 as u64;
+
+        let proven_ct = pk
+            .encrypt_and_prove(
+                msg,
+                crs.public_params(),
+                &metadata,
+                ZkComputeLoad::Proof,
+                params.message_modulus.0 as u64,
+            )
+            .unwrap();
+
+        let expanded = proven_ct
+            .verify_and_expand(
+                crs.public_params(),
+                &pk,
+                &metadata,
+                ShortintCompactCiphertextListCastingMode::NoCasting,
+            )
+            .unwrap();
+        assert_eq!(msg, cks.decrypt(&expanded[0]));
+        assert!(proven_ct
+            .verify(initial_crs.public_params(), &pk, &metadata)
+            .is_invalid());
+    }
+
+    #[test]
+    fn test_zk_crs_bn254_ci_run_filter() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+        let crs = CompactPkeBn254Crs::from_shortint_params(params, 4).unwrap();
+
+        let mut rng = rand::thread_rng();
+        let (updated_crs, proof) = crs.update(&mut rng);
+        assert!(crs.verify_update(&updated_crs, &proof).is_valid());
+        assert!(CompactPkeBn254Crs::verify_ceremony_transcript(
+            &crs,
+            &[(updated_crs, proof.clone())]
+        )
+        .is_valid());
+
+        // An update proof does not apply to another CRS
+        let other_crs = CompactPkeBn254Crs::from_shortint_params(params, 4).unwrap();
+        let (other_updated_crs, _) = other_crs.update(&mut rng);
+        assert!(other_crs
+            .verify_update(&other_updated_crs, &proof)
+            .is_invalid());
+    }
+
+    #[test]
+    fn test_zk_versioned_serialization_ci_run_filter() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+        let crs = CompactPkeCrs::from_shortint_params(params, 4).unwrap();
+        let cks = ClientKey::new(params);
+        let pk = CompactPublicKey::new(&cks);
+
+        let metadata = [b's', b'h', b'o', b'r', b't', b'i', b'n', b't'];
+        let msg = random::<u64>() % params.message_modulus.0 as u64;
+
+        // The group elements of the CRS are compressed
+        let serialized_crs = bincode::serialize(&crs.versionize()).unwrap();
+        assert!(serialized_crs.len() < crs.public_params().serialized_size(Compress::No));
+        let crs =
+            CompactPkeCrs::unversionize(bincode::deserialize(&serialized_crs).unwrap()).unwrap();
+
+        let proven_ct = pk
+            .encrypt_and_prove(
+                msg,
+                crs.public_params(),
+                &metadata,
+                ZkComputeLoad::Proof,
+                params.message_modulus.0 as u64,
+            )
+            .unwrap();
+
+        let serialized_ct = bincode::serialize(&proven_ct.versionize()).unwrap();
+        let proven_ct = ProvenCompactCiphertextList::unversionize(
+            bincode::deserialize(&serialized_ct).unwrap(),
+        )
+        .unwrap();
+
+        let expanded = proven_ct
+            .verify_and_expand(
+                crs.public_params(),
+                &pk,
+                &metadata,
+                ShortintCompactCiphertextListCastingMode::NoCasting,
+            )
+            .unwrap();
+        assert_eq!(msg, cks.decrypt(&expanded[0]));
+    }
+
     #[test]
     fn test_zk_compact_ciphertext_list_encryption_ci_run_filter() {
         let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
@@ -232,4 +729,66 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(msgs, decrypted);
     }
+
+    #[test]
+    fn test_zk_compact_ciphertext_list_secret_key_encryption_ci_run_filter() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+        let crs = CompactPkeCrs::from_shortint_params(params, 512).unwrap();
+        let cks = ClientKey::new(params);
+        let compact_private_key = CompactPrivateKey::try_from(&cks).unwrap();
+        let pk = CompactPublicKey::new(&cks);
+        let other_pk = CompactPublicKey::new(&ClientKey::new(params));
+
+        let metadata = [b's', b'h', b'o', b'r', b't', b'i', b'n', b't'];
+
+        let msgs = (0..512)
+            .map(|_| random::<u64>() % params.message_modulus.0 as u64)
+            .collect::<Vec<_>>();
+
+        let proven_ct = compact_private_key
+            .encrypt_and_prove_slice(
+                &pk,
+                &msgs,
+                crs.public_params(),
+                &metadata,
+                ZkComputeLoad::Proof,
+                params.message_modulus.0 as u64,
+            )
+            .unwrap();
+        assert!(proven_ct
+            .verify(crs.public_params(), &pk, &metadata)
+            .is_valid());
+        assert!(proven_ct
+            .verify(crs.public_params(), &pk, b"wrong metadata")
+            .is_invalid());
+        // The proofs are tied to the public key of the secret key used to encrypt
+        assert!(proven_ct
+            .verify(crs.public_params(), &other_pk, &metadata)
+            .is_invalid());
+        assert_eq!(
+            ProvenCompactCiphertextList::verify_batch(
+                &[
+                    (&proven_ct, &pk, &metadata),
+                    (&proven_ct, &other_pk, &metadata)
+                ],
+                crs.public_params()
+            ),
+            vec![1]
+        );
+
+        let expanded = proven_ct
+            .verify_and_expand(
+                crs.public_params(),
+                &pk,
+                &metadata,
+                ShortintCompactCiphertextListCastingMode::NoCasting,
+            )
+            .unwrap();
+        let decrypted = expanded
+            .iter()
+            .map(|ciphertext| cks.decrypt(ciphertext))
+            .collect::<Vec<_>>();
+        assert_eq!(msgs, decrypted);
+    }
 }
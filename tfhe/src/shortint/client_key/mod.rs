@@ -5,6 +5,8 @@ use tfhe_versionable::Versionize;
 
 use super::PBSOrder;
 use crate::core_crypto::entities::*;
+#[cfg(feature = "zk-pok")]
+use crate::core_crypto::prelude::decrypt_and_prove_lwe_ciphertext;
 use crate::core_crypto::prelude::decrypt_lwe_ciphertext;
 use crate::shortint::backward_compatibility::client_key::ClientKeyVersions;
 use crate::shortint::ciphertext::{Ciphertext, CompressedCiphertext};
@@ -13,6 +15,10 @@ use crate::shortint::parameters::{
     DynamicDistribution, EncryptionKeyChoice, MessageModulus, ShortintParameterSet,
 };
 use crate::shortint::CarryModulus;
+#[cfg(feature = "zk-pok")]
+use crate::shortint::CompactPublicKey;
+#[cfg(feature = "zk-pok")]
+use crate::zk::{DecryptionProof, DecryptionPublicParams, ZkComputeLoad};
 use secret_encryption_key::SecretEncryptionKeyView;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -713,4 +719,77 @@ impl ClientKey {
 
         result as u64 % basis
     }
+
+    /// Decrypts a ciphertext and proves that the returned message and carry is its decryption.
+    ///
+    /// The proof is made against the [`CompactPublicKey`](crate::shortint::CompactPublicKey) of
+    /// this key, which must encrypt under the key the ciphertext is decrypted with. Anyone knowing
+    /// the public key can then check the decryption with [`Ciphertext::verify_decryption`].
+    ///
+    /// Returns an error if the padding bit of the ciphertext is set, as the decryption could not
+    /// be verified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    /// use tfhe::zk::{DecryptionCrs, ZkComputeLoad};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    /// let crs = DecryptionCrs::from_shortint_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS).unwrap();
+    /// let metadata = [b'T', b'F', b'H', b'E', b'-', b'r', b's'];
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let (dec, proof) = cks
+    ///     .decrypt_with_proof(&ct, &pk, crs.public_params(), &metadata, ZkComputeLoad::Proof)
+    ///     .unwrap();
+    /// assert_eq!(dec, msg);
+    ///
+    /// assert!(ct
+    ///     .verify_decryption(dec, &proof, crs.public_params(), &pk, &metadata)
+    ///     .is_valid());
+    /// ```
+    #[cfg(feature = "zk-pok")]
+    pub fn decrypt_with_proof(
+        &self,
+        ct: &Ciphertext,
+        public_key: &CompactPublicKey,
+        public_params: &DecryptionPublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+    ) -> crate::Result<(u64, DecryptionProof)> {
+        let lwe_decryption_key = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => self.large_lwe_secret_key(),
+            PBSOrder::BootstrapKeyswitch => self.small_lwe_secret_key(),
+        };
+
+        let full_modulus = (ct.message_modulus.0 * ct.carry_modulus.0) as u64;
+        let delta = (1_u64 << 63) / full_modulus;
+
+        let (cleartext, proof) = ShortintEngine::with_thread_local_mut(|engine| {
+            decrypt_and_prove_lwe_ciphertext(
+                &lwe_decryption_key,
+                &ct.ct,
+                &public_key.key,
+                delta,
+                &mut engine.random_generator,
+                public_params,
+                metadata,
+                load,
+            )
+        })?;
+
+        if cleartext.0 >= full_modulus {
+            return Err(crate::Error::new(
+                "The padding bit of the ciphertext is set, its decryption cannot be proven"
+                    .to_string(),
+            ));
+        }
+
+        Ok((cleartext.0, proof))
+    }
 }
@@ -121,15 +121,30 @@ impl<'keys> KeySwitchingKeyBuildHelper<'keys> {
         output_key_pair: (&'keys ClientKey, &'keys ServerKey),
         params: ShortintKeySwitchingParameters,
     ) -> Self
+    where
+        InputEncryptionKey: Into<SecretEncryptionKeyView<'input_key>>,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new_with_engine(input_key_pair, output_key_pair, params, engine)
+        })
+    }
+
+    /// Same as [Self::new], the key switching key is generated by the given engine instead of
+    /// the thread local one
+    pub(crate) fn new_with_engine<'input_key, InputEncryptionKey>(
+        input_key_pair: (InputEncryptionKey, Option<&'keys ServerKey>),
+        output_key_pair: (&'keys ClientKey, &'keys ServerKey),
+        params: ShortintKeySwitchingParameters,
+        engine: &mut ShortintEngine,
+    ) -> Self
     where
         InputEncryptionKey: Into<SecretEncryptionKeyView<'input_key>>,
     {
         let input_secret_key: SecretEncryptionKeyView<'_> = input_key_pair.0.into();
 
         // Creation of the key switching key
-        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
-            engine.new_key_switching_key(&input_secret_key, output_key_pair.0, params)
-        });
+        let key_switching_key =
+            engine.new_key_switching_key(&input_secret_key, output_key_pair.0, params);
 
         let full_message_modulus_input =
             input_secret_key.carry_modulus.0 * input_secret_key.message_modulus.0;
@@ -711,15 +726,30 @@ impl<'keys> CompressedKeySwitchingKeyBuildHelper<'keys> {
         output_key_pair: (&'keys ClientKey, &'keys CompressedServerKey),
         params: ShortintKeySwitchingParameters,
     ) -> Self
+    where
+        InputEncryptionKey: Into<SecretEncryptionKeyView<'input_key>>,
+    {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            Self::new_with_engine(input_key_pair, output_key_pair, params, engine)
+        })
+    }
+
+    /// Same as [Self::new], the key switching key is generated by the given engine instead of
+    /// the thread local one
+    pub(crate) fn new_with_engine<'input_key, InputEncryptionKey>(
+        input_key_pair: (InputEncryptionKey, Option<&'keys CompressedServerKey>),
+        output_key_pair: (&'keys ClientKey, &'keys CompressedServerKey),
+        params: ShortintKeySwitchingParameters,
+        engine: &mut ShortintEngine,
+    ) -> Self
     where
         InputEncryptionKey: Into<SecretEncryptionKeyView<'input_key>>,
     {
         let input_secret_key: SecretEncryptionKeyView<'_> = input_key_pair.0.into();
 
         // Creation of the key switching key
-        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
-            engine.new_seeded_key_switching_key(&input_secret_key, output_key_pair.0, params)
-        });
+        let key_switching_key =
+            engine.new_seeded_key_switching_key(&input_secret_key, output_key_pair.0, params);
 
         let full_message_modulus_input =
             input_secret_key.carry_modulus.0 * input_secret_key.message_modulus.0;
@@ -9,6 +9,7 @@ use crate::shortint::parameters::key_switching::*;
 use crate::shortint::parameters::list_compression::*;
 use crate::shortint::parameters::multi_bit::*;
 use crate::shortint::parameters::parameters_wopbs::*;
+use crate::shortint::parameters::sanitization::*;
 use crate::shortint::parameters::*;
 use crate::shortint::wopbs::WopbsKey;
 use crate::shortint::{ClientKey, KeySwitchingKey, ServerKey};
@@ -314,6 +315,23 @@ impl NamedParam for CompressionParameters {
     }
 }
 
+impl NamedParam for SanitizationParameters {
+    fn name(&self) -> String {
+        named_params_impl!(expose SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        named_params_impl!(
+            {
+                *self;
+                Self
+            } == (SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64)
+        );
+
+        format!(
+            "SANITIZATION_PARAM_CUSTOM_BR_LEVEL_{}_NOISE_DISTRIB_{}",
+            self.br_level.0, self.glwe_noise_distribution
+        )
+    }
+}
+
 impl From<PBSParameters> for (ClientKey, ServerKey) {
     fn from(param: PBSParameters) -> Self {
         let param_set = ShortintParameterSet::from(param);
@@ -77,7 +77,20 @@ impl ClientKey {
         &self,
         private_compression_key: &CompressionPrivateKeys,
     ) -> (CompressedCompressionKey, CompressedDecompressionKey) {
-        let cks_params: ClassicPBSParameters = match self.parameters.pbs_parameters().unwrap() {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compressed_compression_decompression_keys(self, private_compression_key)
+        })
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_compressed_compression_decompression_keys(
+        &mut self,
+        client_key: &ClientKey,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressedCompressionKey, CompressedDecompressionKey) {
+        let cks_params: ClassicPBSParameters = match client_key.parameters.pbs_parameters().unwrap()
+        {
             PBSParameters::PBS(a) => a,
             PBSParameters::MultiBitPBS(_) => {
                 panic!("Compression is currently not compatible with Multi Bit PBS")
@@ -92,17 +105,15 @@ impl ClientKey {
             "Compression is only compatible with ciphertext in post PBS dimension"
         );
 
-        let packing_key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
-            allocate_and_generate_new_seeded_lwe_packing_keyswitch_key(
-                &self.large_lwe_secret_key(),
-                &private_compression_key.post_packing_ks_key,
-                params.packing_ks_base_log,
-                params.packing_ks_level,
-                params.packing_ks_key_noise_distribution,
-                self.parameters.ciphertext_modulus(),
-                &mut engine.seeder,
-            )
-        });
+        let packing_key_switching_key = allocate_and_generate_new_seeded_lwe_packing_keyswitch_key(
+            &client_key.large_lwe_secret_key(),
+            &private_compression_key.post_packing_ks_key,
+            params.packing_ks_base_log,
+            params.packing_ks_level,
+            params.packing_ks_key_noise_distribution,
+            client_key.parameters.ciphertext_modulus(),
+            &mut self.seeder,
+        );
 
         let glwe_compression_key = CompressedCompressionKey {
             packing_key_switching_key,
@@ -110,19 +121,17 @@ impl ClientKey {
             storage_log_modulus: params.storage_log_modulus,
         };
 
-        let blind_rotate_key = ShortintEngine::with_thread_local_mut(|engine| {
-            par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
-                &private_compression_key
-                    .post_packing_ks_key
-                    .as_lwe_secret_key(),
-                &self.glwe_secret_key,
-                private_compression_key.params.br_base_log,
-                private_compression_key.params.br_level,
-                self.parameters.glwe_noise_distribution(),
-                self.parameters.ciphertext_modulus(),
-                &mut engine.seeder,
-            )
-        });
+        let blind_rotate_key = par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
+            &private_compression_key
+                .post_packing_ks_key
+                .as_lwe_secret_key(),
+            &client_key.glwe_secret_key,
+            private_compression_key.params.br_base_log,
+            private_compression_key.params.br_level,
+            client_key.parameters.glwe_noise_distribution(),
+            client_key.parameters.ciphertext_modulus(),
+            &mut self.seeder,
+        );
 
         let glwe_decompression_key = CompressedDecompressionKey {
             blind_rotate_key,
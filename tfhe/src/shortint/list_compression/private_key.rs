@@ -22,7 +22,20 @@ impl ClientKey {
         &self,
         params: CompressionParameters,
     ) -> CompressionPrivateKeys {
-        let cks_params: ClassicPBSParameters = match self.parameters.pbs_parameters().unwrap() {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compression_private_key(self, params)
+        })
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_compression_private_key(
+        &mut self,
+        client_key: &ClientKey,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        let cks_params: ClassicPBSParameters = match client_key.parameters.pbs_parameters().unwrap()
+        {
             PBSParameters::PBS(a) => a,
             PBSParameters::MultiBitPBS(_) => {
                 panic!("Compression is currently not compatible with Multi Bit PBS")
@@ -35,13 +48,11 @@ impl ClientKey {
             "Compression is only compatible with ciphertext in post PBS dimension"
         );
 
-        let post_packing_ks_key = ShortintEngine::with_thread_local_mut(|engine| {
-            allocate_and_generate_new_binary_glwe_secret_key(
-                params.packing_ks_glwe_dimension,
-                params.packing_ks_polynomial_size,
-                &mut engine.secret_generator,
-            )
-        });
+        let post_packing_ks_key = allocate_and_generate_new_binary_glwe_secret_key(
+            params.packing_ks_glwe_dimension,
+            params.packing_ks_polynomial_size,
+            &mut self.secret_generator,
+        );
 
         CompressionPrivateKeys {
             post_packing_ks_key,
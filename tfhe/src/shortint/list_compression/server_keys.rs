@@ -45,7 +45,20 @@ impl ClientKey {
         &self,
         private_compression_key: &CompressionPrivateKeys,
     ) -> (CompressionKey, DecompressionKey) {
-        let cks_params: ClassicPBSParameters = match self.parameters.pbs_parameters().unwrap() {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compression_decompression_keys(self, private_compression_key)
+        })
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_compression_decompression_keys(
+        &mut self,
+        client_key: &ClientKey,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        let cks_params: ClassicPBSParameters = match client_key.parameters.pbs_parameters().unwrap()
+        {
             PBSParameters::PBS(a) => a,
             PBSParameters::MultiBitPBS(_) => {
                 panic!("Compression is currently not compatible with Multi Bit PBS")
@@ -60,17 +73,15 @@ impl ClientKey {
             "Compression is only compatible with ciphertext in post PBS dimension"
         );
 
-        let packing_key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
-            allocate_and_generate_new_lwe_packing_keyswitch_key(
-                &self.large_lwe_secret_key(),
-                &private_compression_key.post_packing_ks_key,
-                params.packing_ks_base_log,
-                params.packing_ks_level,
-                params.packing_ks_key_noise_distribution,
-                self.parameters.ciphertext_modulus(),
-                &mut engine.encryption_generator,
-            )
-        });
+        let packing_key_switching_key = allocate_and_generate_new_lwe_packing_keyswitch_key(
+            &client_key.large_lwe_secret_key(),
+            &private_compression_key.post_packing_ks_key,
+            params.packing_ks_base_log,
+            params.packing_ks_level,
+            params.packing_ks_key_noise_distribution,
+            client_key.parameters.ciphertext_modulus(),
+            &mut self.encryption_generator,
+        );
 
         assert!(
             private_compression_key.params.storage_log_modulus.0
@@ -87,20 +98,18 @@ impl ClientKey {
             storage_log_modulus: private_compression_key.params.storage_log_modulus,
         };
 
-        let blind_rotate_key = ShortintEngine::with_thread_local_mut(|engine| {
-            ShortintBootstrappingKey::Classic(
-                engine.new_classic_bootstrapping_key(
-                    &private_compression_key
-                        .post_packing_ks_key
-                        .as_lwe_secret_key(),
-                    &self.glwe_secret_key,
-                    self.parameters.glwe_noise_distribution(),
-                    private_compression_key.params.br_base_log,
-                    private_compression_key.params.br_level,
-                    self.parameters.ciphertext_modulus(),
-                ),
-            )
-        });
+        let blind_rotate_key = ShortintBootstrappingKey::Classic(
+            self.new_classic_bootstrapping_key(
+                &private_compression_key
+                    .post_packing_ks_key
+                    .as_lwe_secret_key(),
+                &client_key.glwe_secret_key,
+                client_key.parameters.glwe_noise_distribution(),
+                private_compression_key.params.br_base_log,
+                private_compression_key.params.br_level,
+                client_key.parameters.ciphertext_modulus(),
+            ),
+        );
 
         let glwe_decompression_key = DecompressionKey {
             blind_rotate_key,
@@ -55,10 +55,14 @@ pub mod key_switching_key;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
 pub mod list_compression;
+pub mod multi_key;
+pub mod noise_squashing;
 pub mod oprf;
 pub mod parameters;
 pub mod prelude;
+pub mod proxy_reencryption;
 pub mod public_key;
+pub mod sanitization;
 pub mod server_key;
 pub mod wopbs;
 
@@ -70,7 +74,8 @@ pub use parameters::{
     MessageModulus, MultiBitPBSParameters, PBSParameters, ShortintParameterSet, WopbsParameters,
 };
 pub use public_key::{
-    CompactPrivateKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey,
+    CompactPrivateKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
+    GlwePublicKey, PublicKey,
 };
 pub use server_key::{CheckError, CompressedServerKey, ServerKey};
 
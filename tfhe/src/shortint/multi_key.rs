@@ -0,0 +1,1140 @@
+//! Multi-key evaluation, computing on ciphertexts encrypted under the keys of several parties.
+//!
+//! Each of the `K` parties generates its [`ClientKey`] independently, with the block parameters
+//! of the [`MultiKeyParameters`], and publishes a [`MultiKeyPublicKey`]. From the public keys of all the parties, each one then
+//! generates a [`MultiKeyServerKeyContribution`]:
+//! - a keyswitching key from its large key to its small key;
+//! - the GGSW encryptions of the bits of its small key under the concatenation
+//!   `Z = (z_1, …, z_K)` of the GLWE keys of all the parties, obtained by public key encryption.
+//!
+//! The contributions are assembled in a [`MultiKeyServerKey`]. A ciphertext of party `i` is
+//! [extended](MultiKeyServerKey::extend) to the concatenated key by padding its mask with zeros,
+//! extended ciphertexts can then be added and bootstrapped together:
+//! - the keyswitch switches each segment of the mask with the key of its party and sums the bodies,
+//!   giving a ciphertext under the concatenation of the small keys;
+//! - the blind rotation uses the joint bootstrapping key, whose GGSW ciphertexts are under `Z`, the
+//!   sample extraction then gives a ciphertext under the concatenation of the large keys.
+//!
+//! Decrypting the result requires all the parties. Each party also generates a
+//! [`NoiseSquashingPrivateKey`], and the server key holds a 128 bits joint bootstrapping key under
+//! the concatenation of the noise squashing keys, built in the same way. The server
+//! [squashes the noise](MultiKeyServerKey::squash_noise) of the ciphertext to decrypt, then each
+//! party publishes a [`MultiKeyPartialDecryption`] of its segment, with a smudging noise hiding its
+//! key, and the partial decryptions are [combined](combine_partial_decryptions) to recover the
+//! message.
+//!
+//! The noise grows with the number of parties, see [`MultiKeyParameters`].
+//!
+//! # Smudging
+//!
+//! The sum of the partial decryptions is `m·Δ + e + Σ e_i`, where `e` is the noise of the
+//! squashed ciphertext, which depends on the secret keys, and `e_i` the smudging noise of party
+//! `i`. With `2^b` a bound on `|e|` and `λ =` [`SMUDGING_STATISTICAL_SECURITY_LOG2`], each party
+//! draws `e_i` uniformly in `[-2^(b+λ), 2^(b+λ))`: the statistical distance between `e + e_i` and
+//! `e_i` is at most `2^-λ`, so the partial decryption of an honest party hides `e` even if all the
+//! other parties collude.
+//!
+//! `2^b` is 16 times the standard deviation of the noise of the squashed ciphertexts, computed
+//! from the parameters of the parties and their number. The decryption is correct if
+//! `|e| + K·2^(b+λ) < Δ/2`, which holds when
+//!
+//! ```text
+//! b + λ + ceil(log2(K)) + 2 <= log2(Δ)
+//! ```
+//!
+//! The partial decryption of a ciphertext not meeting this constraint is rejected.
+//!
+//! ```rust
+//! use tfhe::shortint::multi_key::{combine_partial_decryptions, MultiKeyServerKey};
+//! use tfhe::shortint::noise_squashing::NoiseSquashingPrivateKey;
+//! use tfhe::shortint::parameters::multi_key::MULTI_KEY_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+//! use tfhe::shortint::ClientKey;
+//!
+//! let params = MULTI_KEY_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+//! let alice_cks = ClientKey::new(params.block_parameters);
+//! let alice_nsk = NoiseSquashingPrivateKey::new(params.noise_squashing_parameters);
+//! let bob_cks = ClientKey::new(params.block_parameters);
+//! let bob_nsk = NoiseSquashingPrivateKey::new(params.noise_squashing_parameters);
+//!
+//! let public_keys = [
+//!     alice_cks.new_multi_key_public_key(&alice_nsk),
+//!     bob_cks.new_multi_key_public_key(&bob_nsk),
+//! ];
+//! let contributions = [
+//!     alice_cks
+//!         .new_multi_key_server_key_contribution(0, &public_keys, params)
+//!         .unwrap(),
+//!     bob_cks
+//!         .new_multi_key_server_key_contribution(1, &public_keys, params)
+//!         .unwrap(),
+//! ];
+//! let sks = MultiKeyServerKey::new(&contributions).unwrap();
+//!
+//! let a = sks.extend(0, &alice_cks.encrypt(1)).unwrap();
+//! let b = sks.extend(1, &bob_cks.encrypt(1)).unwrap();
+//!
+//! let lut = sks.generate_lookup_table_bivariate(|x, y| x & y);
+//! let ct = sks.unchecked_apply_lookup_table_bivariate(&a, &b, &lut);
+//! let squashed = sks.squash_noise(&ct);
+//!
+//! let partial_decryptions = [
+//!     alice_cks
+//!         .partial_decrypt_multi_key(0, &alice_nsk, &squashed)
+//!         .unwrap(),
+//!     bob_cks
+//!         .partial_decrypt_multi_key(1, &bob_nsk, &squashed)
+//!         .unwrap(),
+//! ];
+//! assert_eq!(combine_partial_decryptions(&partial_decryptions).unwrap(), 1);
+//! ```
+
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_dot_product;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
+use crate::core_crypto::prelude::*;
+use crate::core_crypto::seeders::new_seeder;
+use crate::shortint::backward_compatibility::multi_key::{
+    MultiKeyPartialDecryptionVersions, MultiKeyPublicKeyVersions,
+    MultiKeyServerKeyContributionVersions, MultiKeyServerKeyVersions,
+};
+use crate::shortint::ciphertext::{NoiseLevel, SquashedNoiseCiphertext};
+use crate::shortint::client_key::ClientKey;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::noise_squashing::NoiseSquashingPrivateKey;
+use crate::shortint::parameters::{MultiKeyParameters, NoiseSquashingParameters};
+use crate::shortint::server_key::{
+    generate_lookup_table, unchecked_add_assign, unchecked_scalar_mul_assign,
+    BivariateLookupTableOwned, LookupTableOwned,
+};
+use crate::shortint::{
+    CarryModulus, Ciphertext, ClassicPBSParameters, EncryptionKeyChoice, MessageModulus, PBSOrder,
+    PBSParameters,
+};
+use concrete_csprng::seeders::Seeder;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// The smudging noise of each party hides the noise of the ciphertexts up to a statistical
+/// distance of `2^-SMUDGING_STATISTICAL_SECURITY_LOG2`.
+pub const SMUDGING_STATISTICAL_SECURITY_LOG2: u32 = 40;
+
+/// The noise of the squashed ciphertexts is bounded by `2^4 = 16` times its standard deviation,
+/// which it exceeds with a probability below `2^-180`.
+const NOISE_BOUND_STD_DEV_FACTOR_LOG2: f64 = 4.0;
+
+/// Public keys of a party, GLWE encryptions of zero under its GLWE key and under the GLWE key of
+/// its [`NoiseSquashingPrivateKey`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(MultiKeyPublicKeyVersions)]
+pub struct MultiKeyPublicKey {
+    pub(crate) glwe_public_key: GlweCiphertextOwned<u64>,
+    pub(crate) noise_squashing_glwe_public_key: GlweCiphertextOwned<u128>,
+}
+
+/// The part of the [`MultiKeyServerKey`] generated by one party.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(MultiKeyServerKeyContributionVersions)]
+pub struct MultiKeyServerKeyContribution {
+    party: usize,
+    num_parties: usize,
+    parameters: MultiKeyParameters,
+    /// Keyswitching key from the large key to the small key of the party
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    /// GGSW encryptions of the small key of the party under the concatenated GLWE key
+    bootstrapping_key: LweBootstrapKeyOwned<u64>,
+    /// GGSW encryptions of the small key of the party under the concatenated noise squashing key
+    noise_squashing_bootstrapping_key: LweBootstrapKeyOwned<u128>,
+}
+
+impl MultiKeyServerKeyContribution {
+    pub fn party(&self) -> usize {
+        self.party
+    }
+
+    pub fn num_parties(&self) -> usize {
+        self.num_parties
+    }
+}
+
+/// Server key evaluating lookup tables on ciphertexts under the concatenated keys of all the
+/// parties.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(MultiKeyServerKeyVersions)]
+pub struct MultiKeyServerKey {
+    /// Keyswitching keys of the parties, in order
+    key_switching_keys: Vec<LweKeyswitchKeyOwned<u64>>,
+    /// Bootstrapping key from the concatenated small keys to the concatenated GLWE keys
+    bootstrapping_key: FourierLweBootstrapKeyOwned,
+    /// Bootstrapping key from the concatenated small keys to the concatenated noise squashing
+    /// keys
+    noise_squashing_bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    ciphertext_modulus: CiphertextModulus<u64>,
+}
+
+/// The contribution of one party to the decryption of a squashed multi-key ciphertext.
+#[derive(Clone, Debug, Serialize, Deserialize, Versionize)]
+#[versionize(MultiKeyPartialDecryptionVersions)]
+pub struct MultiKeyPartialDecryption {
+    party: usize,
+    num_parties: usize,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    value: u128,
+}
+
+impl MultiKeyPartialDecryption {
+    pub fn party(&self) -> usize {
+        self.party
+    }
+}
+
+fn multi_key_parameters(client_key: &ClientKey) -> crate::Result<ClassicPBSParameters> {
+    let params = match client_key.parameters.pbs_parameters() {
+        Some(PBSParameters::PBS(params)) => params,
+        _ => {
+            return Err(crate::Error::new(
+                "Multi-key evaluation is only compatible with classic PBS parameters".to_string(),
+            ))
+        }
+    };
+
+    if params.encryption_key_choice != EncryptionKeyChoice::Big {
+        return Err(crate::Error::new(
+            "Multi-key evaluation is only compatible with ciphertext in post PBS dimension"
+                .to_string(),
+        ));
+    }
+    if !params.ciphertext_modulus.is_native_modulus() {
+        return Err(crate::Error::new(
+            "Multi-key evaluation is only compatible with the native ciphertext modulus"
+                .to_string(),
+        ));
+    }
+
+    Ok(params)
+}
+
+/// log2 of the scaling factor of the squashed messages, the padding bit being accounted for.
+fn delta_log2(message_modulus: MessageModulus, carry_modulus: CarryModulus) -> u32 {
+    127 - (message_modulus.0 * carry_modulus.0).ilog2()
+}
+
+/// Returns log2 of the bound on the noise of the ciphertexts squashed by the joint noise
+/// squashing key of `num_parties` parties, see [`MultiKeyParameters`] for the noise analysis.
+fn squashed_noise_bound_log2(
+    small_lwe_dimension: LweDimension,
+    params: NoiseSquashingParameters,
+    num_parties: usize,
+) -> u32 {
+    let modulus_square = 2f64.powi(256);
+    let glwe_noise_variance = match params.glwe_noise_distribution {
+        DynamicDistribution::Gaussian(gaussian) => gaussian.std * gaussian.std * modulus_square,
+        DynamicDistribution::TUniform(t_uniform) => {
+            (2f64.powi(2 * t_uniform.bound_log2() as i32 + 1) + 1.0) / 6.0
+        }
+    };
+
+    let num_parties = num_parties as f64;
+    let n = small_lwe_dimension.0 as f64 * num_parties;
+    let k = params.glwe_dimension.0 as f64;
+    let big_n = params.polynomial_size.0 as f64;
+    let base = 2f64.powi(params.decomp_base_log.0 as i32);
+    let level = params.decomp_level_count.0 as f64;
+
+    let ggsw_noise_variance = (num_parties * (k + 1.0) * big_n / 2.0 + 1.0) * glwe_noise_variance;
+    let pbs_variance = n * level * (num_parties * k + 1.0) * big_n * base * base / 12.0
+        * ggsw_noise_variance
+        + n * (1.0 + num_parties * k * big_n / 2.0) * modulus_square
+            / (12.0 * base.powf(2.0 * level));
+
+    (pbs_variance.sqrt().log2() + NOISE_BOUND_STD_DEV_FACTOR_LOG2).ceil() as u32
+}
+
+/// Adds `r_i·pk_i` to the segment `i` of the mask and to the body of `output` for each public
+/// key, `r_i` being the `i`-th binary polynomial of `binary_polynomials`.
+///
+/// With noise in `output`, this is a public key encryption of zero under the concatenated key.
+fn add_public_key_products<Scalar: UnsignedInteger>(
+    public_keys: &[GlweCiphertextView<'_, Scalar>],
+    output: &mut GlweCiphertext<&mut [Scalar]>,
+    binary_polynomials: &[u8],
+) {
+    let polynomial_size = output.polynomial_size();
+    let segment_size = output.glwe_size().to_glwe_dimension().0 / public_keys.len();
+
+    let (mut mask, mut body) = output.get_mut_mask_and_body();
+    let mut body = body.as_mut_polynomial();
+    let mut binary_polynomial = Polynomial::new(Scalar::ZERO, polynomial_size);
+
+    for ((public_key, mask_segment), binary) in public_keys
+        .iter()
+        .zip(
+            mask.as_mut()
+                .chunks_exact_mut(segment_size * polynomial_size.0),
+        )
+        .zip(binary_polynomials.chunks_exact(polynomial_size.0))
+    {
+        binary_polynomial
+            .as_mut()
+            .iter_mut()
+            .zip(binary)
+            .for_each(|(dst, &src)| *dst = Scalar::from(src != 0));
+
+        let (public_mask, public_body) = public_key.get_mask_and_body();
+        for (public_mask_polynomial, mask_polynomial) in public_mask
+            .as_polynomial_list()
+            .iter()
+            .zip(mask_segment.chunks_exact_mut(polynomial_size.0))
+        {
+            polynomial_wrapping_add_mul_assign(
+                &mut Polynomial::from_container(mask_polynomial),
+                &public_mask_polynomial,
+                &binary_polynomial,
+            );
+        }
+        polynomial_wrapping_add_mul_assign(
+            &mut body,
+            &public_body.as_polynomial(),
+            &binary_polynomial,
+        );
+    }
+}
+
+/// Generates the GGSW encryptions of the bits of `small_lwe_secret_key` under the concatenation
+/// of the GLWE keys of `public_keys`, see [`add_public_key_products`].
+fn generate_joint_bootstrapping_key<Scalar: UnsignedTorus + Sync + Send>(
+    small_lwe_secret_key: &LweSecretKey<&[u64]>,
+    public_keys: &[GlweCiphertextView<'_, Scalar>],
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: DynamicDistribution<Scalar>,
+    engine: &mut ShortintEngine,
+) -> LweBootstrapKeyOwned<Scalar> {
+    let num_parties = public_keys.len();
+    let polynomial_size = public_keys[0].polynomial_size();
+    let ciphertext_modulus = public_keys[0].ciphertext_modulus();
+    let joint_glwe_size =
+        GlweDimension(num_parties * public_keys[0].glwe_size().to_glwe_dimension().0)
+            .to_glwe_size();
+
+    let mut bootstrapping_key = LweBootstrapKey::new(
+        Scalar::ZERO,
+        joint_glwe_size,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        small_lwe_secret_key.lwe_dimension(),
+        ciphertext_modulus,
+    );
+
+    // One binary polynomial per party for each row of each GGSW
+    let binary_polynomials_per_row = num_parties * polynomial_size.0;
+    let binary_polynomials_per_ggsw =
+        decomp_level_count.0 * joint_glwe_size.0 * binary_polynomials_per_row;
+    let mut binary_polynomials =
+        vec![0u8; small_lwe_secret_key.lwe_dimension().0 * binary_polynomials_per_ggsw];
+
+    engine
+        .encryption_generator
+        .fill_slice_with_random_noise_from_distribution(
+            bootstrapping_key.as_mut(),
+            noise_distribution,
+        );
+    engine
+        .secret_generator
+        .fill_slice_with_random_uniform_binary(&mut binary_polynomials);
+
+    bootstrapping_key
+        .par_iter_mut()
+        .zip(small_lwe_secret_key.as_ref().par_iter())
+        .zip(binary_polynomials.par_chunks_exact(binary_polynomials_per_ggsw))
+        .for_each(|((mut ggsw, &bit), ggsw_binary_polynomials)| {
+            for (level_index, (mut level_matrix, level_binary_polynomials)) in ggsw
+                .iter_mut()
+                .zip(
+                    ggsw_binary_polynomials
+                        .chunks_exact(joint_glwe_size.0 * binary_polynomials_per_row),
+                )
+                .enumerate()
+            {
+                let factor = ggsw_encryption_multiplicative_factor(
+                    ciphertext_modulus,
+                    DecompositionLevel(level_index + 1),
+                    decomp_base_log,
+                    Cleartext(Scalar::from(bit != 0)),
+                );
+                let last_row_index = joint_glwe_size.0 - 1;
+
+                for (row_index, (mut row_as_glwe, row_binary_polynomials)) in level_matrix
+                    .as_mut_glwe_list()
+                    .iter_mut()
+                    .zip(level_binary_polynomials.chunks_exact(binary_polynomials_per_row))
+                    .enumerate()
+                {
+                    add_public_key_products(public_keys, &mut row_as_glwe, row_binary_polynomials);
+
+                    // The row `r < K·k` encrypts `factor·Z_r`, which amounts to subtracting
+                    // `factor` from the mask polynomial `r`, the last row encrypts `-factor`
+                    if row_index < last_row_index {
+                        let mut mask = row_as_glwe.get_mut_mask();
+                        let coefficient = &mut mask.as_mut()[row_index * polynomial_size.0];
+                        *coefficient = coefficient.wrapping_sub(factor);
+                    } else {
+                        let mut body = row_as_glwe.get_mut_body();
+                        let coefficient = &mut body.as_mut()[0];
+                        *coefficient = coefficient.wrapping_sub(factor);
+                    }
+                }
+            }
+        });
+
+    bootstrapping_key
+}
+
+impl ClientKey {
+    /// Generates the public keys of the party, `noise_squashing_private_key` being its noise
+    /// squashing key.
+    pub fn new_multi_key_public_key(
+        &self,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> MultiKeyPublicKey {
+        let noise_squashing_params = noise_squashing_private_key.params;
+        let mut glwe_public_key = GlweCiphertext::new(
+            0u64,
+            self.parameters.glwe_dimension().to_glwe_size(),
+            self.parameters.polynomial_size(),
+            self.parameters.ciphertext_modulus(),
+        );
+        let mut noise_squashing_glwe_public_key = GlweCiphertext::new(
+            0u128,
+            noise_squashing_params.glwe_dimension.to_glwe_size(),
+            noise_squashing_params.polynomial_size,
+            CiphertextModulus::new_native(),
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            encrypt_glwe_ciphertext_assign(
+                &self.glwe_secret_key,
+                &mut glwe_public_key,
+                self.parameters.glwe_noise_distribution(),
+                &mut engine.encryption_generator,
+            );
+            encrypt_glwe_ciphertext_assign(
+                &noise_squashing_private_key.post_noise_squashing_secret_key,
+                &mut noise_squashing_glwe_public_key,
+                noise_squashing_params.glwe_noise_distribution,
+                &mut engine.encryption_generator,
+            );
+        });
+
+        MultiKeyPublicKey {
+            glwe_public_key,
+            noise_squashing_glwe_public_key,
+        }
+    }
+
+    /// Generates the contribution of `party` to the [`MultiKeyServerKey`], `public_keys` being
+    /// the public keys of all the parties, in order.
+    pub fn new_multi_key_server_key_contribution(
+        &self,
+        party: usize,
+        public_keys: &[MultiKeyPublicKey],
+        multi_key_params: MultiKeyParameters,
+    ) -> crate::Result<MultiKeyServerKeyContribution> {
+        let params = multi_key_parameters(self)?;
+        let noise_squashing_params = multi_key_params.noise_squashing_parameters;
+        let num_parties = public_keys.len();
+
+        if params != multi_key_params.block_parameters {
+            return Err(crate::Error::new(
+                "The client key does not have the block parameters of the multi-key parameters"
+                    .to_string(),
+            ));
+        }
+        if num_parties > multi_key_params.max_num_parties {
+            return Err(crate::Error::new(format!(
+                "The multi-key parameters support at most {} parties, got {num_parties}",
+                multi_key_params.max_num_parties
+            )));
+        }
+        if party >= num_parties {
+            return Err(crate::Error::new(format!(
+                "Invalid party {party} for {num_parties} public keys"
+            )));
+        }
+        if let Some(index) = public_keys.iter().position(|public_key| {
+            public_key.glwe_public_key.glwe_size() != params.glwe_dimension.to_glwe_size()
+                || public_key.glwe_public_key.polynomial_size() != params.polynomial_size
+                || public_key.glwe_public_key.ciphertext_modulus() != params.ciphertext_modulus
+                || public_key.noise_squashing_glwe_public_key.glwe_size()
+                    != noise_squashing_params.glwe_dimension.to_glwe_size()
+                || public_key.noise_squashing_glwe_public_key.polynomial_size()
+                    != noise_squashing_params.polynomial_size
+                || !public_key
+                    .noise_squashing_glwe_public_key
+                    .ciphertext_modulus()
+                    .is_native_modulus()
+        }) {
+            return Err(crate::Error::new(format!(
+                "The public key of party {index} does not match the multi-key parameters"
+            )));
+        }
+
+        let small_lwe_secret_key = self.small_lwe_secret_key();
+        let glwe_public_keys = public_keys
+            .iter()
+            .map(|public_key| public_key.glwe_public_key.as_view())
+            .collect::<Vec<_>>();
+        let noise_squashing_glwe_public_keys = public_keys
+            .iter()
+            .map(|public_key| public_key.noise_squashing_glwe_public_key.as_view())
+            .collect::<Vec<_>>();
+
+        let (key_switching_key, bootstrapping_key, noise_squashing_bootstrapping_key) =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+                    &self.large_lwe_secret_key(),
+                    &small_lwe_secret_key,
+                    params.ks_base_log,
+                    params.ks_level,
+                    params.lwe_noise_distribution,
+                    params.ciphertext_modulus,
+                    &mut engine.encryption_generator,
+                );
+                let bootstrapping_key = generate_joint_bootstrapping_key(
+                    &small_lwe_secret_key,
+                    &glwe_public_keys,
+                    multi_key_params.br_base_log,
+                    multi_key_params.br_level,
+                    params.glwe_noise_distribution,
+                    engine,
+                );
+                let noise_squashing_bootstrapping_key = generate_joint_bootstrapping_key(
+                    &small_lwe_secret_key,
+                    &noise_squashing_glwe_public_keys,
+                    noise_squashing_params.decomp_base_log,
+                    noise_squashing_params.decomp_level_count,
+                    noise_squashing_params.glwe_noise_distribution,
+                    engine,
+                );
+
+                (
+                    key_switching_key,
+                    bootstrapping_key,
+                    noise_squashing_bootstrapping_key,
+                )
+            });
+
+        Ok(MultiKeyServerKeyContribution {
+            party,
+            num_parties,
+            parameters: multi_key_params,
+            key_switching_key,
+            bootstrapping_key,
+            noise_squashing_bootstrapping_key,
+        })
+    }
+
+    /// Partially decrypts a squashed multi-key ciphertext with the key of `party` and its noise
+    /// squashing key, the partial decryptions of all the parties are combined with
+    /// [`combine_partial_decryptions`].
+    ///
+    /// A smudging noise is added to hide the key of the party, see the
+    /// [module documentation](crate::shortint::multi_key) for its size. The ciphertext is
+    /// rejected if its noise bound leaves no room for the smudging noise.
+    pub fn partial_decrypt_multi_key(
+        &self,
+        party: usize,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+        ct: &SquashedNoiseCiphertext,
+    ) -> crate::Result<MultiKeyPartialDecryption> {
+        let params = multi_key_parameters(self)?;
+        let secret_key = noise_squashing_private_key
+            .post_noise_squashing_secret_key
+            .as_lwe_secret_key();
+        let segment_size = secret_key.lwe_dimension().0;
+        let lwe_dimension = ct.ct.lwe_size().to_lwe_dimension().0;
+
+        if ct.message_modulus != params.message_modulus
+            || ct.carry_modulus != params.carry_modulus
+            || lwe_dimension % segment_size != 0
+        {
+            return Err(crate::Error::new(
+                "The ciphertext is not a squashed multi-key ciphertext of the client key \
+                parameters"
+                    .to_string(),
+            ));
+        }
+        let num_parties = lwe_dimension / segment_size;
+        if party >= num_parties {
+            return Err(crate::Error::new(format!(
+                "Invalid party {party} for a ciphertext of {num_parties} parties"
+            )));
+        }
+
+        let noise_bound_log2 = squashed_noise_bound_log2(
+            params.lwe_dimension,
+            noise_squashing_private_key.params,
+            num_parties,
+        );
+        let smudging_bound_log2 = noise_bound_log2 + SMUDGING_STATISTICAL_SECURITY_LOG2;
+        if smudging_bound_log2 + num_parties.next_power_of_two().ilog2() + 2
+            > delta_log2(ct.message_modulus, ct.carry_modulus)
+        {
+            return Err(crate::Error::new(format!(
+                "The noise bound 2^{noise_bound_log2} of the ciphertext leaves no room for the \
+                smudging noise of {num_parties} parties"
+            )));
+        }
+
+        let mask_key_dot_product = slice_wrapping_dot_product(
+            &ct.ct.get_mask().as_ref()[party * segment_size..(party + 1) * segment_size],
+            secret_key.as_ref(),
+        );
+
+        let mut generator = RandomGenerator::<ActivatedRandomGenerator>::new(new_seeder().seed());
+        // Uniform in [-2^smudging_bound_log2, 2^smudging_bound_log2)
+        let smudging_noise = (generator.random_uniform::<u128>()
+            & ((1u128 << (smudging_bound_log2 + 1)) - 1))
+            .wrapping_sub(1u128 << smudging_bound_log2);
+
+        let partial = smudging_noise.wrapping_sub(mask_key_dot_product);
+        // Only one of the parties adds the body
+        let value = if party == 0 {
+            partial.wrapping_add(*ct.ct.get_body().data)
+        } else {
+            partial
+        };
+
+        Ok(MultiKeyPartialDecryption {
+            party,
+            num_parties,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            value,
+        })
+    }
+}
+
+/// Sums the partial decryptions of all the parties and decodes the message and carry.
+pub fn combine_partial_decryptions(partials: &[MultiKeyPartialDecryption]) -> crate::Result<u64> {
+    let Some(first) = partials.first() else {
+        return Err(crate::Error::new(
+            "No partial decryption to combine".to_string(),
+        ));
+    };
+
+    let mut parties = vec![false; first.num_parties];
+    for partial in partials {
+        if partial.num_parties != first.num_parties
+            || partial.message_modulus != first.message_modulus
+            || partial.carry_modulus != first.carry_modulus
+        {
+            return Err(crate::Error::new(format!(
+                "Partial decryption of party {} does not match the one of party {}",
+                partial.party, first.party
+            )));
+        }
+        match parties.get_mut(partial.party) {
+            Some(seen) if !*seen => *seen = true,
+            _ => {
+                return Err(crate::Error::new(format!(
+                    "Duplicated or invalid partial decryption of party {}",
+                    partial.party
+                )))
+            }
+        }
+    }
+    if let Some(missing) = parties.iter().position(|seen| !seen) {
+        return Err(crate::Error::new(format!(
+            "Missing the partial decryption of party {missing}"
+        )));
+    }
+
+    let decrypted = partials
+        .iter()
+        .fold(0u128, |sum, partial| sum.wrapping_add(partial.value));
+    // Rounds to the closest multiple of delta
+    let delta_log2 = delta_log2(first.message_modulus, first.carry_modulus);
+    let rounding = 1u128 << (delta_log2 - 1);
+
+    Ok(((decrypted.wrapping_add(rounding) >> delta_log2)
+        & (first.message_modulus.0 * first.carry_modulus.0 - 1) as u128) as u64)
+}
+
+/// Concatenates the bootstrapping keys of the parties, giving a key from the concatenation of
+/// their small keys.
+fn concatenate_bootstrapping_keys<Scalar: UnsignedInteger>(
+    bootstrapping_keys: &[&LweBootstrapKeyOwned<Scalar>],
+) -> LweBootstrapKeyOwned<Scalar> {
+    let first = bootstrapping_keys[0];
+
+    LweBootstrapKey::from_container(
+        bootstrapping_keys
+            .iter()
+            .flat_map(|bootstrapping_key| bootstrapping_key.as_ref().iter().copied())
+            .collect::<Vec<_>>(),
+        first.glwe_size(),
+        first.polynomial_size(),
+        first.decomposition_base_log(),
+        first.decomposition_level_count(),
+        first.ciphertext_modulus(),
+    )
+}
+
+impl MultiKeyServerKey {
+    /// Assembles the contributions of all the parties, which must be given in order.
+    pub fn new(contributions: &[MultiKeyServerKeyContribution]) -> crate::Result<Self> {
+        let Some(first) = contributions.first() else {
+            return Err(crate::Error::new(
+                "No server key contribution to assemble".to_string(),
+            ));
+        };
+        if contributions.len() != first.num_parties {
+            return Err(crate::Error::new(format!(
+                "Expected {} server key contributions, got {}",
+                first.num_parties,
+                contributions.len()
+            )));
+        }
+        for (party, contribution) in contributions.iter().enumerate() {
+            if contribution.party != party {
+                return Err(crate::Error::new(format!(
+                    "Expected the contribution of party {party}, got the one of party {}",
+                    contribution.party
+                )));
+            }
+            if contribution.num_parties != first.num_parties
+                || contribution.parameters != first.parameters
+                || contribution.bootstrapping_key.decomposition_base_log()
+                    != first.bootstrapping_key.decomposition_base_log()
+                || contribution.bootstrapping_key.decomposition_level_count()
+                    != first.bootstrapping_key.decomposition_level_count()
+                || contribution
+                    .noise_squashing_bootstrapping_key
+                    .decomposition_base_log()
+                    != first
+                        .noise_squashing_bootstrapping_key
+                        .decomposition_base_log()
+                || contribution
+                    .noise_squashing_bootstrapping_key
+                    .decomposition_level_count()
+                    != first
+                        .noise_squashing_bootstrapping_key
+                        .decomposition_level_count()
+            {
+                return Err(crate::Error::new(format!(
+                    "The contribution of party {party} does not match the one of party 0"
+                )));
+            }
+        }
+
+        let standard_bootstrapping_key = concatenate_bootstrapping_keys(
+            &contributions
+                .iter()
+                .map(|contribution| &contribution.bootstrapping_key)
+                .collect::<Vec<_>>(),
+        );
+        let mut bootstrapping_key = FourierLweBootstrapKey::new(
+            standard_bootstrapping_key.input_lwe_dimension(),
+            standard_bootstrapping_key.glwe_size(),
+            standard_bootstrapping_key.polynomial_size(),
+            standard_bootstrapping_key.decomposition_base_log(),
+            standard_bootstrapping_key.decomposition_level_count(),
+        );
+        par_convert_standard_lwe_bootstrap_key_to_fourier(
+            &standard_bootstrapping_key,
+            &mut bootstrapping_key,
+        );
+
+        let standard_noise_squashing_bootstrapping_key = concatenate_bootstrapping_keys(
+            &contributions
+                .iter()
+                .map(|contribution| &contribution.noise_squashing_bootstrapping_key)
+                .collect::<Vec<_>>(),
+        );
+        let mut noise_squashing_bootstrapping_key = Fourier128LweBootstrapKey::new(
+            standard_noise_squashing_bootstrapping_key.input_lwe_dimension(),
+            standard_noise_squashing_bootstrapping_key.glwe_size(),
+            standard_noise_squashing_bootstrapping_key.polynomial_size(),
+            standard_noise_squashing_bootstrapping_key.decomposition_base_log(),
+            standard_noise_squashing_bootstrapping_key.decomposition_level_count(),
+        );
+        convert_standard_lwe_bootstrap_key_to_fourier_128(
+            &standard_noise_squashing_bootstrapping_key,
+            &mut noise_squashing_bootstrapping_key,
+        );
+
+        Ok(Self {
+            key_switching_keys: contributions
+                .iter()
+                .map(|contribution| contribution.key_switching_key.clone())
+                .collect(),
+            bootstrapping_key,
+            noise_squashing_bootstrapping_key,
+            message_modulus: first.parameters.block_parameters.message_modulus,
+            carry_modulus: first.parameters.block_parameters.carry_modulus,
+            ciphertext_modulus: first.parameters.block_parameters.ciphertext_modulus,
+        })
+    }
+
+    pub fn num_parties(&self) -> usize {
+        self.key_switching_keys.len()
+    }
+
+    /// Dimension of the large key of each party
+    fn segment_size(&self) -> usize {
+        self.key_switching_keys[0].input_key_lwe_dimension().0
+    }
+
+    /// Extends a ciphertext under the key of `party` to the concatenated key of all the parties.
+    pub fn extend(&self, party: usize, ct: &Ciphertext) -> crate::Result<Ciphertext> {
+        let segment_size = self.segment_size();
+
+        if party >= self.num_parties() {
+            return Err(crate::Error::new(format!(
+                "Invalid party {party} for {} parties",
+                self.num_parties()
+            )));
+        }
+        if ct.pbs_order != PBSOrder::KeyswitchBootstrap
+            || ct.ct.lwe_size().to_lwe_dimension().0 != segment_size
+            || ct.message_modulus != self.message_modulus
+            || ct.carry_modulus != self.carry_modulus
+        {
+            return Err(crate::Error::new(
+                "The ciphertext is not compatible with the multi-key server key".to_string(),
+            ));
+        }
+
+        let mut extended = LweCiphertext::new(
+            0u64,
+            LweDimension(self.num_parties() * segment_size).to_lwe_size(),
+            self.ciphertext_modulus,
+        );
+        extended.get_mut_mask().as_mut()[party * segment_size..(party + 1) * segment_size]
+            .copy_from_slice(ct.ct.get_mask().as_ref());
+        *extended.get_mut_body().data = *ct.ct.get_body().data;
+
+        Ok(Ciphertext::new(
+            extended,
+            ct.degree,
+            ct.noise_level(),
+            ct.message_modulus,
+            ct.carry_modulus,
+            ct.pbs_order,
+        ))
+    }
+
+    pub fn unchecked_add(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> Ciphertext {
+        let mut result = ct_left.clone();
+        unchecked_add_assign(&mut result, ct_right);
+        result
+    }
+
+    pub fn unchecked_scalar_mul(&self, ct: &Ciphertext, scalar: u8) -> Ciphertext {
+        let mut result = ct.clone();
+        unchecked_scalar_mul_assign(&mut result, scalar);
+        result
+    }
+
+    pub fn generate_lookup_table<F>(&self, f: F) -> LookupTableOwned
+    where
+        F: Fn(u64) -> u64,
+    {
+        generate_lookup_table(
+            self.bootstrapping_key.glwe_size(),
+            self.bootstrapping_key.polynomial_size(),
+            self.ciphertext_modulus,
+            self.message_modulus,
+            self.carry_modulus,
+            f,
+        )
+    }
+
+    pub fn generate_lookup_table_bivariate<F>(&self, f: F) -> BivariateLookupTableOwned
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let message_modulus = self.message_modulus.0 as u64;
+        BivariateLookupTableOwned {
+            acc: self.generate_lookup_table(|x| f(x / message_modulus, x % message_modulus)),
+            ct_right_modulus: self.message_modulus,
+        }
+    }
+
+    /// Switches a multi-key ciphertext to the concatenation of the small keys of the parties.
+    fn keyswitch(&self, ct: &Ciphertext) -> LweCiphertextOwned<u64> {
+        let segment_size = self.segment_size();
+        assert_eq!(
+            ct.ct.lwe_size().to_lwe_dimension().0,
+            self.num_parties() * segment_size,
+            "The ciphertext is not under the concatenated key of the multi-key server key"
+        );
+
+        // Each party switches its segment of the mask to its small key
+        let switched_segments: Vec<_> = ct
+            .ct
+            .get_mask()
+            .as_ref()
+            .par_chunks_exact(segment_size)
+            .zip(self.key_switching_keys.par_iter())
+            .map(|(segment, key_switching_key)| {
+                let mut input = LweCiphertext::new(
+                    0u64,
+                    LweDimension(segment_size).to_lwe_size(),
+                    self.ciphertext_modulus,
+                );
+                input.get_mut_mask().as_mut().copy_from_slice(segment);
+
+                let mut output = LweCiphertext::new(
+                    0u64,
+                    key_switching_key.output_lwe_size(),
+                    self.ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(key_switching_key, &input, &mut output);
+                output
+            })
+            .collect();
+
+        let mut small_ct = LweCiphertext::new(
+            0u64,
+            self.bootstrapping_key.input_lwe_dimension().to_lwe_size(),
+            self.ciphertext_modulus,
+        );
+        let mut body = *ct.ct.get_body().data;
+        for (small_segment, switched) in small_ct
+            .get_mut_mask()
+            .as_mut()
+            .chunks_mut(switched_segments[0].lwe_size().to_lwe_dimension().0)
+            .zip(switched_segments.iter())
+        {
+            small_segment.copy_from_slice(switched.get_mask().as_ref());
+            body = body.wrapping_add(*switched.get_body().data);
+        }
+        *small_ct.get_mut_body().data = body;
+
+        small_ct
+    }
+
+    /// Applies a lookup table to a multi-key ciphertext, with the joint keyswitch and PBS.
+    pub fn apply_lookup_table(&self, ct: &Ciphertext, acc: &LookupTableOwned) -> Ciphertext {
+        let small_ct = self.keyswitch(ct);
+
+        let mut output = LweCiphertext::new(
+            0u64,
+            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+            self.ciphertext_modulus,
+        );
+        programmable_bootstrap_lwe_ciphertext(
+            &small_ct,
+            &mut output,
+            &acc.acc,
+            &self.bootstrapping_key,
+        );
+
+        Ciphertext::new(
+            output,
+            acc.degree,
+            NoiseLevel::NOMINAL,
+            ct.message_modulus,
+            ct.carry_modulus,
+            ct.pbs_order,
+        )
+    }
+
+    /// Computes a bivariate function of two multi-key ciphertexts, packed in a single one before
+    /// applying the lookup table.
+    pub fn unchecked_apply_lookup_table_bivariate(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+        acc: &BivariateLookupTableOwned,
+    ) -> Ciphertext {
+        let modulus = ct_right.degree.get() + 1;
+        assert!(modulus <= acc.ct_right_modulus.0);
+
+        let mut packed = self.unchecked_scalar_mul(ct_left, acc.ct_right_modulus.0 as u8);
+        unchecked_add_assign(&mut packed, ct_right);
+
+        self.apply_lookup_table(&packed, &acc.acc)
+    }
+
+    /// Squashes the noise of a multi-key ciphertext, the result encrypts the same message and
+    /// carries under the concatenation of the noise squashing keys of the parties.
+    pub fn squash_noise(&self, ct: &Ciphertext) -> SquashedNoiseCiphertext {
+        let small_ct = self.keyswitch(ct);
+
+        let lifted_ct = LweCiphertext::from_container(
+            small_ct
+                .as_ref()
+                .iter()
+                .map(|&x| u128::from(x) << u64::BITS)
+                .collect::<Vec<_>>(),
+            CiphertextModulus::new_native(),
+        );
+
+        // The padding bit is kept, the LUT covers the message and the carries
+        let full_modulus = ct.message_modulus.0 * ct.carry_modulus.0;
+        let accumulator = generate_programmable_bootstrap_glwe_lut(
+            self.noise_squashing_bootstrapping_key.polynomial_size(),
+            self.noise_squashing_bootstrapping_key.glwe_size(),
+            full_modulus,
+            CiphertextModulus::new_native(),
+            (1u128 << 127) / full_modulus as u128,
+            |x| x,
+        );
+
+        let mut squashed_ct = LweCiphertext::new(
+            0u128,
+            self.noise_squashing_bootstrapping_key
+                .output_lwe_dimension()
+                .to_lwe_size(),
+            CiphertextModulus::new_native(),
+        );
+        programmable_bootstrap_f128_lwe_ciphertext(
+            &lifted_ct,
+            &mut squashed_ct,
+            &accumulator,
+            &self.noise_squashing_bootstrapping_key,
+        );
+
+        SquashedNoiseCiphertext {
+            ct: squashed_ct,
+            degree: ct.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{combine_partial_decryptions, MultiKeyServerKey};
+    use crate::core_crypto::prelude::*;
+    use crate::shortint::ciphertext::{Degree, SquashedNoiseCiphertext};
+    use crate::shortint::noise_squashing::NoiseSquashingPrivateKey;
+    use crate::shortint::parameters::multi_key::MULTI_KEY_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::shortint::{
+        CarryModulus, Ciphertext, ClassicPBSParameters, ClientKey, MessageModulus,
+    };
+
+    #[test]
+    fn multi_key_pbs_two_parties() {
+        let params = MULTI_KEY_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+        let client_keys = [
+            ClientKey::new(params.block_parameters),
+            ClientKey::new(params.block_parameters),
+        ];
+        let noise_squashing_private_keys = [
+            NoiseSquashingPrivateKey::new(params.noise_squashing_parameters),
+            NoiseSquashingPrivateKey::new(params.noise_squashing_parameters),
+        ];
+        let public_keys = client_keys
+            .iter()
+            .zip(&noise_squashing_private_keys)
+            .map(|(cks, nsk)| cks.new_multi_key_public_key(nsk))
+            .collect::<Vec<_>>();
+        let mut contributions = client_keys
+            .iter()
+            .enumerate()
+            .map(|(party, cks)| {
+                cks.new_multi_key_server_key_contribution(party, &public_keys, params)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        contributions.swap(0, 1);
+        assert!(MultiKeyServerKey::new(&contributions).is_err());
+        contributions.swap(0, 1);
+        let sks = MultiKeyServerKey::new(&contributions).unwrap();
+
+        let decrypt = |ct: &Ciphertext| {
+            let squashed = sks.squash_noise(ct);
+            let partials = client_keys
+                .iter()
+                .zip(&noise_squashing_private_keys)
+                .enumerate()
+                .map(|(party, (cks, nsk))| {
+                    cks.partial_decrypt_multi_key(party, nsk, &squashed)
+                        .unwrap()
+                })
+                .collect::<Vec<_>>();
+            assert!(combine_partial_decryptions(&partials[..1]).is_err());
+            combine_partial_decryptions(&partials).unwrap()
+        };
+
+        for (a, b) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            let ct_a = sks.extend(0, &client_keys[0].encrypt(a)).unwrap();
+            let ct_b = sks.extend(1, &client_keys[1].encrypt(b)).unwrap();
+
+            let sum = sks.unchecked_add(&ct_a, &ct_b);
+            assert_eq!(decrypt(&sum), a + b);
+
+            let lut = sks.generate_lookup_table(|x| x % 2);
+            let reduced = sks.apply_lookup_table(&sum, &lut);
+            assert_eq!(decrypt(&reduced), (a + b) % 2);
+
+            let lut = sks.generate_lookup_table_bivariate(|x, y| x & y);
+            let product = sks.unchecked_apply_lookup_table_bivariate(&ct_a, &ct_b, &lut);
+            assert_eq!(decrypt(&product), a & b);
+
+            // The result of a PBS can be used in further PBS
+            let lut = sks.generate_lookup_table_bivariate(|x, y| x | y);
+            let or = sks.unchecked_apply_lookup_table_bivariate(&reduced, &product, &lut);
+            assert_eq!(decrypt(&or), ((a + b) % 2) | (a & b));
+        }
+    }
+
+    #[test]
+    fn multi_key_parameters_are_enforced() {
+        let params = MULTI_KEY_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64;
+        let cks = ClientKey::new(params.block_parameters);
+        let nsk = NoiseSquashingPrivateKey::new(params.noise_squashing_parameters);
+        let public_key = cks.new_multi_key_public_key(&nsk);
+
+        // More parties than the failure probability of the parameters allows
+        let public_keys = vec![public_key.clone(); params.max_num_parties + 1];
+        assert!(cks
+            .new_multi_key_server_key_contribution(0, &public_keys, params)
+            .is_err());
+
+        // A client key with other block parameters
+        let other_cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let public_keys = [public_key.clone(), public_key];
+        assert!(other_cks
+            .new_multi_key_server_key_contribution(0, &public_keys, params)
+            .is_err());
+
+        // With 13 bits of message and carry, log2(Δ) = 114 leaves no room for the smudging
+        // noise of 2 parties
+        let tight_cks = ClientKey::new(ClassicPBSParameters {
+            message_modulus: MessageModulus(1 << 7),
+            carry_modulus: CarryModulus(1 << 6),
+            ..params.block_parameters
+        });
+        let squashed = SquashedNoiseCiphertext {
+            ct: LweCiphertext::new(
+                0u128,
+                LweDimension(
+                    2 * nsk
+                        .post_noise_squashing_secret_key
+                        .as_lwe_secret_key()
+                        .lwe_dimension()
+                        .0,
+                )
+                .to_lwe_size(),
+                CiphertextModulus::new_native(),
+            ),
+            degree: Degree::new(0),
+            message_modulus: MessageModulus(1 << 7),
+            carry_modulus: CarryModulus(1 << 6),
+        };
+        assert!(tight_cks
+            .partial_decrypt_multi_key(0, &nsk, &squashed)
+            .is_err());
+    }
+}
@@ -0,0 +1,391 @@
+//! Noise squashing, bootstrapping ciphertexts to 128 bits ciphertexts with a tiny noise.
+//!
+//! Decryption protocols, like MPC decryption, need ciphertexts whose noise is very small compared
+//! to their modulus. The noise squashing bootstraps a 64 bits ciphertext with a 128 bits PBS to a
+//! [`SquashedNoiseCiphertext`], encrypted under a dedicated [`NoiseSquashingPrivateKey`].
+//!
+//! Squashed ciphertexts can be stored in a [`CompressedSquashedNoiseCiphertextList`], whose modulus
+//! is reduced to [`NoiseSquashingParameters::storage_log_modulus`].
+//!
+//! See [`NoiseSquashingParameters`] for the noise analysis.
+
+use crate::core_crypto::prelude::*;
+use crate::shortint::backward_compatibility::noise_squashing::{
+    NoiseSquashingKeyVersions, NoiseSquashingPrivateKeyVersions,
+};
+use crate::shortint::ciphertext::{CompressedSquashedNoiseCiphertextList, SquashedNoiseCiphertext};
+use crate::shortint::client_key::ClientKey;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::NoiseSquashingParameters;
+use crate::shortint::{
+    Ciphertext, ClassicPBSParameters, EncryptionKeyChoice, PBSOrder, PBSParameters, ServerKey,
+};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+/// The key decrypting squashed ciphertexts.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingPrivateKeyVersions)]
+pub struct NoiseSquashingPrivateKey {
+    pub(crate) post_noise_squashing_secret_key: GlweSecretKeyOwned<u128>,
+    pub(crate) params: NoiseSquashingParameters,
+}
+
+impl NoiseSquashingPrivateKey {
+    pub fn new(params: NoiseSquashingParameters) -> Self {
+        let post_noise_squashing_secret_key = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_binary_glwe_secret_key(
+                params.glwe_dimension,
+                params.polynomial_size,
+                &mut engine.secret_generator,
+            )
+        });
+
+        Self {
+            post_noise_squashing_secret_key,
+            params,
+        }
+    }
+
+    pub fn params(&self) -> NoiseSquashingParameters {
+        self.params
+    }
+
+    pub fn into_raw_parts(self) -> (GlweSecretKeyOwned<u128>, NoiseSquashingParameters) {
+        let Self {
+            post_noise_squashing_secret_key,
+            params,
+        } = self;
+        (post_noise_squashing_secret_key, params)
+    }
+
+    pub fn from_raw_parts(
+        post_noise_squashing_secret_key: GlweSecretKeyOwned<u128>,
+        params: NoiseSquashingParameters,
+    ) -> Self {
+        assert_eq!(
+            post_noise_squashing_secret_key.glwe_dimension(),
+            params.glwe_dimension,
+            "Mismatch between the GlweDimension of the key and of the parameters"
+        );
+        assert_eq!(
+            post_noise_squashing_secret_key.polynomial_size(),
+            params.polynomial_size,
+            "Mismatch between the PolynomialSize of the key and of the parameters"
+        );
+
+        Self {
+            post_noise_squashing_secret_key,
+            params,
+        }
+    }
+
+    /// Decrypts a squashed ciphertext, returning its message and carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext is not encrypted under a key of the same dimension.
+    pub fn decrypt_squashed_noise_ciphertext(&self, ct: &SquashedNoiseCiphertext) -> u128 {
+        let lwe_secret_key = self.post_noise_squashing_secret_key.as_lwe_secret_key();
+        assert_eq!(
+            ct.ct.lwe_size().to_lwe_dimension(),
+            lwe_secret_key.lwe_dimension(),
+            "The squashed ciphertext is not encrypted under a key of the same dimension"
+        );
+
+        let decrypted = decrypt_lwe_ciphertext(&lwe_secret_key, &ct.ct).0;
+
+        // The padding bit is kept, as in 64 bits ciphertexts
+        let delta = (1u128 << 127) / (ct.message_modulus.0 * ct.carry_modulus.0) as u128;
+        let rounding = (decrypted & (delta >> 1)) << 1;
+
+        decrypted.wrapping_add(rounding) / delta
+    }
+}
+
+/// The key squashing the noise of the ciphertexts of a [`ClientKey`], see
+/// [`crate::shortint::noise_squashing`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingKeyVersions)]
+pub struct NoiseSquashingKey {
+    /// Bootstrapping key from the small key to the noise squashing key
+    pub(crate) bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+    pub(crate) storage_log_modulus: CiphertextModulusLog,
+}
+
+impl NoiseSquashingKey {
+    pub fn new(
+        client_key: &ClientKey,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> Self {
+        let cks_params: ClassicPBSParameters = match client_key.parameters.pbs_parameters().unwrap()
+        {
+            PBSParameters::PBS(a) => a,
+            PBSParameters::MultiBitPBS(_) => {
+                panic!("Noise squashing is currently not compatible with Multi Bit PBS")
+            }
+        };
+
+        assert_eq!(
+            cks_params.encryption_key_choice,
+            EncryptionKeyChoice::Big,
+            "Noise squashing is only compatible with ciphertext in post PBS dimension"
+        );
+        assert!(
+            cks_params.ciphertext_modulus.is_native_modulus(),
+            "Noise squashing is only compatible with the native ciphertext modulus"
+        );
+
+        let params = noise_squashing_private_key.params;
+
+        let small_lwe_secret_key = LweSecretKey::from_container(
+            client_key
+                .small_lwe_secret_key()
+                .as_ref()
+                .iter()
+                .map(|&bit| u128::from(bit))
+                .collect::<Vec<_>>(),
+        );
+
+        let standard_bootstrapping_key = ShortintEngine::with_thread_local_mut(|engine| {
+            par_allocate_and_generate_new_lwe_bootstrap_key(
+                &small_lwe_secret_key,
+                &noise_squashing_private_key.post_noise_squashing_secret_key,
+                params.decomp_base_log,
+                params.decomp_level_count,
+                params.glwe_noise_distribution,
+                CiphertextModulus::new_native(),
+                &mut engine.encryption_generator,
+            )
+        });
+
+        let mut bootstrapping_key = Fourier128LweBootstrapKey::new(
+            standard_bootstrapping_key.input_lwe_dimension(),
+            standard_bootstrapping_key.glwe_size(),
+            standard_bootstrapping_key.polynomial_size(),
+            standard_bootstrapping_key.decomposition_base_log(),
+            standard_bootstrapping_key.decomposition_level_count(),
+        );
+        convert_standard_lwe_bootstrap_key_to_fourier_128(
+            &standard_bootstrapping_key,
+            &mut bootstrapping_key,
+        );
+
+        Self {
+            bootstrapping_key,
+            storage_log_modulus: params.storage_log_modulus,
+        }
+    }
+
+    pub fn into_raw_parts(self) -> (Fourier128LweBootstrapKeyOwned, CiphertextModulusLog) {
+        let Self {
+            bootstrapping_key,
+            storage_log_modulus,
+        } = self;
+        (bootstrapping_key, storage_log_modulus)
+    }
+
+    pub fn from_raw_parts(
+        bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+        storage_log_modulus: CiphertextModulusLog,
+    ) -> Self {
+        Self {
+            bootstrapping_key,
+            storage_log_modulus,
+        }
+    }
+
+    /// Squashes the noise of a ciphertext, the result encrypts the same message and carries
+    /// under the [`NoiseSquashingPrivateKey`] of the key.
+    ///
+    /// `src_server_key` is the server key of the ciphertext, used to keyswitch it to the small
+    /// key before the PBS.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+    ///     NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    /// let noise_squashing_key = NoiseSquashingKey::new(&cks, &noise_squashing_private_key);
+    ///
+    /// let ct = sks.unchecked_add(&cks.encrypt(3), &cks.encrypt(2));
+    /// let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+    ///
+    /// let decrypted = noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed);
+    /// assert_eq!(decrypted, 5);
+    /// ```
+    pub fn squash_ciphertext_noise(
+        &self,
+        ct: &Ciphertext,
+        src_server_key: &ServerKey,
+    ) -> SquashedNoiseCiphertext {
+        assert_eq!(
+            ct.pbs_order,
+            PBSOrder::KeyswitchBootstrap,
+            "Noise squashing is only compatible with ciphertext in post PBS dimension"
+        );
+        assert_eq!(
+            src_server_key.key_switching_key.output_key_lwe_dimension(),
+            self.bootstrapping_key.input_lwe_dimension(),
+            "The server key does not match the key of the noise squashing key"
+        );
+
+        let mut small_ct = LweCiphertext::new(
+            0u64,
+            src_server_key
+                .key_switching_key
+                .output_key_lwe_dimension()
+                .to_lwe_size(),
+            ct.ct.ciphertext_modulus(),
+        );
+        keyswitch_lwe_ciphertext(&src_server_key.key_switching_key, &ct.ct, &mut small_ct);
+
+        let lifted_ct = LweCiphertext::from_container(
+            small_ct
+                .as_ref()
+                .iter()
+                .map(|&x| u128::from(x) << u64::BITS)
+                .collect::<Vec<_>>(),
+            CiphertextModulus::new_native(),
+        );
+
+        // The padding bit is kept, the LUT covers the message and the carries
+        let full_modulus = ct.message_modulus.0 * ct.carry_modulus.0;
+        let accumulator = generate_programmable_bootstrap_glwe_lut(
+            self.bootstrapping_key.polynomial_size(),
+            self.bootstrapping_key.glwe_size(),
+            full_modulus,
+            CiphertextModulus::new_native(),
+            (1u128 << 127) / full_modulus as u128,
+            |x| x,
+        );
+
+        let mut squashed_ct = LweCiphertext::new(
+            0u128,
+            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+            CiphertextModulus::new_native(),
+        );
+        programmable_bootstrap_f128_lwe_ciphertext(
+            &lifted_ct,
+            &mut squashed_ct,
+            &accumulator,
+            &self.bootstrapping_key,
+        );
+
+        SquashedNoiseCiphertext {
+            ct: squashed_ct,
+            degree: ct.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+        }
+    }
+
+    /// Stores squashed ciphertexts in a list, with their modulus switched to the storage
+    /// modulus of the key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is empty or if the ciphertexts do not all have the same message and
+    /// carry moduli.
+    pub fn compress_squashed_noise_ciphertexts_into_list(
+        &self,
+        ciphertexts: &[SquashedNoiseCiphertext],
+    ) -> CompressedSquashedNoiseCiphertextList {
+        let first_ct = &ciphertexts[0];
+
+        let message_modulus = first_ct.message_modulus;
+        let carry_modulus = first_ct.carry_modulus;
+
+        let modulus_switched_lwe_ciphertext_list = ciphertexts
+            .par_iter()
+            .map(|ct| {
+                assert_eq!(
+                    message_modulus, ct.message_modulus,
+                    "All ciphertexts do not have the same message modulus"
+                );
+                assert_eq!(
+                    carry_modulus, ct.carry_modulus,
+                    "All ciphertexts do not have the same carry modulus"
+                );
+
+                CompressedModulusSwitchedLweCiphertext::compress(&ct.ct, self.storage_log_modulus)
+            })
+            .collect();
+
+        CompressedSquashedNoiseCiphertextList {
+            modulus_switched_lwe_ciphertext_list,
+            degrees: ciphertexts.iter().map(|ct| ct.degree).collect(),
+            message_modulus,
+            carry_modulus,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NoiseSquashingKey, NoiseSquashingPrivateKey};
+    use crate::core_crypto::prelude::*;
+    use crate::shortint::gen_keys;
+    use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+    #[test]
+    fn squash_and_compress_message_and_carries() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        let (cks, sks) = gen_keys(params);
+        let noise_squashing_private_key = NoiseSquashingPrivateKey::new(
+            NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+        let noise_squashing_key = NoiseSquashingKey::new(&cks, &noise_squashing_private_key);
+
+        let full_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64;
+        let delta = (1u128 << 127) / u128::from(full_modulus);
+
+        let squashed = (0..full_modulus)
+            .map(|clear| {
+                let carries = sks.unchecked_scalar_mul(&cks.encrypt(clear / 4), 4);
+                let ct = sks.unchecked_add(&cks.encrypt(clear % 4), &carries);
+                let squashed = noise_squashing_key.squash_ciphertext_noise(&ct, &sks);
+
+                assert_eq!(squashed.degree(), ct.degree);
+                assert_eq!(
+                    noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed),
+                    u128::from(clear)
+                );
+
+                // The noise of the 128 bits PBS is about 2^60, far below the modulus
+                let plaintext = decrypt_lwe_ciphertext(
+                    &noise_squashing_private_key
+                        .post_noise_squashing_secret_key
+                        .as_lwe_secret_key(),
+                    squashed.lwe_ciphertext(),
+                );
+                let noise = plaintext.0.wrapping_sub(u128::from(clear) * delta) as i128;
+                assert!(noise.unsigned_abs() < 1 << 66);
+
+                squashed
+            })
+            .collect::<Vec<_>>();
+
+        let list = noise_squashing_key.compress_squashed_noise_ciphertexts_into_list(&squashed);
+        assert_eq!(list.len(), squashed.len());
+        assert!(list.unpack(squashed.len()).is_none());
+
+        for (clear, squashed) in squashed.iter().enumerate() {
+            let unpacked = list.unpack(clear).unwrap();
+            assert_eq!(unpacked.degree(), squashed.degree());
+            assert_eq!(
+                noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&unpacked),
+                clear as u128
+            );
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Parameters of the distributed key generation.
+//!
+//! Each of the N parties of the distributed key generation adds a noise drawn from the
+//! distributions of the parameters to the public keys, so that the keys stay secure against a
+//! coalition of N-1 parties, which knows its own noises. The noise of the keyswitching,
+//! bootstrapping and compact public keys is hence N times larger (in variance) than the one of
+//! centrally generated keys.
+//!
+//! # Noise analysis
+//!
+//! Apart from the modulus switch before the blind rotation, which only depends on the keys and
+//! not on their noise, every term of the noise before a PBS is linear in the variance of the
+//! noise of the keys. With at most `max_num_parties` parties, the variance of this noise is at
+//! most `max_num_parties` times the one of the parameters the cryptographic parameters come from.
+//!
+//! The parameters below keep the cryptographic parameters of a set with 2 more bits of message
+//! and carry: their scaling factor `Δ` is 4 times larger, which absorbs a variance 16 times
+//! larger for the same failure probability. `max_num_parties` is set accordingly, and the
+//! parameters are rejected for more parties.
+//!
+//! The noise squashing bootstrapping key also sums the noise of the N parties, its noise is
+//! accounted for in the
+//! [`NoiseSquashingKeyShare`](crate::threshold::NoiseSquashingKeyShare)s of the parties.
+
+use crate::shortint::backward_compatibility::parameters::distributed_keygen::DistributedKeygenParametersVersions;
+use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use crate::shortint::parameters::{
+    CarryModulus, ClassicPBSParameters, MaxNoiseLevel, MessageModulus, NoiseSquashingParameters,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(DistributedKeygenParametersVersions)]
+pub struct DistributedKeygenParameters {
+    pub block_parameters: ClassicPBSParameters,
+    pub noise_squashing_parameters: NoiseSquashingParameters,
+    /// The largest number of parties for which the failure probability of the parameters holds
+    pub max_num_parties: usize,
+}
+
+/// Distributed key generation parameters for up to 16 parties, with 1 bit of message and 1 bit
+/// of carry.
+///
+/// The cryptographic parameters are the ones of
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64`], whose failure probability of `2^-64.138`
+/// holds for a noise variance 16 times larger with the 2 bits of precision removed. The maximum
+/// noise level is the one of the usual 1 bit of message and 1 bit of carry parameters, below the
+/// one of the original set.
+///
+/// With 16 parties, the standard deviation of the squashed noise goes from about `2^60.1` to
+/// about `2^62.1`, far below `Δ/2 = 2^124`.
+pub const DKG_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64: DistributedKeygenParameters =
+    DistributedKeygenParameters {
+        block_parameters: ClassicPBSParameters {
+            message_modulus: MessageModulus(2),
+            carry_modulus: CarryModulus(2),
+            max_noise_level: MaxNoiseLevel::new(3),
+            ..PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64
+        },
+        noise_squashing_parameters: NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        max_num_parties: 16,
+    };
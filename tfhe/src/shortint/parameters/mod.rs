@@ -24,12 +24,17 @@ pub mod classic;
 pub mod compact_public_key_only;
 #[cfg(tarpaulin)]
 pub mod coverage_parameters;
+pub mod distributed_keygen;
 pub mod key_switching;
 pub mod list_compression;
 pub mod multi_bit;
+pub mod multi_key;
+pub mod noise_squashing;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub mod parameters_wopbs_only;
+pub mod proxy_reencryption;
+pub mod sanitization;
 
 pub use super::ciphertext::{Degree, MaxNoiseLevel, NoiseLevel};
 pub use super::PBSOrder;
@@ -40,7 +45,12 @@ pub use crate::shortint::parameters::classic::gaussian::p_fail_2_minus_64::ks_pb
 pub use crate::shortint::parameters::classic::gaussian::p_fail_2_minus_64::pbs_ks::*;
 pub use crate::shortint::parameters::classic::tuniform::p_fail_2_minus_64::ks_pbs::*;
 pub use crate::shortint::parameters::classic::tuniform::p_fail_2_minus_64::pbs_ks::*;
+pub use crate::shortint::parameters::distributed_keygen::DistributedKeygenParameters;
 pub use crate::shortint::parameters::list_compression::CompressionParameters;
+pub use crate::shortint::parameters::multi_key::MultiKeyParameters;
+pub use crate::shortint::parameters::noise_squashing::NoiseSquashingParameters;
+pub use crate::shortint::parameters::proxy_reencryption::ProxyReencryptionParameters;
+pub use crate::shortint::parameters::sanitization::SanitizationParameters;
 pub use compact_public_key_only::{
     CompactCiphertextListExpansionKind, CompactPublicKeyEncryptionParameters,
     ShortintCompactCiphertextListCastingMode,
@@ -0,0 +1,83 @@
+//! Parameters of the multi-key PBS.
+//!
+//! The multi-key PBS evaluates a lookup table on a ciphertext encrypted under the concatenation of
+//! the keys of `K` parties, see [`crate::shortint::multi_key`]. Each party keeps the parameters of
+//! its client key, only the decomposition of the bootstrapping key differs.
+//!
+//! # Noise analysis
+//!
+//! With `(k, N, σ²)` the GLWE parameters of the parties, the GGSW ciphertexts of the joint
+//! bootstrapping key are public key encryptions under the concatenated GLWE key, of dimension
+//! `K·k`. Their noise has a variance of about
+//!
+//! ```text
+//! Var(e_ggsw) ≈ (K·(k+1)·N/2 + 1)·σ²
+//! ```
+//!
+//! which replaces `σ²` in the usual PBS formula, with `n = Σ n_i` and a GLWE dimension `K·k`:
+//!
+//! ```text
+//! Var(e_pbs) ≈ n·l·(K·k+1)·N·B²/12·Var(e_ggsw) + n·(1 + K·k·N/2)·q²/(12·B^(2l))
+//! ```
+//!
+//! The joint keyswitch adds the keyswitch noise of each party, and the modulus switch before the
+//! blind rotation rounds `n + 1` coefficients instead of `n_i + 1`, so both roughly grow linearly
+//! with `K`.
+//!
+//! Multi-key ciphertexts are decrypted after their noise is squashed by a 128 bits multi-key PBS,
+//! built in the same way from the [`NoiseSquashingParameters`], see
+//! [`crate::shortint::multi_key`] for the smudging constraint of the partial decryptions.
+
+use crate::shortint::backward_compatibility::parameters::multi_key::MultiKeyParametersVersions;
+use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use crate::shortint::parameters::{
+    CarryModulus, ClassicPBSParameters, DecompositionBaseLog, DecompositionLevelCount,
+    MaxNoiseLevel, MessageModulus, NoiseSquashingParameters,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(MultiKeyParametersVersions)]
+pub struct MultiKeyParameters {
+    /// Parameters of the client keys of the parties
+    pub block_parameters: ClassicPBSParameters,
+    pub br_base_log: DecompositionBaseLog,
+    pub br_level: DecompositionLevelCount,
+    /// Parameters of the noise squashing keys of the parties
+    pub noise_squashing_parameters: NoiseSquashingParameters,
+    /// The largest number of parties for which the failure probability of the parameters holds
+    pub max_num_parties: usize,
+}
+
+/// Multi-key parameters for up to 16 parties, with 1 bit of message and 1 bit of carry.
+///
+/// The cryptographic parameters are the ones of
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64`], with `n_i = 887`, `k = 1`, `N = 2048` and
+/// `σ² ≈ 2^34/3`. With 2 bits of precision removed, `Δ/2 = 2^60`. The single key decomposition
+/// (`B = 2^22`, `l = 1`) would make the PBS noise dominant from 4 parties on, it is replaced by
+/// `B = 2^15` and `l = 2`.
+///
+/// With `K = 2`, the PBS noise variance is about `2^95.2`, 9 times larger at the maximum noise
+/// level of 3. The modulus switch (about `2^110.2`) and the two keyswitches (about `2^107.5`)
+/// dominate, for a total variance of about `2^110.4` and a failure probability of about `2^-557`
+/// per PBS. With `K = 16`, the total variance reaches about `2^113.4`, for a failure probability
+/// of about `2^-72`, below the `2^-64` of the single key parameters.
+///
+/// The noise squashing parameters are the single key ones. The bound on the squashed noise goes
+/// from `2^72` with 2 parties to `2^76` with 16 parties, which meets the smudging constraint with
+/// `log2(Δ) = 125`.
+pub const MULTI_KEY_PARAM_MESSAGE_1_CARRY_1_KS_PBS_TUNIFORM_2M64: MultiKeyParameters =
+    MultiKeyParameters {
+        block_parameters: ClassicPBSParameters {
+            message_modulus: MessageModulus(2),
+            carry_modulus: CarryModulus(2),
+            max_noise_level: MaxNoiseLevel::new(3),
+            ..PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64
+        },
+        br_base_log: DecompositionBaseLog(15),
+        br_level: DecompositionLevelCount(2),
+        noise_squashing_parameters: NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        max_num_parties: 16,
+    };
@@ -0,0 +1,62 @@
+//! Parameters of the noise squashing.
+//!
+//! The noise squashing bootstraps a ciphertext with a 128 bits PBS, whose output noise is tiny
+//! compared to the `2^128` modulus. Such ciphertexts are meant for decryption protocols, like MPC
+//! decryption, which require a large gap between the noise and the message.
+//!
+//! # Noise analysis
+//!
+//! With `q = 2^128`, `n` the dimension of the small LWE key of the computation parameters and
+//! `(k, N, B = 2^decomp_base_log, l = decomp_level_count, σ²)` the parameters of the 128 bits
+//! bootstrapping key, the variance of the noise after the PBS is
+//!
+//! ```text
+//! Var(e_pbs) ≈ n·l·(k+1)·N·B²/12·σ² + n·(1 + k·N/2)·q²/(12·B^(2l))
+//! ```
+//!
+//! Squashed ciphertexts are compressed by switching their modulus to `2^storage_log_modulus`,
+//! which adds a rounding noise of variance
+//!
+//! ```text
+//! Var(e_ms) ≈ (1 + k·N/2)·q²/(12·2^(2·storage_log_modulus))
+//! ```
+
+use crate::core_crypto::prelude::CiphertextModulusLog;
+use crate::shortint::backward_compatibility::parameters::noise_squashing::NoiseSquashingParametersVersions;
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution, GlweDimension,
+    PolynomialSize,
+};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(NoiseSquashingParametersVersions)]
+pub struct NoiseSquashingParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_noise_distribution: DynamicDistribution<u128>,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub decomp_level_count: DecompositionLevelCount,
+    /// Modulus of the squashed ciphertexts once compressed
+    pub storage_log_modulus: CiphertextModulusLog,
+}
+
+/// Noise squashing parameters for
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).
+///
+/// The GLWE key has the parameters of the one of the
+/// [sanitization](crate::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64),
+/// with a coarser decomposition: the standard deviation of the squashed noise is about `2^60.1`, far below `Δ/2 = 2^122`.
+///
+/// With a storage modulus of `2^80`, the compression adds a noise whose standard deviation is
+/// about `2^51.7`, keeping the noise of compressed ciphertexts at about `2^60.1`.
+pub const NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64: NoiseSquashingParameters =
+    NoiseSquashingParameters {
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(4096),
+        glwe_noise_distribution: DynamicDistribution::new_t_uniform(36),
+        decomp_base_log: DecompositionBaseLog(14),
+        decomp_level_count: DecompositionLevelCount(6),
+        storage_log_modulus: CiphertextModulusLog(80),
+    };
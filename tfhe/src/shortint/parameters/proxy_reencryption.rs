@@ -0,0 +1,55 @@
+//! Parameters of the proxy re-encryption keyswitching key.
+//!
+//! The proxy re-encryption key switches ciphertexts from a secret key to the key of a
+//! [`CompactPublicKey`](crate::shortint::CompactPublicKey). Its elements are encrypted with the
+//! compact public key, so they carry the noise of a public key encryption rather than the noise of
+//! a secret key encryption.
+//!
+//! # Noise analysis
+//!
+//! With `n_in` the dimension of the input key, `n_pk` the dimension of the compact public key and
+//! `σ²` the variance of its encryption noise, the elements of the keyswitching key have a
+//! variance of about `n_pk/2·σ² + σ²`. With `B = 2^ks_base_log` and `l = ks_level` the
+//! keyswitch adds a noise of variance
+//!
+//! ```text
+//! Var(e_ks) ≈ n_in·l·B²/12·(n_pk/2 + 1)·σ² + n_in/2·q²/(12·B^(2l))
+//! ```
+//!
+//! the ciphertext then goes through the usual path of a freshly expanded compact ciphertext under
+//! the target key set: an optional casting keyswitch, and a PBS cleaning the noise.
+
+use crate::shortint::backward_compatibility::parameters::proxy_reencryption::ProxyReencryptionParametersVersions;
+use crate::shortint::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Versionize)]
+#[versionize(ProxyReencryptionParametersVersions)]
+pub struct ProxyReencryptionParameters {
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+}
+
+/// Proxy re-encryption parameters between key sets using
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64),
+/// with or without the dedicated compact public key parameters
+/// [`PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64`](crate::shortint::parameters::compact_public_key_only::p_fail_2_minus_64::ks_pbs::PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).
+///
+/// The failure probability of the re-encrypted ciphertexts is the one of the computation
+/// parameters (`2^-64.138`), as long as the noise added on the way to the modulus switch of the
+/// PBS does not exceed the one of the keyswitch of the computation parameters (`2^53.3`), with
+/// an input ciphertext at the maximum noise level:
+/// - with the dedicated compact public key (`n_pk = 1024`, `TUniform(42)`) as target and the large
+///   key (`n_in = 2048`) as input, the re-encryption keyswitch has a standard deviation of about
+///   `2^52.5` (its rounding term being negligible). Together with the casting keyswitch (`2^52.8`)
+///   the standard deviation is about `2^53.1`, below the keyswitch of the computation parameters.
+/// - with the compact public key of the computation parameters (`n_pk = 2048`, `TUniform(17)`) as
+///   target, the re-encryption keyswitch has a standard deviation of about `2^49.2` and is followed
+///   by the keyswitch of the computation parameters. It increases the variance of the noise at the
+///   modulus switch by less than `0.05%`, which keeps the failure probability below `2^-64.1`.
+pub const PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64:
+    ProxyReencryptionParameters = ProxyReencryptionParameters {
+    ks_base_log: DecompositionBaseLog(1),
+    ks_level: DecompositionLevelCount(18),
+};
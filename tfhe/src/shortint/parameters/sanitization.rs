@@ -0,0 +1,89 @@
+//! Parameters of the sanitization bootstrap.
+//!
+//! The sanitization bootstraps a ciphertext with a 128 bits PBS, adds a flooding noise much larger
+//! than the noise of the PBS, switches the modulus back to 64 bits and keyswitches the result to
+//! the large key of the computation parameters.
+//!
+//! # Noise analysis
+//!
+//! With `q = 2^128`, `n` the dimension of the small LWE key of the computation parameters and
+//! `(k, N, B = 2^br_base_log, l = br_level, σ²)` the parameters of the 128 bits bootstrapping key,
+//! the variance of the noise after the PBS is
+//!
+//! ```text
+//! Var(e_pbs) ≈ n·l·(k+1)·N·B²/12·σ² + n·(1 + k·N/2)·q²/(12·B^(2l))
+//! ```
+//!
+//! The PBS output is refreshed with a public key encryption of zero, whose noise does not depend
+//! on the input ciphertext. The flooding noise is then drawn from a TUniform distribution bounded
+//! by `2^f`: with `2^b` a bound on `|e_pbs|`, except with probability `p_b`, a sanitized ciphertext
+//! is at statistical distance at most `2^b/2^(f+1) + p_b` from the same ciphertext with the
+//! flooding noise only, which does not depend on the circuit. The flooded value still decrypts
+//! correctly as long as `2^f + 2^b < Δ/2`, `Δ` being the scaling factor of the message and carry
+//! bits (with their padding bit).
+//!
+//! After the modulus switch to `2^64` the flooding noise is bounded by `2^(f-64)`, the keyswitch
+//! to the large key adds a noise of variance
+//!
+//! ```text
+//! Var(e_ks) ≈ k·N·l_ks·B_ks²/12·σ²_ks + k·N/2·2^128/(12·B_ks^(2·l_ks))
+//! ```
+//!
+//! which must keep the total below `Δ/2` on 64 bits.
+//!
+//! # Security
+//!
+//! The 128 bits GLWE key is checked with the lattice estimator along with the other parameters,
+//! see `ci/lattice_estimator.sage`.
+
+use crate::shortint::backward_compatibility::parameters::sanitization::SanitizationParametersVersions;
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution, GlweDimension,
+    PolynomialSize,
+};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(SanitizationParametersVersions)]
+pub struct SanitizationParameters {
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub glwe_noise_distribution: DynamicDistribution<u128>,
+    pub br_base_log: DecompositionBaseLog,
+    pub br_level: DecompositionLevelCount,
+    pub flooding_noise_distribution: DynamicDistribution<u128>,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+}
+
+/// Sanitization parameters for
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64).
+///
+/// The GLWE key of the bootstrapping key has twice the dimension of the computation GLWE key, for
+/// a noise `log2(q/σ) = 128 - 36 = 92` smaller than twice the `64 - 17 = 47` of the computation
+/// parameters.
+///
+/// With `n = 887`, `k = 1`, `N = 4096`, `B = 2^7`, `l = 12` and `σ² ≈ 2^72/3`, the standard
+/// deviation of the PBS noise is about `2^53.8` (`2^53.6` from the key noise and `2^52.6` from the
+/// decomposition). The PBS noise is bounded by `2^57` (about `9.4σ`) except with probability below
+/// `2^-67`, the flooding noise bounded by `2^121` then gives a statistical distance below `2^-64`
+/// to the flooding noise alone, while `2^121 + 2^57 < Δ/2 = 2^122`.
+///
+/// The public key encryption of zero refreshing the mask adds a noise whose standard deviation is
+/// about `2^41`, negligible before the flooding.
+///
+/// After the modulus switch, the flooding noise is bounded by `2^57` and the keyswitch (with the
+/// `TUniform(17)` noise of the computation parameters) has a standard deviation of about `2^37.2`,
+/// the total staying far below `Δ/2 = 2^58`.
+pub const SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64: SanitizationParameters =
+    SanitizationParameters {
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(4096),
+        glwe_noise_distribution: DynamicDistribution::new_t_uniform(36),
+        br_base_log: DecompositionBaseLog(7),
+        br_level: DecompositionLevelCount(12),
+        flooding_noise_distribution: DynamicDistribution::new_t_uniform(121),
+        ks_base_log: DecompositionBaseLog(16),
+        ks_level: DecompositionLevelCount(3),
+    };
@@ -0,0 +1,497 @@
+//! Proxy re-encryption of ciphertexts to the key of a compact public key.
+//!
+//! The owner of a secret key generates a [`ProxyReencryptionKey`] using only the
+//! [`CompactPublicKey`] of the recipient. A server holding this key and the server key of the
+//! recipient turns ciphertexts under the secret key into ciphertexts the recipient can decrypt,
+//! without decrypting them.
+//!
+//! The proxy re-encryption key encrypts the secret key of its owner under the key of the
+//! recipient: it must not be given to the recipient, who would be able to recover the secret key.
+
+use crate::core_crypto::prelude::*;
+use crate::shortint::backward_compatibility::proxy_reencryption::ProxyReencryptionKeyVersions;
+use crate::shortint::ciphertext::{CompactCiphertextList, CompressedCiphertextList, Degree};
+use crate::shortint::client_key::ClientKey;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::list_compression::CompressionPrivateKeys;
+use crate::shortint::parameters::{
+    CompactCiphertextListExpansionKind, NoiseLevel, ProxyReencryptionParameters,
+    ShortintCompactCiphertextListCastingMode,
+};
+use crate::shortint::public_key::{CompactPrivateKey, CompactPublicKey};
+use crate::shortint::{CarryModulus, Ciphertext, MessageModulus, ServerKey};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(ProxyReencryptionKeyVersions)]
+pub struct ProxyReencryptionKey {
+    /// Keyswitching key from the input secret key to the key of the compact public key, its
+    /// elements being encrypted with the compact public key
+    pub key_switching_key: LweKeyswitchKeyOwned<u64>,
+    /// Expansion kind of the compact public key, telling if a casting keyswitch is required to
+    /// reach the computation parameters of the recipient
+    pub expansion_kind: CompactCiphertextListExpansionKind,
+}
+
+impl ProxyReencryptionKey {
+    fn generate(
+        input_lwe_secret_key: &LweSecretKey<&[u64]>,
+        public_key: &CompactPublicKey,
+        params: ProxyReencryptionParameters,
+    ) -> Self {
+        let noise_distribution = public_key.parameters.encryption_noise_distribution;
+
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            par_allocate_and_generate_new_lwe_keyswitch_key_with_compact_public_key(
+                input_lwe_secret_key,
+                &public_key.key,
+                params.ks_base_log,
+                params.ks_level,
+                noise_distribution,
+                noise_distribution,
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+            )
+        });
+
+        Self {
+            key_switching_key,
+            expansion_kind: public_key.parameters.expansion_kind,
+        }
+    }
+
+    /// Keyswitches `lwe` to the key of the compact public key, then casts it to the computation
+    /// key of `server_key` if needed.
+    ///
+    /// The cast bootstraps the result, otherwise it is not bootstrapped and its noise is unknown.
+    fn keyswitch_to_server_key(
+        &self,
+        lwe: &LweCiphertext<&[u64]>,
+        degree: Degree,
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+        server_key: &ServerKey,
+        casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<Ciphertext> {
+        if lwe.lwe_size()
+            != self
+                .key_switching_key
+                .input_key_lwe_dimension()
+                .to_lwe_size()
+        {
+            return Err(crate::Error::new(
+                "The ciphertext is not encrypted under the input key \
+                of the proxy re-encryption key"
+                    .to_string(),
+            ));
+        }
+        if message_modulus != server_key.message_modulus
+            || carry_modulus != server_key.carry_modulus
+        {
+            return Err(crate::Error::new(
+                "The ciphertext does not use the parameters of the server key".to_string(),
+            ));
+        }
+
+        let mut keyswitched = LweCiphertext::new(
+            0u64,
+            self.key_switching_key.output_lwe_size(),
+            self.key_switching_key.ciphertext_modulus(),
+        );
+        keyswitch_lwe_ciphertext(&self.key_switching_key, lwe, &mut keyswitched);
+
+        match (self.expansion_kind, casting_mode) {
+            (
+                CompactCiphertextListExpansionKind::RequiresCasting,
+                ShortintCompactCiphertextListCastingMode::NoCasting,
+            ) => Err(crate::Error::new(
+                "The compact public key of the proxy re-encryption key requires casting, \
+                a casting key is required"
+                    .to_string(),
+            )),
+            (
+                CompactCiphertextListExpansionKind::RequiresCasting,
+                ShortintCompactCiphertextListCastingMode::CastIfNecessary(casting_key),
+            ) => {
+                if casting_key
+                    .key_switching_key_material
+                    .key_switching_key
+                    .input_key_lwe_dimension()
+                    != self.key_switching_key.output_key_lwe_dimension()
+                {
+                    return Err(crate::Error::new(
+                        "The casting key does not take inputs under the key \
+                        of the proxy re-encryption key"
+                            .to_string(),
+                    ));
+                }
+
+                let to_cast = Ciphertext::new(
+                    keyswitched,
+                    degree,
+                    NoiseLevel::UNKNOWN,
+                    message_modulus,
+                    carry_modulus,
+                    casting_key.dest_server_key.pbs_order,
+                );
+                Ok(casting_key.cast(&to_cast))
+            }
+            (CompactCiphertextListExpansionKind::NoCasting(pbs_order), _) => {
+                let expected_lwe_size = server_key.ciphertext_lwe_dimension().to_lwe_size();
+                if pbs_order != server_key.pbs_order || keyswitched.lwe_size() != expected_lwe_size
+                {
+                    return Err(crate::Error::new(
+                        "The compact public key of the proxy re-encryption key \
+                        does not encrypt under the key of the server key"
+                            .to_string(),
+                    ));
+                }
+
+                Ok(Ciphertext::new(
+                    keyswitched,
+                    degree,
+                    NoiseLevel::UNKNOWN,
+                    message_modulus,
+                    carry_modulus,
+                    pbs_order,
+                ))
+            }
+        }
+    }
+
+    /// Re-encrypts a ciphertext to the key of the compact public key, the result is bootstrapped
+    /// with the `server_key` of the recipient.
+    ///
+    /// `casting_mode` must provide the casting key of the recipient when its compact public key
+    /// uses dedicated parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::proxy_reencryption::PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintCompactCiphertextListCastingMode, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// };
+    /// use tfhe::shortint::{gen_keys, CompactPublicKey};
+    ///
+    /// let (alice_cks, alice_sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let (bob_cks, bob_sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let bob_public_key = CompactPublicKey::new(&bob_cks);
+    ///
+    /// let reencryption_key = alice_cks.new_proxy_reencryption_key(
+    ///     &bob_public_key,
+    ///     PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    /// );
+    ///
+    /// let ct = alice_sks.unchecked_add(&alice_cks.encrypt(1), &alice_cks.encrypt(2));
+    /// let reencrypted = reencryption_key
+    ///     .reencrypt(
+    ///         &ct,
+    ///         &bob_sks,
+    ///         ShortintCompactCiphertextListCastingMode::NoCasting,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(bob_cks.decrypt(&reencrypted), 3);
+    /// ```
+    pub fn reencrypt(
+        &self,
+        ct: &Ciphertext,
+        server_key: &ServerKey,
+        casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<Ciphertext> {
+        let mut result = self.keyswitch_to_server_key(
+            &ct.ct.as_view(),
+            ct.degree,
+            ct.message_modulus,
+            ct.carry_modulus,
+            server_key,
+            casting_mode,
+        )?;
+
+        // Refresh if the cast did not apply a PBS
+        if result.noise_level() == NoiseLevel::UNKNOWN {
+            let identity = server_key.generate_lookup_table(|x| x);
+            server_key.apply_lookup_table_assign(&mut result, &identity);
+        }
+        // The encrypted value is unchanged, and so is its degree
+        result.degree = ct.degree;
+
+        Ok(result)
+    }
+
+    /// Keyswitches the ciphertexts of a compact list to the computation key of the recipient,
+    /// without bootstrapping them.
+    pub(crate) fn keyswitch_compact_list(
+        &self,
+        list: &CompactCiphertextList,
+        server_key: &ServerKey,
+        casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<Vec<Ciphertext>> {
+        let mut lwe_list = LweCiphertextList::new(
+            0u64,
+            list.ct_list.lwe_size(),
+            list.ct_list.lwe_ciphertext_count(),
+            list.ct_list.ciphertext_modulus(),
+        );
+        par_expand_lwe_compact_ciphertext_list(&mut lwe_list, &list.ct_list);
+
+        lwe_list
+            .par_iter()
+            .map(|lwe| {
+                self.keyswitch_to_server_key(
+                    &lwe,
+                    list.degree,
+                    list.message_modulus,
+                    list.carry_modulus,
+                    server_key,
+                    casting_mode,
+                )
+            })
+            .collect()
+    }
+
+    /// Re-encrypts the ciphertexts of a compact list, see [`Self::reencrypt`].
+    ///
+    /// The proxy re-encryption key must have been generated from the key the compact list is
+    /// encrypted under, see [`CompactPrivateKey::new_proxy_reencryption_key`] for compact public
+    /// keys using dedicated parameters.
+    pub fn reencrypt_compact_list(
+        &self,
+        list: &CompactCiphertextList,
+        server_key: &ServerKey,
+        casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<Vec<Ciphertext>> {
+        let mut blocks = self.keyswitch_compact_list(list, server_key, casting_mode)?;
+
+        let identity = server_key.generate_lookup_table(|x| x);
+        blocks.par_iter_mut().for_each(|block| {
+            if block.noise_level() == NoiseLevel::UNKNOWN {
+                server_key.apply_lookup_table_assign(block, &identity);
+            }
+            block.degree = list.degree;
+        });
+
+        Ok(blocks)
+    }
+
+    /// Re-encrypts the ciphertexts of a compressed list, see [`Self::reencrypt`].
+    ///
+    /// The proxy re-encryption key must have been generated with
+    /// [`CompressionPrivateKeys::new_proxy_reencryption_key`], the list does not need to be
+    /// decompressed first.
+    pub fn reencrypt_compressed_list(
+        &self,
+        list: &CompressedCiphertextList,
+        server_key: &ServerKey,
+        casting_mode: ShortintCompactCiphertextListCastingMode<'_>,
+    ) -> crate::Result<Vec<Ciphertext>> {
+        let message_modulus = list.message_modulus;
+        let carry_modulus = list.carry_modulus;
+
+        // The compression stores the messages in the carries
+        let degree = Degree::new((message_modulus.0 - 1) * message_modulus.0);
+        let carry_extract = server_key.generate_lookup_table(|x| x / message_modulus.0 as u64);
+
+        // Each message is decoded from `message_modulus` consecutive values, centering the
+        // ciphertext in this interval gives the same margin to negative and positive noises
+        let delta = (1u64 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
+        let centering = Plaintext(delta / 2 * (message_modulus.0 as u64 - 1));
+
+        list.modulus_switched_glwe_ciphertext_list
+            .par_iter()
+            .flat_map(|packed_glwe| {
+                let glwe = packed_glwe.extract();
+                let lwe_size = glwe
+                    .glwe_size()
+                    .to_glwe_dimension()
+                    .to_equivalent_lwe_dimension(glwe.polynomial_size())
+                    .to_lwe_size();
+
+                (0..packed_glwe.bodies_count().0)
+                    .into_par_iter()
+                    .map(|index| {
+                        let mut lwe = LweCiphertext::new(0u64, lwe_size, list.ciphertext_modulus);
+                        extract_lwe_sample_from_glwe_ciphertext(
+                            &glwe,
+                            &mut lwe,
+                            MonomialDegree(index),
+                        );
+                        lwe_ciphertext_plaintext_add_assign(&mut lwe, centering);
+
+                        let mut ct = self.keyswitch_to_server_key(
+                            &lwe.as_view(),
+                            degree,
+                            message_modulus,
+                            carry_modulus,
+                            server_key,
+                            casting_mode,
+                        )?;
+                        server_key.apply_lookup_table_assign(&mut ct, &carry_extract);
+
+                        Ok(ct)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+impl ClientKey {
+    /// Generates a proxy re-encryption key from the encryption key of this client key to the key
+    /// of `public_key`.
+    pub fn new_proxy_reencryption_key(
+        &self,
+        public_key: &CompactPublicKey,
+        params: ProxyReencryptionParameters,
+    ) -> ProxyReencryptionKey {
+        ProxyReencryptionKey::generate(&self.encryption_key_and_noise().0, public_key, params)
+    }
+}
+
+impl<C: Container<Element = u64>> CompactPrivateKey<C> {
+    /// Generates a proxy re-encryption key from this key to the key of `public_key`, used to
+    /// re-encrypt compact lists encrypted with the corresponding public key.
+    pub fn new_proxy_reencryption_key(
+        &self,
+        public_key: &CompactPublicKey,
+        params: ProxyReencryptionParameters,
+    ) -> ProxyReencryptionKey {
+        ProxyReencryptionKey::generate(&self.key(), public_key, params)
+    }
+}
+
+impl CompressionPrivateKeys {
+    /// Generates a proxy re-encryption key from this key to the key of `public_key`, used to
+    /// re-encrypt compressed lists.
+    pub fn new_proxy_reencryption_key(
+        &self,
+        public_key: &CompactPublicKey,
+        params: ProxyReencryptionParameters,
+    ) -> ProxyReencryptionKey {
+        ProxyReencryptionKey::generate(
+            &self.post_packing_ks_key.as_lwe_secret_key(),
+            public_key,
+            params,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::shortint::ciphertext::{Degree, NoiseLevel};
+    use crate::shortint::gen_keys;
+    use crate::shortint::key_switching_key::KeySwitchingKey;
+    use crate::shortint::parameters::compact_public_key_only::p_fail_2_minus_64::ks_pbs::PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::key_switching::p_fail_2_minus_64::ks_pbs::PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::proxy_reencryption::PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::{
+        ShortintCompactCiphertextListCastingMode, PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+    };
+    use crate::shortint::public_key::{CompactPrivateKey, CompactPublicKey};
+
+    #[test]
+    fn reencrypt_to_dedicated_public_key() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        let (alice_cks, alice_sks) = gen_keys(params);
+        let (bob_cks, bob_sks) = gen_keys(params);
+        let bob_private_key =
+            CompactPrivateKey::new(PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let bob_public_key = CompactPublicKey::new(&bob_private_key);
+        let bob_casting_key = KeySwitchingKey::new(
+            (&bob_private_key, None),
+            (&bob_cks, &bob_sks),
+            PARAM_KEYSWITCH_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+        let casting_mode =
+            ShortintCompactCiphertextListCastingMode::CastIfNecessary(bob_casting_key.as_view());
+
+        let alice_compression_key = alice_cks
+            .new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let (compression_key, _) =
+            alice_cks.new_compression_decompression_keys(&alice_compression_key);
+
+        let reencryption_key = alice_cks.new_proxy_reencryption_key(
+            &bob_public_key,
+            PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+        let compressed_reencryption_key = alice_compression_key.new_proxy_reencryption_key(
+            &bob_public_key,
+            PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+
+        // Without the casting key the result is not under a key of the server key
+        let ct = alice_cks.encrypt(1);
+        assert!(reencryption_key
+            .reencrypt(
+                &ct,
+                &bob_sks,
+                ShortintCompactCiphertextListCastingMode::NoCasting
+            )
+            .is_err());
+
+        let full_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64;
+        for clear in 0..full_modulus {
+            // Carries are filled and the noise is at its maximum level
+            let carries = alice_sks.unchecked_scalar_mul(&alice_cks.encrypt(clear / 4), 4);
+            let ct = alice_sks.unchecked_add(&alice_cks.encrypt(clear % 4), &carries);
+
+            let reencrypted = reencryption_key
+                .reencrypt(&ct, &bob_sks, casting_mode)
+                .unwrap();
+            assert_eq!(reencrypted.degree, ct.degree);
+            assert_eq!(reencrypted.noise_level(), NoiseLevel::NOMINAL);
+            assert_eq!(bob_cks.decrypt_message_and_carry(&reencrypted), clear);
+        }
+
+        let messages = (0..params.message_modulus.0 as u64).collect::<Vec<_>>();
+        let cts = messages
+            .iter()
+            .map(|&msg| alice_cks.encrypt(msg))
+            .collect::<Vec<_>>();
+        let compressed = compression_key.compress_ciphertexts_into_list(&cts);
+
+        let reencrypted = compressed_reencryption_key
+            .reencrypt_compressed_list(&compressed, &bob_sks, casting_mode)
+            .unwrap();
+        assert_eq!(reencrypted.len(), messages.len());
+        for (ct, msg) in reencrypted.iter().zip(messages.iter()) {
+            assert_eq!(ct.degree, Degree::new(params.message_modulus.0 - 1));
+            assert_eq!(bob_cks.decrypt(ct), *msg);
+        }
+    }
+
+    #[test]
+    fn reencrypt_compact_list_from_dedicated_public_key() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        let alice_private_key =
+            CompactPrivateKey::new(PARAM_PKE_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let alice_public_key = CompactPublicKey::new(&alice_private_key);
+
+        let (bob_cks, bob_sks) = gen_keys(params);
+        let bob_public_key = CompactPublicKey::new(&bob_cks);
+
+        let reencryption_key = alice_private_key.new_proxy_reencryption_key(
+            &bob_public_key,
+            PROXY_REENCRYPTION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
+        );
+
+        let messages = (0..params.message_modulus.0 as u64).collect::<Vec<_>>();
+        let list = alice_public_key.encrypt_slice(&messages);
+
+        let reencrypted = reencryption_key
+            .reencrypt_compact_list(
+                &list,
+                &bob_sks,
+                ShortintCompactCiphertextListCastingMode::NoCasting,
+            )
+            .unwrap();
+        assert_eq!(reencrypted.len(), messages.len());
+        for (ct, msg) in reencrypted.iter().zip(messages.iter()) {
+            assert_eq!(bob_cks.decrypt(ct), *msg);
+        }
+    }
+}
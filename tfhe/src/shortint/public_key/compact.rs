@@ -1,21 +1,24 @@
+#[cfg(feature = "zk-pok")]
+use crate::core_crypto::algorithms::lwe_encryption::encrypt_and_prove_lwe_compact_ciphertext_list_with_secret_key_with_opening;
 use crate::core_crypto::prelude::{
     allocate_and_generate_new_binary_lwe_secret_key,
-    allocate_and_generate_new_seeded_lwe_compact_public_key, generate_lwe_compact_public_key,
+    allocate_and_generate_new_seeded_lwe_compact_public_key,
+    encrypt_lwe_compact_ciphertext_list_with_secret_key, generate_lwe_compact_public_key,
     Container, LweCiphertextCount, LweCompactCiphertextListOwned, LweCompactPublicKeyOwned,
     LweSecretKey, Plaintext, PlaintextList, SeededLweCompactPublicKeyOwned,
 };
 use crate::shortint::backward_compatibility::public_key::{
     CompactPrivateKeyVersions, CompactPublicKeyVersions, CompressedCompactPublicKeyVersions,
 };
-#[cfg(feature = "zk-pok")]
-use crate::shortint::ciphertext::ProvenCompactCiphertextList;
 use crate::shortint::ciphertext::{CompactCiphertextList, Degree, NoiseLevel};
+#[cfg(feature = "zk-pok")]
+use crate::shortint::ciphertext::{CompactListEncryptionKind, ProvenCompactCiphertextList};
 use crate::shortint::client_key::secret_encryption_key::SecretEncryptionKeyView;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::compact_public_key_only::CompactPublicKeyEncryptionParameters;
 use crate::shortint::{CarryModulus, ClientKey, MessageModulus};
 #[cfg(feature = "zk-pok")]
-use crate::zk::{CompactPkePublicParams, ZkComputeLoad};
+use crate::zk::{CompactPkeProofOpening, CompactPkePublicParams, ZkComputeLoad};
 use crate::Error;
 use serde::{Deserialize, Serialize};
 use tfhe_versionable::Versionize;
@@ -70,19 +73,175 @@ impl<C: Container<Element = u64>> CompactPrivateKey<C> {
     }
 }
 
+impl<C: Container<Element = u64>> CompactPrivateKey<C> {
+    /// Encrypts the messages coming from the iterator into a compact ciphertext list
+    ///
+    /// Values of the messages should be in range [0..encryption_modulus[
+    /// (a modulo operation is applied to each input)
+    ///
+    /// # Panic
+    ///
+    /// - This will panic is encryption modulus is greater that message_modulus * carry_modulus
+    pub fn encrypt_iter_with_modulus(
+        &self,
+        messages: impl Iterator<Item = u64>,
+        encryption_modulus: u64,
+    ) -> CompactCiphertextList {
+        let plaintext_container = to_plaintext_iterator(
+            messages,
+            encryption_modulus,
+            self.parameters.message_modulus,
+            self.parameters.carry_modulus,
+        )
+        .map(|plaintext| plaintext.0)
+        .collect::<Vec<_>>();
+
+        let plaintext_list = PlaintextList::from_container(plaintext_container);
+        let mut ct_list = LweCompactCiphertextListOwned::new(
+            0u64,
+            self.key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(plaintext_list.plaintext_count().0),
+            self.parameters.ciphertext_modulus,
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            encrypt_lwe_compact_ciphertext_list_with_secret_key(
+                &self.key,
+                &mut ct_list,
+                &plaintext_list,
+                self.parameters.encryption_noise_distribution,
+                &mut engine.encryption_generator,
+            );
+        });
+
+        CompactCiphertextList {
+            ct_list,
+            degree: Degree::new(encryption_modulus as usize - 1),
+            message_modulus: self.parameters.message_modulus,
+            carry_modulus: self.parameters.carry_modulus,
+            expansion_kind: self.parameters.expansion_kind,
+            noise_level: NoiseLevel::NOMINAL,
+        }
+    }
+
+    /// Encrypts the messages with the secret key and proves the encryption, the resulting list
+    /// is verified with [`ProvenCompactCiphertextList::verify`] against `public_key`.
+    ///
+    /// `public_key` must have been generated from this private key, the proofs also show that.
+    #[cfg(feature = "zk-pok")]
+    pub fn encrypt_and_prove_slice(
+        &self,
+        public_key: &CompactPublicKey,
+        messages: &[u64],
+        public_params: &CompactPkePublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        encryption_modulus: u64,
+    ) -> crate::Result<ProvenCompactCiphertextList> {
+        self.encrypt_and_prove_slice_with_openings(
+            public_key,
+            messages,
+            public_params,
+            metadata,
+            load,
+            encryption_modulus,
+        )
+        .map(|(list, _)| list)
+    }
+
+    /// Same as [`Self::encrypt_and_prove_slice`], also returning the openings of the commitments
+    /// of the proofs, which are needed to link range proofs to the encrypted messages.
+    #[cfg(feature = "zk-pok")]
+    pub(crate) fn encrypt_and_prove_slice_with_openings(
+        &self,
+        public_key: &CompactPublicKey,
+        messages: &[u64],
+        public_params: &CompactPkePublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        encryption_modulus: u64,
+    ) -> crate::Result<(ProvenCompactCiphertextList, Vec<CompactPkeProofOpening>)> {
+        let plaintext_modulus =
+            (self.parameters.message_modulus.0 * self.parameters.carry_modulus.0) as u64;
+        let delta = (1u64 << 63) / plaintext_modulus;
+        assert!(encryption_modulus <= plaintext_modulus);
+
+        // A single mask is used per proof, which can be shared by at most lwe_dimension lwe
+        let max_ciphertext_per_bin = self.key.lwe_dimension().0;
+        // This is the maximum of lwe message a single proof can prove
+        let max_num_message = public_params.k;
+        // One of the two is the limiting factor for how much we can pack messages
+        let message_chunk_size = max_num_message.min(max_ciphertext_per_bin);
+
+        let num_lists = messages.len().div_ceil(message_chunk_size);
+        let mut proved_lists = Vec::with_capacity(num_lists);
+        let mut openings = Vec::with_capacity(num_lists);
+        for message_chunk in messages.chunks(message_chunk_size) {
+            let mut ct_list = LweCompactCiphertextListOwned::new(
+                0u64,
+                self.key.lwe_dimension().to_lwe_size(),
+                LweCiphertextCount(message_chunk.len()),
+                self.parameters.ciphertext_modulus,
+            );
+
+            let (proof, opening) = ShortintEngine::with_thread_local_mut(|engine| {
+                encrypt_and_prove_lwe_compact_ciphertext_list_with_secret_key_with_opening(
+                    &self.key,
+                    &public_key.key,
+                    &mut ct_list,
+                    &message_chunk,
+                    delta,
+                    self.parameters.encryption_noise_distribution,
+                    &mut engine.encryption_generator,
+                    &mut engine.random_generator,
+                    public_params,
+                    metadata,
+                    load,
+                )
+            })?;
+
+            let ciphertext = CompactCiphertextList {
+                ct_list,
+                degree: Degree::new(encryption_modulus as usize - 1),
+                message_modulus: self.parameters.message_modulus,
+                carry_modulus: self.parameters.carry_modulus,
+                expansion_kind: self.parameters.expansion_kind,
+                noise_level: NoiseLevel::NOMINAL,
+            };
+
+            proved_lists.push((ciphertext, proof));
+            openings.push(opening);
+        }
+
+        Ok((
+            ProvenCompactCiphertextList {
+                proved_lists,
+                encryption_kind: CompactListEncryptionKind::SecretKey,
+            },
+            openings,
+        ))
+    }
+}
+
 impl CompactPrivateKey<Vec<u64>> {
     pub fn new(parameters: CompactPublicKeyEncryptionParameters) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| engine.new_compact_private_key(parameters))
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_compact_private_key(
+        &mut self,
+        parameters: CompactPublicKeyEncryptionParameters,
+    ) -> CompactPrivateKey<Vec<u64>> {
         let parameters = parameters.validate();
-        let encryption_lwe_dimension = parameters.encryption_lwe_dimension;
 
-        let key = ShortintEngine::with_thread_local_mut(|engine| {
-            allocate_and_generate_new_binary_lwe_secret_key(
-                encryption_lwe_dimension,
-                &mut engine.secret_generator,
-            )
-        });
+        let key = allocate_and_generate_new_binary_lwe_secret_key(
+            parameters.encryption_lwe_dimension,
+            &mut self.secret_generator,
+        );
 
-        Self { key, parameters }
+        CompactPrivateKey { key, parameters }
     }
 }
 
@@ -380,6 +539,27 @@ impl CompactPublicKey {
         load: ZkComputeLoad,
         encryption_modulus: u64,
     ) -> crate::Result<ProvenCompactCiphertextList> {
+        self.encrypt_and_prove_slice_with_openings(
+            messages,
+            public_params,
+            metadata,
+            load,
+            encryption_modulus,
+        )
+        .map(|(list, _)| list)
+    }
+
+    /// Same as [`Self::encrypt_and_prove_slice`], also returning the openings of the commitments
+    /// of the proofs, which are needed to link range proofs to the encrypted messages.
+    #[cfg(feature = "zk-pok")]
+    pub(crate) fn encrypt_and_prove_slice_with_openings(
+        &self,
+        messages: &[u64],
+        public_params: &CompactPkePublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        encryption_modulus: u64,
+    ) -> crate::Result<(ProvenCompactCiphertextList, Vec<CompactPkeProofOpening>)> {
         let plaintext_modulus =
             (self.parameters.message_modulus.0 * self.parameters.carry_modulus.0) as u64;
         let delta = (1u64 << 63) / plaintext_modulus;
@@ -395,6 +575,7 @@ impl CompactPublicKey {
 
         let num_lists = messages.len().div_ceil(message_chunk_size);
         let mut proved_lists = Vec::with_capacity(num_lists);
+        let mut openings = Vec::with_capacity(num_lists);
         for message_chunk in messages.chunks(message_chunk_size) {
             let mut ct_list = LweCompactCiphertextListOwned::new(
                 0u64,
@@ -407,10 +588,10 @@ impl CompactPublicKey {
 
             // No parallelism allowed
             #[cfg(all(feature = "__wasm_api", not(feature = "parallel-wasm-api")))]
-            let proof = {
-                use crate::core_crypto::prelude::encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key;
+            let (proof, opening) = {
+                use crate::core_crypto::algorithms::lwe_encryption::encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key_with_opening;
                 ShortintEngine::with_thread_local_mut(|engine| {
-                    encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+                    encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key_with_opening(
                         &self.key,
                         &mut ct_list,
                         &message_chunk,
@@ -429,10 +610,10 @@ impl CompactPublicKey {
 
             // Parallelism allowed  /
             #[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
-            let proof = {
-                use crate::core_crypto::prelude::par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key;
+            let (proof, opening) = {
+                use crate::core_crypto::algorithms::lwe_encryption::par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key_with_opening;
                 ShortintEngine::with_thread_local_mut(|engine| {
-                    par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key(
+                    par_encrypt_and_prove_lwe_compact_ciphertext_list_with_compact_public_key_with_opening(
                         &self.key,
                         &mut ct_list,
                         &message_chunk,
@@ -460,9 +641,16 @@ impl CompactPublicKey {
             };
 
             proved_lists.push((ciphertext, proof));
+            openings.push(opening);
         }
 
-        Ok(ProvenCompactCiphertextList { proved_lists })
+        Ok((
+            ProvenCompactCiphertextList {
+                proved_lists,
+                encryption_kind: CompactListEncryptionKind::PublicKey,
+            },
+            openings,
+        ))
     }
 
     pub fn size_elements(&self) -> usize {
@@ -0,0 +1,127 @@
+//! Module with the definition of the GlwePublicKey.
+use crate::core_crypto::entities::*;
+#[cfg(feature = "zk-pok")]
+use crate::core_crypto::prelude::encrypt_and_prove_glwe_ciphertext_with_public_key;
+use crate::core_crypto::prelude::encrypt_glwe_ciphertext;
+#[cfg(feature = "zk-pok")]
+use crate::shortint::ciphertext::{Degree, ProvenGlweCiphertextList};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{EncryptionKeyChoice, ShortintParameterSet};
+use crate::shortint::ClientKey;
+#[cfg(feature = "zk-pok")]
+use crate::zk::{GlwePkePublicParams, ZkComputeLoad};
+use crate::Error;
+use serde::{Deserialize, Serialize};
+
+/// A public key encrypting messages in GLWE ciphertexts under the GLWE secret key of a
+/// [`ClientKey`].
+///
+/// The key is a GLWE encryption of zero under the GLWE secret key. Its ciphertexts are proven
+/// against it with [`GlwePublicKey::encrypt_and_prove_slice`], and their messages are expanded
+/// under the large LWE key, so the parameters must encrypt with the big key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GlwePublicKey {
+    pub(crate) key: GlweCiphertextOwned<u64>,
+    pub parameters: ShortintParameterSet,
+}
+
+impl GlwePublicKey {
+    /// Generates a public key from a client key.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the parameters of the client key do not encrypt with the big key.
+    pub fn new(client_key: &ClientKey) -> Self {
+        Self::try_new(client_key).expect(
+            "Incompatible parameters, the GLWE public key requires parameters encrypting with \
+            the big key",
+        )
+    }
+
+    pub fn try_new(client_key: &ClientKey) -> Result<Self, Error> {
+        let parameters = client_key.parameters;
+        if parameters.encryption_key_choice() != EncryptionKeyChoice::Big {
+            return Err(Error::new(String::from(
+                "The GLWE public key requires parameters encrypting with the big key",
+            )));
+        }
+
+        let polynomial_size = parameters.polynomial_size();
+        let mut key = GlweCiphertextOwned::new(
+            0u64,
+            parameters.glwe_dimension().to_glwe_size(),
+            polynomial_size,
+            parameters.ciphertext_modulus(),
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            encrypt_glwe_ciphertext(
+                &client_key.glwe_secret_key,
+                &mut key,
+                &PlaintextList::new(0u64, PlaintextCount(polynomial_size.0)),
+                parameters.glwe_noise_distribution(),
+                &mut engine.encryption_generator,
+            );
+        });
+
+        Ok(Self { key, parameters })
+    }
+
+    /// Encrypts the messages in GLWE ciphertexts with this public key and proves the encryption,
+    /// each ciphertext holding up to `polynomial_size` messages.
+    ///
+    /// The resulting list is verified against this key with
+    /// [`ProvenGlweCiphertextList::verify`].
+    #[cfg(feature = "zk-pok")]
+    pub fn encrypt_and_prove_slice(
+        &self,
+        messages: &[u64],
+        public_params: &GlwePkePublicParams,
+        metadata: &[u8],
+        load: ZkComputeLoad,
+        encryption_modulus: u64,
+    ) -> crate::Result<ProvenGlweCiphertextList> {
+        let message_modulus = self.parameters.message_modulus();
+        let carry_modulus = self.parameters.carry_modulus();
+        let plaintext_modulus = (message_modulus.0 * carry_modulus.0) as u64;
+        let delta = (1u64 << 63) / plaintext_modulus;
+        assert!(encryption_modulus <= plaintext_modulus);
+
+        let polynomial_size = self.key.polynomial_size();
+        let mut proved_glwes = Vec::with_capacity(messages.len().div_ceil(polynomial_size.0));
+        for message_chunk in messages.chunks(polynomial_size.0) {
+            let mut glwe = GlweCiphertextOwned::new(
+                0u64,
+                self.key.glwe_size(),
+                polynomial_size,
+                self.key.ciphertext_modulus(),
+            );
+
+            let proof = ShortintEngine::with_thread_local_mut(|engine| {
+                encrypt_and_prove_glwe_ciphertext_with_public_key(
+                    &self.key,
+                    &mut glwe,
+                    &message_chunk,
+                    delta,
+                    self.parameters.glwe_noise_distribution(),
+                    &mut engine.secret_generator,
+                    &mut engine.encryption_generator,
+                    &mut engine.random_generator,
+                    public_params,
+                    metadata,
+                    load,
+                )
+            })?;
+
+            proved_glwes.push((glwe, proof));
+        }
+
+        Ok(ProvenGlweCiphertextList {
+            proved_glwes,
+            message_count: messages.len(),
+            degree: Degree::new(encryption_modulus as usize - 1),
+            message_modulus,
+            carry_modulus,
+        })
+    }
+}
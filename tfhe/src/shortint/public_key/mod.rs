@@ -2,8 +2,10 @@
 
 pub mod compact;
 pub mod compressed;
+pub mod glwe;
 pub mod standard;
 
 pub use compact::{CompactPrivateKey, CompactPublicKey, CompressedCompactPublicKey};
 pub use compressed::CompressedPublicKey;
+pub use glwe::GlwePublicKey;
 pub use standard::PublicKey;
@@ -0,0 +1,318 @@
+//! Sanitization of ciphertexts, hiding the circuit that computed them.
+//!
+//! The noise of a ciphertext depends on the operations that produced it, so a party able to
+//! decrypt it may learn something about the circuit. The sanitization bootstraps the ciphertext
+//! with a 128 bits PBS, whose noise is small enough to be flooded by a statistically hiding noise
+//! while keeping the message intact, then switches the result back to the 64 bits large key.
+//!
+//! The PBS is deterministic: anyone holding the original ciphertext could recompute its output
+//! and link it to the sanitized ciphertext through its mask. Before the flooding, the mask is
+//! refreshed by adding a public key encryption of zero, making the mask of the sanitized
+//! ciphertext independent of the original one.
+//!
+//! See [`SanitizationParameters`] for the noise analysis.
+
+use crate::core_crypto::prelude::*;
+use crate::shortint::backward_compatibility::sanitization::SanitizationKeyVersions;
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::client_key::ClientKey;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{DynamicDistribution, SanitizationParameters};
+use crate::shortint::{
+    Ciphertext, ClassicPBSParameters, EncryptionKeyChoice, PBSOrder, PBSParameters, ServerKey,
+};
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(SanitizationKeyVersions)]
+pub struct SanitizationKey {
+    /// Bootstrapping key from the small key to the 128 bits sanitization key
+    pub bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+    /// Keyswitching key from the sanitization key to the large key, on 64 bits
+    pub key_switching_key: LweKeyswitchKeyOwned<u64>,
+    /// Public key of the 128 bits sanitization key, to refresh the mask of the PBS output
+    pub public_key: LweCompactPublicKeyOwned<u128>,
+    pub mask_refresh_noise_distribution: DynamicDistribution<u128>,
+    pub flooding_noise_distribution: DynamicDistribution<u128>,
+}
+
+impl ClientKey {
+    pub fn new_sanitization_key(&self, params: SanitizationParameters) -> SanitizationKey {
+        let cks_params: ClassicPBSParameters = match self.parameters.pbs_parameters().unwrap() {
+            PBSParameters::PBS(a) => a,
+            PBSParameters::MultiBitPBS(_) => {
+                panic!("Sanitization is currently not compatible with Multi Bit PBS")
+            }
+        };
+
+        assert_eq!(
+            cks_params.encryption_key_choice,
+            EncryptionKeyChoice::Big,
+            "Sanitization is only compatible with ciphertext in post PBS dimension"
+        );
+        assert!(
+            cks_params.ciphertext_modulus.is_native_modulus(),
+            "Sanitization is only compatible with the native ciphertext modulus"
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let sanitization_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+                params.glwe_dimension,
+                params.polynomial_size,
+                &mut engine.secret_generator,
+            );
+
+            let small_lwe_secret_key = LweSecretKey::from_container(
+                self.small_lwe_secret_key()
+                    .as_ref()
+                    .iter()
+                    .map(|&bit| u128::from(bit))
+                    .collect::<Vec<_>>(),
+            );
+
+            let standard_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+                &small_lwe_secret_key,
+                &sanitization_glwe_secret_key,
+                params.br_base_log,
+                params.br_level,
+                params.glwe_noise_distribution,
+                CiphertextModulus::new_native(),
+                &mut engine.encryption_generator,
+            );
+
+            let mut bootstrapping_key = Fourier128LweBootstrapKey::new(
+                standard_bootstrapping_key.input_lwe_dimension(),
+                standard_bootstrapping_key.glwe_size(),
+                standard_bootstrapping_key.polynomial_size(),
+                standard_bootstrapping_key.decomposition_base_log(),
+                standard_bootstrapping_key.decomposition_level_count(),
+            );
+            convert_standard_lwe_bootstrap_key_to_fourier_128(
+                &standard_bootstrapping_key,
+                &mut bootstrapping_key,
+            );
+
+            let public_key = allocate_and_generate_new_lwe_compact_public_key(
+                &sanitization_glwe_secret_key.as_lwe_secret_key(),
+                params.glwe_noise_distribution,
+                CiphertextModulus::new_native(),
+                &mut engine.encryption_generator,
+            );
+
+            // The coefficients of the key are binary, they are the same on 64 bits
+            let sanitization_lwe_secret_key = LweSecretKey::from_container(
+                sanitization_glwe_secret_key
+                    .as_lwe_secret_key()
+                    .as_ref()
+                    .iter()
+                    .map(|&bit| bit as u64)
+                    .collect::<Vec<_>>(),
+            );
+
+            let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+                &sanitization_lwe_secret_key,
+                &self.large_lwe_secret_key(),
+                params.ks_base_log,
+                params.ks_level,
+                self.parameters.glwe_noise_distribution(),
+                self.parameters.ciphertext_modulus(),
+                &mut engine.encryption_generator,
+            );
+
+            SanitizationKey {
+                bootstrapping_key,
+                key_switching_key,
+                public_key,
+                mask_refresh_noise_distribution: params.glwe_noise_distribution,
+                flooding_noise_distribution: params.flooding_noise_distribution,
+            }
+        })
+    }
+}
+
+impl SanitizationKey {
+    /// Sanitizes a ciphertext, the result encrypts the same message and carries under the same
+    /// key, with a noise that does not depend on the operations that produced `ct`.
+    ///
+    /// The result has a much larger noise than a PBS output, it is meant to be decrypted and
+    /// should not be used in further computations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sanitization_key =
+    ///     cks.new_sanitization_key(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    ///
+    /// let ct = sks.unchecked_add(&cks.encrypt(3), &cks.encrypt(2));
+    /// let sanitized = sanitization_key.sanitize(&sks, &ct);
+    ///
+    /// assert_eq!(cks.decrypt_message_and_carry(&sanitized), 5);
+    /// ```
+    pub fn sanitize(&self, server_key: &ServerKey, ct: &Ciphertext) -> Ciphertext {
+        assert_eq!(
+            ct.pbs_order,
+            PBSOrder::KeyswitchBootstrap,
+            "Sanitization is only compatible with ciphertext in post PBS dimension"
+        );
+        assert_eq!(
+            ct.ct.lwe_size(),
+            self.key_switching_key
+                .output_key_lwe_dimension()
+                .to_lwe_size(),
+            "The ciphertext is not encrypted under the key of the sanitization key"
+        );
+
+        let mut small_ct = LweCiphertext::new(
+            0u64,
+            server_key
+                .key_switching_key
+                .output_key_lwe_dimension()
+                .to_lwe_size(),
+            ct.ct.ciphertext_modulus(),
+        );
+        keyswitch_lwe_ciphertext(&server_key.key_switching_key, &ct.ct, &mut small_ct);
+
+        let lifted_ct = LweCiphertext::from_container(
+            small_ct
+                .as_ref()
+                .iter()
+                .map(|&x| u128::from(x) << u64::BITS)
+                .collect::<Vec<_>>(),
+            CiphertextModulus::new_native(),
+        );
+
+        // The padding bit is kept, the LUT covers the message and the carries
+        let full_modulus = ct.message_modulus.0 * ct.carry_modulus.0;
+        let accumulator = generate_programmable_bootstrap_glwe_lut(
+            self.bootstrapping_key.polynomial_size(),
+            self.bootstrapping_key.glwe_size(),
+            full_modulus,
+            CiphertextModulus::new_native(),
+            (1u128 << 127) / full_modulus as u128,
+            |x| x,
+        );
+
+        let mut bootstrapped_ct = LweCiphertext::new(
+            0u128,
+            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+            CiphertextModulus::new_native(),
+        );
+        programmable_bootstrap_f128_lwe_ciphertext(
+            &lifted_ct,
+            &mut bootstrapped_ct,
+            &accumulator,
+            &self.bootstrapping_key,
+        );
+
+        let mut encrypted_zero = LweCiphertext::new(
+            0u128,
+            self.public_key.lwe_dimension().to_lwe_size(),
+            CiphertextModulus::new_native(),
+        );
+        let flooding_noise: u128 = ShortintEngine::with_thread_local_mut(|engine| {
+            encrypt_lwe_ciphertext_with_compact_public_key(
+                &self.public_key,
+                &mut encrypted_zero,
+                Plaintext(0u128),
+                self.mask_refresh_noise_distribution,
+                self.mask_refresh_noise_distribution,
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+            );
+            engine
+                .encryption_generator
+                .random_noise_from_distribution(self.flooding_noise_distribution)
+        });
+        // The mask of the PBS output is a deterministic function of `ct`, the encryption of zero
+        // makes it uniformly random
+        lwe_ciphertext_add_assign(&mut bootstrapped_ct, &encrypted_zero);
+        let body = bootstrapped_ct.get_mut_body().data;
+        *body = body.wrapping_add(flooding_noise);
+
+        // Modulus switch with rounding from 2^128 to 2^64
+        let switched_ct = LweCiphertext::from_container(
+            bootstrapped_ct
+                .as_ref()
+                .iter()
+                .map(|&x| ((x >> (u64::BITS - 1)).wrapping_add(1) >> 1) as u64)
+                .collect::<Vec<_>>(),
+            ct.ct.ciphertext_modulus(),
+        );
+
+        let mut output_ct = LweCiphertext::new(
+            0u64,
+            self.key_switching_key
+                .output_key_lwe_dimension()
+                .to_lwe_size(),
+            ct.ct.ciphertext_modulus(),
+        );
+        keyswitch_lwe_ciphertext(&self.key_switching_key, &switched_ct, &mut output_ct);
+
+        // The flooding noise is far above the nominal one, force a refresh if the ciphertext is
+        // used in a computation anyway
+        Ciphertext::new(
+            output_ct,
+            ct.degree,
+            NoiseLevel::MAX,
+            ct.message_modulus,
+            ct.carry_modulus,
+            ct.pbs_order,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core_crypto::prelude::*;
+    use crate::shortint::gen_keys;
+    use crate::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+    #[test]
+    fn sanitize_message_and_carries() {
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+        let (cks, sks) = gen_keys(params);
+        let sanitization_key =
+            cks.new_sanitization_key(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+
+        let full_modulus = (params.message_modulus.0 * params.carry_modulus.0) as u64;
+        let delta = (1u64 << 63) / full_modulus;
+
+        for clear in 0..full_modulus {
+            let carries = sks.unchecked_scalar_mul(&cks.encrypt(clear / 4), 4);
+            let ct = sks.unchecked_add(&cks.encrypt(clear % 4), &carries);
+            let sanitized = sanitization_key.sanitize(&sks, &ct);
+
+            assert_eq!(sanitized.degree, ct.degree);
+            assert_eq!(cks.decrypt_message_and_carry(&sanitized), clear);
+
+            // The flooding noise is bounded by 2^57, far above the noise of a PBS
+            let plaintext = decrypt_lwe_ciphertext(&cks.large_lwe_secret_key(), &sanitized.ct);
+            let noise = plaintext.0.wrapping_sub(clear * delta) as i64;
+            assert!(noise.unsigned_abs() < 1 << 58);
+        }
+    }
+
+    #[test]
+    fn sanitize_refreshes_the_mask() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+        let sanitization_key =
+            cks.new_sanitization_key(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+
+        // Without the refresh, sanitizing a ciphertext twice would give the same mask, as the
+        // PBS is deterministic
+        let ct = cks.encrypt(3);
+        let first = sanitization_key.sanitize(&sks, &ct);
+        let second = sanitization_key.sanitize(&sks, &ct);
+
+        assert_ne!(first.ct.get_mask().as_ref(), second.ct.get_mask().as_ref());
+        assert_eq!(cks.decrypt_message_and_carry(&first), 3);
+        assert_eq!(cks.decrypt_message_and_carry(&second), 3);
+    }
+}
@@ -24,6 +24,7 @@ pub use bivariate_pbs::{
     BivariateLookupTableMutView, BivariateLookupTableOwned, BivariateLookupTableView,
 };
 pub use compressed::{CompressedServerKey, ShortintCompressedBootstrappingKey};
+pub(crate) use add::unchecked_add_assign;
 pub(crate) use scalar_mul::unchecked_scalar_mul_assign;
 use tfhe_versionable::{Unversionize, UnversionizeError, Versionize, VersionizeOwned};
 
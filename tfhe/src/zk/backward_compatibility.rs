@@ -0,0 +1,18 @@
+use tfhe_versionable::VersionsDispatch;
+
+use super::{CompactPkeBn254Crs, CompactPkeCrs, RangeConstraint};
+
+#[derive(VersionsDispatch)]
+pub enum CompactPkeCrsVersions {
+    V0(CompactPkeCrs),
+}
+
+#[derive(VersionsDispatch)]
+pub enum CompactPkeBn254CrsVersions {
+    V0(CompactPkeBn254Crs),
+}
+
+#[derive(VersionsDispatch)]
+pub enum RangeConstraintVersions {
+    V0(RangeConstraint),
+}
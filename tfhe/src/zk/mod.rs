@@ -0,0 +1,474 @@
+pub mod backward_compatibility;
+
+use crate::core_crypto::commons::math::random::BoundedDistribution;
+use crate::core_crypto::prelude::*;
+use crate::named::Named;
+use backward_compatibility::{
+    CompactPkeBn254CrsVersions, CompactPkeCrsVersions, RangeConstraintVersions,
+};
+use rand_core::RngCore;
+use std::cmp::Ordering;
+use std::collections::Bound;
+use std::fmt::Debug;
+use tfhe_versionable::Versionize;
+use tfhe_zk_pok::proofs::ceremony::UpdatableParams;
+use tfhe_zk_pok::proofs::pke::crs_gen;
+
+pub use tfhe_zk_pok::proofs::ComputeLoad as ZkComputeLoad;
+pub use tfhe_zk_pok::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Validate,
+};
+type Curve = tfhe_zk_pok::curve_api::Bls12_446;
+type Bn254 = tfhe_zk_pok::curve_api::Bn254;
+pub type CompactPkeProof = tfhe_zk_pok::proofs::pke::Proof<Curve>;
+pub type CompactPkePublicParams = tfhe_zk_pok::proofs::pke::PublicParams<Curve>;
+pub type CrsUpdateProof = tfhe_zk_pok::proofs::ceremony::UpdateProof<Curve>;
+pub type CompactPkeBn254Proof = tfhe_zk_pok::proofs::pke_v2::Proof<Bn254>;
+pub type CompactPkeBn254PublicParams = tfhe_zk_pok::proofs::pke_v2::PublicParams<Bn254>;
+pub type Bn254CrsUpdateProof = tfhe_zk_pok::proofs::ceremony::UpdateProof<Bn254>;
+pub type RangePublicParams = tfhe_zk_pok::proofs::range::PublicParams<Curve>;
+pub type RangeProof = tfhe_zk_pok::proofs::range::UpperBoundProof<Curve>;
+pub type RangeCommit = tfhe_zk_pok::proofs::range::PublicCommit<Curve>;
+pub type RangeLinkProof = tfhe_zk_pok::proofs::range::LinkProof<Curve>;
+pub type CompactPkeProofOpening = tfhe_zk_pok::proofs::range::BitsOpening<Curve>;
+pub type GlwePkeProof = tfhe_zk_pok::proofs::rlwe::Proof<Curve>;
+pub type GlwePkePublicParams = tfhe_zk_pok::proofs::rlwe::PublicParams<Curve>;
+pub type DecryptionProof = tfhe_zk_pok::proofs::decryption::Proof<Curve>;
+pub type DecryptionPublicParams = tfhe_zk_pok::proofs::decryption::PublicParams<Curve>;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum ZkVerificationOutCome {
+    /// The proof ands its entity were valid
+    Valid,
+    /// The proof ands its entity were not
+    Invalid,
+}
+
+impl ZkVerificationOutCome {
+    pub fn is_valid(self) -> bool {
+        self == Self::Valid
+    }
+
+    pub fn is_invalid(self) -> bool {
+        self == Self::Invalid
+    }
+}
+
+/// Public parameters used to prove and verify encryptions with a [`CompactPkeProof`].
+///
+/// The group elements of the parameters are compressed when serialized, and are checked to be on
+/// the curve and in the prime order subgroup when deserialized, so a CRS can be loaded from an
+/// untrusted source.
+#[derive(serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(CompactPkeCrsVersions)]
+pub struct CompactPkeCrs {
+    public_params: CompactPkePublicParams,
+}
+
+impl Named for CompactPkeCrs {
+    const NAME: &'static str = "zk::CompactPkeCrs";
+}
+
+impl CompactPkeCrs {
+    /// Prepare and check the CRS parameters.
+    ///
+    /// The output of this function can be used in [tfhe_zk_pok::proofs::pke::compute_crs_params].
+    pub fn prepare_crs_parameters<Scalar, NoiseDistribution>(
+        lwe_dim: LweDimension,
+        max_num_cleartext: usize,
+        noise_distribution: NoiseDistribution,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+        plaintext_modulus: Scalar,
+    ) -> crate::Result<(LweDimension, usize, Scalar, u64, Scalar)>
+    where
+        Scalar: UnsignedInteger + CastInto<u64> + Debug,
+        NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    {
+        // The bound for the crs has to be a power of two,
+        // it is [-b, b) (non-inclusive for the high bound)
+        // so we may have to give a bound that is bigger than
+        // what the distribution generates
+        let high_bound = match noise_distribution.high_bound() {
+            Bound::Included(high_b) => {
+                let high_b = high_b.wrapping_abs().into_unsigned();
+                if high_b.is_power_of_two() {
+                    high_b * Scalar::TWO
+                } else {
+                    high_b.next_power_of_two()
+                }
+            }
+            Bound::Excluded(high_b) => {
+                let high_b = high_b.wrapping_abs().into_unsigned();
+                if high_b.is_power_of_two() {
+                    high_b
+                } else {
+                    high_b.next_power_of_two()
+                }
+            }
+            Bound::Unbounded => {
+                return Err("requires bounded distribution".into());
+            }
+        };
+
+        let abs_low_bound = match noise_distribution.low_bound() {
+            Bound::Included(low_b) => {
+                let low_b = low_b.wrapping_abs().into_unsigned();
+                if low_b.is_power_of_two() {
+                    low_b * Scalar::TWO
+                } else {
+                    low_b.next_power_of_two()
+                }
+            }
+            Bound::Excluded(low_b) => {
+                let low_b = low_b.wrapping_abs().into_unsigned();
+                if low_b.is_power_of_two() {
+                    low_b
+                } else {
+                    low_b.next_power_of_two()
+                }
+            }
+            Bound::Unbounded => {
+                return Err("requires bounded distribution".into());
+            }
+        };
+
+        let noise_bound = abs_low_bound.max(high_bound);
+
+        if Scalar::BITS > 64 && noise_bound >= (Scalar::ONE << 64usize) {
+            return Err("noise bounds exceeds 64 bits modulus".into());
+        }
+
+        if Scalar::BITS > 64 && plaintext_modulus >= (Scalar::ONE << 64usize) {
+            return Err("Plaintext modulus exceeds 64 bits modulus".into());
+        }
+
+        let q = if ciphertext_modulus.is_native_modulus() {
+            match Scalar::BITS.cmp(&64) {
+                Ordering::Greater => Err(
+                    "Zero Knowledge proof do not support ciphertext modulus > 64 bits".to_string(),
+                ),
+                Ordering::Equal => Ok(0u64),
+                Ordering::Less => Ok(1u64 << Scalar::BITS),
+            }
+        } else {
+            let custom_modulus = ciphertext_modulus.get_custom_modulus();
+            if custom_modulus > (u64::MAX) as u128 {
+                Err("Zero Knowledge proof do not support ciphertext modulus > 64 bits".to_string())
+            } else {
+                Ok(custom_modulus as u64)
+            }
+        }?;
+
+        Ok((
+            lwe_dim,
+            max_num_cleartext,
+            noise_bound,
+            q,
+            plaintext_modulus,
+        ))
+    }
+
+    /// Generates a new zk CRS from the tfhe parameters.
+    pub fn new<Scalar, NoiseDistribution>(
+        lwe_dim: LweDimension,
+        max_num_cleartext: usize,
+        noise_distribution: NoiseDistribution,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+        plaintext_modulus: Scalar,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<Self>
+    where
+        Scalar: UnsignedInteger + CastInto<u64> + Debug,
+        NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    {
+        let (d, k, b, q, t) = Self::prepare_crs_parameters(
+            lwe_dim,
+            max_num_cleartext,
+            noise_distribution,
+            ciphertext_modulus,
+            plaintext_modulus,
+        )?;
+        let public_params = crs_gen(d.0, k, b.cast_into(), q, t.cast_into(), rng);
+
+        Ok(Self { public_params })
+    }
+
+    pub fn public_params(&self) -> &CompactPkePublicParams {
+        &self.public_params
+    }
+
+    /// Rerandomizes the CRS as a participant of a multi-party generation ceremony.
+    ///
+    /// Whoever generates a CRS with [`Self::new`] knows a trapdoor allowing to forge proofs. In a
+    /// ceremony, each participant updates the CRS published by the previous one and publishes the
+    /// result along with the returned proof. The final CRS has no known trapdoor as long as one of
+    /// the participants is honest.
+    pub fn update(&self, rng: &mut impl RngCore) -> (Self, CrsUpdateProof) {
+        let (public_params, proof) = self.public_params.update(rng);
+
+        (Self { public_params }, proof)
+    }
+
+    /// Verifies that `updated` was produced from this CRS with [`Self::update`].
+    pub fn verify_update(&self, updated: &Self, proof: &CrsUpdateProof) -> ZkVerificationOutCome {
+        match self
+            .public_params
+            .verify_update(&updated.public_params, proof)
+        {
+            Ok(()) => ZkVerificationOutCome::Valid,
+            Err(()) => ZkVerificationOutCome::Invalid,
+        }
+    }
+
+    /// Verifies the transcript of a ceremony starting from `initial`, where each entry is the CRS
+    /// published by a participant and its update proof.
+    ///
+    /// The CRS to use is the last one of the transcript.
+    pub fn verify_ceremony_transcript(
+        initial: &Self,
+        updates: &[(Self, CrsUpdateProof)],
+    ) -> ZkVerificationOutCome {
+        if !initial.public_params.is_well_formed() {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        let mut current = initial;
+        for (updated, proof) in updates {
+            if current.verify_update(updated, proof).is_invalid() {
+                return ZkVerificationOutCome::Invalid;
+            }
+            current = updated;
+        }
+
+        ZkVerificationOutCome::Valid
+    }
+}
+
+/// Public parameters used to prove and verify encryptions on the BN254 curve, whose pairings are
+/// available as precompiles on EVM chains so that proofs can be verified on-chain.
+///
+/// This curve only provides about 100 bits of security, [`CompactPkeCrs`] should be preferred
+/// when proofs are not verified on-chain. Proofs on this curve use the
+/// [`pke_v2`](tfhe_zk_pok::proofs::pke_v2) scheme, whose CRS is much smaller for the same
+/// parameters.
+#[derive(serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(CompactPkeBn254CrsVersions)]
+pub struct CompactPkeBn254Crs {
+    public_params: CompactPkeBn254PublicParams,
+}
+
+impl Named for CompactPkeBn254Crs {
+    const NAME: &'static str = "zk::CompactPkeBn254Crs";
+}
+
+impl CompactPkeBn254Crs {
+    /// Generates a new zk CRS from the tfhe parameters.
+    ///
+    /// The parameters are checked with [`CompactPkeCrs::prepare_crs_parameters`].
+    pub fn new<Scalar, NoiseDistribution>(
+        lwe_dim: LweDimension,
+        max_num_cleartext: usize,
+        noise_distribution: NoiseDistribution,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+        plaintext_modulus: Scalar,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<Self>
+    where
+        Scalar: UnsignedInteger + CastInto<u64> + Debug,
+        NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    {
+        let (d, k, b, q, t) = CompactPkeCrs::prepare_crs_parameters(
+            lwe_dim,
+            max_num_cleartext,
+            noise_distribution,
+            ciphertext_modulus,
+            plaintext_modulus,
+        )?;
+        let public_params =
+            tfhe_zk_pok::proofs::pke_v2::crs_gen(d.0, k, b.cast_into(), q, t.cast_into(), rng);
+
+        Ok(Self { public_params })
+    }
+
+    pub fn public_params(&self) -> &CompactPkeBn254PublicParams {
+        &self.public_params
+    }
+
+    /// Rerandomizes the CRS as a participant of a multi-party generation ceremony, see
+    /// [`CompactPkeCrs::update`].
+    pub fn update(&self, rng: &mut impl RngCore) -> (Self, Bn254CrsUpdateProof) {
+        let (public_params, proof) = self.public_params.update(rng);
+
+        (Self { public_params }, proof)
+    }
+
+    /// Verifies that `updated` was produced from this CRS with [`Self::update`].
+    pub fn verify_update(
+        &self,
+        updated: &Self,
+        proof: &Bn254CrsUpdateProof,
+    ) -> ZkVerificationOutCome {
+        match self
+            .public_params
+            .verify_update(&updated.public_params, proof)
+        {
+            Ok(()) => ZkVerificationOutCome::Valid,
+            Err(()) => ZkVerificationOutCome::Invalid,
+        }
+    }
+
+    /// Verifies the transcript of a ceremony starting from `initial`, see
+    /// [`CompactPkeCrs::verify_ceremony_transcript`].
+    pub fn verify_ceremony_transcript(
+        initial: &Self,
+        updates: &[(Self, Bn254CrsUpdateProof)],
+    ) -> ZkVerificationOutCome {
+        if !initial.public_params.is_well_formed() {
+            return ZkVerificationOutCome::Invalid;
+        }
+
+        let mut current = initial;
+        for (updated, proof) in updates {
+            if current.verify_update(updated, proof).is_invalid() {
+                return ZkVerificationOutCome::Invalid;
+            }
+            current = updated;
+        }
+
+        ZkVerificationOutCome::Valid
+    }
+}
+
+/// Constraint on the plaintext value of an encrypted input, proven with a [`RangeProof`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(RangeConstraintVersions)]
+pub enum RangeConstraint {
+    /// The value fits in the given number of bits
+    NumBits(usize),
+    /// The value is smaller or equal to the bound
+    UpperBound(u64),
+}
+
+impl RangeConstraint {
+    /// Returns the inclusive upper bound of the values satisfying the constraint.
+    pub fn upper_bound(self) -> u64 {
+        match self {
+            Self::NumBits(num_bits) if num_bits >= u64::BITS as usize => u64::MAX,
+            Self::NumBits(num_bits) => (1u64 << num_bits) - 1,
+            Self::UpperBound(bound) => bound,
+        }
+    }
+
+    pub fn is_satisfied_by(self, value: u64) -> bool {
+        value <= self.upper_bound()
+    }
+}
+
+/// Public parameters used to prove and verify [`RangeConstraint`]s.
+pub struct RangeCrs {
+    public_params: RangePublicParams,
+}
+
+impl RangeCrs {
+    /// Generates a new CRS allowing to prove constraints on values of at most `max_num_bits` bits.
+    pub fn new(max_num_bits: usize, rng: &mut impl RngCore) -> Self {
+        let public_params = tfhe_zk_pok::proofs::range::crs_gen(max_num_bits, rng);
+
+        Self { public_params }
+    }
+
+    pub fn public_params(&self) -> &RangePublicParams {
+        &self.public_params
+    }
+}
+
+/// Public parameters used to prove and verify GLWE encryptions with a [`GlwePkeProof`].
+pub struct GlwePkeCrs {
+    public_params: GlwePkePublicParams,
+}
+
+impl GlwePkeCrs {
+    /// Generates a new zk CRS for GLWE ciphertexts encrypted with the given parameters.
+    ///
+    /// Ciphertexts are proven to be encrypted with a GLWE public key. The binary randomness, the
+    /// noise and the messages of a proven ciphertext share the same bound, which is the largest of
+    /// the noise bound and the plaintext modulus.
+    pub fn new<Scalar, NoiseDistribution>(
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        noise_distribution: NoiseDistribution,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+        plaintext_modulus: Scalar,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<Self>
+    where
+        Scalar: UnsignedInteger + CastInto<u64> + Debug,
+        NoiseDistribution: BoundedDistribution<Scalar::Signed>,
+    {
+        let (_, _, noise_bound, q, t) = CompactPkeCrs::prepare_crs_parameters(
+            LweDimension(polynomial_size.0),
+            1,
+            noise_distribution,
+            ciphertext_modulus,
+            plaintext_modulus,
+        )?;
+        let noise_bound: u64 = noise_bound.cast_into();
+        let t: u64 = t.cast_into();
+        let b_i = noise_bound.max(t.next_power_of_two());
+
+        // The statement is mask_i = A_i * r + e1_i and body = B * r + e2 + delta * m for a public
+        // key (A, B), with glwe_dimension + 1 polynomial rows and glwe_dimension + 3 polynomial
+        // unknowns
+        let public_params = tfhe_zk_pok::proofs::rlwe::crs_gen(
+            polynomial_size.0,
+            glwe_dimension.0 + 1,
+            glwe_dimension.0 + 3,
+            b_i,
+            q,
+            rng,
+        );
+
+        Ok(Self { public_params })
+    }
+
+    pub fn public_params(&self) -> &GlwePkePublicParams {
+        &self.public_params
+    }
+}
+
+/// Public parameters used to prove and verify decryptions with a [`DecryptionProof`].
+pub struct DecryptionCrs {
+    public_params: DecryptionPublicParams,
+}
+
+impl DecryptionCrs {
+    /// Generates a new zk CRS to prove decryptions of ciphertexts of dimension `lwe_dim`, whose
+    /// messages are scaled by `delta`.
+    ///
+    /// A ciphertext decrypts correctly as long as its noise is in `[-delta / 2, delta / 2)`, which
+    /// is the noise bound of the CRS.
+    pub fn new(
+        lwe_dim: LweDimension,
+        ciphertext_modulus: CiphertextModulus<u64>,
+        delta: u64,
+        rng: &mut impl RngCore,
+    ) -> crate::Result<Self> {
+        if !ciphertext_modulus.is_native_modulus() {
+            return Err("Decryption proofs only support the native modulus".into());
+        }
+        if !lwe_dim.0.is_power_of_two() {
+            return Err("Decryption proofs require a power of two LweDimension".into());
+        }
+        if delta < 2 || !delta.is_power_of_two() {
+            return Err("Decryption proofs require delta to be a power of two".into());
+        }
+
+        let public_params = tfhe_zk_pok::proofs::decryption::crs_gen(lwe_dim.0, delta / 2, 0, rng);
+
+        Ok(Self { public_params })
+    }
+
+    pub fn public_params(&self) -> &DecryptionPublicParams {
+        &self.public_params
+    }
+}